@@ -45,6 +45,7 @@ pub mod weights;
 #[frame_support::pallet]
 pub mod pallet {
     pub use super::weights::WeightInfo;
+    use asset_info::AssetInfo;
     use core::convert::TryInto;
     use extrinsic_extra::GetExtrinsicExtra;
     use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
@@ -620,6 +621,15 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> AssetInfo for Module<T> {
+        type RegistryId = T::RegistryId;
+        type AssetId = T::AssetId;
+
+        fn asset_exists(registry_id: Self::RegistryId, asset_id: Self::AssetId) -> bool {
+            <Assets<T>>::contains_key(registry_id, asset_id)
+        }
+    }
+
     impl<T: Config> Module<T> {
         // -- rpc api functions --
 
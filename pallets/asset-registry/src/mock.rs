@@ -6,8 +6,10 @@ use frame_system::{self as system, EnsureOneOf, EnsureSigned};
 use runtime::{
     primitives::{FactStringLimit, NameLimit},
     AssetPropertyLimit, BulkDidLimit, BulkDidPropertyLimit, CatalogDidLimit, ClaimConsumerLimit,
-    ClaimIssuerLimit, ControllerLimit, GroupChainLimit, GroupMaxMembers, GroupMaxProposalLength,
-    GroupMaxProposals, LeaseAssetLimit, PropertyLimit, StatementLimit,
+    ClaimIssuerLimit, ControllerLimit, GroupChainLimit, GroupMaxBudgetTiers, GroupMaxJointGroups,
+    GroupMaxMembers, GroupMaxProposalLength, GroupMaxProposals, GroupMaxScheduleDeferral,
+    GroupMaxScheduledPerBlock, GroupMaximumScheduledWeight, LeaseAssetLimit, PropertyLimit,
+    StatementLimit,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -41,6 +43,7 @@ type Balance = u64;
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub const GroupProposalLifetime: u64 = 100;
 }
 
 impl system::Config for Test {
@@ -129,6 +132,13 @@ impl groups::Config for Test {
     type WeightInfo = ();
     type NameLimit = NameLimit;
     type GroupChainLimit = GroupChainLimit;
+    type MaxBudgetTiers = GroupMaxBudgetTiers;
+    type GroupsOriginJointApproved = groups::EnsureJointApproved<Test>;
+    type MaxJointGroups = GroupMaxJointGroups;
+    type ProposalLifetime = GroupProposalLifetime;
+    type MaxScheduledPerBlock = GroupMaxScheduledPerBlock;
+    type MaximumScheduledWeight = GroupMaximumScheduledWeight;
+    type MaxScheduleDeferral = GroupMaxScheduleDeferral;
 }
 
 impl identity::Config for Test {
@@ -9,7 +9,7 @@ use primitives::*;
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime amalgamator file (the `runtime/src/lib.rs`)
 sp_api::decl_runtime_apis! {
-    pub trait AuditsApi<AccountId,ProposalId,AuditId,ControlPointId,EvidenceId,ObservationId,BoundedStringName,BoundedStringUrl>
+    pub trait AuditsApi<AccountId,ProposalId,AuditId,ControlPointId,EvidenceId,ObservationId,FrameworkId,AssetRegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BlockNumber,ClaimId,Moment,Balance,AuditScheduleId,BoundedStringName,BoundedStringUrl>
     where
     AccountId: Codec,
     ProposalId: Codec,
@@ -17,6 +17,17 @@ sp_api::decl_runtime_apis! {
     ControlPointId: Codec,
     EvidenceId: Codec,
     ObservationId: Codec,
+    FrameworkId: Codec,
+    AssetRegistryId: Codec,
+    AssetId: Codec,
+    RegistryId: Codec,
+    DefinitionId: Codec,
+    ProcessId: Codec,
+    BlockNumber: Codec,
+    ClaimId: Codec,
+    Moment: Codec,
+    Balance: Codec,
+    AuditScheduleId: Codec,
     BoundedStringName: Codec + Into<Vec<u8>>,
     BoundedStringUrl: Codec + Into<Vec<u8>>,
      {
@@ -32,6 +43,12 @@ sp_api::decl_runtime_apis! {
 
         fn get_audit_by_proposal(proposal_id:ProposalId) -> Option<(AuditId,Audit<AccountId,ProposalId>)>;
 
+        fn get_audit_fee(audit_id:AuditId) -> Option<AuditFee<Balance>>;
+
+        fn get_audit_subjects(audit_id:AuditId) -> Vec<AuditSubject<AssetRegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BoundedStringName>>;
+
+        fn get_audits_by_subject(subject:AuditSubject<AssetRegistryId,AssetId,RegistryId,DefinitionId,ProcessId,Vec<u8>>) -> Vec<(AuditId,Audit<AccountId,ProposalId>)>;
+
         fn get_observation(audit_id:AuditId,control_point_id:ControlPointId,observation_id:ObservationId)->Option<(Observation<ProposalId>,Vec<(EvidenceId,Evidence<ProposalId,BoundedStringName, BoundedStringUrl>)>)>;
 
         fn get_observation_by_proposal(proposal_id: ProposalId)->Option<(ObservationId,Observation<ProposalId>,Vec<(EvidenceId,Evidence<ProposalId,BoundedStringName, BoundedStringUrl>)>)>;
@@ -47,5 +64,33 @@ sp_api::decl_runtime_apis! {
         fn get_evidence_links_by_evidence(evidence_id:EvidenceId)->Vec<ObservationId>;
 
         fn get_evidence_links_by_observation(observation_id:ObservationId)->Vec<EvidenceId>;
+
+        fn get_framework(framework_id:FrameworkId,version:Option<u32>)->Option<(AuditFramework<AccountId,BoundedStringName>,u32,Vec<(ControlPointId,ControlPoint<ControlPointId,BoundedStringName>)>)>;
+
+        fn get_control_point_coverage(audit_id:AuditId)->Option<(FrameworkId,u32,Vec<(ControlPointId,ControlPointCoverage)>)>;
+
+        fn get_corrective_actions(audit_id:AuditId)->Vec<(ControlPointId,ObservationId,CorrectiveAction<AccountId,ProposalId,BlockNumber,EvidenceId>)>;
+
+        fn get_open_corrective_actions_by_creator(account: AccountId)->Vec<(AuditId,ControlPointId,ObservationId,CorrectiveAction<AccountId,ProposalId,BlockNumber,EvidenceId>)>;
+
+        fn get_open_corrective_actions_by_auditing_org(account: AccountId)->Vec<(AuditId,ControlPointId,ObservationId,CorrectiveAction<AccountId,ProposalId,BlockNumber,EvidenceId>)>;
+
+        fn get_certificates_by_did(did:Did)->Vec<(AuditId,Certificate<ClaimId,Moment,AuditId,BoundedStringName>)>;
+
+        fn get_audit_report(audit_id:AuditId)->Option<AuditReport<AccountId,ProposalId,AuditId,ControlPointId,ObservationId,EvidenceId,FrameworkId,BoundedStringName,BoundedStringUrl>>;
+
+        fn get_upcoming_audits_by_creator(account: AccountId)->Vec<(AuditScheduleId,AuditSchedule<AccountId,ProposalId,FrameworkId,BlockNumber,AuditId,AuditSubject<AssetRegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BoundedStringName>>)>;
+
+        fn get_upcoming_audits_by_auditing_org(account: AccountId)->Vec<(AuditScheduleId,AuditSchedule<AccountId,ProposalId,FrameworkId,BlockNumber,AuditId,AuditSubject<AssetRegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BoundedStringName>>)>;
+
+        fn get_missed_audits(schedule_id:AuditScheduleId)->Vec<(AuditId,BlockNumber)>;
+
+        fn get_audit_trail(audit_id:AuditId)->Vec<AuditTrailEntry<AccountId,ProposalId,BlockNumber,ControlPointId,ObservationId,BoundedStringName>>;
+
+        fn get_note_key(account:AccountId)->Option<[u8;32]>;
+
+        fn get_confidential_notes(audit_id:AuditId)->Vec<([u8;32],ConfidentialNote<AccountId,ProposalId,ControlPointId,ObservationId>)>;
+
+        fn get_note_envelopes(audit_id:AuditId,ciphertext_hash:[u8;32])->Vec<(AccountId,KeyEnvelope)>;
     }
 }
@@ -7,7 +7,7 @@ use super::*;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
     dispatch::Vec,
-    traits::{Currency, EnsureOrigin, Get, UnfilteredDispatchable},
+    traits::{Currency, EnsureOrigin, Get, Hooks, UnfilteredDispatchable},
 };
 use frame_system::{self, RawOrigin as SystemOrigin};
 use primitives::*;
@@ -18,8 +18,6 @@ use crate::Call;
 #[allow(unused)]
 use crate::Pallet as AuditsPallet;
 
-type BalanceOf<T> =
-    <<T as groups::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 //we only use one group for all parties due to a limitation of `successful_origin()`
 fn create_group<T: Config>() -> Result<T::AccountId, &'static str> {
     let account_id: T::AccountId = whitelisted_caller();
@@ -44,13 +42,91 @@ fn create_group<T: Config>() -> Result<T::AccountId, &'static str> {
 fn audit_create<T: Config>() -> Result<T::AccountId, &'static str> {
     let group_account = create_group::<T>()?;
     let origin = T::GroupsOriginByGroupThreshold::successful_origin();
-    let call = Call::<T>::create_audit(group_account.clone(), 1u32);
+    let call = Call::<T>::create_audit(
+        group_account.clone(),
+        None,
+        sites::<T>(1),
+        Some(fee::<T>(1)),
+        None,
+        1u32,
+    );
     call.dispatch_bypass_filter(origin)?;
     let audit_id = T::AuditId::unique_saturated_from(1u32);
     assert!(<Audits<T>>::contains_key(audit_id));
     Ok(group_account)
 }
 
+fn audit_schedule_create<T: Config>(
+) -> Result<(T::AuditScheduleId, <T as frame_system::Config>::Origin), &'static str> {
+    let group_account = create_group::<T>()?;
+    let origin = T::GroupsOriginByGroupThreshold::successful_origin();
+    let first_due = <frame_system::Module<T>>::block_number() + 100u32.into();
+    let call = Call::<T>::create_audit_schedule(
+        group_account,
+        None,
+        sites::<T>(1),
+        100u32.into(),
+        10u32.into(),
+        first_due,
+    );
+    call.dispatch_bypass_filter(origin.clone())?;
+    let schedule_id = T::AuditScheduleId::unique_saturated_from(1u32);
+    assert!(<AuditSchedules<T>>::contains_key(schedule_id));
+    Ok((schedule_id, origin))
+}
+
+//a fee with a milestone at each observation up to `milestones`
+fn fee<T: Config>(milestones: u32) -> (BalanceOf<T>, Vec<(u32, BalanceOf<T>)>) {
+    (
+        1_000u32.into(),
+        (1..=milestones).map(|i| (i, 10u32.into())).collect(),
+    )
+}
+
+fn sites<T: Config>(
+    count: u32,
+) -> Vec<
+    AuditSubject<
+        T::AssetRegistryId,
+        T::AssetId,
+        T::RegistryId,
+        T::DefinitionId,
+        T::ProcessId,
+        Vec<u8>,
+    >,
+> {
+    (0..count)
+        .map(|i| {
+            let mut site = vec![42u8; <T as Config>::NameLimit::get() as usize];
+            site[0] = i as u8;
+            AuditSubject::Site(site)
+        })
+        .collect()
+}
+
+fn control_points<T: Config>(
+    count: u32,
+) -> Vec<(T::ControlPointId, ControlPoint<T::ControlPointId, Vec<u8>>)> {
+    (0..count)
+        .map(|i| {
+            (
+                T::ControlPointId::unique_saturated_from(i + 1),
+                ControlPoint {
+                    code: vec![42u8; <T as Config>::NameLimit::get() as usize],
+                    title: vec![42u8; <T as Config>::NameLimit::get() as usize],
+                    description_hash: [42u8; 32],
+                    severity: ControlPointSeverity::High,
+                    parent: if i == 0 {
+                        None
+                    } else {
+                        Some(T::ControlPointId::unique_saturated_from(1u32))
+                    },
+                },
+            )
+        })
+        .collect()
+}
+
 fn audit_create_and_assign<T: Config>() -> Result<
     (
         T::AccountId,
@@ -71,10 +147,12 @@ fn audit_create_and_assign<T: Config>() -> Result<
 
 benchmarks! {
     create_audit {
+        let a in 1 .. <T as Config>::SubjectLimit::get();
+        let b in 0 .. <T as Config>::FeeMilestoneLimit::get();
         let audit_creator= create_group::<T>()?;
         let auditing_org:T::AccountId=account("auditing_org", 1, 1);
         let origin=T::GroupsOriginByGroupThreshold::successful_origin();
-        let call = Call::<T>::create_audit(auditing_org.clone(),1u32);
+        let call = Call::<T>::create_audit(auditing_org.clone(),None,sites::<T>(a),Some(fee::<T>(b)),None,1u32);
 
     }: { call.dispatch_bypass_filter(origin)? }
 
@@ -112,7 +190,7 @@ benchmarks! {
 
     link_audit {
         let (auditors,parent_audit_id,origin) = audit_create_and_assign::<T>()?;
-        let call = Call::<T>::create_audit(auditors.clone(),1u32);
+        let call = Call::<T>::create_audit(auditors.clone(),None,sites::<T>(1),None,None,1u32);
         call.dispatch_bypass_filter(origin.clone())?;
         let child_audit_id = T::AuditId::unique_saturated_from(2u32);
         assert!(<Audits<T>>::contains_key(child_audit_id));
@@ -129,7 +207,7 @@ benchmarks! {
 
     unlink_audit {
         let (auditors,parent_audit_id,origin) = audit_create_and_assign::<T>()?;
-        let call = Call::<T>::create_audit(auditors.clone(),1u32);
+        let call = Call::<T>::create_audit(auditors.clone(),None,sites::<T>(1),None,None,1u32);
         call.dispatch_bypass_filter(origin.clone())?;
         let child_audit_id = T::AuditId::unique_saturated_from(2u32);
         assert!(<Audits<T>>::contains_key(child_audit_id));
@@ -248,16 +326,15 @@ benchmarks! {
         let a in 1 .. <T as Config>::NameLimit::get(); //name
         let b in 1 .. <T as Config>::NameLimit::get(); //content_type
         let c in 1 .. <T as Config>::UrlLimit::get(); //url
-        let d in 1 .. <T as Config>::NameLimit::get(); //hash
 
         let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
 
         let name=vec![42u8;a as usize];
         let content_type=vec![42u8;b as usize];
         let url=Some(vec![42u8;c as usize]);
-        let hash=vec![42u8;d as usize];
+        let hash=[42u8;32];
 
-        let call = Call::<T>::create_evidence(audit_id, name, content_type, url, hash);
+        let call = Call::<T>::create_evidence(audit_id, name, content_type, url, hash, <T as Config>::EvidenceSizeLimit::get());
     }: { call.dispatch_bypass_filter(origin)? }
 
     verify {
@@ -268,7 +345,7 @@ benchmarks! {
         assert_eq!(stored_evidence.name.len() , a as usize );
         assert_eq!(stored_evidence.content_type.len(), b as usize);
         assert_eq!(stored_evidence.url.unwrap().len(), c as usize);
-        assert_eq!(stored_evidence.hash.len(), d as usize);
+        assert_eq!(stored_evidence.hash, hash);
     }
 
     link_evidence {
@@ -278,8 +355,8 @@ benchmarks! {
         let name=vec![42u8;<T as Config>::NameLimit::get() as usize];
         let content_type=vec![42u8;<T as Config>::NameLimit::get() as usize];
         let url=Some(vec![42u8;<T as Config>::NameLimit::get() as usize]);
-        let hash=vec![42u8;<T as Config>::NameLimit::get() as usize];
-        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash);
+        let hash=[42u8;32];
+        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash,<T as Config>::EvidenceSizeLimit::get());
         call.dispatch_bypass_filter(origin.clone())? ;
         let evidence_id=T::EvidenceId::unique_saturated_from(1u32);
         let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::Compliant),Some([42u8;32]));
@@ -301,8 +378,8 @@ benchmarks! {
         let name=vec![42u8;<T as Config>::NameLimit::get() as usize];
         let content_type=vec![42u8;<T as Config>::NameLimit::get() as usize];
         let url=Some(vec![42u8;<T as Config>::NameLimit::get() as usize]);
-        let hash=vec![42u8;<T as Config>::NameLimit::get() as usize];
-        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash);
+        let hash=[42u8;32];
+        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash,<T as Config>::EvidenceSizeLimit::get());
         call.dispatch_bypass_filter(origin.clone())?;
         let evidence_id=T::EvidenceId::unique_saturated_from(1u32);
         let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::Compliant),Some([42u8;32]));
@@ -330,8 +407,8 @@ benchmarks! {
         let name=vec![42u8;<T as Config>::NameLimit::get() as usize];
         let content_type=vec![42u8;<T as Config>::NameLimit::get() as usize];
         let url=Some(vec![42u8;<T as Config>::NameLimit::get() as usize]);
-        let hash=vec![42u8;<T as Config>::NameLimit::get() as usize];
-        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash);
+        let hash=[42u8;32];
+        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash,<T as Config>::EvidenceSizeLimit::get());
         call.dispatch_bypass_filter(origin.clone())?;
         let evidence_id=T::EvidenceId::unique_saturated_from(1u32);
 
@@ -355,6 +432,287 @@ benchmarks! {
         }
         assert!(!<Evidences<T>>::contains_key(audit_id,evidence_id));
     }
+
+    create_framework {
+        let a in 1 .. <T as Config>::ControlPointLimit::get();
+        let _ = create_group::<T>()?;
+        let origin=T::GroupsOriginByGroupThreshold::successful_origin();
+        let name=vec![42u8;<T as Config>::NameLimit::get() as usize];
+        let call = Call::<T>::create_framework(name,control_points::<T>(a));
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let framework_id=T::FrameworkId::unique_saturated_from(1u32);
+        let framework=<Frameworks<T>>::get(framework_id);
+        assert!(framework.is_some());
+        assert_eq!(framework.unwrap().latest_version,1);
+        assert!(<FrameworkControlPoints<T>>::contains_key((framework_id,1),T::ControlPointId::unique_saturated_from(a)));
+    }
+
+    publish_framework_version {
+        let a in 1 .. <T as Config>::ControlPointLimit::get();
+        let _ = create_group::<T>()?;
+        let origin=T::GroupsOriginByGroupThreshold::successful_origin();
+        let name=vec![42u8;<T as Config>::NameLimit::get() as usize];
+        let call = Call::<T>::create_framework(name,control_points::<T>(1));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let framework_id=T::FrameworkId::unique_saturated_from(1u32);
+        let call = Call::<T>::publish_framework_version(framework_id,control_points::<T>(a));
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let framework=<Frameworks<T>>::get(framework_id);
+        assert!(framework.is_some());
+        assert_eq!(framework.unwrap().latest_version,2);
+        assert!(<FrameworkControlPoints<T>>::contains_key((framework_id,2),T::ControlPointId::unique_saturated_from(a)));
+    }
+
+    create_corrective_action {
+        let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let control_point_id=T::ControlPointId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::NonCompliant),Some([42u8;32]));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let observation_id=T::ObservationId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_corrective_action(audit_id,control_point_id,observation_id,[42u8;32],T::BlockNumber::max_value(),auditors);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let corrective_action=<CorrectiveActions<T>>::get(audit_id,(control_point_id,observation_id));
+        assert!(corrective_action.is_some());
+        assert_eq!(corrective_action.unwrap().status,CorrectiveActionStatus::Open);
+    }
+
+    close_corrective_action {
+        let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let control_point_id=T::ControlPointId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::NonCompliant),Some([42u8;32]));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let observation_id=T::ObservationId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_corrective_action(audit_id,control_point_id,observation_id,[42u8;32],T::BlockNumber::max_value(),auditors);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let name=vec![42u8;<T as Config>::NameLimit::get() as usize];
+        let content_type=vec![42u8;<T as Config>::NameLimit::get() as usize];
+        let url=Some(vec![42u8;<T as Config>::NameLimit::get() as usize]);
+        let hash=[42u8;32];
+        let call = Call::<T>::create_evidence(audit_id, name ,content_type,url,hash,<T as Config>::EvidenceSizeLimit::get());
+        call.dispatch_bypass_filter(origin.clone())? ;
+        let evidence_id=T::EvidenceId::unique_saturated_from(1u32);
+        let call = Call::<T>::link_evidence(audit_id,control_point_id,observation_id,evidence_id);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::close_corrective_action(audit_id,control_point_id,observation_id,evidence_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let corrective_action=<CorrectiveActions<T>>::get(audit_id,(control_point_id,observation_id));
+        assert!(corrective_action.is_some());
+        assert_eq!(corrective_action.unwrap().closing_evidence,Some(evidence_id));
+        assert_eq!(<OpenFindings<T>>::get(audit_id),0);
+    }
+
+    dispute_fee {
+        let (_,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let call = Call::<T>::dispute_fee(audit_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert_eq!(<AuditFees<T>>::get(audit_id).unwrap().status,AuditFeeStatus::Disputed);
+    }
+
+    resolve_fee_dispute {
+        let (_,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let call = Call::<T>::dispute_fee(audit_id);
+        call.dispatch_bypass_filter(origin)?;
+        let origin = T::FeeDisputeOrigin::successful_origin();
+        let call = Call::<T>::resolve_fee_dispute(audit_id,500u32.into());
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let fee=<AuditFees<T>>::get(audit_id).unwrap();
+        assert_eq!(fee.status,AuditFeeStatus::Resolved);
+        assert_eq!(fee.released,500u32.into());
+    }
+
+    create_audit_schedule {
+        let a in 1 .. <T as Config>::SubjectLimit::get();
+
+        let group_account = create_group::<T>()?;
+        let origin = T::GroupsOriginByGroupThreshold::successful_origin();
+        let first_due = <frame_system::Module<T>>::block_number() + 100u32.into();
+        let call = Call::<T>::create_audit_schedule(group_account,None,sites::<T>(a),100u32.into(),10u32.into(),first_due);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let schedule_id = T::AuditScheduleId::unique_saturated_from(1u32);
+        let schedule = <AuditSchedules<T>>::get(schedule_id).unwrap();
+        assert_eq!(schedule.subjects.len(),a as usize);
+    }
+
+    pause_audit_schedule {
+        let (schedule_id,origin) = audit_schedule_create::<T>()?;
+        let call = Call::<T>::pause_audit_schedule(schedule_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert_eq!(<AuditSchedules<T>>::get(schedule_id).unwrap().status,AuditScheduleStatus::Paused);
+    }
+
+    resume_audit_schedule {
+        let (schedule_id,origin) = audit_schedule_create::<T>()?;
+        let call = Call::<T>::pause_audit_schedule(schedule_id);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::resume_audit_schedule(schedule_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert_eq!(<AuditSchedules<T>>::get(schedule_id).unwrap().status,AuditScheduleStatus::Active);
+    }
+
+    cancel_audit_schedule {
+        let (schedule_id,origin) = audit_schedule_create::<T>()?;
+        let call = Call::<T>::cancel_audit_schedule(schedule_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert_eq!(<AuditSchedules<T>>::get(schedule_id).unwrap().status,AuditScheduleStatus::Cancelled);
+    }
+
+    reopen_audit {
+        let a in 1 .. <T as Config>::NameLimit::get();
+
+        let (_,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let control_point_id=T::ControlPointId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::Compliant),Some([42u8;32]));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::complete_audit(audit_id);
+        call.dispatch_bypass_filter(origin.clone())?;
+        //the other party has already requested, so this call reopens the audit
+        let other_party: T::AccountId = account("other_party", 0, 0);
+        <AuditReopenRequests<T>>::insert(audit_id, other_party);
+        let call = Call::<T>::reopen_audit(audit_id,vec![42u8;a as usize]);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert_eq!(<Audits<T>>::get(audit_id).unwrap().status,AuditStatus::InProgress);
+        assert!(<AuditReopenRequests<T>>::get(audit_id).is_none());
+    }
+
+    withdraw_reopen_request {
+        let (_,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let control_point_id=T::ControlPointId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::Compliant),Some([42u8;32]));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::complete_audit(audit_id);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::reopen_audit(audit_id,vec![42u8;<T as Config>::NameLimit::get() as usize]);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::withdraw_reopen_request(audit_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert!(<AuditReopenRequests<T>>::get(audit_id).is_none());
+        assert_eq!(<Audits<T>>::get(audit_id).unwrap().status,AuditStatus::Completed);
+    }
+
+    supersede_observation {
+        let a in 1 .. <T as Config>::NameLimit::get();
+
+        let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let control_point_id=T::ControlPointId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::NonCompliant),Some([42u8;32]));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let observation_id=T::ObservationId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_corrective_action(audit_id,control_point_id,observation_id,[42u8;32],T::BlockNumber::max_value(),auditors);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::supersede_observation(audit_id,control_point_id,observation_id,Some(Compliance::NonCompliant),Some([42u8;32]),vec![42u8;a as usize]);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        let new_observation_id=T::ObservationId::unique_saturated_from(2u32);
+        assert_eq!(<SupersededObservations<T>>::get(observation_id),Some(new_observation_id));
+        assert!(<CorrectiveActions<T>>::get(audit_id,(control_point_id,new_observation_id)).is_some());
+    }
+
+    register_note_key {
+        let caller: T::AccountId = whitelisted_caller();
+        let origin: <T as frame_system::Config>::Origin = SystemOrigin::Signed(caller.clone()).into();
+        let call = Call::<T>::register_note_key([42u8;32]);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert_eq!(<NoteKeys<T>>::get(caller),Some([42u8;32]));
+    }
+
+    create_confidential_note {
+        let a in 1 .. <T as Config>::NoteRecipientLimit::get();
+
+        let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
+        let control_point_id=T::ControlPointId::unique_saturated_from(1u32);
+        let call = Call::<T>::create_observation(audit_id,control_point_id,Some(Compliance::Compliant),Some([42u8;32]));
+        call.dispatch_bypass_filter(origin.clone())?;
+        let observation_id=T::ObservationId::unique_saturated_from(1u32);
+        //every recipient is the one group that is all parties
+        <NoteKeys<T>>::insert(&auditors,[42u8;32]);
+        let envelopes=(0..a).map(|_| {
+            (auditors.clone(),KeyEnvelope{recipient_key:[42u8;32],ephemeral_key:[42u8;32],encrypted_key:[42u8;48]})
+        }).collect();
+        let call = Call::<T>::create_confidential_note(audit_id,[42u8;32],Some((control_point_id,observation_id)),envelopes);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert!(<ConfidentialNotes<T>>::get(audit_id,[42u8;32]).is_some());
+    }
+
+    grant_note_access {
+        let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
+        <NoteKeys<T>>::insert(&auditors,[42u8;32]);
+        let envelope=KeyEnvelope{recipient_key:[42u8;32],ephemeral_key:[42u8;32],encrypted_key:[42u8;48]};
+        let call = Call::<T>::create_confidential_note(audit_id,[42u8;32],None,vec![]);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::grant_note_access(audit_id,[42u8;32],auditors.clone(),envelope);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert!(<NoteEnvelopes<T>>::get((audit_id,[42u8;32]),auditors).is_some());
+    }
+
+    revoke_note_access {
+        let (auditors,audit_id,origin) = audit_create_and_assign::<T>()?;
+        <NoteKeys<T>>::insert(&auditors,[42u8;32]);
+        let envelope=KeyEnvelope{recipient_key:[42u8;32],ephemeral_key:[42u8;32],encrypted_key:[42u8;48]};
+        let call = Call::<T>::create_confidential_note(audit_id,[42u8;32],None,vec![(auditors.clone(),envelope)]);
+        call.dispatch_bypass_filter(origin.clone())?;
+        let call = Call::<T>::revoke_note_access(audit_id,[42u8;32],auditors.clone());
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert!(<NoteEnvelopes<T>>::get((audit_id,[42u8;32]),auditors).is_none());
+    }
+
+    on_initialize {
+        let a in 1 .. <T as Config>::MaxScheduledPerBlock::get();
+        let b in 1 .. <T as Config>::MaxScheduledPerBlock::get();
+
+        let group_account = create_group::<T>()?;
+        let origin = T::GroupsOriginByGroupThreshold::successful_origin();
+        let lead_time:T::BlockNumber = 10u32.into();
+        let due = <frame_system::Module<T>>::block_number() + 20u32.into();
+        //b audits are requested before the benchmarked block and missed at it
+        for _ in 0..b {
+            let call = Call::<T>::create_audit_schedule(group_account.clone(),None,sites::<T>(<T as Config>::SubjectLimit::get()),100u32.into(),lead_time,due);
+            call.dispatch_bypass_filter(origin.clone())?;
+        }
+        //a audits are requested at the benchmarked block
+        for _ in 0..a {
+            let call = Call::<T>::create_audit_schedule(group_account.clone(),None,sites::<T>(<T as Config>::SubjectLimit::get()),100u32.into(),lead_time,due + lead_time);
+            call.dispatch_bypass_filter(origin.clone())?;
+        }
+        AuditsPallet::<T>::on_initialize(due - lead_time);
+    }: { AuditsPallet::<T>::on_initialize(due); }
+
+    verify {
+        assert_eq!(<MissedAudits<T>>::iter().count(),b as usize);
+        assert_eq!(<ScheduledAuditsDue<T>>::iter_prefix(due + lead_time).count(),a as usize);
+    }
 }
 
 impl_benchmark_test_suite!(AuditsPallet, crate::mock::new_test_ext(), crate::mock::Test,);
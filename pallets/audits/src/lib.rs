@@ -12,22 +12,61 @@
 //!
 //! #### For the Audit Creator
 //! * `create_audit` - Creates a new audit. Assigning an Auditing Organization is done as part of creation. Auditing Organization cannot be changed.
+//!   An audit may reference a version of an Audit Framework, in which case observations can only be made against control points in that version.
+//!   The scope of an audit is one or more subjects: DIDs, assets, provenance processes or free-text site references.
+//!   A joint proposal co-signed by the Audit Creator and the Auditing Organization may also create an audit.
+//!   An audit may carry a fee for the Auditing Organization, optionally released in part as observations are made.
 //! * `delete_audit` - Delete an audit. Audits can only be deleted before they have been accepted by an Auditing Organization
+//! * `dispute_fee` - The Audit Creator or the Auditing Organization freezes the fee held in escrow until the council resolves the dispute
+//! * `create_audit_schedule` - Schedules recurring audits with an Auditing Organization. Each audit is requested automatically a lead time before it is due.
+//!                             The cadence must be at least `MinCadence` and an Audit Creator may have at most `AuditScheduleLimit` schedules that are not cancelled.
+//!                             At most `MaxScheduledPerBlock` audits are requested or checked in a block, later ones in the next block with room.
+//!   Audits that are not completed by their due date are flagged as missed.
+//! * `pause_audit_schedule` - Stop requesting audits. Audits that fall due while paused are skipped.
+//! * `resume_audit_schedule` - Resume requesting audits from the next due date
+//! * `cancel_audit_schedule` - Cancel a schedule. Audits already requested are unaffected.
+//! * `create_corrective_action` - Respond to a non-compliant observation with a corrective action plan
 //!
 //! #### For the Auditing Organization
-//! * `accept_audit` - The Auditing Organization accepts an Audit
+//! * `accept_audit` - The Auditing Organization accepts an Audit. The fee, if any, is moved from the Audit Creator into an escrow account of the pallet for the audit.
 //! * `assign_auditors` - The Auditing Organization assigns and auditor or auditors. Use a group or subgroup when assigning multiple auditors.
-//! * `reject_audit` - The Auditing Organization rejects an Audit
-//! * `complete_audit` - The Auditing Organization completes an Audit
+//! * `reject_audit` - The Auditing Organization rejects an Audit. A fee is refunded to the Audit Creator.
+//! * `complete_audit` - The Auditing Organization completes an Audit. If corrective actions are still open the audit is completed with open findings.
+//!                      Corrective actions and evidence for the open findings can still be added until the last one is closed.
+//!   Any fee still held in escrow is released to the Auditing Organization. A fee that cannot be paid out does not stop completion, it stays in escrow and can be disputed.
 //! * `create_observation` - An Auditor creates an observation
+//! * `issue_certificate` - The Auditing Organization certifies a DID in scope of a completed audit. The certificate is an attested claim against the DID in the Identity module.
+//! * `revoke_certificate` - Revoke a certificate. Only the issuer may revoke it, optionally citing a later audit of the DID it conducted that failed.
+//!
+//! * `reopen_audit` - Reopen a completed audit to correct it. Either the creator or the auditing org requests it with a reason and the other party approves it.
+//! * `withdraw_reopen_request` - Withdraw a request to reopen a completed audit that the other party has not approved
 //!
 //! #### For the Auditors
-//! * `create_evidence` - An Auditor creates an item of evidence
+//! * `create_evidence` - An Auditor creates an item of evidence, declaring the blake2-256 hash and size of its content.
+//!   The content can be uploaded to a node's evidence store, from which other nodes fetch it by hash.
 //! * `link_evidence` - An Auditor links evidence to an observation
 //! * `unlink_evidence` - An Auditor removes a link between evidence and an observation
 //! * `delete_evidence` - An Auditor removes a link between evidence and an observation
 //! * `link_audit` - An Auditor links to another audit
 //! * `unlink_audit` - An Auditor removes a link to another audit
+//! * `close_corrective_action` - An Auditor verifies closure of a corrective action with evidence linked to the observation.
+//!   Closing the last open finding of an audit completed with open findings completes it.
+//!
+//! #### For the Audit Creator, the Auditing Organization and the Auditors
+//! * `create_confidential_note` - Attach a note encrypted client-side with a symmetric key, declaring the blake2-256 hash of its ciphertext.
+//!   The key is shared in envelopes encrypted to the X25519 keys registered by members of the audit creator, the auditing org or the auditors.
+//! * `grant_note_access` - The author of a note shares its key with another member
+//! * `revoke_note_access` - The author of a note removes the envelope of a member
+//!
+//! #### For Members of a Party to an Audit
+//! * `register_note_key` - Register the X25519 key that confidential notes are shared with
+//!
+//! #### For the Council
+//! * `resolve_fee_dispute` - Splits a disputed fee between the Auditing Organization and the Audit Creator
+//!
+//! #### For Standards Bodies
+//! * `create_framework` - Creates an Audit Framework and publishes its first version, a catalog of control points.
+//! * `publish_framework_version` - Publishes a new version of an Audit Framework. Published versions cannot be changed.
 //!
 //! ### RPC Methods
 //!
@@ -35,11 +74,27 @@
 //! * `get_audits_by_auditing_org` - Get the collection of audits by Auditing Organization
 //! * `get_audits_by_auditors` - Get the collection of audits by Auditors
 //! * `get_audit` - Get an audit by Audit Id
+//! * `get_audit_fee` - Get the fee of an audit and how much of it has been released
+//! * `get_audit_subjects` - Get the subjects in scope of an audit
+//! * `get_audits_by_subject` - Get the collection of audits of a DID, asset, provenance process or site
 //! * `get_observation_by_control_point` - Get the collection of observations by Control Point
 //! * `get_evidence` - Get the collection of evidence for an Audit
 //! * `get_observation_by_control_point` - Get the collection of observations by Control Point
 //! * `get_evidence_links_by_evidence` - Get the collection of observations linked to an item of evidence
 //! * `get_evidence_links_by_observation` - Get the collection of evidence linked to an observation
+//! * `get_framework` - Get a version of an Audit Framework with its control points
+//! * `get_control_point_coverage` - Get the observations made against each control point of the framework an audit references
+//! * `get_corrective_actions` - Get the corrective actions of an audit
+//! * `get_open_corrective_actions_by_creator` - Get the open corrective actions of audits by Audit Creator
+//! * `get_open_corrective_actions_by_auditing_org` - Get the open corrective actions of audits by Auditing Organization
+//! * `get_certificates_by_did` - Get the audit certificates issued against a DID
+//! * `get_audit_report` - Get the compliance of an audit by control point, with a score weighted by control point severity
+//! * `get_upcoming_audits_by_creator` - Get the audit schedules of an Audit Creator that are not cancelled, by next due date
+//! * `get_upcoming_audits_by_auditing_org` - Get the audit schedules of an Auditing Organization that are not cancelled, by next due date
+//! * `get_missed_audits` - Get the audits of a schedule that were not completed by their due date
+//! * `get_note_key` - Get the X25519 key an account registered for confidential notes
+//! * `get_confidential_notes` - Get the confidential notes of an audit
+//! * `get_note_envelopes` - Get the key envelopes of a confidential note by recipient
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -60,16 +115,77 @@ pub mod migration;
 pub mod pallet {
 
     pub use super::weights::WeightInfo;
+    use asset_info::AssetInfo;
     use core::convert::TryInto;
+    use did_info::DidInfo;
     use extrinsic_extra::GetExtrinsicExtra;
-    use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+    use frame_support::{
+        dispatch::{DispatchResult, DispatchResultWithPostInfo},
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement},
+    };
     use frame_system::pallet_prelude::*;
+    use issue_claim::IssueClaim;
     use primitives::{bounded_vec::BoundedVec, *};
-    use sp_runtime::traits::{AtLeast32Bit, CheckedAdd, MaybeSerializeDeserialize, Member, One};
-    use sp_std::prelude::*;
+    use process_info::ProcessInfo;
+    use sp_runtime::Either;
+    use sp_runtime::{
+        traits::{
+            AccountIdConversion, AtLeast32Bit, CheckedAdd, MaybeSerializeDeserialize, Member, One,
+            Saturating, UniqueSaturatedInto, Zero,
+        },
+        ModuleId, Permill,
+    };
+    use sp_std::{collections::btree_set::BTreeSet, prelude::*, vec};
 
     const MODULE_INDEX: u8 = 5;
 
+    /// X25519 public keys of small order, with the unused top bit cleared. A shared secret with any of them is predictable.
+    const LOW_ORDER_NOTE_KEYS: [[u8; 32]; 7] = [
+        // 0 (order 4)
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        // 1 (order 1)
+        [
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        // order 8
+        [
+            0xe0, 0xeb, 0x7a, 0x7c, 0x3b, 0x41, 0xb8, 0xae, 0x16, 0x56, 0xe3, 0xfa, 0xf1, 0x9f,
+            0xc4, 0x6a, 0xda, 0x09, 0x8d, 0xeb, 0x9c, 0x32, 0xb1, 0xfd, 0x86, 0x62, 0x05, 0x16,
+            0x5f, 0x49, 0xb8, 0x00,
+        ],
+        // order 8
+        [
+            0x5f, 0x9c, 0x95, 0xbc, 0xa3, 0x50, 0x8c, 0x24, 0xb1, 0xd0, 0xb1, 0x55, 0x9c, 0x83,
+            0xef, 0x5b, 0x04, 0x44, 0x5c, 0xc4, 0x58, 0x1c, 0x8e, 0x86, 0xd8, 0x22, 0x4e, 0xdd,
+            0xd0, 0x9f, 0x11, 0x57,
+        ],
+        // p - 1 (order 2)
+        [
+            0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0x7f,
+        ],
+        // p, which is 0 (order 4)
+        [
+            0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0x7f,
+        ],
+        // p + 1, which is 1 (order 1)
+        [
+            0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0x7f,
+        ],
+    ];
+
     #[repr(u8)]
     pub enum ExtrinsicIndex {
         Audit = 51,
@@ -81,6 +197,7 @@ pub mod pallet {
     pub enum Releases {
         V1,
         V2,
+        V3,
     }
 
     #[pallet::config]
@@ -94,7 +211,7 @@ pub mod pallet {
             + Copy
             + MaybeSerializeDeserialize
             + PartialEq;
-        ///An identifying id for each control point. Provided by the caller. Only needs to be unique per audit.
+        ///An identifying id for each control point. Unique per framework version. Provided by the caller for audits without a framework, in which case it only needs to be unique per audit.
         type ControlPointId: Parameter
             + Member
             + AtLeast32Bit
@@ -115,6 +232,13 @@ pub mod pallet {
             + Copy
             + MaybeSerializeDeserialize
             + PartialEq;
+        ///A unique id for each audit framework. Serial generated on chain.
+        type FrameworkId: Parameter
+            + Member
+            + AtLeast32Bit
+            + Copy
+            + MaybeSerializeDeserialize
+            + PartialEq;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
         /// The maximum length of a name or symbol stored on-chain.
@@ -123,8 +247,98 @@ pub mod pallet {
         type UrlLimit: Get<u32>;
         /// The maximum number of evidence_links that can be removed in one attempt when deleting evidence.
         type MaxLinkRemove: Get<u32>;
+        /// The maximum number of control points in a version of an audit framework.
+        type ControlPointLimit: Get<u32>;
+        /// The asset registry's id for a registry, used to reference an asset in scope of an audit
+        type AssetRegistryId: Parameter + Member + Copy + PartialEq;
+        /// The asset registry's id for an asset
+        type AssetId: Parameter + Member + Copy + PartialEq;
+        /// The provenance pallet's id for a registry, used to reference a process in scope of an audit
+        type RegistryId: Parameter + Member + Copy + PartialEq;
+        /// The provenance pallet's id for a definition
+        type DefinitionId: Parameter + Member + Copy + PartialEq;
+        /// The provenance pallet's id for a process
+        type ProcessId: Parameter + Member + Copy + PartialEq;
+        /// Checks that an asset in scope of an audit is registered
+        type AssetInfoSource: AssetInfo<RegistryId = Self::AssetRegistryId, AssetId = Self::AssetId>;
+        /// Checks that a DID in scope of an audit has been created
+        type DidInfoSource: DidInfo<Did = Did>;
+        /// Checks that a provenance process in scope of an audit has been created
+        type ProcessInfoSource: ProcessInfo<
+            RegistryId = Self::RegistryId,
+            DefinitionId = Self::DefinitionId,
+            ProcessId = Self::ProcessId,
+        >;
+        /// The maximum number of subjects in scope of an audit.
+        type SubjectLimit: Get<u32>;
+        /// The identity pallet's id for a claim
+        type ClaimId: Parameter + Member + Copy + PartialEq;
+        /// The identity pallet's timestamp, used for the validity of certificates
+        type Moment: Parameter + Member + Copy + PartialEq;
+        /// Issues certificates as attested claims against a DID
+        type IssueClaimSource: IssueClaim<
+            AccountId = Self::AccountId,
+            Did = Did,
+            ClaimId = Self::ClaimId,
+            Moment = Self::Moment,
+            Statement = Statement<Vec<u8>, Vec<u8>>,
+        >;
+        /// The maximum number of partial releases of an audit fee.
+        type FeeMilestoneLimit: Get<u32>;
+        /// The maximum declared size in bytes of the content of an item of evidence.
+        type EvidenceSizeLimit: Get<u64>;
+        /// Resolves disputes over an audit fee, usually the council.
+        type FeeDisputeOrigin: EnsureOrigin<Self::Origin>;
+        ///A unique id for each audit schedule. Serial generated on chain.
+        type AuditScheduleId: Parameter
+            + Member
+            + AtLeast32Bit
+            + Copy
+            + MaybeSerializeDeserialize
+            + PartialEq;
+        /// The maximum number of recipients a confidential note can be shared with when it is created.
+        type NoteRecipientLimit: Get<u32>;
+        /// The minimum number of blocks between the due dates of consecutive audits of a schedule.
+        type MinCadence: Get<Self::BlockNumber>;
+        /// The maximum number of audit schedules of an audit creator that are not cancelled.
+        type AuditScheduleLimit: Get<u32>;
+        /// The maximum number of scheduled audits requested, and of scheduled audits checked, at the start of a block.
+        type MaxScheduledPerBlock: Get<u32>;
+        /// Audit fees are held in escrow in sub-accounts of this id, one per audit.
+        type FeeEscrowModuleId: Get<ModuleId>;
     }
 
+    pub type BalanceOf<T> = <<T as groups::Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    pub type AuditSubjectOf<T> = AuditSubject<
+        <T as Config>::AssetRegistryId,
+        <T as Config>::AssetId,
+        <T as Config>::RegistryId,
+        <T as Config>::DefinitionId,
+        <T as Config>::ProcessId,
+        BoundedVec<u8, <T as Config>::NameLimit>,
+    >;
+
+    pub type AuditScheduleOf<T> = AuditSchedule<
+        <T as frame_system::Config>::AccountId,
+        <T as groups::Config>::ProposalId,
+        <T as Config>::FrameworkId,
+        <T as frame_system::Config>::BlockNumber,
+        <T as Config>::AuditId,
+        AuditSubjectOf<T>,
+    >;
+
+    pub type AuditTrailEntryOf<T> = AuditTrailEntry<
+        <T as frame_system::Config>::AccountId,
+        <T as groups::Config>::ProposalId,
+        <T as frame_system::Config>::BlockNumber,
+        <T as Config>::ControlPointId,
+        <T as Config>::ObservationId,
+        BoundedVec<u8, <T as Config>::NameLimit>,
+    >;
+
     #[pallet::event]
     #[pallet::metadata(
         T::AccountId = "AccountId",
@@ -132,7 +346,12 @@ pub mod pallet {
         T::ObservationId = "ObservationId",
         T::ControlPointId = "ControlPointId",
         T::EvidenceId = "EvidenceId",
-        T::ProposalId = "ProposalId"
+        T::ProposalId = "ProposalId",
+        T::FrameworkId = "FrameworkId",
+        T::ClaimId = "ClaimId",
+        BalanceOf<T> = "Balance",
+        T::AuditScheduleId = "AuditScheduleId",
+        T::BlockNumber = "BlockNumber"
     )]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -184,6 +403,90 @@ pub mod pallet {
         EvidenceDeleted(T::AccountId, T::ProposalId, T::AuditId, T::EvidenceId),
         /// Evidence could not be deleted due to too many observation links. Call delete_evidence again. (auditors, proposal_id, audit_id, evidence_id)
         EvidenceDeleteFailed(T::AccountId, T::ProposalId, T::AuditId, T::EvidenceId),
+        /// Audit framework created with its first version (publisher, proposal_id, framework_id)
+        FrameworkCreated(T::AccountId, Option<T::ProposalId>, T::FrameworkId),
+        /// New version of an audit framework published (publisher, proposal_id, framework_id, version)
+        FrameworkVersionPublished(T::AccountId, Option<T::ProposalId>, T::FrameworkId, u32),
+        /// Audit completed while corrective actions were still open (auditing_org, proposal_id, audit_id, open_findings)
+        AuditCompletedWithOpenFindings(T::AccountId, T::ProposalId, T::AuditId, u32),
+        /// Corrective action created for a non-compliant observation (audit_creator, proposal_id, audit_id, control_point_id, observation_id)
+        CorrectiveActionCreated(
+            T::AccountId,
+            T::ProposalId,
+            T::AuditId,
+            T::ControlPointId,
+            T::ObservationId,
+        ),
+        /// Corrective action closed (auditors, proposal_id, audit_id, control_point_id, observation_id, evidence_id)
+        CorrectiveActionClosed(
+            T::AccountId,
+            T::ProposalId,
+            T::AuditId,
+            T::ControlPointId,
+            T::ObservationId,
+            T::EvidenceId,
+        ),
+        /// Certificate issued as an attested claim (auditing_org, proposal_id, audit_id, did, claim_id)
+        CertificateIssued(T::AccountId, T::ProposalId, T::AuditId, Did, T::ClaimId),
+        /// Certificate revoked (auditing_org, proposal_id, audit_id, did, claim_id)
+        CertificateRevoked(T::AccountId, T::ProposalId, T::AuditId, Did, T::ClaimId),
+        /// Audit fee moved from the audit creator into escrow (auditing_org, proposal_id, audit_id, amount)
+        FeeReserved(T::AccountId, T::ProposalId, T::AuditId, BalanceOf<T>),
+        /// Audit fee released to the auditing org, in full on completion or at a milestone (caller, proposal_id, audit_id, amount)
+        FeeReleased(T::AccountId, T::ProposalId, T::AuditId, BalanceOf<T>),
+        /// Audit fee refunded to the audit creator (caller, proposal_id, audit_id, amount)
+        FeeRefunded(T::AccountId, T::ProposalId, T::AuditId, BalanceOf<T>),
+        /// Audit fee frozen by a dispute (caller, proposal_id, audit_id)
+        FeeDisputed(T::AccountId, T::ProposalId, T::AuditId),
+        /// Audit fee dispute resolved (audit_id, released to the auditing org, refunded to the audit creator)
+        FeeDisputeResolved(T::AuditId, BalanceOf<T>, BalanceOf<T>),
+        /// Audit schedule created (audit_creator, proposal_id, schedule_id)
+        AuditScheduleCreated(T::AccountId, T::ProposalId, T::AuditScheduleId),
+        /// Audit schedule paused (audit_creator, proposal_id, schedule_id)
+        AuditSchedulePaused(T::AccountId, T::ProposalId, T::AuditScheduleId),
+        /// Audit schedule resumed (audit_creator, proposal_id, schedule_id)
+        AuditScheduleResumed(T::AccountId, T::ProposalId, T::AuditScheduleId),
+        /// Audit schedule cancelled (audit_creator, proposal_id, schedule_id)
+        AuditScheduleCancelled(T::AccountId, T::ProposalId, T::AuditScheduleId),
+        /// Audit requested by a schedule (schedule_id, audit_id, due)
+        ScheduledAuditRequested(T::AuditScheduleId, T::AuditId, T::BlockNumber),
+        /// Scheduled audit not completed by its due date (schedule_id, audit_id, due)
+        ScheduledAuditMissed(T::AuditScheduleId, T::AuditId, T::BlockNumber),
+        /// Reopening of a completed audit requested, pending approval by the other party (caller, proposal_id, audit_id)
+        AuditReopenRequested(T::AccountId, T::ProposalId, T::AuditId),
+        /// Completed audit reopened and in progress again (caller, proposal_id, audit_id)
+        AuditReopened(T::AccountId, T::ProposalId, T::AuditId),
+        /// Request to reopen a completed audit withdrawn (caller, proposal_id, audit_id)
+        AuditReopenRequestWithdrawn(T::AccountId, T::ProposalId, T::AuditId),
+        /// Observation replaced by a new observation (auditors, proposal_id, audit_id, control_point_id, superseded observation_id, new observation_id)
+        ObservationSuperseded(
+            T::AccountId,
+            T::ProposalId,
+            T::AuditId,
+            T::ControlPointId,
+            T::ObservationId,
+            T::ObservationId,
+        ),
+        /// X25519 key for confidential notes registered (account)
+        NoteKeyRegistered(T::AccountId),
+        /// Confidential note created (author, proposal_id, audit_id, ciphertext_hash)
+        ConfidentialNoteCreated(T::AccountId, T::ProposalId, T::AuditId, [u8; 32]),
+        /// Access to a confidential note granted (author, proposal_id, audit_id, ciphertext_hash, recipient)
+        NoteAccessGranted(
+            T::AccountId,
+            T::ProposalId,
+            T::AuditId,
+            [u8; 32],
+            T::AccountId,
+        ),
+        /// Access to a confidential note revoked (author, proposal_id, audit_id, ciphertext_hash, recipient)
+        NoteAccessRevoked(
+            T::AccountId,
+            T::ProposalId,
+            T::AuditId,
+            [u8; 32],
+            T::AccountId,
+        ),
     }
 
     #[pallet::error]
@@ -216,6 +519,122 @@ pub mod pallet {
         RemoveLinkLimitExceeded,
         /// The maximum allowed url length was exceeded
         UrLLimitExceeded,
+        /// The auditing organization must co-sign a joint proposal creating an audit
+        AuditingOrgNotCoSigner,
+        /// The audit creator must be a co-signing group other than the auditing organization, or the calling group
+        InvalidAuditCreator,
+        /// The audit framework does not exist
+        FrameworkNotFound,
+        /// The audit framework version does not exist
+        FrameworkVersionNotFound,
+        /// The caller must be the publisher of the audit framework to execute this action.
+        NotPublisher,
+        /// Too many control points in a framework version
+        ControlPointLimitExceeded,
+        /// Control point ids must be unique within a framework version and a parent must precede its children
+        InvalidControlPoint,
+        /// The control point is not in the framework version referenced by the audit
+        ControlPointNotInFramework,
+        /// An audit must have at least one subject in scope
+        AuditScopeEmpty,
+        /// Too many subjects in scope of an audit
+        SubjectLimitExceeded,
+        /// A subject may only appear once in scope of an audit
+        DuplicateSubject,
+        /// The DID does not exist
+        DidNotFound,
+        /// The asset does not exist
+        AssetNotFound,
+        /// The provenance process does not exist
+        ProcessNotFound,
+        /// Corrective actions can only respond to non-compliant observations
+        ObservationNotNonCompliant,
+        /// The observation already has a corrective action
+        CorrectiveActionExists,
+        /// The corrective action does not exist
+        CorrectiveActionNotFound,
+        /// The corrective action must be open
+        CorrectiveActionNotOpen,
+        /// The evidence must be linked to the observation
+        EvidenceNotLinked,
+        /// The audit must be in the `Completed` state.
+        AuditIsNotCompleted,
+        /// The DID is not a subject of the audit
+        DidNotInScope,
+        /// A certificate was already issued against the DID for this audit
+        CertificateExists,
+        /// The certificate does not exist
+        CertificateNotFound,
+        /// The certificate was already revoked
+        CertificateRevoked,
+        /// A certificate can only be revoked by a later audit that completed with open findings
+        AuditNotFailed,
+        /// Too many fee milestones
+        FeeMilestoneLimitExceeded,
+        /// Fee milestones must be for increasing observation counts and must not add up to more than the fee
+        InvalidFeeMilestones,
+        /// The audit creator cannot cover the fee
+        InsufficientBalanceForFee,
+        /// The audit has no fee
+        FeeNotFound,
+        /// The fee must be held in escrow
+        FeeNotReserved,
+        /// The fee is not disputed
+        FeeNotDisputed,
+        /// Only the audit creator or the auditing org may do this
+        NotAuditParty,
+        /// More than the fee remaining in escrow was awarded
+        FeeAwardExceedsEscrow,
+        /// The escrow account of the audit cannot pay out the amount
+        InsufficientEscrow,
+        /// Audit schedule not found
+        AuditScheduleNotFound,
+        /// The cadence must be at least the minimum and the lead time must be greater than zero and no longer than the cadence
+        InvalidCadence,
+        /// Too many audit schedules that are not cancelled
+        AuditScheduleLimitExceeded,
+        /// The declared size of evidence must be greater than zero and within the limit
+        InvalidEvidenceSize,
+        /// The first audit of the schedule would have to be requested in the past
+        ScheduleStartsInPast,
+        /// The audit schedule must be active
+        AuditScheduleNotActive,
+        /// The audit schedule must be paused
+        AuditScheduleNotPaused,
+        /// The audit schedule has been cancelled
+        AuditScheduleCancelled,
+        /// The audit must be in the `Completed` or `CompletedWithOpenFindings` state.
+        AuditIsNotFinished,
+        /// The caller already requested reopening the audit. The other party must approve it.
+        ReopenAlreadyRequested,
+        /// The caller has not requested reopening the audit
+        ReopenNotRequested,
+        /// The observation was already superseded
+        ObservationSuperseded,
+        /// Only the audit creator, the auditing org or the auditors may do this
+        NotAuditPartyOrAuditor,
+        /// Too many recipients of a confidential note
+        NoteRecipientLimitExceeded,
+        /// A note with the same ciphertext was already attached to the audit
+        ConfidentialNoteExists,
+        /// The confidential note does not exist
+        ConfidentialNoteNotFound,
+        /// The caller must be the author of the confidential note to execute this action.
+        NotNoteAuthor,
+        /// Confidential notes can only be shared with members of the audit creator, the auditing org or the auditors
+        NotNoteRecipient,
+        /// The recipient has not registered an X25519 key
+        NoteKeyNotRegistered,
+        /// The envelope was sealed to a key other than the one the recipient registered
+        NoteKeyMismatch,
+        /// The X25519 key is of small order, so notes sealed to it could be read by anyone
+        InvalidNoteKey,
+        /// The recipient does not have access to the confidential note
+        NoteAccessNotFound,
+        /// Once the audit is completed only observations with open findings may be worked on
+        FindingNotOpen,
+        /// The fee must be at least the minimum balance of an account, so it can be held in escrow
+        FeeBelowMinimumBalance,
     }
 
     #[pallet::type_value]
@@ -235,13 +654,44 @@ pub mod pallet {
     pub fn EvidenceIdDefault<T: Config>() -> T::EvidenceId {
         1u32.into()
     }
+    #[pallet::type_value]
+    pub fn FrameworkIdDefault<T: Config>() -> T::FrameworkId {
+        1u32.into()
+    }
+    #[pallet::type_value]
+    pub fn AuditScheduleIdDefault<T: Config>() -> T::AuditScheduleId {
+        1u32.into()
+    }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(block_number: T::BlockNumber) -> frame_support::weights::Weight {
+            // `queue_audit_request` and `queue_due_check` queue at most `MaxScheduledPerBlock` of each for a block
+            // collected first, as requesting an audit queues the next one of the schedule
+            let requests: Vec<T::AuditScheduleId> =
+                <AuditScheduleQueue<T>>::drain_prefix(block_number)
+                    .map(|(schedule_id, _)| schedule_id)
+                    .collect();
+            <AuditScheduleQueueLength<T>>::remove(block_number);
+            let request_count = requests.len() as u32;
+            for schedule_id in requests {
+                Self::request_scheduled_audit(schedule_id);
+            }
+            let mut due_count = 0;
+            <ScheduledAuditsDue<T>>::drain_prefix(block_number).for_each(
+                |(audit_id, (schedule_id, due))| {
+                    due_count += 1;
+                    Self::check_scheduled_audit(schedule_id, audit_id, due);
+                },
+            );
+            <ScheduledAuditsDueLength<T>>::remove(block_number);
+            <T as Config>::WeightInfo::on_initialize(request_count, due_count)
+        }
+
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             //forgot to update version number so removed
             // super::migration::migrate_to_v2::<T>()
-            0
+            super::migration::migrate_to_v3::<T>()
         }
     }
 
@@ -261,7 +711,7 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
-            <StorageVersion<T>>::put(Releases::V2);
+            <StorageVersion<T>>::put(Releases::V3);
         }
     }
 
@@ -297,6 +747,18 @@ pub mod pallet {
     pub type NextEvidenceId<T: Config> =
         StorageValue<_, T::EvidenceId, ValueQuery, EvidenceIdDefault<T>>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn next_framework_id)]
+    /// The next available framework index
+    pub type NextFrameworkId<T: Config> =
+        StorageValue<_, T::FrameworkId, ValueQuery, FrameworkIdDefault<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_audit_schedule_id)]
+    /// The next available audit schedule index
+    pub type NextAuditScheduleId<T: Config> =
+        StorageValue<_, T::AuditScheduleId, ValueQuery, AuditScheduleIdDefault<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn audits)]
     /// Audit by audit_id
@@ -390,9 +852,22 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn observed_control_points)]
+    /// audit_id, control_point_id => ()
+    pub type ObservedControlPoints<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuditId,
+        Blake2_128Concat,
+        T::ControlPointId,
+        (),
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn evidences)]
-    /// audit_id, evidence_id => Evidence(Name, Content-Type, URL, Hash))
+    /// audit_id, evidence_id => Evidence(Name, Content-Type, URL, Hash, Size))
     pub type Evidences<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
@@ -438,42 +913,358 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn frameworks)]
+    /// Audit framework by framework_id
+    pub type Frameworks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::FrameworkId,
+        AuditFramework<T::AccountId, BoundedVec<u8, <T as Config>::NameLimit>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn framework_control_points)]
+    /// (framework_id, version), control_point_id => ControlPoint
+    pub type FrameworkControlPoints<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::FrameworkId, u32),
+        Blake2_128Concat,
+        T::ControlPointId,
+        ControlPoint<T::ControlPointId, BoundedVec<u8, <T as Config>::NameLimit>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_frameworks)]
+    /// audit_id => (framework_id, version)
+    pub type AuditFrameworks<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditId, (T::FrameworkId, u32), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_references)]
+    /// audit_id => the creator's reference given to create_audit
+    pub type AuditReferences<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditId, u32, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_subjects)]
+    /// audit_id => subjects in scope of the audit
+    pub type AuditSubjects<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditId, Vec<AuditSubjectOf<T>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audits_by_subject)]
+    /// subject, audit_id => ()
+    pub type AuditsBySubject<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AuditSubjectOf<T>,
+        Blake2_128Concat,
+        T::AuditId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn corrective_actions)]
+    /// (audit_id, (control_point_id, observation_id)) => CorrectiveAction
+    pub type CorrectiveActions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuditId,
+        Blake2_128Concat,
+        (T::ControlPointId, T::ObservationId),
+        CorrectiveAction<T::AccountId, T::ProposalId, T::BlockNumber, T::EvidenceId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn open_findings)]
+    /// Non-compliant observations of an audit that do not yet have a closed corrective action
+    pub type OpenFindings<T: Config> = StorageMap<_, Blake2_128Concat, T::AuditId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn certificates)]
+    /// did, audit_id => Certificate
+    pub type Certificates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Did,
+        Blake2_128Concat,
+        T::AuditId,
+        Certificate<T::ClaimId, T::Moment, T::AuditId, BoundedVec<u8, <T as Config>::NameLimit>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_fees)]
+    /// audit_id => AuditFee
+    pub type AuditFees<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditId, AuditFee<BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_schedules)]
+    /// schedule_id => AuditSchedule
+    pub type AuditSchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditScheduleId, AuditScheduleOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_schedules_by_creator)]
+    /// audit_creator, schedule_id => ()
+    pub type AuditSchedulesByCreator<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AuditScheduleId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_schedules_by_auditing_org)]
+    /// auditing_org, schedule_id => ()
+    pub type AuditSchedulesByAuditingOrg<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AuditScheduleId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_schedule_queue)]
+    /// Schedules by the block at which their next audit is requested
+    /// block_number, schedule_id => ()
+    pub(super) type AuditScheduleQueue<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Blake2_128Concat,
+        T::AuditScheduleId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_schedule_queue_length)]
+    /// Number of audit requests queued for a block
+    /// block_number => u32
+    pub(super) type AuditScheduleQueueLength<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::BlockNumber, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_audits_due)]
+    /// Audits requested by a schedule, by the block they are checked at, which is their due date unless that block was full
+    /// block_number, audit_id => (schedule_id, due)
+    pub(super) type ScheduledAuditsDue<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Blake2_128Concat,
+        T::AuditId,
+        (T::AuditScheduleId, T::BlockNumber),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_audits_due_length)]
+    /// Number of scheduled audits checked at a block
+    /// block_number => u32
+    pub(super) type ScheduledAuditsDueLength<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::BlockNumber, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_schedule_count)]
+    /// Number of audit schedules of an audit creator that are not cancelled
+    /// audit_creator => u32
+    pub type AuditScheduleCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn missed_audits)]
+    /// schedule_id, audit_id => due
+    pub type MissedAudits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuditScheduleId,
+        Blake2_128Concat,
+        T::AuditId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_trail)]
+    /// Every change of status of an audit and every amendment to it, by their order
+    /// audit_id, index => AuditTrailEntry
+    pub type AuditTrail<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuditId,
+        Blake2_128Concat,
+        u32,
+        AuditTrailEntryOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_trail_length)]
+    /// Number of entries in the audit trail of an audit
+    /// audit_id => u32
+    pub type AuditTrailLength<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn audit_reopen_requests)]
+    /// Requests to reopen a completed audit awaiting approval by the other party
+    /// audit_id => the requesting party
+    pub type AuditReopenRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuditId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn superseded_observations)]
+    /// superseded observation_id => observation_id of the observation that replaced it
+    pub type SupersededObservations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ObservationId, T::ObservationId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn note_keys)]
+    /// X25519 public keys that confidential notes are shared with
+    /// account_id => key
+    pub type NoteKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 32], OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn confidential_notes)]
+    /// audit_id, ciphertext_hash => ConfidentialNote
+    pub type ConfidentialNotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AuditId,
+        Blake2_128Concat,
+        [u8; 32],
+        ConfidentialNote<T::AccountId, T::ProposalId, T::ControlPointId, T::ObservationId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn note_envelopes)]
+    /// (audit_id, ciphertext_hash), recipient => KeyEnvelope
+    pub type NoteEnvelopes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::AuditId, [u8; 32]),
+        Blake2_128Concat,
+        T::AccountId,
+        KeyEnvelope,
+        OptionQuery,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Create a new audit
         ///
         /// Arguments:
         /// `auditing_org` : account_id of the auditing_org (can be an individual account or a group)
-        /// `unique_ref` : a reference to ensure that proposals remain unique.
-        #[pallet::weight(<T as Config>::WeightInfo::create_audit())]
+        /// `framework` : the framework_id and version of the audit framework the audit is carried out against, if any
+        /// `subjects` : what is being audited. DIDs, assets and processes must exist.
+        /// `fee` : an optional fee for the auditing_org with partial releases (observation count, amount). It is reserved from the creator when the audit is accepted.
+        /// `audit_creator` : the account of the group creating the audit. Required when called by a joint proposal, otherwise it must be the calling group if given.
+        /// `unique_ref` : the creator's reference for the audit, which also keeps otherwise identical proposals unique
+        ///
+        /// May also be called by a joint proposal, in which case the auditing_org and the audit_creator must be different co-signing groups.
+        /// Jointly created audits have no proposal_id and are not indexed by proposal.
+        #[pallet::weight(<T as Config>::WeightInfo::create_audit(
+            subjects.len() as u32,
+            fee.as_ref().map_or(0, |(_, milestones)| milestones.len() as u32)
+        ))]
         pub fn create_audit(
             origin: OriginFor<T>,
             auditing_org: T::AccountId,
-            _unique_ref: u32,
+            framework: Option<(T::FrameworkId, u32)>,
+            subjects: Vec<
+                AuditSubject<
+                    T::AssetRegistryId,
+                    T::AssetId,
+                    T::RegistryId,
+                    T::DefinitionId,
+                    T::ProcessId,
+                    Vec<u8>,
+                >,
+            >,
+            fee: Option<(BalanceOf<T>, Vec<(u32, BalanceOf<T>)>)>,
+            audit_creator: Option<T::AccountId>,
+            unique_ref: u32,
         ) -> DispatchResultWithPostInfo {
-            let (_, proposal_id, _, _, group_account) =
-                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
-
-            let audit_id = next_id!(NextAuditId<T>, T);
-
-            let audit = Audit {
-                proposal_id,
-                status: AuditStatus::Requested,
-                audit_creator: group_account.clone(),
-                auditing_org: auditing_org.clone(),
-                auditors: None,
+            let (proposal_id, group_account, joint) =
+                match <T as groups::Config>::GroupsOriginJointApproved::try_origin(origin) {
+                    Ok(co_signers) => {
+                        ensure!(
+                            co_signers
+                                .iter()
+                                .any(|(_, account)| *account == auditing_org),
+                            <Error<T>>::AuditingOrgNotCoSigner
+                        );
+                        ensure!(audit_creator.is_some(), <Error<T>>::InvalidAuditCreator);
+                        let creator = audit_creator.unwrap();
+                        ensure!(
+                            creator != auditing_org
+                                && co_signers.iter().any(|(_, account)| *account == creator),
+                            <Error<T>>::InvalidAuditCreator
+                        );
+                        (T::ProposalId::default(), creator, true)
+                    }
+                    Err(origin) => {
+                        let (_, proposal_id, _, _, group_account) =
+                            <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(
+                                origin,
+                            )?;
+                        ensure!(
+                            audit_creator.map_or(true, |creator| creator == group_account),
+                            <Error<T>>::InvalidAuditCreator
+                        );
+                        (proposal_id, group_account, false)
+                    }
+                };
+
+            if let Some((framework_id, version)) = framework {
+                let maybe_framework = <Frameworks<T>>::get(framework_id);
+                ensure!(maybe_framework.is_some(), <Error<T>>::FrameworkNotFound);
+                ensure!(
+                    version > 0 && version <= maybe_framework.unwrap().latest_version,
+                    <Error<T>>::FrameworkVersionNotFound
+                );
+            }
+            let subjects = Self::validate_subjects(subjects)?;
+            let fee = match fee {
+                Some((amount, milestones)) => Some(Self::validate_fee(amount, milestones)?),
+                None => None,
             };
 
+            let audit_id = Self::insert_audit(
+                if joint { None } else { Some(proposal_id) },
+                &group_account,
+                &auditing_org,
+                framework,
+                subjects,
+            )?;
+            <AuditReferences<T>>::insert(&audit_id, unique_ref);
+
             T::GetExtrinsicExtraSource::charge_extrinsic_extra(
                 &MODULE_INDEX,
                 &(ExtrinsicIndex::Audit as u8),
                 &group_account,
             );
 
-            <Audits<T>>::insert(&audit_id, audit);
-            <AuditByProposal<T>>::insert(&proposal_id, audit_id);
-            <AuditsByCreator<T>>::insert(&group_account, &audit_id, ());
-            <AuditsByAuditingOrg<T>>::insert(&auditing_org, &audit_id, ());
+            if let Some(fee) = fee {
+                <AuditFees<T>>::insert(&audit_id, fee);
+            }
 
             Self::deposit_event(Event::AuditCreated(group_account, proposal_id, audit_id));
             Ok(().into())
@@ -504,6 +1295,15 @@ pub mod pallet {
             <AuditByProposal<T>>::remove(&proposal_id);
             <AuditsByCreator<T>>::remove(&group_account, &audit_id);
             <AuditsByAuditingOrg<T>>::remove(&audit.auditing_org, &audit_id);
+            <AuditFrameworks<T>>::remove(&audit_id);
+            <AuditReferences<T>>::remove(&audit_id);
+            for subject in <AuditSubjects<T>>::take(&audit_id) {
+                <AuditsBySubject<T>>::remove(&subject, &audit_id);
+            }
+            Self::refund_fee(&group_account, proposal_id, audit_id, &audit.audit_creator);
+            <AuditFees<T>>::remove(&audit_id);
+            <AuditTrail<T>>::remove_prefix(&audit_id);
+            <AuditTrailLength<T>>::remove(&audit_id);
 
             Self::deposit_event(Event::AuditRemoved(group_account, proposal_id, audit_id));
             Ok(().into())
@@ -529,9 +1329,34 @@ pub mod pallet {
                 <Error<T>>::AuditIsNotRequested
             );
             ensure!(audit.auditing_org == group_account, <Error<T>>::NotAuditor);
+
+            if let Some(mut fee) = <AuditFees<T>>::get(audit_id) {
+                <T as groups::Config>::Currency::transfer(
+                    &audit.audit_creator,
+                    &Self::fee_escrow_account(audit_id),
+                    fee.amount,
+                    ExistenceRequirement::KeepAlive,
+                )
+                .map_err(|_| <Error<T>>::InsufficientBalanceForFee)?;
+                fee.status = AuditFeeStatus::Reserved;
+                Self::deposit_event(Event::FeeReserved(
+                    group_account.clone(),
+                    proposal_id,
+                    audit_id,
+                    fee.amount,
+                ));
+                <AuditFees<T>>::insert(audit_id, fee);
+            }
+
             audit.status = AuditStatus::Accepted;
 
             <Audits<T>>::insert(audit_id, audit);
+            Self::record_change(
+                audit_id,
+                Some(proposal_id),
+                &group_account,
+                AuditChange::Status(AuditStatus::Accepted),
+            );
 
             Self::deposit_event(Event::AuditAccepted(group_account, proposal_id, audit_id));
             Ok(().into())
@@ -608,9 +1433,17 @@ pub mod pallet {
             );
             ensure!(audit.auditing_org == group_account, <Error<T>>::NotAuditor);
 
+            Self::refund_fee(&group_account, proposal_id, audit_id, &audit.audit_creator);
+
             audit.status = AuditStatus::Rejected;
 
             <Audits<T>>::insert(audit_id, audit);
+            Self::record_change(
+                audit_id,
+                Some(proposal_id),
+                &group_account,
+                AuditChange::Status(AuditStatus::Rejected),
+            );
 
             Self::deposit_event(Event::AuditRejected(group_account, proposal_id, audit_id));
             Ok(().into())
@@ -637,11 +1470,55 @@ pub mod pallet {
             );
             ensure!(audit.auditing_org == group_account, <Error<T>>::NotAuditor);
 
+            //a disputed fee stays frozen until the dispute is resolved.
+            //the fee never holds up completion, if it cannot be paid out it stays in escrow and can be disputed
+            if let Some(mut fee) = <AuditFees<T>>::get(audit_id) {
+                let remainder = fee.amount.saturating_sub(fee.released);
+                if fee.status == AuditFeeStatus::Reserved
+                    && Self::pay_from_escrow(audit_id, &audit.auditing_org, remainder).is_ok()
+                {
+                    fee.released = fee.amount;
+                    fee.status = AuditFeeStatus::Released;
+                    <AuditFees<T>>::insert(audit_id, fee);
+                    Self::deposit_event(Event::FeeReleased(
+                        group_account.clone(),
+                        proposal_id,
+                        audit_id,
+                        remainder,
+                    ));
+                }
+            }
+
+            let open_findings = <OpenFindings<T>>::get(audit_id);
+            if open_findings > 0 {
+                audit.status = AuditStatus::CompletedWithOpenFindings;
+                <Audits<T>>::insert(audit_id, audit);
+                Self::record_change(
+                    audit_id,
+                    Some(proposal_id),
+                    &group_account,
+                    AuditChange::Status(AuditStatus::CompletedWithOpenFindings),
+                );
+                Self::deposit_event(Event::AuditCompletedWithOpenFindings(
+                    group_account,
+                    proposal_id,
+                    audit_id,
+                    open_findings,
+                ));
+                return Ok(().into());
+            }
+
             audit.status = AuditStatus::Completed;
 
             <Audits<T>>::insert(audit_id, audit);
-
-            Self::deposit_event(Event::AuditCompleted(group_account, proposal_id, audit_id));
+            Self::record_change(
+                audit_id,
+                Some(proposal_id),
+                &group_account,
+                AuditChange::Status(AuditStatus::Completed),
+            );
+
+            Self::deposit_event(Event::AuditCompleted(group_account, proposal_id, audit_id));
             Ok(().into())
         }
 
@@ -669,7 +1546,8 @@ pub mod pallet {
             ensure!(
                 parent_audit.status == AuditStatus::Accepted
                     || parent_audit.status == AuditStatus::InProgress
-                    || parent_audit.status == AuditStatus::Completed,
+                    || parent_audit.status == AuditStatus::Completed
+                    || parent_audit.status == AuditStatus::CompletedWithOpenFindings,
                 <Error<T>>::AuditIsNotAcceptedOrInProgressOrCompleted
             );
 
@@ -683,7 +1561,8 @@ pub mod pallet {
             ensure!(
                 child_audit.status == AuditStatus::Accepted
                     || child_audit.status == AuditStatus::InProgress
-                    || child_audit.status == AuditStatus::Completed,
+                    || child_audit.status == AuditStatus::Completed
+                    || child_audit.status == AuditStatus::CompletedWithOpenFindings,
                 <Error<T>>::AuditIsNotAcceptedOrInProgressOrCompleted
             );
 
@@ -740,7 +1619,7 @@ pub mod pallet {
         ///
         /// Arguments:
         /// - `audit_id` id created on chain of audit
-        /// - `control_point_id` control point id of audit
+        /// - `control_point_id` control point id of audit. Must be in the framework version referenced by the audit, if any.
         /// - `observation` (compliance, procedural notes)
         #[pallet::weight(<T as Config>::WeightInfo::create_observation())]
         pub fn create_observation(
@@ -765,6 +1644,41 @@ pub mod pallet {
                 *audit.auditors.as_ref().unwrap() == group_account,
                 <Error<T>>::NotAuditor
             );
+            if let Some(framework) = <AuditFrameworks<T>>::get(audit_id) {
+                ensure!(
+                    <FrameworkControlPoints<T>>::contains_key(framework, control_point_id),
+                    <Error<T>>::ControlPointNotInFramework
+                );
+            }
+
+            if let Some(mut fee) = <AuditFees<T>>::get(audit_id) {
+                fee.observations = fee.observations.saturating_add(1);
+                let observations = fee.observations;
+                let milestone = fee
+                    .milestones
+                    .iter()
+                    .find(|(milestone_observations, _)| *milestone_observations == observations)
+                    .map(|(_, amount)| *amount);
+                //a milestone that cannot be paid out stays in escrow until completion
+                let paid = match (milestone, &fee.status) {
+                    (Some(amount), AuditFeeStatus::Reserved) => {
+                        Self::pay_from_escrow(audit_id, &audit.auditing_org, amount)
+                            .ok()
+                            .map(|_| amount)
+                    }
+                    _ => None,
+                };
+                if let Some(amount) = paid {
+                    fee.released = fee.released.saturating_add(amount);
+                    Self::deposit_event(Event::FeeReleased(
+                        group_account.clone(),
+                        proposal_id,
+                        audit_id,
+                        amount,
+                    ));
+                }
+                <AuditFees<T>>::insert(audit_id, fee);
+            }
 
             if audit.status == AuditStatus::Accepted {
                 Self::deposit_event(Event::AuditStarted(
@@ -775,6 +1689,12 @@ pub mod pallet {
 
                 audit.status = AuditStatus::InProgress;
                 <Audits<T>>::insert(audit_id, audit);
+                Self::record_change(
+                    audit_id,
+                    Some(proposal_id),
+                    &group_account,
+                    AuditChange::Status(AuditStatus::InProgress),
+                );
             }
 
             T::GetExtrinsicExtraSource::charge_extrinsic_extra(
@@ -785,6 +1705,12 @@ pub mod pallet {
 
             let observation_id = next_id!(NextObservationId<T>, T);
 
+            if compliance == Some(Compliance::NonCompliant) {
+                <OpenFindings<T>>::mutate(audit_id, |open_findings| {
+                    *open_findings = open_findings.saturating_add(1)
+                });
+            }
+
             let observation = Observation {
                 proposal_id,
                 compliance,
@@ -792,6 +1718,7 @@ pub mod pallet {
             };
 
             <Observations<T>>::insert((&audit_id, &control_point_id), &observation_id, observation);
+            <ObservedControlPoints<T>>::insert(&audit_id, &control_point_id, ());
             <ObservationByProposal<T>>::insert(
                 &proposal_id,
                 (audit_id, control_point_id, observation_id),
@@ -812,12 +1739,12 @@ pub mod pallet {
         /// Arguments:
         /// - `audit_id` id of audit created on chain
         /// - `evidence` Body of evidence
+        /// - `hash` blake2-256 hash of the content, as returned by the `upload_evidence` RPC
+        /// - `size` size of the content in bytes
         #[pallet::weight(<T as Config>::WeightInfo::create_evidence(
             name.len() as u32,
             content_type.len() as u32,
             url.as_ref().map_or(0,|url|url.len()) as u32,
-            hash.len() as u32,
-
         ))]
         pub fn create_evidence(
             origin: OriginFor<T>,
@@ -825,8 +1752,8 @@ pub mod pallet {
             name: Vec<u8>,
             content_type: Vec<u8>,
             url: Option<Vec<u8>>,
-            //TODO: use [u8; 32]
-            hash: Vec<u8>,
+            hash: [u8; 32],
+            size: u64,
         ) -> DispatchResultWithPostInfo {
             let (_, proposal_id, _, _, group_account) =
                 <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
@@ -834,8 +1761,11 @@ pub mod pallet {
             let maybe_audit = <Audits<T>>::get(audit_id);
             ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
             let audit = maybe_audit.unwrap();
+            //evidence of closing open findings may still be attached once the audit is completed
             ensure!(
-                audit.status == AuditStatus::Accepted || audit.status == AuditStatus::InProgress,
+                audit.status == AuditStatus::Accepted
+                    || audit.status == AuditStatus::InProgress
+                    || audit.status == AuditStatus::CompletedWithOpenFindings,
                 <Error<T>>::AuditIsNotAcceptedOrInProgress
             );
             ensure!(audit.auditors.is_some(), <Error<T>>::AuditorNotAssigned);
@@ -844,8 +1774,11 @@ pub mod pallet {
                 <Error<T>>::NotAuditor
             );
 
+            ensure!(
+                size > 0 && size <= <T as Config>::EvidenceSizeLimit::get(),
+                <Error<T>>::InvalidEvidenceSize
+            );
             let bounded_content_type = enforce_limit!(content_type);
-            let bounded_hash = enforce_limit!(hash);
             let bounded_name = enforce_limit!(name);
             let bounded_url = enforce_url_limit_option!(url);
 
@@ -860,9 +1793,11 @@ pub mod pallet {
             let evidence = Evidence {
                 proposal_id,
                 content_type: bounded_content_type,
-                hash: bounded_hash,
+                hash,
+                size,
                 name: bounded_name,
                 url: bounded_url,
+                legacy_hash: None,
             };
 
             <Evidences<T>>::insert(&audit_id, &evidence_id, evidence);
@@ -899,7 +1834,8 @@ pub mod pallet {
             ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
             let audit = maybe_audit.unwrap();
             ensure!(
-                audit.status == AuditStatus::InProgress,
+                audit.status == AuditStatus::InProgress
+                    || audit.status == AuditStatus::CompletedWithOpenFindings,
                 <Error<T>>::AuditIsNotInProgress
             );
             ensure!(audit.auditors.is_some(), <Error<T>>::AuditorNotAssigned);
@@ -911,6 +1847,11 @@ pub mod pallet {
                 <Observations<T>>::contains_key((audit_id, control_point_id), observation_id),
                 <Error<T>>::ObservationNotFound
             );
+            ensure!(
+                audit.status == AuditStatus::InProgress
+                    || Self::is_open_finding(audit_id, control_point_id, observation_id),
+                <Error<T>>::FindingNotOpen
+            );
             ensure!(
                 <Evidences<T>>::contains_key(audit_id, evidence_id),
                 <Error<T>>::EvidenceNotFound
@@ -1043,200 +1984,1279 @@ pub mod pallet {
             ));
             Ok(().into())
         }
-    }
 
-    impl<T: Config> Module<T> {
-        // -- rpc api functions --
+        /// Create an Audit Framework and publish its first version. The caller, an account or a group by proposal, becomes the publisher.
+        ///
+        /// Arguments:
+        /// - `name` name of the framework
+        /// - `control_points` the catalog of control points. A parent must precede its children.
+        #[pallet::weight(<T as Config>::WeightInfo::create_framework(
+            control_points.len() as u32
+        ))]
+        pub fn create_framework(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            control_points: Vec<(T::ControlPointId, ControlPoint<T::ControlPointId, Vec<u8>>)>,
+        ) -> DispatchResultWithPostInfo {
+            let (publisher, proposal_id) = Self::ensure_account_or_approved(origin)?;
 
-        pub fn get_audits_by_creator(
-            account: T::AccountId,
-        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
-            let mut audits = Vec::new();
-            <AuditsByCreator<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
-                let audit = <Audits<T>>::get(audit_id).unwrap();
-                audits.push((audit_id, audit))
-            });
-            audits
-        }
+            let bounded_name = enforce_limit!(name);
+            let control_points = Self::validate_control_points(control_points)?;
 
-        pub fn get_audits_by_auditing_org(
-            account: T::AccountId,
-        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
-            let mut audits = Vec::new();
-            <AuditsByAuditingOrg<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
-                let audit = <Audits<T>>::get(audit_id).unwrap();
-                audits.push((audit_id, audit))
-            });
-            audits
-        }
+            let framework_id = next_id!(NextFrameworkId<T>, T);
 
-        pub fn get_audits_by_auditors(
-            account: T::AccountId,
-        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
-            let mut audits = Vec::new();
-            <AuditsByAuditors<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
-                let audit = <Audits<T>>::get(audit_id).unwrap();
-                audits.push((audit_id, audit))
-            });
-            audits
-        }
+            let framework = AuditFramework {
+                publisher: publisher.clone(),
+                name: bounded_name,
+                latest_version: 1,
+            };
 
-        pub fn get_linked_audits(
-            audit_id: T::AuditId,
-        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
-            let mut audits = Vec::new();
-            <LinkedAudits<T>>::iter_prefix(audit_id).for_each(|(child_audit_id, _)| {
-                let audit_maybe = <Audits<T>>::get(child_audit_id);
-                if let Some(audit) = audit_maybe {
-                    audits.push((child_audit_id, audit));
-                }
-            });
-            audits
-        }
+            <Frameworks<T>>::insert(&framework_id, framework);
+            for (control_point_id, control_point) in control_points {
+                <FrameworkControlPoints<T>>::insert(
+                    (framework_id, 1),
+                    control_point_id,
+                    control_point,
+                );
+            }
 
-        pub fn get_audit(audit_id: T::AuditId) -> Option<Audit<T::AccountId, T::ProposalId>> {
-            <Audits<T>>::get(audit_id)
+            Self::deposit_event(Event::FrameworkCreated(
+                publisher,
+                proposal_id,
+                framework_id,
+            ));
+            Ok(().into())
         }
 
-        pub fn get_audit_by_proposal(
-            proposal_id: T::ProposalId,
-        ) -> Option<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
-            <AuditByProposal<T>>::get(proposal_id)
-                .map(|audit_id| (audit_id, <Audits<T>>::get(audit_id).unwrap()))
+        /// Publish a new version of an Audit Framework. Audits keep the version they were created with.
+        ///
+        /// Arguments:
+        /// - `framework_id` the framework
+        /// - `control_points` the complete catalog of control points for the new version. A parent must precede its children.
+        #[pallet::weight(<T as Config>::WeightInfo::publish_framework_version(
+            control_points.len() as u32
+        ))]
+        pub fn publish_framework_version(
+            origin: OriginFor<T>,
+            framework_id: T::FrameworkId,
+            control_points: Vec<(T::ControlPointId, ControlPoint<T::ControlPointId, Vec<u8>>)>,
+        ) -> DispatchResultWithPostInfo {
+            let (publisher, proposal_id) = Self::ensure_account_or_approved(origin)?;
+
+            let maybe_framework = <Frameworks<T>>::get(framework_id);
+            ensure!(maybe_framework.is_some(), <Error<T>>::FrameworkNotFound);
+            let mut framework = maybe_framework.unwrap();
+            ensure!(framework.publisher == publisher, <Error<T>>::NotPublisher);
+
+            let control_points = Self::validate_control_points(control_points)?;
+
+            let version = framework
+                .latest_version
+                .checked_add(1)
+                .ok_or(<Error<T>>::NoIdAvailable)?;
+            framework.latest_version = version;
+
+            <Frameworks<T>>::insert(&framework_id, framework);
+            for (control_point_id, control_point) in control_points {
+                <FrameworkControlPoints<T>>::insert(
+                    (framework_id, version),
+                    control_point_id,
+                    control_point,
+                );
+            }
+
+            Self::deposit_event(Event::FrameworkVersionPublished(
+                publisher,
+                proposal_id,
+                framework_id,
+                version,
+            ));
+            Ok(().into())
         }
 
-        pub fn get_observation(
+        /// Respond to a non-compliant observation with a corrective action plan. The audit stays open for findings until the auditors close the action.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit
+        /// - `control_point_id` the control point the observation was made against
+        /// - `observation_id` the non-compliant observation
+        /// - `description_hash` hash of the plan, which is kept off chain
+        /// - `due_date` the block by which the action is due to be closed
+        /// - `responsible` the account responsible for carrying out the plan
+        #[pallet::weight(<T as Config>::WeightInfo::create_corrective_action())]
+        pub fn create_corrective_action(
+            origin: OriginFor<T>,
             audit_id: T::AuditId,
             control_point_id: T::ControlPointId,
             observation_id: T::ObservationId,
-        ) -> Option<(
-            Observation<T::ProposalId>,
-            Vec<(
-                T::EvidenceId,
-                Evidence<
-                    T::ProposalId,
-                    BoundedVec<u8, <T as Config>::NameLimit>,
-                    BoundedVec<u8, <T as Config>::UrlLimit>,
-                >,
-            )>,
-        )> {
-            <Observations<T>>::get((audit_id, control_point_id), observation_id).map(
-                |observation| {
-                    let mut evidences = Vec::new();
-                    <EvidenceLinksByObservation<T>>::iter_prefix(observation_id).for_each(
-                        |(evidence_id, _)| {
-                            if let Some(evidence) = <Evidences<T>>::get(audit_id, evidence_id) {
-                                evidences.push((evidence_id, evidence));
-                            }
-                        },
-                    );
-                    (observation, evidences)
-                },
-            )
-        }
+            description_hash: [u8; 32],
+            due_date: T::BlockNumber,
+            responsible: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
 
-        pub fn get_observation_by_proposal(
-            proposal_id: T::ProposalId,
-        ) -> Option<(
-            T::ObservationId,
-            Observation<T::ProposalId>,
-            Vec<(
-                T::EvidenceId,
-                Evidence<
-                    T::ProposalId,
-                    BoundedVec<u8, <T as Config>::NameLimit>,
-                    BoundedVec<u8, <T as Config>::UrlLimit>,
-                >,
-            )>,
-        )> {
-            <ObservationByProposal<T>>::get(proposal_id).map(
-                |(audit_id, control_point_id, observation_id)| {
-                    let mut evidences = Vec::new();
-                    <EvidenceLinksByObservation<T>>::iter_prefix(observation_id).for_each(
-                        |(evidence_id, _)| {
-                            if let Some(evidence) = <Evidences<T>>::get(audit_id, evidence_id) {
-                                evidences.push((evidence_id, evidence));
-                            }
-                        },
-                    );
-                    (
-                        observation_id,
-                        <Observations<T>>::get((audit_id, control_point_id), observation_id)
-                            .unwrap(),
-                        evidences,
-                    )
-                },
-            )
-        }
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+            ensure!(audit.audit_creator == group_account, <Error<T>>::NotCreator);
+            //an observation without a corrective action is still an open finding
+            ensure!(
+                audit.status == AuditStatus::InProgress
+                    || audit.status == AuditStatus::CompletedWithOpenFindings,
+                <Error<T>>::AuditIsNotInProgress
+            );
+            let maybe_observation =
+                <Observations<T>>::get((audit_id, control_point_id), observation_id);
+            ensure!(maybe_observation.is_some(), <Error<T>>::ObservationNotFound);
+            ensure!(
+                maybe_observation.unwrap().compliance == Some(Compliance::NonCompliant),
+                <Error<T>>::ObservationNotNonCompliant
+            );
+            ensure!(
+                !<SupersededObservations<T>>::contains_key(observation_id),
+                <Error<T>>::ObservationSuperseded
+            );
+            ensure!(
+                !<CorrectiveActions<T>>::contains_key(audit_id, (control_point_id, observation_id)),
+                <Error<T>>::CorrectiveActionExists
+            );
 
-        pub fn get_observation_by_control_point(
-            audit_id: T::AuditId,
-            control_point_id: T::ControlPointId,
-        ) -> Vec<(
-            T::ObservationId,
-            Observation<T::ProposalId>,
-            Vec<(
-                T::EvidenceId,
-                Evidence<
-                    T::ProposalId,
-                    BoundedVec<u8, <T as Config>::NameLimit>,
-                    BoundedVec<u8, <T as Config>::UrlLimit>,
-                >,
-            )>,
-        )> {
-            let mut observations = Vec::new();
-            <Observations<T>>::iter_prefix((audit_id, control_point_id)).for_each(
-                |(observation_id, observation)| {
-                    let mut evidences = Vec::new();
-                    <EvidenceLinksByObservation<T>>::iter_prefix(observation_id).for_each(
-                        |(evidence_id, _)| {
-                            if let Some(evidence) = <Evidences<T>>::get(audit_id, evidence_id) {
-                                evidences.push((evidence_id, evidence));
-                            }
-                        },
-                    );
-                    observations.push((observation_id, observation, evidences))
-                },
+            let corrective_action = CorrectiveAction {
+                proposal_id,
+                description_hash,
+                due_date,
+                responsible,
+                status: CorrectiveActionStatus::Open,
+                closing_evidence: None,
+            };
+            <CorrectiveActions<T>>::insert(
+                audit_id,
+                (control_point_id, observation_id),
+                corrective_action,
             );
-            observations
-        }
 
-        pub fn get_evidence(
-            audit_id: T::AuditId,
-            evidence_id: T::EvidenceId,
-        ) -> Option<
-            Evidence<
-                T::ProposalId,
-                BoundedVec<u8, <T as Config>::NameLimit>,
-                BoundedVec<u8, <T as Config>::UrlLimit>,
-            >,
-        > {
-            <Evidences<T>>::get(audit_id, evidence_id)
+            Self::deposit_event(Event::CorrectiveActionCreated(
+                group_account,
+                proposal_id,
+                audit_id,
+                control_point_id,
+                observation_id,
+            ));
+            Ok(().into())
         }
 
-        pub fn get_evidence_by_audit(
+        /// Verify closure of a corrective action. The evidence must already be linked to the observation with `link_evidence`.
+        /// An audit completed with open findings becomes completed once the last one is closed.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit
+        /// - `control_point_id` the control point the observation was made against
+        /// - `observation_id` the observation the corrective action responds to
+        /// - `evidence_id` the evidence of closure
+        #[pallet::weight(<T as Config>::WeightInfo::close_corrective_action())]
+        pub fn close_corrective_action(
+            origin: OriginFor<T>,
             audit_id: T::AuditId,
-        ) -> Vec<(
-            T::EvidenceId,
-            Evidence<
-                T::ProposalId,
-                BoundedVec<u8, <T as Config>::NameLimit>,
-                BoundedVec<u8, <T as Config>::UrlLimit>,
-            >,
-        )> {
-            let mut evidences = Vec::new();
-            <Evidences<T>>::iter_prefix(audit_id)
-                .for_each(|(evidence_id, evidence)| evidences.push((evidence_id, evidence)));
-            evidences
-        }
+            control_point_id: T::ControlPointId,
+            observation_id: T::ObservationId,
+            evidence_id: T::EvidenceId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
 
-        pub fn get_evidence_by_proposal(
-            proposal_id: T::ProposalId,
-        ) -> Option<(
-            T::EvidenceId,
-            Evidence<
-                T::ProposalId,
-                BoundedVec<u8, <T as Config>::NameLimit>,
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let mut audit = maybe_audit.unwrap();
+            ensure!(
+                audit.status == AuditStatus::InProgress
+                    || audit.status == AuditStatus::CompletedWithOpenFindings,
+                <Error<T>>::AuditIsNotInProgress
+            );
+            ensure!(audit.auditors.is_some(), <Error<T>>::AuditorNotAssigned);
+            ensure!(
+                *audit.auditors.as_ref().unwrap() == group_account,
+                <Error<T>>::NotAuditor
+            );
+            let maybe_corrective_action =
+                <CorrectiveActions<T>>::get(audit_id, (control_point_id, observation_id));
+            ensure!(
+                maybe_corrective_action.is_some(),
+                <Error<T>>::CorrectiveActionNotFound
+            );
+            let mut corrective_action = maybe_corrective_action.unwrap();
+            ensure!(
+                corrective_action.status == CorrectiveActionStatus::Open,
+                <Error<T>>::CorrectiveActionNotOpen
+            );
+            ensure!(
+                <EvidenceLinksByObservation<T>>::contains_key(observation_id, evidence_id),
+                <Error<T>>::EvidenceNotLinked
+            );
+
+            corrective_action.status = CorrectiveActionStatus::Closed;
+            corrective_action.closing_evidence = Some(evidence_id);
+            <CorrectiveActions<T>>::insert(
+                audit_id,
+                (control_point_id, observation_id),
+                corrective_action,
+            );
+            let open_findings = <OpenFindings<T>>::mutate(audit_id, |open_findings| {
+                *open_findings = open_findings.saturating_sub(1);
+                *open_findings
+            });
+
+            Self::deposit_event(Event::CorrectiveActionClosed(
+                group_account.clone(),
+                proposal_id,
+                audit_id,
+                control_point_id,
+                observation_id,
+                evidence_id,
+            ));
+
+            if audit.status == AuditStatus::CompletedWithOpenFindings && open_findings == 0 {
+                audit.status = AuditStatus::Completed;
+                <Audits<T>>::insert(audit_id, audit);
+                Self::record_change(
+                    audit_id,
+                    Some(proposal_id),
+                    &group_account,
+                    AuditChange::Status(AuditStatus::Completed),
+                );
+                Self::deposit_event(Event::AuditCompleted(group_account, proposal_id, audit_id));
+            }
+            Ok(().into())
+        }
+
+        /// Certify a DID in scope of a completed audit. The certificate is made and attested as a claim against the DID, with the auditing org as issuer.
+        /// The DID controller must have authorized the auditing org as a claim issuer.
+        ///
+        /// Arguments:
+        /// - `audit_id` the completed audit
+        /// - `did` the audited entity
+        /// - `scope` what the certificate covers
+        /// - `grade` the grade awarded
+        /// - `valid_until` when the certificate expires
+        #[pallet::weight(<T as Config>::WeightInfo::issue_certificate(
+            scope.len() as u32,
+            grade.len() as u32,
+        ))]
+        pub fn issue_certificate(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            did: Did,
+            scope: Vec<u8>,
+            grade: Vec<u8>,
+            valid_until: T::Moment,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+            ensure!(audit.auditing_org == group_account, <Error<T>>::NotAuditor);
+            ensure!(
+                audit.status == AuditStatus::Completed,
+                <Error<T>>::AuditIsNotCompleted
+            );
+            ensure!(
+                <AuditSubjects<T>>::get(audit_id).contains(&AuditSubject::Did(did)),
+                <Error<T>>::DidNotInScope
+            );
+            ensure!(
+                !<Certificates<T>>::contains_key(&did, audit_id),
+                <Error<T>>::CertificateExists
+            );
+
+            let bounded_grade = enforce_limit!(grade.clone());
+
+            let mut statements = vec![
+                Statement {
+                    name: b"audit_id".to_vec(),
+                    fact: Fact::U128(audit_id.unique_saturated_into()),
+                    for_issuer: true,
+                },
+                Statement {
+                    name: b"scope".to_vec(),
+                    fact: Fact::Text(scope),
+                    for_issuer: true,
+                },
+                Statement {
+                    name: b"grade".to_vec(),
+                    fact: Fact::Text(grade),
+                    for_issuer: true,
+                },
+            ];
+            if let Some((framework_id, version)) = <AuditFrameworks<T>>::get(audit_id) {
+                statements.push(Statement {
+                    name: b"framework_id".to_vec(),
+                    fact: Fact::U128(framework_id.unique_saturated_into()),
+                    for_issuer: true,
+                });
+                statements.push(Statement {
+                    name: b"framework_version".to_vec(),
+                    fact: Fact::U32(version),
+                    for_issuer: true,
+                });
+            }
+
+            let claim_id = T::IssueClaimSource::issue_claim(
+                &group_account,
+                &did,
+                b"Audit Certificate".to_vec(),
+                statements,
+                valid_until,
+            )?;
+
+            <Certificates<T>>::insert(
+                &did,
+                audit_id,
+                Certificate {
+                    claim_id,
+                    grade: bounded_grade,
+                    valid_until,
+                    status: CertificateStatus::Valid,
+                },
+            );
+
+            Self::deposit_event(Event::CertificateIssued(
+                group_account,
+                proposal_id,
+                audit_id,
+                did,
+                claim_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Revoke a certificate. The attestation of its claim is removed.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit the certificate was issued for
+        /// - `did` the certified entity
+        /// - `failed_audit_id` a later audit of the DID by the issuer that completed with open findings.
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_certificate())]
+        pub fn revoke_certificate(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            did: Did,
+            failed_audit_id: Option<T::AuditId>,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_certificate = <Certificates<T>>::get(&did, audit_id);
+            ensure!(maybe_certificate.is_some(), <Error<T>>::CertificateNotFound);
+            let mut certificate = maybe_certificate.unwrap();
+            ensure!(
+                certificate.status == CertificateStatus::Valid,
+                <Error<T>>::CertificateRevoked
+            );
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+
+            // only the issuer may revoke, another auditing org cannot revoke it by failing the DID itself
+            ensure!(audit.auditing_org == group_account, <Error<T>>::NotAuditor);
+            if let Some(failed_audit_id) = failed_audit_id {
+                ensure!(failed_audit_id > audit_id, <Error<T>>::AuditNotFailed);
+                let maybe_failed_audit = <Audits<T>>::get(failed_audit_id);
+                ensure!(maybe_failed_audit.is_some(), <Error<T>>::AuditNotFound);
+                let failed_audit = maybe_failed_audit.unwrap();
+                ensure!(
+                    failed_audit.auditing_org == audit.auditing_org,
+                    <Error<T>>::NotAuditor
+                );
+                ensure!(
+                    failed_audit.status == AuditStatus::CompletedWithOpenFindings,
+                    <Error<T>>::AuditNotFailed
+                );
+                ensure!(
+                    <AuditSubjects<T>>::get(failed_audit_id).contains(&AuditSubject::Did(did)),
+                    <Error<T>>::DidNotInScope
+                );
+            }
+
+            T::IssueClaimSource::revoke_claim(&audit.auditing_org, &did, certificate.claim_id)?;
+
+            let claim_id = certificate.claim_id;
+            certificate.status = CertificateStatus::Revoked(failed_audit_id);
+            <Certificates<T>>::insert(&did, audit_id, certificate);
+
+            Self::deposit_event(Event::CertificateRevoked(
+                group_account,
+                proposal_id,
+                audit_id,
+                did,
+                claim_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Freeze the fee held in escrow for an audit until the dispute is resolved.
+        ///
+        /// Arguments:
+        /// - `audit_id`
+        #[pallet::weight(<T as Config>::WeightInfo::dispute_fee())]
+        pub fn dispute_fee(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+            ensure!(
+                audit.audit_creator == group_account || audit.auditing_org == group_account,
+                <Error<T>>::NotAuditParty
+            );
+
+            let maybe_fee = <AuditFees<T>>::get(audit_id);
+            ensure!(maybe_fee.is_some(), <Error<T>>::FeeNotFound);
+            let mut fee = maybe_fee.unwrap();
+            ensure!(
+                fee.status == AuditFeeStatus::Reserved,
+                <Error<T>>::FeeNotReserved
+            );
+
+            fee.status = AuditFeeStatus::Disputed;
+            <AuditFees<T>>::insert(audit_id, fee);
+
+            Self::deposit_event(Event::FeeDisputed(group_account, proposal_id, audit_id));
+            Ok(().into())
+        }
+
+        /// Resolve a disputed audit fee. The award is released to the auditing org and the rest of the escrow is refunded to the audit creator.
+        ///
+        /// Arguments:
+        /// - `audit_id`
+        /// - `award` the part of the fee remaining in escrow that is released to the auditing org
+        #[pallet::weight(<T as Config>::WeightInfo::resolve_fee_dispute())]
+        pub fn resolve_fee_dispute(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            award: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::FeeDisputeOrigin::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+
+            let maybe_fee = <AuditFees<T>>::get(audit_id);
+            ensure!(maybe_fee.is_some(), <Error<T>>::FeeNotFound);
+            let mut fee = maybe_fee.unwrap();
+            ensure!(
+                fee.status == AuditFeeStatus::Disputed,
+                <Error<T>>::FeeNotDisputed
+            );
+            let remainder = fee.amount.saturating_sub(fee.released);
+            ensure!(award <= remainder, <Error<T>>::FeeAwardExceedsEscrow);
+            ensure!(
+                <T as groups::Config>::Currency::free_balance(&Self::fee_escrow_account(audit_id))
+                    >= remainder,
+                <Error<T>>::InsufficientEscrow
+            );
+
+            Self::pay_from_escrow(audit_id, &audit.auditing_org, award)?;
+            let refund = remainder.saturating_sub(award);
+            Self::pay_from_escrow(audit_id, &audit.audit_creator, refund)?;
+
+            fee.released = fee.released.saturating_add(award);
+            fee.status = AuditFeeStatus::Resolved;
+            <AuditFees<T>>::insert(audit_id, fee);
+
+            Self::deposit_event(Event::FeeDisputeResolved(audit_id, award, refund));
+            Ok(().into())
+        }
+
+        /// Schedule recurring audits
+        ///
+        /// Arguments:
+        /// - `auditing_org` account_id of the auditing_org of each audit
+        /// - `framework` the audit framework each audit is carried out against, at its latest version when the audit is requested
+        /// - `subjects` what is being audited. DIDs, assets and processes must exist.
+        /// - `cadence` blocks between the due dates of consecutive audits. At least `MinCadence`.
+        /// - `lead_time` blocks before the due date that each audit is requested. At least one block.
+        /// - `first_due` the block the first audit is due
+        #[pallet::weight(<T as Config>::WeightInfo::create_audit_schedule(subjects.len() as u32))]
+        pub fn create_audit_schedule(
+            origin: OriginFor<T>,
+            auditing_org: T::AccountId,
+            framework: Option<T::FrameworkId>,
+            subjects: Vec<
+                AuditSubject<
+                    T::AssetRegistryId,
+                    T::AssetId,
+                    T::RegistryId,
+                    T::DefinitionId,
+                    T::ProcessId,
+                    Vec<u8>,
+                >,
+            >,
+            cadence: T::BlockNumber,
+            lead_time: T::BlockNumber,
+            first_due: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            if let Some(framework_id) = framework {
+                ensure!(
+                    <Frameworks<T>>::contains_key(framework_id),
+                    <Error<T>>::FrameworkNotFound
+                );
+            }
+            ensure!(
+                cadence >= T::MinCadence::get() && !lead_time.is_zero() && lead_time <= cadence,
+                <Error<T>>::InvalidCadence
+            );
+            let schedule_count = <AuditScheduleCount<T>>::get(&group_account);
+            ensure!(
+                schedule_count < T::AuditScheduleLimit::get(),
+                <Error<T>>::AuditScheduleLimitExceeded
+            );
+            ensure!(
+                first_due >= lead_time
+                    && first_due - lead_time > <frame_system::Module<T>>::block_number(),
+                <Error<T>>::ScheduleStartsInPast
+            );
+            let subjects = Self::validate_subjects(subjects)?;
+
+            let schedule_id = next_id!(NextAuditScheduleId<T>, T);
+
+            let schedule = AuditSchedule {
+                proposal_id,
+                audit_creator: group_account.clone(),
+                auditing_org: auditing_org.clone(),
+                framework,
+                subjects,
+                cadence,
+                lead_time,
+                next_due: first_due,
+                last_audit: None,
+                missed: 0,
+                status: AuditScheduleStatus::Active,
+            };
+
+            <AuditSchedules<T>>::insert(&schedule_id, schedule);
+            <AuditSchedulesByCreator<T>>::insert(&group_account, &schedule_id, ());
+            <AuditSchedulesByAuditingOrg<T>>::insert(&auditing_org, &schedule_id, ());
+            <AuditScheduleCount<T>>::insert(&group_account, schedule_count + 1);
+            Self::queue_audit_request(first_due - lead_time, schedule_id);
+
+            Self::deposit_event(Event::AuditScheduleCreated(
+                group_account,
+                proposal_id,
+                schedule_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Stop requesting the audits of a schedule. Audits that fall due while it is paused are skipped.
+        ///
+        /// Arguments:
+        /// - `schedule_id`
+        #[pallet::weight(<T as Config>::WeightInfo::pause_audit_schedule())]
+        pub fn pause_audit_schedule(
+            origin: OriginFor<T>,
+            schedule_id: T::AuditScheduleId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_schedule = <AuditSchedules<T>>::get(schedule_id);
+            ensure!(maybe_schedule.is_some(), <Error<T>>::AuditScheduleNotFound);
+            let mut schedule = maybe_schedule.unwrap();
+            ensure!(
+                schedule.audit_creator == group_account,
+                <Error<T>>::NotCreator
+            );
+            ensure!(
+                schedule.status == AuditScheduleStatus::Active,
+                <Error<T>>::AuditScheduleNotActive
+            );
+
+            schedule.status = AuditScheduleStatus::Paused;
+            <AuditSchedules<T>>::insert(&schedule_id, schedule);
+
+            Self::deposit_event(Event::AuditSchedulePaused(
+                group_account,
+                proposal_id,
+                schedule_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Resume requesting the audits of a paused schedule, from the next due date.
+        ///
+        /// Arguments:
+        /// - `schedule_id`
+        #[pallet::weight(<T as Config>::WeightInfo::resume_audit_schedule())]
+        pub fn resume_audit_schedule(
+            origin: OriginFor<T>,
+            schedule_id: T::AuditScheduleId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_schedule = <AuditSchedules<T>>::get(schedule_id);
+            ensure!(maybe_schedule.is_some(), <Error<T>>::AuditScheduleNotFound);
+            let mut schedule = maybe_schedule.unwrap();
+            ensure!(
+                schedule.audit_creator == group_account,
+                <Error<T>>::NotCreator
+            );
+            ensure!(
+                schedule.status == AuditScheduleStatus::Paused,
+                <Error<T>>::AuditScheduleNotPaused
+            );
+
+            schedule.status = AuditScheduleStatus::Active;
+            <AuditSchedules<T>>::insert(&schedule_id, schedule);
+
+            Self::deposit_event(Event::AuditScheduleResumed(
+                group_account,
+                proposal_id,
+                schedule_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Cancel a schedule. Audits it has already requested are unaffected.
+        ///
+        /// Arguments:
+        /// - `schedule_id`
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_audit_schedule())]
+        pub fn cancel_audit_schedule(
+            origin: OriginFor<T>,
+            schedule_id: T::AuditScheduleId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_schedule = <AuditSchedules<T>>::get(schedule_id);
+            ensure!(maybe_schedule.is_some(), <Error<T>>::AuditScheduleNotFound);
+            let mut schedule = maybe_schedule.unwrap();
+            ensure!(
+                schedule.audit_creator == group_account,
+                <Error<T>>::NotCreator
+            );
+            ensure!(
+                schedule.status != AuditScheduleStatus::Cancelled,
+                <Error<T>>::AuditScheduleCancelled
+            );
+
+            //a request moved on from a full block is dropped when it is drained, as the schedule is cancelled
+            <AuditScheduleQueue<T>>::remove(
+                schedule.next_due.saturating_sub(schedule.lead_time),
+                &schedule_id,
+            );
+            <AuditScheduleCount<T>>::mutate(&group_account, |schedule_count| {
+                *schedule_count = schedule_count.saturating_sub(1)
+            });
+            schedule.status = AuditScheduleStatus::Cancelled;
+            <AuditSchedules<T>>::insert(&schedule_id, schedule);
+
+            Self::deposit_event(Event::AuditScheduleCancelled(
+                group_account,
+                proposal_id,
+                schedule_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Reopen a completed audit to correct it. Either the audit creator or the auditing org requests it, then the other party approves it by calling this too.
+        /// The audit is then in progress again. Certificates already issued and fees already released are not affected.
+        ///
+        /// Arguments:
+        /// - `audit_id` the completed audit
+        /// - `reason` why the audit is reopened, recorded in the audit trail
+        #[pallet::weight(<T as Config>::WeightInfo::reopen_audit(reason.len() as u32))]
+        pub fn reopen_audit(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            reason: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let mut audit = maybe_audit.unwrap();
+            ensure!(
+                audit.status == AuditStatus::Completed
+                    || audit.status == AuditStatus::CompletedWithOpenFindings,
+                <Error<T>>::AuditIsNotFinished
+            );
+            ensure!(
+                audit.audit_creator == group_account || audit.auditing_org == group_account,
+                <Error<T>>::NotAuditParty
+            );
+            let bounded_reason = enforce_limit!(reason);
+
+            match <AuditReopenRequests<T>>::get(audit_id) {
+                Some(requested_by) => {
+                    ensure!(
+                        requested_by != group_account,
+                        <Error<T>>::ReopenAlreadyRequested
+                    );
+                    <AuditReopenRequests<T>>::remove(audit_id);
+                    audit.status = AuditStatus::InProgress;
+                    <Audits<T>>::insert(audit_id, audit);
+                    Self::record_change(
+                        audit_id,
+                        Some(proposal_id),
+                        &group_account,
+                        AuditChange::Reopened(bounded_reason),
+                    );
+
+                    Self::deposit_event(Event::AuditReopened(group_account, proposal_id, audit_id));
+                }
+                None => {
+                    <AuditReopenRequests<T>>::insert(audit_id, &group_account);
+                    Self::record_change(
+                        audit_id,
+                        Some(proposal_id),
+                        &group_account,
+                        AuditChange::ReopenRequested(bounded_reason),
+                    );
+
+                    Self::deposit_event(Event::AuditReopenRequested(
+                        group_account,
+                        proposal_id,
+                        audit_id,
+                    ));
+                }
+            }
+            Ok(().into())
+        }
+
+        /// Withdraw a request to reopen a completed audit before the other party approves it.
+        ///
+        /// Arguments:
+        /// - `audit_id` the completed audit
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_reopen_request())]
+        pub fn withdraw_reopen_request(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            ensure!(
+                <AuditReopenRequests<T>>::get(audit_id).as_ref() == Some(&group_account),
+                <Error<T>>::ReopenNotRequested
+            );
+
+            <AuditReopenRequests<T>>::remove(audit_id);
+            Self::record_change(
+                audit_id,
+                Some(proposal_id),
+                &group_account,
+                AuditChange::ReopenRequestWithdrawn,
+            );
+
+            Self::deposit_event(Event::AuditReopenRequestWithdrawn(
+                group_account,
+                proposal_id,
+                audit_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Replace an observation with a new one. The superseded observation is kept for history but is no longer counted in coverage or reports.
+        /// If the superseded observation was an open finding, the finding and any open corrective action carry over to the new observation when it is also non-compliant, and are withdrawn otherwise.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit, which must be in progress
+        /// - `control_point_id` the control point the observation was made against
+        /// - `observation_id` the observation to replace
+        /// - `compliance` compliance of the new observation
+        /// - `procedural_note_hash` procedural note of the new observation
+        /// - `reason` why the observation is replaced, recorded in the audit trail
+        #[pallet::weight(<T as Config>::WeightInfo::supersede_observation(reason.len() as u32))]
+        pub fn supersede_observation(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            control_point_id: T::ControlPointId,
+            observation_id: T::ObservationId,
+            compliance: Option<Compliance>,
+            procedural_note_hash: Option<[u8; 32]>,
+            reason: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+            ensure!(
+                audit.status == AuditStatus::InProgress,
+                <Error<T>>::AuditIsNotInProgress
+            );
+            ensure!(audit.auditors.is_some(), <Error<T>>::AuditorNotAssigned);
+            ensure!(
+                *audit.auditors.as_ref().unwrap() == group_account,
+                <Error<T>>::NotAuditor
+            );
+            let maybe_observation =
+                <Observations<T>>::get((audit_id, control_point_id), observation_id);
+            ensure!(maybe_observation.is_some(), <Error<T>>::ObservationNotFound);
+            let superseded = maybe_observation.unwrap();
+            ensure!(
+                !<SupersededObservations<T>>::contains_key(observation_id),
+                <Error<T>>::ObservationSuperseded
+            );
+            let bounded_reason = enforce_limit!(reason);
+
+            let new_observation_id = next_id!(NextObservationId<T>, T);
+
+            let non_compliant = compliance == Some(Compliance::NonCompliant);
+            let maybe_corrective_action =
+                <CorrectiveActions<T>>::get(audit_id, (control_point_id, observation_id));
+            let open_finding = superseded.compliance == Some(Compliance::NonCompliant)
+                && maybe_corrective_action
+                    .as_ref()
+                    .map_or(true, |corrective_action| {
+                        corrective_action.status == CorrectiveActionStatus::Open
+                    });
+            if open_finding {
+                if let Some(corrective_action) = maybe_corrective_action {
+                    <CorrectiveActions<T>>::remove(audit_id, (control_point_id, observation_id));
+                    if non_compliant {
+                        <CorrectiveActions<T>>::insert(
+                            audit_id,
+                            (control_point_id, new_observation_id),
+                            corrective_action,
+                        );
+                    }
+                }
+                if !non_compliant {
+                    <OpenFindings<T>>::mutate(audit_id, |open_findings| {
+                        *open_findings = open_findings.saturating_sub(1)
+                    });
+                }
+            } else if non_compliant {
+                <OpenFindings<T>>::mutate(audit_id, |open_findings| {
+                    *open_findings = open_findings.saturating_add(1)
+                });
+            }
+
+            let observation = Observation {
+                proposal_id,
+                compliance,
+                procedural_note_hash,
+            };
+            <Observations<T>>::insert(
+                (&audit_id, &control_point_id),
+                &new_observation_id,
+                observation,
+            );
+            <ObservationByProposal<T>>::insert(
+                &proposal_id,
+                (audit_id, control_point_id, new_observation_id),
+            );
+            <SupersededObservations<T>>::insert(observation_id, new_observation_id);
+            Self::record_change(
+                audit_id,
+                Some(proposal_id),
+                &group_account,
+                AuditChange::ObservationSuperseded(
+                    control_point_id,
+                    observation_id,
+                    new_observation_id,
+                    bounded_reason,
+                ),
+            );
+
+            Self::deposit_event(Event::ObservationSuperseded(
+                group_account,
+                proposal_id,
+                audit_id,
+                control_point_id,
+                observation_id,
+                new_observation_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Register the X25519 public key that confidential notes are shared with. A new key replaces the old one.
+        /// Notes already shared under the old key must be granted again to be read with the new one.
+        ///
+        /// Arguments:
+        /// - `key` the X25519 public key. Keys of small order, such as all zeroes, are rejected.
+        #[pallet::weight(<T as Config>::WeightInfo::register_note_key())]
+        pub fn register_note_key(
+            origin: OriginFor<T>,
+            key: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let mut masked_key = key;
+            masked_key[31] &= 0x7f;
+            ensure!(
+                !LOW_ORDER_NOTE_KEYS.contains(&masked_key),
+                <Error<T>>::InvalidNoteKey
+            );
+
+            <NoteKeys<T>>::insert(&sender, key);
+
+            Self::deposit_event(Event::NoteKeyRegistered(sender));
+            Ok(().into())
+        }
+
+        /// Attach a confidential note to an audit. The note is encrypted client-side with a symmetric key and its ciphertext is kept off chain.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit, which must have been accepted
+        /// - `ciphertext_hash` blake2-256 hash of the ciphertext of the note
+        /// - `observation` the control point and observation the note is about, if any
+        /// - `envelopes` the symmetric key encrypted to the registered key of each recipient.
+        ///   Recipients must be members of the audit creator, the auditing org or the auditors.
+        #[pallet::weight(<T as Config>::WeightInfo::create_confidential_note(envelopes.len() as u32))]
+        pub fn create_confidential_note(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            ciphertext_hash: [u8; 32],
+            observation: Option<(T::ControlPointId, T::ObservationId)>,
+            envelopes: Vec<(T::AccountId, KeyEnvelope)>,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+            ensure!(
+                audit.status != AuditStatus::Requested && audit.status != AuditStatus::Rejected,
+                <Error<T>>::AuditIsNotAcceptedOrInProgressOrCompleted
+            );
+            ensure!(
+                Self::is_party_or_auditor(&audit, &group_account),
+                <Error<T>>::NotAuditPartyOrAuditor
+            );
+            ensure!(
+                envelopes.len() <= T::NoteRecipientLimit::get() as usize,
+                <Error<T>>::NoteRecipientLimitExceeded
+            );
+            ensure!(
+                !<ConfidentialNotes<T>>::contains_key(audit_id, ciphertext_hash),
+                <Error<T>>::ConfidentialNoteExists
+            );
+            if let Some((control_point_id, observation_id)) = observation {
+                ensure!(
+                    <Observations<T>>::contains_key((audit_id, control_point_id), observation_id),
+                    <Error<T>>::ObservationNotFound
+                );
+            }
+            for (recipient, envelope) in envelopes.iter() {
+                Self::check_note_recipient(&audit, recipient, envelope)?;
+            }
+
+            let note = ConfidentialNote {
+                proposal_id,
+                author: group_account.clone(),
+                observation,
+            };
+            <ConfidentialNotes<T>>::insert(audit_id, ciphertext_hash, note);
+            for (recipient, envelope) in envelopes {
+                <NoteEnvelopes<T>>::insert((audit_id, ciphertext_hash), recipient, envelope);
+            }
+
+            Self::deposit_event(Event::ConfidentialNoteCreated(
+                group_account,
+                proposal_id,
+                audit_id,
+                ciphertext_hash,
+            ));
+            Ok(().into())
+        }
+
+        /// Share the key of a confidential note with a recipient, replacing their envelope if they already have one.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit
+        /// - `ciphertext_hash` the note
+        /// - `recipient` a member of the audit creator, the auditing org or the auditors
+        /// - `envelope` the symmetric key encrypted to the registered key of the recipient
+        #[pallet::weight(<T as Config>::WeightInfo::grant_note_access())]
+        pub fn grant_note_access(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            ciphertext_hash: [u8; 32],
+            recipient: T::AccountId,
+            envelope: KeyEnvelope,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_audit = <Audits<T>>::get(audit_id);
+            ensure!(maybe_audit.is_some(), <Error<T>>::AuditNotFound);
+            let audit = maybe_audit.unwrap();
+            let maybe_note = <ConfidentialNotes<T>>::get(audit_id, ciphertext_hash);
+            ensure!(maybe_note.is_some(), <Error<T>>::ConfidentialNoteNotFound);
+            ensure!(
+                maybe_note.unwrap().author == group_account,
+                <Error<T>>::NotNoteAuthor
+            );
+            Self::check_note_recipient(&audit, &recipient, &envelope)?;
+
+            <NoteEnvelopes<T>>::insert((audit_id, ciphertext_hash), &recipient, envelope);
+
+            Self::deposit_event(Event::NoteAccessGranted(
+                group_account,
+                proposal_id,
+                audit_id,
+                ciphertext_hash,
+                recipient,
+            ));
+            Ok(().into())
+        }
+
+        /// Remove the envelope of a recipient of a confidential note. A recipient who already decrypted the key keeps it,
+        /// so the note should be encrypted under a new key to exclude them from later versions.
+        ///
+        /// Arguments:
+        /// - `audit_id` the audit
+        /// - `ciphertext_hash` the note
+        /// - `recipient` the recipient to remove
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_note_access())]
+        pub fn revoke_note_access(
+            origin: OriginFor<T>,
+            audit_id: T::AuditId,
+            ciphertext_hash: [u8; 32],
+            recipient: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let (_, proposal_id, _, _, group_account) =
+                <T as groups::Config>::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let maybe_note = <ConfidentialNotes<T>>::get(audit_id, ciphertext_hash);
+            ensure!(maybe_note.is_some(), <Error<T>>::ConfidentialNoteNotFound);
+            ensure!(
+                maybe_note.unwrap().author == group_account,
+                <Error<T>>::NotNoteAuthor
+            );
+            ensure!(
+                <NoteEnvelopes<T>>::contains_key((audit_id, ciphertext_hash), &recipient),
+                <Error<T>>::NoteAccessNotFound
+            );
+
+            <NoteEnvelopes<T>>::remove((audit_id, ciphertext_hash), &recipient);
+
+            Self::deposit_event(Event::NoteAccessRevoked(
+                group_account,
+                proposal_id,
+                audit_id,
+                ciphertext_hash,
+                recipient,
+            ));
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Module<T> {
+        // -- rpc api functions --
+
+        pub fn get_audits_by_creator(
+            account: T::AccountId,
+        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
+            let mut audits = Vec::new();
+            <AuditsByCreator<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
+                let audit = <Audits<T>>::get(audit_id).unwrap();
+                audits.push((audit_id, audit))
+            });
+            audits
+        }
+
+        pub fn get_audits_by_auditing_org(
+            account: T::AccountId,
+        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
+            let mut audits = Vec::new();
+            <AuditsByAuditingOrg<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
+                let audit = <Audits<T>>::get(audit_id).unwrap();
+                audits.push((audit_id, audit))
+            });
+            audits
+        }
+
+        pub fn get_audits_by_auditors(
+            account: T::AccountId,
+        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
+            let mut audits = Vec::new();
+            <AuditsByAuditors<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
+                let audit = <Audits<T>>::get(audit_id).unwrap();
+                audits.push((audit_id, audit))
+            });
+            audits
+        }
+
+        pub fn get_linked_audits(
+            audit_id: T::AuditId,
+        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
+            let mut audits = Vec::new();
+            <LinkedAudits<T>>::iter_prefix(audit_id).for_each(|(child_audit_id, _)| {
+                let audit_maybe = <Audits<T>>::get(child_audit_id);
+                if let Some(audit) = audit_maybe {
+                    audits.push((child_audit_id, audit));
+                }
+            });
+            audits
+        }
+
+        pub fn get_audit(audit_id: T::AuditId) -> Option<Audit<T::AccountId, T::ProposalId>> {
+            <Audits<T>>::get(audit_id)
+        }
+
+        pub fn get_audit_trail(audit_id: T::AuditId) -> Vec<AuditTrailEntryOf<T>> {
+            (0..<AuditTrailLength<T>>::get(audit_id))
+                .filter_map(|index| <AuditTrail<T>>::get(audit_id, index))
+                .collect()
+        }
+
+        pub fn get_audit_by_proposal(
+            proposal_id: T::ProposalId,
+        ) -> Option<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
+            <AuditByProposal<T>>::get(proposal_id)
+                .map(|audit_id| (audit_id, <Audits<T>>::get(audit_id).unwrap()))
+        }
+
+        pub fn get_observation(
+            audit_id: T::AuditId,
+            control_point_id: T::ControlPointId,
+            observation_id: T::ObservationId,
+        ) -> Option<(
+            Observation<T::ProposalId>,
+            Vec<(
+                T::EvidenceId,
+                Evidence<
+                    T::ProposalId,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    BoundedVec<u8, <T as Config>::UrlLimit>,
+                >,
+            )>,
+        )> {
+            <Observations<T>>::get((audit_id, control_point_id), observation_id).map(
+                |observation| {
+                    let mut evidences = Vec::new();
+                    <EvidenceLinksByObservation<T>>::iter_prefix(observation_id).for_each(
+                        |(evidence_id, _)| {
+                            if let Some(evidence) = <Evidences<T>>::get(audit_id, evidence_id) {
+                                evidences.push((evidence_id, evidence));
+                            }
+                        },
+                    );
+                    (observation, evidences)
+                },
+            )
+        }
+
+        pub fn get_observation_by_proposal(
+            proposal_id: T::ProposalId,
+        ) -> Option<(
+            T::ObservationId,
+            Observation<T::ProposalId>,
+            Vec<(
+                T::EvidenceId,
+                Evidence<
+                    T::ProposalId,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    BoundedVec<u8, <T as Config>::UrlLimit>,
+                >,
+            )>,
+        )> {
+            <ObservationByProposal<T>>::get(proposal_id).map(
+                |(audit_id, control_point_id, observation_id)| {
+                    let mut evidences = Vec::new();
+                    <EvidenceLinksByObservation<T>>::iter_prefix(observation_id).for_each(
+                        |(evidence_id, _)| {
+                            if let Some(evidence) = <Evidences<T>>::get(audit_id, evidence_id) {
+                                evidences.push((evidence_id, evidence));
+                            }
+                        },
+                    );
+                    (
+                        observation_id,
+                        <Observations<T>>::get((audit_id, control_point_id), observation_id)
+                            .unwrap(),
+                        evidences,
+                    )
+                },
+            )
+        }
+
+        pub fn get_observation_by_control_point(
+            audit_id: T::AuditId,
+            control_point_id: T::ControlPointId,
+        ) -> Vec<(
+            T::ObservationId,
+            Observation<T::ProposalId>,
+            Vec<(
+                T::EvidenceId,
+                Evidence<
+                    T::ProposalId,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    BoundedVec<u8, <T as Config>::UrlLimit>,
+                >,
+            )>,
+        )> {
+            let mut observations = Vec::new();
+            <Observations<T>>::iter_prefix((audit_id, control_point_id)).for_each(
+                |(observation_id, observation)| {
+                    let mut evidences = Vec::new();
+                    <EvidenceLinksByObservation<T>>::iter_prefix(observation_id).for_each(
+                        |(evidence_id, _)| {
+                            if let Some(evidence) = <Evidences<T>>::get(audit_id, evidence_id) {
+                                evidences.push((evidence_id, evidence));
+                            }
+                        },
+                    );
+                    observations.push((observation_id, observation, evidences))
+                },
+            );
+            observations
+        }
+
+        pub fn get_evidence(
+            audit_id: T::AuditId,
+            evidence_id: T::EvidenceId,
+        ) -> Option<
+            Evidence<
+                T::ProposalId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::UrlLimit>,
+            >,
+        > {
+            <Evidences<T>>::get(audit_id, evidence_id)
+        }
+
+        pub fn get_evidence_by_audit(
+            audit_id: T::AuditId,
+        ) -> Vec<(
+            T::EvidenceId,
+            Evidence<
+                T::ProposalId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::UrlLimit>,
+            >,
+        )> {
+            let mut evidences = Vec::new();
+            <Evidences<T>>::iter_prefix(audit_id)
+                .for_each(|(evidence_id, evidence)| evidences.push((evidence_id, evidence)));
+            evidences
+        }
+
+        pub fn get_evidence_by_proposal(
+            proposal_id: T::ProposalId,
+        ) -> Option<(
+            T::EvidenceId,
+            Evidence<
+                T::ProposalId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
                 BoundedVec<u8, <T as Config>::UrlLimit>,
             >,
         )> {
@@ -1264,6 +3284,762 @@ pub mod pallet {
             evidence_links
         }
 
+        pub fn get_audit_subjects(audit_id: T::AuditId) -> Vec<AuditSubjectOf<T>> {
+            <AuditSubjects<T>>::get(audit_id)
+        }
+
+        pub fn get_audits_by_subject(
+            subject: AuditSubject<
+                T::AssetRegistryId,
+                T::AssetId,
+                T::RegistryId,
+                T::DefinitionId,
+                T::ProcessId,
+                Vec<u8>,
+            >,
+        ) -> Vec<(T::AuditId, Audit<T::AccountId, T::ProposalId>)> {
+            let subject = match subject {
+                AuditSubject::Did(did) => AuditSubject::Did(did),
+                AuditSubject::Asset(registry_id, asset_id) => {
+                    AuditSubject::Asset(registry_id, asset_id)
+                }
+                AuditSubject::Process(registry_id, definition_id, process_id) => {
+                    AuditSubject::Process(registry_id, definition_id, process_id)
+                }
+                AuditSubject::Site(site) => match site.try_into() {
+                    Ok(site) => AuditSubject::Site(site),
+                    //a site longer than the limit cannot be in scope of any audit
+                    Err(_) => return Vec::new(),
+                },
+            };
+            let mut audits = Vec::new();
+            <AuditsBySubject<T>>::iter_prefix(subject).for_each(|(audit_id, _)| {
+                if let Some(audit) = <Audits<T>>::get(audit_id) {
+                    audits.push((audit_id, audit));
+                }
+            });
+            audits
+        }
+
+        /// A version of a framework with its control points. The latest version if `version` is `None`.
+        pub fn get_framework(
+            framework_id: T::FrameworkId,
+            version: Option<u32>,
+        ) -> Option<(
+            AuditFramework<T::AccountId, BoundedVec<u8, <T as Config>::NameLimit>>,
+            u32,
+            Vec<(
+                T::ControlPointId,
+                ControlPoint<T::ControlPointId, BoundedVec<u8, <T as Config>::NameLimit>>,
+            )>,
+        )> {
+            <Frameworks<T>>::get(framework_id).and_then(|framework| {
+                let version = version.unwrap_or(framework.latest_version);
+                if version == 0 || version > framework.latest_version {
+                    return None;
+                }
+                let mut control_points = Vec::new();
+                <FrameworkControlPoints<T>>::iter_prefix((framework_id, version))
+                    .for_each(|control_point| control_points.push(control_point));
+                control_points.sort_by_key(|(control_point_id, _)| *control_point_id);
+                Some((framework, version, control_points))
+            })
+        }
+
+        /// The observations made against each control point of the framework version referenced by the audit.
+        /// Control points without observations are included. Superseded observations are not counted.
+        pub fn get_control_point_coverage(
+            audit_id: T::AuditId,
+        ) -> Option<(
+            T::FrameworkId,
+            u32,
+            Vec<(T::ControlPointId, ControlPointCoverage)>,
+        )> {
+            <AuditFrameworks<T>>::get(audit_id).map(|(framework_id, version)| {
+                let mut coverage = Vec::new();
+                <FrameworkControlPoints<T>>::iter_prefix((framework_id, version)).for_each(
+                    |(control_point_id, _)| {
+                        let mut control_point_coverage = ControlPointCoverage::default();
+                        <Observations<T>>::iter_prefix((audit_id, control_point_id))
+                            .filter(|(observation_id, _)| {
+                                !<SupersededObservations<T>>::contains_key(observation_id)
+                            })
+                            .for_each(|(_, observation)| {
+                                control_point_coverage.observations += 1;
+                                match observation.compliance {
+                                    Some(Compliance::Compliant) => {
+                                        control_point_coverage.compliant += 1
+                                    }
+                                    Some(Compliance::NonCompliant) => {
+                                        control_point_coverage.non_compliant += 1
+                                    }
+                                    Some(Compliance::NotApplicable) => {
+                                        control_point_coverage.not_applicable += 1
+                                    }
+                                    None => (),
+                                }
+                            });
+                        coverage.push((control_point_id, control_point_coverage));
+                    },
+                );
+                coverage.sort_by_key(|(control_point_id, _)| *control_point_id);
+                (framework_id, version, coverage)
+            })
+        }
+
+        #[allow(clippy::type_complexity)]
+        pub fn get_corrective_actions(
+            audit_id: T::AuditId,
+        ) -> Vec<(
+            T::ControlPointId,
+            T::ObservationId,
+            CorrectiveAction<T::AccountId, T::ProposalId, T::BlockNumber, T::EvidenceId>,
+        )> {
+            let mut corrective_actions = Vec::new();
+            <CorrectiveActions<T>>::iter_prefix(audit_id).for_each(
+                |((control_point_id, observation_id), corrective_action)| {
+                    corrective_actions.push((control_point_id, observation_id, corrective_action))
+                },
+            );
+            corrective_actions
+        }
+
+        #[allow(clippy::type_complexity)]
+        pub fn get_open_corrective_actions_by_creator(
+            account: T::AccountId,
+        ) -> Vec<(
+            T::AuditId,
+            T::ControlPointId,
+            T::ObservationId,
+            CorrectiveAction<T::AccountId, T::ProposalId, T::BlockNumber, T::EvidenceId>,
+        )> {
+            let mut corrective_actions = Vec::new();
+            <AuditsByCreator<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
+                Self::push_open_corrective_actions(audit_id, &mut corrective_actions)
+            });
+            corrective_actions
+        }
+
+        #[allow(clippy::type_complexity)]
+        pub fn get_open_corrective_actions_by_auditing_org(
+            account: T::AccountId,
+        ) -> Vec<(
+            T::AuditId,
+            T::ControlPointId,
+            T::ObservationId,
+            CorrectiveAction<T::AccountId, T::ProposalId, T::BlockNumber, T::EvidenceId>,
+        )> {
+            let mut corrective_actions = Vec::new();
+            <AuditsByAuditingOrg<T>>::iter_prefix(account).for_each(|(audit_id, _)| {
+                Self::push_open_corrective_actions(audit_id, &mut corrective_actions)
+            });
+            corrective_actions
+        }
+
+        #[allow(clippy::type_complexity)]
+        pub fn get_certificates_by_did(
+            did: Did,
+        ) -> Vec<(
+            T::AuditId,
+            Certificate<
+                T::ClaimId,
+                T::Moment,
+                T::AuditId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+            >,
+        )> {
+            let mut certificates = Vec::new();
+            <Certificates<T>>::iter_prefix(did)
+                .for_each(|(audit_id, certificate)| certificates.push((audit_id, certificate)));
+            certificates
+        }
+
+        pub fn get_audit_fee(audit_id: T::AuditId) -> Option<AuditFee<BalanceOf<T>>> {
+            <AuditFees<T>>::get(audit_id)
+        }
+
+        /// Compliance of an audit by control point, with a score weighted by control point severity.
+        /// Control points of the referenced framework version are included even when they have no observations.
+        /// Superseded observations are left out.
+        #[allow(clippy::type_complexity)]
+        pub fn get_audit_report(
+            audit_id: T::AuditId,
+        ) -> Option<
+            AuditReport<
+                T::AccountId,
+                T::ProposalId,
+                T::AuditId,
+                T::ControlPointId,
+                T::ObservationId,
+                T::EvidenceId,
+                T::FrameworkId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::UrlLimit>,
+            >,
+        > {
+            let audit = <Audits<T>>::get(audit_id)?;
+            let framework = <AuditFrameworks<T>>::get(audit_id);
+
+            let mut control_points = Vec::new();
+            match framework {
+                Some((framework_id, version)) => {
+                    <FrameworkControlPoints<T>>::iter_prefix((framework_id, version)).for_each(
+                        |(control_point_id, control_point)| {
+                            control_points.push((control_point_id, Some(control_point.severity)))
+                        },
+                    );
+                }
+                None => {
+                    //without a framework the control points are only known from the observations
+                    <ObservedControlPoints<T>>::iter_prefix(audit_id).for_each(
+                        |(control_point_id, _)| control_points.push((control_point_id, None)),
+                    );
+                }
+            }
+            control_points.sort_by_key(|(control_point_id, _)| *control_point_id);
+
+            let mut totals = ControlPointCoverage::default();
+            let mut earned = 0u32;
+            let mut possible = 0u32;
+            let control_points = control_points
+                .into_iter()
+                .map(|(control_point_id, severity)| {
+                    let weight = severity.as_ref().map_or(1, |severity| severity.weight());
+                    let mut coverage = ControlPointCoverage::default();
+                    let observations: Vec<_> =
+                        Self::get_observation_by_control_point(audit_id, control_point_id)
+                            .into_iter()
+                            .filter(|(observation_id, _, _)| {
+                                !<SupersededObservations<T>>::contains_key(observation_id)
+                            })
+                            .collect();
+                    observations.iter().for_each(|(_, observation, _)| {
+                        coverage.observations += 1;
+                        match observation.compliance {
+                            Some(Compliance::Compliant) => {
+                                coverage.compliant += 1;
+                                earned = earned.saturating_add(weight);
+                                possible = possible.saturating_add(weight);
+                            }
+                            Some(Compliance::NonCompliant) => {
+                                coverage.non_compliant += 1;
+                                possible = possible.saturating_add(weight);
+                            }
+                            Some(Compliance::NotApplicable) => coverage.not_applicable += 1,
+                            None => (),
+                        }
+                    });
+                    totals.observations += coverage.observations;
+                    totals.compliant += coverage.compliant;
+                    totals.non_compliant += coverage.non_compliant;
+                    totals.not_applicable += coverage.not_applicable;
+                    ControlPointReport {
+                        control_point_id,
+                        severity,
+                        coverage,
+                        observations,
+                    }
+                })
+                .collect();
+
+            let score = if possible == 0 {
+                None
+            } else {
+                Some(Permill::from_rational_approximation(earned, possible))
+            };
+
+            let mut linked_audits = Vec::new();
+            <LinkedAudits<T>>::iter_prefix(audit_id)
+                .for_each(|(child_audit_id, _)| linked_audits.push(child_audit_id));
+
+            Some(AuditReport {
+                audit,
+                framework,
+                control_points,
+                totals,
+                score,
+                linked_audits,
+            })
+        }
+
+        pub fn get_upcoming_audits_by_creator(
+            account: T::AccountId,
+        ) -> Vec<(T::AuditScheduleId, AuditScheduleOf<T>)> {
+            Self::upcoming_audits(
+                <AuditSchedulesByCreator<T>>::iter_prefix(account)
+                    .map(|(schedule_id, _)| schedule_id),
+            )
+        }
+
+        pub fn get_upcoming_audits_by_auditing_org(
+            account: T::AccountId,
+        ) -> Vec<(T::AuditScheduleId, AuditScheduleOf<T>)> {
+            Self::upcoming_audits(
+                <AuditSchedulesByAuditingOrg<T>>::iter_prefix(account)
+                    .map(|(schedule_id, _)| schedule_id),
+            )
+        }
+
+        pub fn get_missed_audits(
+            schedule_id: T::AuditScheduleId,
+        ) -> Vec<(T::AuditId, T::BlockNumber)> {
+            <MissedAudits<T>>::iter_prefix(schedule_id).collect()
+        }
+
+        pub fn get_note_key(account: T::AccountId) -> Option<[u8; 32]> {
+            <NoteKeys<T>>::get(account)
+        }
+
+        pub fn get_confidential_notes(
+            audit_id: T::AuditId,
+        ) -> Vec<(
+            [u8; 32],
+            ConfidentialNote<T::AccountId, T::ProposalId, T::ControlPointId, T::ObservationId>,
+        )> {
+            <ConfidentialNotes<T>>::iter_prefix(audit_id).collect()
+        }
+
+        pub fn get_note_envelopes(
+            audit_id: T::AuditId,
+            ciphertext_hash: [u8; 32],
+        ) -> Vec<(T::AccountId, KeyEnvelope)> {
+            <NoteEnvelopes<T>>::iter_prefix((audit_id, ciphertext_hash)).collect()
+        }
+
         // -- private functions --
+
+        /// The calling account, or the group account of an approved proposal with its proposal id
+        fn ensure_account_or_approved(
+            origin: OriginFor<T>,
+        ) -> Result<(T::AccountId, Option<T::ProposalId>), DispatchError> {
+            let either =
+                <T as groups::Config>::GroupsOriginAccountOrApproved::ensure_origin(origin)?;
+            Ok(match either {
+                Either::Left(account_id) => (account_id, None),
+                Either::Right((_, proposal_id, _, _, group_account)) => {
+                    (group_account, Some(proposal_id))
+                }
+            })
+        }
+
+        fn upcoming_audits(
+            schedule_ids: impl Iterator<Item = T::AuditScheduleId>,
+        ) -> Vec<(T::AuditScheduleId, AuditScheduleOf<T>)> {
+            let mut schedules: Vec<(T::AuditScheduleId, AuditScheduleOf<T>)> = schedule_ids
+                .filter_map(|schedule_id| {
+                    <AuditSchedules<T>>::get(schedule_id)
+                        .filter(|schedule| schedule.status != AuditScheduleStatus::Cancelled)
+                        .map(|schedule| (schedule_id, schedule))
+                })
+                .collect();
+            schedules.sort_by(|(_, a), (_, b)| a.next_due.cmp(&b.next_due));
+            schedules
+        }
+
+        /// Writes a new Requested audit. Audits without a proposal_id (joint or scheduled) are not indexed by proposal.
+        fn insert_audit(
+            proposal_id: Option<T::ProposalId>,
+            audit_creator: &T::AccountId,
+            auditing_org: &T::AccountId,
+            framework: Option<(T::FrameworkId, u32)>,
+            subjects: Vec<AuditSubjectOf<T>>,
+        ) -> Result<T::AuditId, Error<T>> {
+            let audit_id = next_id!(NextAuditId<T>, T);
+
+            let audit = Audit {
+                proposal_id: proposal_id.unwrap_or_default(),
+                status: AuditStatus::Requested,
+                audit_creator: audit_creator.clone(),
+                auditing_org: auditing_org.clone(),
+                auditors: None,
+            };
+
+            <Audits<T>>::insert(&audit_id, audit);
+            if let Some(proposal_id) = proposal_id {
+                <AuditByProposal<T>>::insert(&proposal_id, audit_id);
+            }
+            <AuditsByCreator<T>>::insert(audit_creator, &audit_id, ());
+            <AuditsByAuditingOrg<T>>::insert(auditing_org, &audit_id, ());
+            if let Some(framework) = framework {
+                <AuditFrameworks<T>>::insert(&audit_id, framework);
+            }
+            for subject in subjects.iter() {
+                <AuditsBySubject<T>>::insert(subject, &audit_id, ());
+            }
+            <AuditSubjects<T>>::insert(&audit_id, subjects);
+            Self::record_change(
+                audit_id,
+                proposal_id,
+                audit_creator,
+                AuditChange::Status(AuditStatus::Requested),
+            );
+            Ok(audit_id)
+        }
+
+        /// A non-compliant observation that has not been superseded and has no closed corrective action.
+        fn is_open_finding(
+            audit_id: T::AuditId,
+            control_point_id: T::ControlPointId,
+            observation_id: T::ObservationId,
+        ) -> bool {
+            <Observations<T>>::get((audit_id, control_point_id), observation_id)
+                .map_or(false, |observation| {
+                    observation.compliance == Some(Compliance::NonCompliant)
+                })
+                && !<SupersededObservations<T>>::contains_key(observation_id)
+                && <CorrectiveActions<T>>::get(audit_id, (control_point_id, observation_id))
+                    .map_or(true, |corrective_action| {
+                        corrective_action.status == CorrectiveActionStatus::Open
+                    })
+        }
+
+        /// Appends a change to the audit trail of an audit.
+        fn record_change(
+            audit_id: T::AuditId,
+            proposal_id: Option<T::ProposalId>,
+            account: &T::AccountId,
+            change: AuditChange<
+                T::ControlPointId,
+                T::ObservationId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+            >,
+        ) {
+            let index = <AuditTrailLength<T>>::get(audit_id);
+            <AuditTrailLength<T>>::insert(audit_id, index.saturating_add(1));
+            <AuditTrail<T>>::insert(
+                audit_id,
+                index,
+                AuditTrailEntry {
+                    proposal_id,
+                    account: account.clone(),
+                    block_number: <frame_system::Module<T>>::block_number(),
+                    change,
+                },
+            );
+        }
+
+        fn is_party_or_auditor(
+            audit: &Audit<T::AccountId, T::ProposalId>,
+            account: &T::AccountId,
+        ) -> bool {
+            audit.audit_creator == *account
+                || audit.auditing_org == *account
+                || audit.auditors.as_ref() == Some(account)
+        }
+
+        /// Checks that a recipient of a confidential note is, or is a member of, the audit creator, the auditing org or the auditors,
+        /// and that the envelope was sealed to the key they registered.
+        fn check_note_recipient(
+            audit: &Audit<T::AccountId, T::ProposalId>,
+            recipient: &T::AccountId,
+            envelope: &KeyEnvelope,
+        ) -> DispatchResult {
+            let is_member = |party: &T::AccountId| {
+                party == recipient
+                    || <groups::Module<T>>::group_by_account(party).map_or(false, |group_id| {
+                        <groups::Module<T>>::is_member(group_id, recipient)
+                    })
+            };
+            ensure!(
+                is_member(&audit.audit_creator)
+                    || is_member(&audit.auditing_org)
+                    || audit.auditors.as_ref().map_or(false, is_member),
+                <Error<T>>::NotNoteRecipient
+            );
+            let maybe_key = <NoteKeys<T>>::get(recipient);
+            ensure!(maybe_key.is_some(), <Error<T>>::NoteKeyNotRegistered);
+            ensure!(
+                maybe_key.unwrap() == envelope.recipient_key,
+                <Error<T>>::NoteKeyMismatch
+            );
+            Ok(())
+        }
+
+        /// Requests the audit of a schedule that is next due, unless the schedule is paused, and queues the one after.
+        fn request_scheduled_audit(schedule_id: T::AuditScheduleId) {
+            let maybe_schedule = <AuditSchedules<T>>::get(schedule_id);
+            if maybe_schedule.is_none() {
+                return;
+            }
+            let mut schedule = maybe_schedule.unwrap();
+            if schedule.status == AuditScheduleStatus::Cancelled {
+                return;
+            }
+            let due = schedule.next_due;
+
+            if schedule.status == AuditScheduleStatus::Active {
+                let framework = schedule.framework.and_then(|framework_id| {
+                    <Frameworks<T>>::get(framework_id)
+                        .map(|framework| (framework_id, framework.latest_version))
+                });
+                if let Ok(audit_id) = Self::insert_audit(
+                    None,
+                    &schedule.audit_creator,
+                    &schedule.auditing_org,
+                    framework,
+                    schedule.subjects.clone(),
+                ) {
+                    Self::queue_due_check(due, audit_id, schedule_id);
+                    schedule.last_audit = Some((audit_id, due));
+                    Self::deposit_event(Event::ScheduledAuditRequested(schedule_id, audit_id, due));
+                }
+            }
+
+            schedule.next_due = due.saturating_add(schedule.cadence);
+            Self::queue_audit_request(
+                schedule.next_due.saturating_sub(schedule.lead_time),
+                schedule_id,
+            );
+            <AuditSchedules<T>>::insert(schedule_id, schedule);
+        }
+
+        /// Queues the request of the next audit of a schedule at `when`, or the next block with room.
+        fn queue_audit_request(mut when: T::BlockNumber, schedule_id: T::AuditScheduleId) {
+            while <AuditScheduleQueueLength<T>>::get(when) >= T::MaxScheduledPerBlock::get() {
+                when = when.saturating_add(One::one());
+            }
+            <AuditScheduleQueueLength<T>>::mutate(when, |length| {
+                *length = length.saturating_add(1)
+            });
+            <AuditScheduleQueue<T>>::insert(when, schedule_id, ());
+        }
+
+        /// Queues the check of a scheduled audit at its due date, or the next block with room.
+        fn queue_due_check(
+            due: T::BlockNumber,
+            audit_id: T::AuditId,
+            schedule_id: T::AuditScheduleId,
+        ) {
+            let mut when = due;
+            while <ScheduledAuditsDueLength<T>>::get(when) >= T::MaxScheduledPerBlock::get() {
+                when = when.saturating_add(One::one());
+            }
+            <ScheduledAuditsDueLength<T>>::mutate(when, |length| {
+                *length = length.saturating_add(1)
+            });
+            <ScheduledAuditsDue<T>>::insert(when, audit_id, (schedule_id, due));
+        }
+
+        /// Flags a scheduled audit that has not been completed by its due date as missed.
+        fn check_scheduled_audit(
+            schedule_id: T::AuditScheduleId,
+            audit_id: T::AuditId,
+            due: T::BlockNumber,
+        ) {
+            let completed = <Audits<T>>::get(audit_id).map_or(false, |audit| {
+                audit.status == AuditStatus::Completed
+                    || audit.status == AuditStatus::CompletedWithOpenFindings
+            });
+            if completed {
+                return;
+            }
+            <MissedAudits<T>>::insert(schedule_id, audit_id, due);
+            <AuditSchedules<T>>::mutate(schedule_id, |maybe_schedule| {
+                if let Some(schedule) = maybe_schedule {
+                    schedule.missed = schedule.missed.saturating_add(1);
+                }
+            });
+            Self::deposit_event(Event::ScheduledAuditMissed(schedule_id, audit_id, due));
+        }
+
+        #[allow(clippy::type_complexity)]
+        fn push_open_corrective_actions(
+            audit_id: T::AuditId,
+            corrective_actions: &mut Vec<(
+                T::AuditId,
+                T::ControlPointId,
+                T::ObservationId,
+                CorrectiveAction<T::AccountId, T::ProposalId, T::BlockNumber, T::EvidenceId>,
+            )>,
+        ) {
+            <CorrectiveActions<T>>::iter_prefix(audit_id).for_each(
+                |((control_point_id, observation_id), corrective_action)| {
+                    if corrective_action.status == CorrectiveActionStatus::Open {
+                        corrective_actions.push((
+                            audit_id,
+                            control_point_id,
+                            observation_id,
+                            corrective_action,
+                        ))
+                    }
+                },
+            );
+        }
+
+        fn validate_fee(
+            amount: BalanceOf<T>,
+            milestones: Vec<(u32, BalanceOf<T>)>,
+        ) -> Result<AuditFee<BalanceOf<T>>, Error<T>> {
+            ensure!(
+                milestones.len() <= <T as Config>::FeeMilestoneLimit::get() as usize,
+                <Error<T>>::FeeMilestoneLimitExceeded
+            );
+            let mut previous_observations = 0u32;
+            let mut total = BalanceOf::<T>::zero();
+            for (observations, milestone_amount) in milestones.iter() {
+                ensure!(
+                    *observations > previous_observations,
+                    <Error<T>>::InvalidFeeMilestones
+                );
+                previous_observations = *observations;
+                total = total
+                    .checked_add(milestone_amount)
+                    .ok_or(<Error<T>>::InvalidFeeMilestones)?;
+            }
+            ensure!(total <= amount, <Error<T>>::InvalidFeeMilestones);
+            //the escrow account of the audit has to be able to exist
+            ensure!(
+                amount >= <T as groups::Config>::Currency::minimum_balance(),
+                <Error<T>>::FeeBelowMinimumBalance
+            );
+            Ok(AuditFee {
+                amount,
+                milestones,
+                released: Zero::zero(),
+                observations: 0,
+                status: AuditFeeStatus::Pending,
+            })
+        }
+
+        /// The account holding the fee of an audit in escrow.
+        pub fn fee_escrow_account(audit_id: T::AuditId) -> T::AccountId {
+            <T as Config>::FeeEscrowModuleId::get().into_sub_account(audit_id)
+        }
+
+        /// Pay part of the fee held in escrow for an audit. Only the final payment may close the escrow account.
+        fn pay_from_escrow(
+            audit_id: T::AuditId,
+            account: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            let escrow = Self::fee_escrow_account(audit_id);
+            let existence = if <T as groups::Config>::Currency::free_balance(&escrow) > amount {
+                ExistenceRequirement::KeepAlive
+            } else {
+                ExistenceRequirement::AllowDeath
+            };
+            <T as groups::Config>::Currency::transfer(&escrow, account, amount, existence)
+                .map_err(|_| <Error<T>>::InsufficientEscrow)?;
+            Ok(())
+        }
+
+        /// Return whatever is left of the fee in escrow to the audit creator. A fee is only held in escrow once an audit is accepted.
+        /// A refund that cannot be paid out stays in escrow and can be disputed.
+        fn refund_fee(
+            account: &T::AccountId,
+            proposal_id: T::ProposalId,
+            audit_id: T::AuditId,
+            audit_creator: &T::AccountId,
+        ) {
+            if let Some(mut fee) = <AuditFees<T>>::get(audit_id) {
+                if fee.status == AuditFeeStatus::Reserved {
+                    let refund = fee.amount.saturating_sub(fee.released);
+                    if Self::pay_from_escrow(audit_id, audit_creator, refund).is_err() {
+                        return;
+                    }
+                    Self::deposit_event(Event::FeeRefunded(
+                        account.clone(),
+                        proposal_id,
+                        audit_id,
+                        refund,
+                    ));
+                }
+                fee.status = AuditFeeStatus::Refunded;
+                <AuditFees<T>>::insert(audit_id, fee);
+            }
+        }
+
+        #[allow(clippy::type_complexity)]
+        fn validate_subjects(
+            subjects: Vec<
+                AuditSubject<
+                    T::AssetRegistryId,
+                    T::AssetId,
+                    T::RegistryId,
+                    T::DefinitionId,
+                    T::ProcessId,
+                    Vec<u8>,
+                >,
+            >,
+        ) -> Result<Vec<AuditSubjectOf<T>>, Error<T>> {
+            ensure!(!subjects.is_empty(), <Error<T>>::AuditScopeEmpty);
+            ensure!(
+                subjects.len() <= <T as Config>::SubjectLimit::get() as usize,
+                <Error<T>>::SubjectLimitExceeded
+            );
+            let mut validated: Vec<AuditSubjectOf<T>> = Vec::new();
+            for subject in subjects {
+                let subject = match subject {
+                    AuditSubject::Did(did) => {
+                        ensure!(T::DidInfoSource::did_exists(&did), <Error<T>>::DidNotFound);
+                        AuditSubject::Did(did)
+                    }
+                    AuditSubject::Asset(registry_id, asset_id) => {
+                        ensure!(
+                            T::AssetInfoSource::asset_exists(registry_id, asset_id),
+                            <Error<T>>::AssetNotFound
+                        );
+                        AuditSubject::Asset(registry_id, asset_id)
+                    }
+                    AuditSubject::Process(registry_id, definition_id, process_id) => {
+                        ensure!(
+                            T::ProcessInfoSource::process_exists(
+                                registry_id,
+                                definition_id,
+                                process_id
+                            ),
+                            <Error<T>>::ProcessNotFound
+                        );
+                        AuditSubject::Process(registry_id, definition_id, process_id)
+                    }
+                    AuditSubject::Site(site) => AuditSubject::Site(enforce_limit!(site)),
+                };
+                ensure!(!validated.contains(&subject), <Error<T>>::DuplicateSubject);
+                validated.push(subject);
+            }
+            Ok(validated)
+        }
+
+        #[allow(clippy::type_complexity)]
+        fn validate_control_points(
+            control_points: Vec<(T::ControlPointId, ControlPoint<T::ControlPointId, Vec<u8>>)>,
+        ) -> Result<
+            Vec<(
+                T::ControlPointId,
+                ControlPoint<T::ControlPointId, BoundedVec<u8, <T as Config>::NameLimit>>,
+            )>,
+            Error<T>,
+        > {
+            ensure!(
+                control_points.len() <= <T as Config>::ControlPointLimit::get() as usize,
+                <Error<T>>::ControlPointLimitExceeded
+            );
+            let mut seen = BTreeSet::new();
+            control_points
+                .into_iter()
+                .map(|(control_point_id, control_point)| {
+                    if let Some(parent) = control_point.parent {
+                        ensure!(seen.contains(&parent), <Error<T>>::InvalidControlPoint);
+                    }
+                    ensure!(
+                        seen.insert(control_point_id),
+                        <Error<T>>::InvalidControlPoint
+                    );
+                    Ok((
+                        control_point_id,
+                        ControlPoint {
+                            code: enforce_limit!(control_point.code),
+                            title: enforce_limit!(control_point.title),
+                            description_hash: control_point.description_hash,
+                            severity: control_point.severity,
+                            parent: control_point.parent,
+                        },
+                    ))
+                })
+                .collect()
+        }
     }
 }
@@ -1,18 +1,28 @@
 use super::*;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use primitives::Observation;
+use primitives::{bounded_vec::BoundedVec, Evidence, Observation};
 
 pub mod deprecated {
     use codec::{Decode, Encode};
     use primitives::Compliance;
     use sp_runtime::RuntimeDebug;
+    use sp_std::prelude::*;
 
     #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
     pub struct OldObservation {
         pub compliance: Option<Compliance>,
         pub procedural_note_hash: Option<[u8; 32]>,
     }
+
+    #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+    pub struct OldEvidence<ProposalId, BoundedString, BoundedStringUrl> {
+        pub proposal_id: ProposalId,
+        pub name: BoundedString,
+        pub content_type: BoundedString,
+        pub url: Option<BoundedStringUrl>,
+        pub hash: Vec<u8>,
+    }
 }
 #[allow(clippy::unnecessary_cast)]
 pub fn migrate_to_v2<T: Config>() -> Weight {
@@ -42,3 +52,72 @@ pub fn migrate_to_v2<T: Config>() -> Weight {
     }
     weight
 }
+
+/// Evidence hashes become fixed 32 byte blake2-256 hashes with a declared size.
+/// Hex encoded 32 byte hashes are decoded. Hashes that cannot be decoded are zeroed and kept as uploaded in `legacy_hash`.
+/// The control points observed in each audit are indexed.
+/// Storage left at V1 is migrated too, as `migrate_to_v2` ran without updating the version.
+#[allow(clippy::unnecessary_cast)]
+pub fn migrate_to_v3<T: Config>() -> Weight {
+    let mut weight: Weight = 0;
+
+    let storage_version_maybe = <StorageVersion<T>>::get();
+
+    if storage_version_maybe.is_none()
+        || storage_version_maybe == Some(Releases::V1)
+        || storage_version_maybe == Some(Releases::V2)
+    {
+        <Evidences<T>>::translate::<
+            deprecated::OldEvidence<
+                T::ProposalId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::UrlLimit>,
+            >,
+            _,
+        >(|_audit_id, _evidence_id, old_evidence| {
+            weight += T::DbWeight::get().reads_writes(1 as Weight, 1 as Weight);
+            let mut hash = [0u8; 32];
+            let mut legacy_hash = None;
+            if old_evidence.hash.len() == 32 {
+                hash.copy_from_slice(&old_evidence.hash);
+            } else if let Some(decoded) = decode_hex_hash(&old_evidence.hash) {
+                hash = decoded;
+            } else if !old_evidence.hash.is_empty() {
+                legacy_hash = Some(old_evidence.hash);
+            }
+            Some(Evidence {
+                proposal_id: old_evidence.proposal_id,
+                name: old_evidence.name,
+                content_type: old_evidence.content_type,
+                url: old_evidence.url,
+                hash,
+                size: 0,
+                legacy_hash,
+            })
+        });
+        <Observations<T>>::iter().for_each(|((audit_id, control_point_id), _, _)| {
+            weight += T::DbWeight::get().reads_writes(1 as Weight, 1 as Weight);
+            <ObservedControlPoints<T>>::insert(audit_id, control_point_id, ());
+        });
+        <StorageVersion<T>>::put(Releases::V3);
+        weight += T::DbWeight::get().writes(1 as Weight);
+    } else {
+        frame_support::debug::info!(" >>> Unused migration!");
+    }
+    weight
+}
+
+/// Decode a hex encoded 32 byte hash, with or without a `0x` prefix.
+fn decode_hex_hash(value: &[u8]) -> Option<[u8; 32]> {
+    let digits = value.strip_prefix(b"0x").unwrap_or(value);
+    if digits.len() != 64 {
+        return None;
+    }
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(digits.chunks(2)) {
+        let high = (pair[0] as char).to_digit(16)?;
+        let low = (pair[1] as char).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(hash)
+}
@@ -1,12 +1,22 @@
 //! Mocks for the module.
 
 use crate as pallet_audits;
-use frame_support::parameter_types;
-use frame_system::{self as system, EnsureOneOf, EnsureSigned};
+use asset_info::AssetInfo;
+use did_info::DidInfo;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    parameter_types,
+};
+use frame_system::{self as system, EnsureOneOf, EnsureRoot, EnsureSigned};
+use issue_claim::IssueClaim;
+use process_info::ProcessInfo;
 use runtime::primitives::NameLimit;
 use runtime::{
-    GroupChainLimit, GroupMaxMembers, GroupMaxProposalLength, GroupMaxProposals, MaxLinkRemove,
-    UrlLimit,
+    AuditEvidenceSizeLimit, AuditFeeEscrowModuleId, AuditFeeMilestoneLimit,
+    AuditMaxScheduledPerBlock, AuditNoteRecipientLimit, AuditScheduleLimit, AuditSubjectLimit,
+    ControlPointLimit, GroupChainLimit, GroupMaxBudgetTiers, GroupMaxJointGroups, GroupMaxMembers,
+    GroupMaxProposalLength, GroupMaxProposals, GroupMaxScheduleDeferral, GroupMaxScheduledPerBlock,
+    GroupMaximumScheduledWeight, MaxLinkRemove, UrlLimit,
 };
 
 use sp_core::H256;
@@ -39,6 +49,8 @@ type Balance = u64;
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub const GroupProposalLifetime: u64 = 100;
+    pub const AuditMinCadence: u64 = 10;
 }
 
 impl system::Config for Test {
@@ -89,16 +101,104 @@ impl settings::Config for Test {
     type WeightInfo = ();
 }
 
+/// The only DID created in the mock identity pallet
+pub const FARM_DID: primitives::Did = primitives::Did { id: [1u8; 32] };
+/// The only asset registered in the mock asset registry (registry_id, asset_id)
+pub const ASSET: (u32, u32) = (1, 1);
+/// The only process created in the mock provenance pallet (registry_id, definition_id, process_id)
+pub const PROCESS: (u32, u32, u32) = (1, 1, 1);
+
+pub struct MockIdentity;
+impl DidInfo for MockIdentity {
+    type Did = primitives::Did;
+
+    fn did_exists(did: &primitives::Did) -> bool {
+        *did == FARM_DID
+    }
+}
+
+/// The id of every claim issued by the mock identity pallet
+pub const CLAIM_ID: u32 = 1;
+
+impl IssueClaim for MockIdentity {
+    type AccountId = u64;
+    type Did = primitives::Did;
+    type ClaimId = u32;
+    type Moment = u64;
+    type Statement = primitives::Statement<Vec<u8>, Vec<u8>>;
+
+    fn issue_claim(
+        _issuer: &u64,
+        did: &primitives::Did,
+        _description: Vec<u8>,
+        _statements: Vec<Self::Statement>,
+        _valid_until: u64,
+    ) -> Result<u32, DispatchError> {
+        if *did != FARM_DID {
+            return Err(DispatchError::Other("DID not found"));
+        }
+        Ok(CLAIM_ID)
+    }
+
+    fn revoke_claim(_issuer: &u64, _did: &primitives::Did, _claim_id: u32) -> DispatchResult {
+        Ok(())
+    }
+}
+
+pub struct MockAssetRegistry;
+impl AssetInfo for MockAssetRegistry {
+    type RegistryId = u32;
+    type AssetId = u32;
+
+    fn asset_exists(registry_id: u32, asset_id: u32) -> bool {
+        (registry_id, asset_id) == ASSET
+    }
+}
+
+pub struct MockProvenance;
+impl ProcessInfo for MockProvenance {
+    type RegistryId = u32;
+    type DefinitionId = u32;
+    type ProcessId = u32;
+
+    fn process_exists(registry_id: u32, definition_id: u32, process_id: u32) -> bool {
+        (registry_id, definition_id, process_id) == PROCESS
+    }
+}
+
 impl pallet_audits::Config for Test {
     type AuditId = u32;
     type ControlPointId = u32;
     type ObservationId = u32;
     type EvidenceId = u32;
+    type FrameworkId = u32;
+    type AuditScheduleId = u32;
     type Event = Event;
     type WeightInfo = ();
     type NameLimit = NameLimit;
     type UrlLimit = UrlLimit;
     type MaxLinkRemove = MaxLinkRemove;
+    type ControlPointLimit = ControlPointLimit;
+    type AssetRegistryId = u32;
+    type AssetId = u32;
+    type RegistryId = u32;
+    type DefinitionId = u32;
+    type ProcessId = u32;
+    type AssetInfoSource = MockAssetRegistry;
+    type DidInfoSource = MockIdentity;
+    type ProcessInfoSource = MockProvenance;
+    type SubjectLimit = AuditSubjectLimit;
+    type ClaimId = u32;
+    type Moment = u64;
+    type IssueClaimSource = MockIdentity;
+    type FeeMilestoneLimit = AuditFeeMilestoneLimit;
+    type EvidenceSizeLimit = AuditEvidenceSizeLimit;
+    type FeeDisputeOrigin = EnsureRoot<AccountId>;
+    type NoteRecipientLimit = AuditNoteRecipientLimit;
+    type MinCadence = AuditMinCadence;
+    type AuditScheduleLimit = AuditScheduleLimit;
+    type MaxScheduledPerBlock = AuditMaxScheduledPerBlock;
+    type FeeEscrowModuleId = AuditFeeEscrowModuleId;
 }
 
 impl groups::Config for Test {
@@ -125,6 +225,13 @@ impl groups::Config for Test {
     type WeightInfo = ();
     type NameLimit = NameLimit;
     type GroupChainLimit = GroupChainLimit;
+    type MaxBudgetTiers = GroupMaxBudgetTiers;
+    type GroupsOriginJointApproved = groups::EnsureJointApproved<Test>;
+    type MaxJointGroups = GroupMaxJointGroups;
+    type ProposalLifetime = GroupProposalLifetime;
+    type MaxScheduledPerBlock = GroupMaxScheduledPerBlock;
+    type MaximumScheduledWeight = GroupMaximumScheduledWeight;
+    type MaxScheduleDeferral = GroupMaxScheduleDeferral;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -3,9 +3,14 @@
 use super::*;
 use crate::mock::*;
 use core::convert::TryInto;
-use frame_support::{assert_ok, dispatch::Weight};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchError, Weight},
+    traits::ReservableCurrency,
+};
 use primitives::*;
 use sp_core::blake2_256;
+use sp_runtime::Permill;
 
 fn create_group(member: u64, group_id: u32) -> u64 {
     assert_ok!(Groups::create_group(
@@ -31,6 +36,10 @@ fn create_audit(
         1,
         Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
             auditing_org,
+            None,
+            vec![AuditSubject::Did(FARM_DID)],
+            None,
+            None,
             1u32
         ))),
         1,
@@ -51,6 +60,11 @@ fn create_audit(
         audit.auditing_org,
         audit_id
     ));
+    assert!(<AuditsBySubject<Test>>::contains_key(
+        AuditSubject::Did(FARM_DID),
+        audit_id
+    ));
+    assert_eq!(AuditsModule::audit_references(audit_id), Some(1));
     audit_id
 }
 
@@ -148,6 +162,27 @@ fn create_observation(auditors_member: u64, auditors_group_id: u32, audit_id: u3
     );
 }
 
+fn control_point(code: &[u8], parent: Option<u32>) -> ControlPoint<u32, Vec<u8>> {
+    ControlPoint {
+        code: code.to_vec(),
+        title: b"Title".to_vec(),
+        description_hash: blake2_256(code),
+        severity: ControlPointSeverity::High,
+        parent,
+    }
+}
+
+fn last_proposal_succeeded() -> bool {
+    let last_event = frame_system::Pallet::<Test>::events()
+        .last()
+        .unwrap()
+        .clone();
+    match last_event.event {
+        mock::Event::groups(groups::Event::Approved(_, _, _, _, success, _)) => success,
+        _ => panic!("unexpected event"),
+    }
+}
+
 fn create_evidence(auditors_member: u64, auditors_group_id: u32, audit_id: u32) {
     assert_ok!(Groups::propose(
         Origin::signed(auditors_member),
@@ -158,7 +193,8 @@ fn create_evidence(auditors_member: u64, auditors_group_id: u32, audit_id: u32)
                 b"name".to_vec(),
                 b"image/png".to_vec(),
                 Some(b"url".to_vec()),
-                b"hash".to_vec(),
+                [42u8; 32],
+                4,
             )
         )),
         1,
@@ -178,7 +214,9 @@ fn create_evidence(auditors_member: u64, auditors_group_id: u32, audit_id: u32)
             name: b"name".to_vec().try_into().unwrap(),
             content_type: b"image/png".to_vec().try_into().unwrap(),
             url: Some(b"url".to_vec().try_into().unwrap()),
-            hash: b"hash".to_vec().try_into().unwrap(),
+            hash: [42u8; 32],
+            size: 4,
+            legacy_hash: None,
         }
     );
 }
@@ -199,6 +237,174 @@ fn create_audit_should_work() {
     });
 }
 
+#[test]
+fn create_audit_jointly_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org_group_account = create_group(auditing_org_member, auditing_org_group_id);
+
+        // the auditing org cannot also be the creator
+        assert_ok!(Groups::propose_joint(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                auditing_org_group_account,
+                None,
+                vec![AuditSubject::Did(FARM_DID)],
+                None,
+                Some(auditing_org_group_account),
+                1u32
+            ))),
+            vec![(audit_creator_group_id, 1), (auditing_org_group_id, 1)],
+            100
+        ));
+        let proposal_id = 1u32;
+        assert_ok!(Groups::vote_joint(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            proposal_id,
+            true
+        ));
+        assert_ok!(Groups::close_joint(
+            Origin::signed(audit_creator_member),
+            proposal_id,
+            1_000_000_000,
+            100
+        ));
+        assert!(!Audits::<Test>::contains_key(1u32));
+
+        assert_ok!(Groups::propose_joint(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                auditing_org_group_account,
+                None,
+                vec![AuditSubject::Did(FARM_DID)],
+                None,
+                Some(audit_creator_group_account),
+                1u32
+            ))),
+            vec![(audit_creator_group_id, 1), (auditing_org_group_id, 1)],
+            100
+        ));
+        let proposal_id = 2u32;
+        assert_ok!(Groups::vote_joint(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            proposal_id,
+            true
+        ));
+        assert_ok!(Groups::close_joint(
+            Origin::signed(audit_creator_member),
+            proposal_id,
+            1_000_000_000,
+            100
+        ));
+
+        let audit_id = 1u32;
+        let audit = Audits::<Test>::get(audit_id).unwrap();
+        assert_eq!(audit.audit_creator, audit_creator_group_account);
+        assert_eq!(audit.auditing_org, auditing_org_group_account);
+        assert_eq!(audit.status, AuditStatus::Requested);
+        assert!(<AuditsByCreator<Test>>::contains_key(
+            audit_creator_group_account,
+            audit_id
+        ));
+        assert!(<AuditsByAuditingOrg<Test>>::contains_key(
+            auditing_org_group_account,
+            audit_id
+        ));
+        assert!(!<AuditByProposal<Test>>::contains_key(proposal_id));
+    });
+}
+
+#[test]
+fn audit_subjects_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org = 2;
+
+        let subjects = vec![
+            AuditSubject::Did(FARM_DID),
+            AuditSubject::Asset(ASSET.0, ASSET.1),
+            AuditSubject::Process(PROCESS.0, PROCESS.1, PROCESS.2),
+            AuditSubject::Site(b"North Field".to_vec()),
+        ];
+
+        //subjects must exist and be unique
+        for invalid_subjects in vec![
+            vec![],
+            vec![AuditSubject::Did(primitives::Did { id: [2u8; 32] })],
+            vec![AuditSubject::Asset(ASSET.0, 2)],
+            vec![AuditSubject::Process(PROCESS.0, PROCESS.1, 2)],
+            vec![AuditSubject::Did(FARM_DID), AuditSubject::Did(FARM_DID)],
+        ] {
+            assert_ok!(Groups::propose(
+                Origin::signed(audit_creator_member),
+                audit_creator_group_id,
+                Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                    auditing_org,
+                    None,
+                    invalid_subjects,
+                    None,
+                    None,
+                    1u32
+                ))),
+                1,
+                100
+            ));
+            assert!(!last_proposal_succeeded());
+        }
+
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                auditing_org,
+                None,
+                subjects.clone(),
+                None,
+                None,
+                1u32
+            ))),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        let audit_id = 1u32;
+
+        assert_eq!(AuditsModule::get_audit_subjects(audit_id).len(), 4);
+        for subject in subjects {
+            let audits = AuditsModule::get_audits_by_subject(subject);
+            assert_eq!(audits.len(), 1);
+            assert_eq!(audits[0].0, audit_id);
+        }
+        assert!(
+            AuditsModule::get_audits_by_subject(AuditSubject::Site(b"South Field".to_vec()))
+                .is_empty()
+        );
+
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::delete_audit(
+                audit_id
+            ))),
+            1,
+            100
+        ));
+        assert!(AuditsModule::get_audits_by_subject(AuditSubject::Did(FARM_DID)).is_empty());
+        assert!(AuditsModule::get_audit_subjects(audit_id).is_empty());
+    });
+}
+
 #[test]
 fn delete_audit_should_work() {
     new_test_ext().execute_with(|| {
@@ -232,6 +438,9 @@ fn delete_audit_should_work() {
             auditing_org,
             audit_id
         ));
+        assert!(!<AuditReferences<Test>>::contains_key(audit_id));
+        assert!(!<AuditTrail<Test>>::contains_key(audit_id, 0));
+        assert!(!<AuditTrailLength<Test>>::contains_key(audit_id));
     });
 }
 
@@ -464,6 +673,26 @@ fn creating_evidence_should_work() {
             auditing_org_member,
             auditors_group_account,
         );
+        //evidence must declare a size within the limit
+        for size in [0, <Test as Config>::EvidenceSizeLimit::get() + 1].iter() {
+            assert_ok!(Groups::propose(
+                Origin::signed(auditors_member),
+                auditors_group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::create_evidence(
+                        audit_id,
+                        b"name".to_vec(),
+                        b"image/png".to_vec(),
+                        None,
+                        [42u8; 32],
+                        *size,
+                    )
+                )),
+                1,
+                100
+            ));
+            assert!(!last_proposal_succeeded());
+        }
         //create evidence
         create_evidence(auditors_member, auditors_group_id, audit_id);
     });
@@ -766,46 +995,1988 @@ fn delete_evidence_should_have_link_limit() {
     });
 }
 
-//Make sure weights cannot exceed 10% of total allowance for block.
-
 #[test]
-fn weights_should_not_be_excessive() {
+fn audit_framework_should_work() {
     new_test_ext().execute_with(|| {
-        const MAXIMUM_ALLOWED_WEIGHT: Weight = 130_000_000_000;
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let standards_body_member = 2;
+        let standards_body_group_id = 2;
+        let standards_body = create_group(standards_body_member, standards_body_group_id);
+        let auditing_org_member = 3;
+        let auditing_org_group_id = 3;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
 
-        let weight = <Test as Config>::WeightInfo::create_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::delete_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::link_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::unlink_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::accept_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::assign_auditors_initial_assign();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::assign_auditors_replace();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::reject_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::complete_audit();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::create_observation();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::create_evidence(
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::NameLimit::get(),
+        assert_ok!(Groups::propose(
+            Origin::signed(standards_body_member),
+            standards_body_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_framework(
+                    b"Food Safety".to_vec(),
+                    vec![
+                        (1, control_point(b"4", None)),
+                        (2, control_point(b"4.1", Some(1)))
+                    ]
+                )
+            )),
+            1,
+            100
+        ));
+        let framework_id = 1u32;
+        assert_eq!(
+            Frameworks::<Test>::get(framework_id),
+            Some(AuditFramework {
+                publisher: standards_body,
+                name: b"Food Safety".to_vec().try_into().unwrap(),
+                latest_version: 1,
+            })
         );
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::link_evidence();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::unlink_evidence();
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight =
-            <Test as Config>::WeightInfo::delete_evidence(<Test as Config>::MaxLinkRemove::get());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(standards_body_member),
+            standards_body_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::publish_framework_version(
+                    framework_id,
+                    vec![
+                        (1, control_point(b"4", None)),
+                        (2, control_point(b"4.1", Some(1))),
+                        (3, control_point(b"4.2", Some(1)))
+                    ]
+                )
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            Frameworks::<Test>::get(framework_id)
+                .unwrap()
+                .latest_version,
+            2
+        );
+        assert!(FrameworkControlPoints::<Test>::contains_key(
+            (framework_id, 2),
+            3
+        ));
+        assert!(!FrameworkControlPoints::<Test>::contains_key(
+            (framework_id, 1),
+            3
+        ));
+
+        //a parent must precede its children
+        assert_ok!(Groups::propose(
+            Origin::signed(standards_body_member),
+            standards_body_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::publish_framework_version(
+                    framework_id,
+                    vec![
+                        (2, control_point(b"4.1", Some(1))),
+                        (1, control_point(b"4", None))
+                    ]
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+        assert_eq!(
+            Frameworks::<Test>::get(framework_id)
+                .unwrap()
+                .latest_version,
+            2
+        );
+
+        //an account may publish a framework itself
+        assert_ok!(AuditsModule::create_framework(
+            Origin::signed(standards_body_member),
+            b"Organic".to_vec(),
+            vec![(1, control_point(b"1", None))]
+        ));
+        assert_eq!(
+            Frameworks::<Test>::get(2).unwrap().publisher,
+            standards_body_member
+        );
+        assert_noop!(
+            AuditsModule::publish_framework_version(
+                Origin::signed(auditing_org_member),
+                2,
+                vec![(1, control_point(b"1", None))]
+            ),
+            Error::<Test>::NotPublisher
+        );
+        assert_ok!(AuditsModule::publish_framework_version(
+            Origin::signed(standards_body_member),
+            2,
+            vec![(1, control_point(b"1", None))]
+        ));
+
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                auditing_org,
+                Some((framework_id, 1)),
+                vec![AuditSubject::Did(FARM_DID)],
+                None,
+                None,
+                1u32
+            ))),
+            1,
+            100
+        ));
+        let audit_id = 1u32;
+        let audit = Audits::<Test>::get(audit_id).unwrap();
+        assert_eq!(audit.audit_creator, audit_creator_group_account);
+        assert_eq!(
+            AuditFrameworks::<Test>::get(audit_id),
+            Some((framework_id, 1))
+        );
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditing_org,
+        );
+
+        //control point 3 is not in version 1
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_observation(audit_id, 3, Some(Compliance::Compliant), None)
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_observation(audit_id, 2, Some(Compliance::NonCompliant), None)
+            )),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+
+        let (_, version, control_points) =
+            AuditsModule::get_framework(framework_id, Some(1)).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(
+            control_points,
+            vec![
+                (1, control_point(b"4", None)),
+                (2, control_point(b"4.1", Some(1)))
+            ]
+            .into_iter()
+            .map(|(control_point_id, control_point)| (
+                control_point_id,
+                ControlPoint {
+                    code: control_point.code.try_into().unwrap(),
+                    title: control_point.title.try_into().unwrap(),
+                    description_hash: control_point.description_hash,
+                    severity: control_point.severity,
+                    parent: control_point.parent,
+                }
+            ))
+            .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            AuditsModule::get_control_point_coverage(audit_id),
+            Some((
+                framework_id,
+                1,
+                vec![
+                    (1, ControlPointCoverage::default()),
+                    (
+                        2,
+                        ControlPointCoverage {
+                            observations: 1,
+                            compliant: 0,
+                            non_compliant: 1,
+                            not_applicable: 0,
+                        }
+                    )
+                ]
+            ))
+        );
+    });
+}
+
+#[test]
+fn audit_report_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        create_group(audit_creator_member, audit_creator_group_id);
+        let standards_body_member = 2;
+        let standards_body_group_id = 2;
+        create_group(standards_body_member, standards_body_group_id);
+        let auditing_org_member = 3;
+        let auditing_org_group_id = 3;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+
+        let with_severity = |code: &[u8], severity| ControlPoint {
+            severity,
+            ..control_point(code, None)
+        };
+        assert_ok!(Groups::propose(
+            Origin::signed(standards_body_member),
+            standards_body_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_framework(
+                    b"Food Safety".to_vec(),
+                    vec![
+                        (1, with_severity(b"1", ControlPointSeverity::Low)),
+                        (2, with_severity(b"2", ControlPointSeverity::Critical)),
+                        (3, with_severity(b"3", ControlPointSeverity::Medium))
+                    ]
+                )
+            )),
+            1,
+            100
+        ));
+        let framework_id = 1u32;
+
+        for framework in [Some((framework_id, 1)), None].iter() {
+            assert_ok!(Groups::propose(
+                Origin::signed(audit_creator_member),
+                audit_creator_group_id,
+                Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                    auditing_org,
+                    *framework,
+                    vec![AuditSubject::Did(FARM_DID)],
+                    None,
+                    None,
+                    1u32
+                ))),
+                1,
+                100
+            ));
+        }
+        let audit_id = 1u32;
+        let linked_audit_id = 2u32;
+        for id in [audit_id, linked_audit_id].iter() {
+            accept_audit(auditing_org_group_id, *id, auditing_org_member);
+            assign_auditors(
+                *id,
+                auditing_org_group_id,
+                auditing_org_member,
+                auditing_org,
+            );
+        }
+
+        for (id, control_point_id, compliance) in [
+            (audit_id, 1, Compliance::Compliant),
+            (audit_id, 2, Compliance::NonCompliant),
+            (audit_id, 2, Compliance::Compliant),
+            (audit_id, 1, Compliance::NotApplicable),
+            (linked_audit_id, 7, Compliance::Compliant),
+        ]
+        .iter()
+        {
+            assert_ok!(Groups::propose(
+                Origin::signed(auditing_org_member),
+                auditing_org_group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::create_observation(
+                        *id,
+                        *control_point_id,
+                        Some(compliance.clone()),
+                        None
+                    )
+                )),
+                1,
+                100
+            ));
+            assert!(last_proposal_succeeded());
+        }
+        create_evidence(auditing_org_member, auditing_org_group_id, audit_id);
+        let evidence_id = 1;
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::link_evidence(
+                audit_id,
+                1,
+                1,
+                evidence_id
+            ))),
+            1,
+            100
+        ));
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::link_audit(
+                audit_id,
+                linked_audit_id
+            ))),
+            1,
+            100
+        ));
+
+        let report = AuditsModule::get_audit_report(audit_id).unwrap();
+        assert_eq!(report.audit, Audits::<Test>::get(audit_id).unwrap());
+        assert_eq!(report.framework, Some((framework_id, 1)));
+        assert_eq!(
+            report
+                .control_points
+                .iter()
+                .map(|control_point| (
+                    control_point.control_point_id,
+                    control_point.severity.clone(),
+                    control_point.coverage.clone()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    1,
+                    Some(ControlPointSeverity::Low),
+                    ControlPointCoverage {
+                        observations: 2,
+                        compliant: 1,
+                        non_compliant: 0,
+                        not_applicable: 1,
+                    }
+                ),
+                (
+                    2,
+                    Some(ControlPointSeverity::Critical),
+                    ControlPointCoverage {
+                        observations: 2,
+                        compliant: 1,
+                        non_compliant: 1,
+                        not_applicable: 0,
+                    }
+                ),
+                (
+                    3,
+                    Some(ControlPointSeverity::Medium),
+                    ControlPointCoverage::default()
+                )
+            ]
+        );
+        assert_eq!(
+            report.totals,
+            ControlPointCoverage {
+                observations: 4,
+                compliant: 2,
+                non_compliant: 1,
+                not_applicable: 1,
+            }
+        );
+        //a compliant low and a compliant critical out of one more critical
+        assert_eq!(
+            report.score,
+            Some(Permill::from_rational_approximation(5u32, 9u32))
+        );
+        let (_, _, evidences) = report.control_points[0]
+            .observations
+            .iter()
+            .find(|(observation_id, _, _)| *observation_id == 1)
+            .unwrap();
+        assert_eq!(
+            evidences,
+            &vec![(
+                evidence_id,
+                Evidences::<Test>::get(audit_id, evidence_id).unwrap()
+            )]
+        );
+        assert_eq!(report.linked_audits, vec![linked_audit_id]);
+
+        //without a framework only observed control points are reported, all weighted equally
+        let report = AuditsModule::get_audit_report(linked_audit_id).unwrap();
+        assert_eq!(report.framework, None);
+        assert_eq!(report.control_points.len(), 1);
+        assert_eq!(report.control_points[0].control_point_id, 7);
+        assert_eq!(report.control_points[0].severity, None);
+        assert!(ObservedControlPoints::<Test>::contains_key(
+            linked_audit_id,
+            7
+        ));
+        assert!(!ObservedControlPoints::<Test>::contains_key(
+            linked_audit_id,
+            1
+        ));
+        assert_eq!(report.score, Some(Permill::from_percent(100)));
+        assert!(report.linked_audits.is_empty());
+
+        assert!(AuditsModule::get_audit_report(3).is_none());
+    });
+}
+
+#[test]
+fn audit_fee_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator = create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+
+        let propose_audit = |fee: Option<(u64, Vec<(u32, u64)>)>| {
+            assert_ok!(Groups::propose(
+                Origin::signed(audit_creator_member),
+                audit_creator_group_id,
+                Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                    auditing_org,
+                    None,
+                    vec![AuditSubject::Did(FARM_DID)],
+                    fee,
+                    None,
+                    1u32
+                ))),
+                1,
+                100
+            ));
+        };
+
+        //milestones must not add up to more than the fee
+        propose_audit(Some((1_000, vec![(1, 600), (2, 600)])));
+        assert!(!last_proposal_succeeded());
+        //milestones must be for increasing observation counts
+        propose_audit(Some((1_000, vec![(2, 100), (1, 100)])));
+        assert!(!last_proposal_succeeded());
+        //the escrow account must be able to hold the fee
+        propose_audit(Some((0, vec![])));
+        assert!(!last_proposal_succeeded());
+
+        propose_audit(Some((1_000, vec![(1, 100), (2, 200)])));
+        let audit_id = 1u32;
+        assert_eq!(
+            AuditFees::<Test>::get(audit_id),
+            Some(AuditFee {
+                amount: 1_000,
+                milestones: vec![(1, 100), (2, 200)],
+                released: 0,
+                observations: 0,
+                status: AuditFeeStatus::Pending,
+            })
+        );
+        let escrow = AuditsModule::fee_escrow_account(audit_id);
+        assert_eq!(Balances::free_balance(escrow), 0);
+
+        let auditing_org_balance = Balances::free_balance(auditing_org);
+        let audit_creator_balance = Balances::free_balance(audit_creator);
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        assert_eq!(Balances::free_balance(escrow), 1_000);
+        assert_eq!(
+            Balances::free_balance(audit_creator),
+            audit_creator_balance - 1_000
+        );
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditing_org,
+        );
+
+        create_observation(auditing_org_member, auditing_org_group_id, audit_id);
+        assert_eq!(
+            Balances::free_balance(auditing_org),
+            auditing_org_balance + 100
+        );
+        create_observation(auditing_org_member, auditing_org_group_id, audit_id);
+        assert_eq!(
+            Balances::free_balance(auditing_org),
+            auditing_org_balance + 300
+        );
+        assert_eq!(Balances::free_balance(escrow), 700);
+
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::dispute_fee(
+                audit_id
+            ))),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            AuditFees::<Test>::get(audit_id).unwrap().status,
+            AuditFeeStatus::Disputed
+        );
+
+        //a disputed fee is not released on completion
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(audit_id)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::Completed
+        );
+        assert_eq!(Balances::free_balance(escrow), 700);
+
+        assert_noop!(
+            AuditsModule::resolve_fee_dispute(Origin::signed(audit_creator_member), audit_id, 0),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AuditsModule::resolve_fee_dispute(Origin::root(), audit_id, 701),
+            Error::<Test>::FeeAwardExceedsEscrow
+        );
+        //nothing is paid out if the escrow no longer covers the remainder
+        Balances::make_free_balance_be(&escrow, 600);
+        assert_noop!(
+            AuditsModule::resolve_fee_dispute(Origin::root(), audit_id, 500),
+            Error::<Test>::InsufficientEscrow
+        );
+        Balances::make_free_balance_be(&escrow, 700);
+        let audit_creator_balance = Balances::free_balance(audit_creator);
+        assert_ok!(AuditsModule::resolve_fee_dispute(
+            Origin::root(),
+            audit_id,
+            500
+        ));
+        assert_eq!(
+            Balances::free_balance(auditing_org),
+            auditing_org_balance + 800
+        );
+        assert_eq!(
+            Balances::free_balance(audit_creator),
+            audit_creator_balance + 200
+        );
+        assert_eq!(Balances::free_balance(escrow), 0);
+        let fee = AuditFees::<Test>::get(audit_id).unwrap();
+        assert_eq!(fee.released, 800);
+        assert_eq!(fee.status, AuditFeeStatus::Resolved);
+
+        //released in full on completion
+        propose_audit(Some((300, vec![])));
+        let audit_id = 2u32;
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditing_org,
+        );
+        create_observation(auditing_org_member, auditing_org_group_id, audit_id);
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(audit_id)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            Balances::free_balance(auditing_org),
+            auditing_org_balance + 1_100
+        );
+        assert_eq!(
+            AuditFees::<Test>::get(audit_id).unwrap().status,
+            AuditFeeStatus::Released
+        );
+        assert_eq!(
+            Balances::free_balance(AuditsModule::fee_escrow_account(audit_id)),
+            0
+        );
+
+        //nothing is held before acceptance
+        propose_audit(Some((300, vec![])));
+        let audit_id = 3u32;
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::reject_audit(
+                audit_id
+            ))),
+            1,
+            100
+        ));
+        assert_eq!(
+            AuditFees::<Test>::get(audit_id).unwrap().status,
+            AuditFeeStatus::Refunded
+        );
+        assert_eq!(
+            Balances::free_balance(AuditsModule::fee_escrow_account(audit_id)),
+            0
+        );
+
+        //a fee that cannot be paid out does not hold up completion
+        propose_audit(Some((300, vec![])));
+        let audit_id = 4u32;
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditing_org,
+        );
+        create_observation(auditing_org_member, auditing_org_group_id, audit_id);
+        let escrow = AuditsModule::fee_escrow_account(audit_id);
+        Balances::make_free_balance_be(&escrow, 100);
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(audit_id)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::Completed
+        );
+        assert_eq!(
+            AuditFees::<Test>::get(audit_id).unwrap().status,
+            AuditFeeStatus::Reserved
+        );
+        assert_eq!(Balances::free_balance(escrow), 100);
+    });
+}
+
+#[test]
+fn audit_schedule_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator = create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+
+        let propose = |member: u64, group_id: u32, call: super::Call<Test>| {
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(call)),
+                1,
+                100
+            ));
+        };
+        let create_schedule = |cadence: u64, lead_time: u64, first_due: u64| {
+            propose(
+                audit_creator_member,
+                audit_creator_group_id,
+                super::Call::create_audit_schedule(
+                    auditing_org,
+                    None,
+                    vec![AuditSubject::Did(FARM_DID)],
+                    cadence,
+                    lead_time,
+                    first_due,
+                ),
+            );
+        };
+        let run_to_block = |block_number: u64| {
+            System::set_block_number(block_number);
+            <AuditsModule as frame_support::traits::OnInitialize<u64>>::on_initialize(block_number);
+        };
+
+        //the lead time cannot be longer than the cadence
+        create_schedule(10, 20, 50);
+        assert!(!last_proposal_succeeded());
+        //the first audit cannot be requested in the past
+        create_schedule(100, 20, 21);
+        assert!(!last_proposal_succeeded());
+
+        create_schedule(100, 20, 50);
+        assert!(last_proposal_succeeded());
+        let schedule_id = 1u32;
+        let schedule = AuditSchedules::<Test>::get(schedule_id).unwrap();
+        assert_eq!(schedule.audit_creator, audit_creator);
+        assert_eq!(schedule.auditing_org, auditing_org);
+        assert_eq!(schedule.next_due, 50);
+        assert_eq!(schedule.status, AuditScheduleStatus::Active);
+        assert!(AuditScheduleQueue::<Test>::contains_key(30, schedule_id));
+
+        let upcoming = AuditsModule::get_upcoming_audits_by_creator(audit_creator);
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].0, schedule_id);
+        assert_eq!(
+            AuditsModule::get_upcoming_audits_by_auditing_org(auditing_org).len(),
+            1
+        );
+
+        //the first audit is requested at the start of its lead time
+        run_to_block(29);
+        assert!(Audits::<Test>::get(1).is_none());
+        run_to_block(30);
+        let audit_id = 1u32;
+        let audit = Audits::<Test>::get(audit_id).unwrap();
+        assert_eq!(audit.status, AuditStatus::Requested);
+        assert_eq!(audit.audit_creator, audit_creator);
+        assert_eq!(audit.auditing_org, auditing_org);
+        assert!(<AuditsBySubject<Test>>::contains_key(
+            AuditSubject::Did(FARM_DID),
+            audit_id
+        ));
+        assert_eq!(
+            ScheduledAuditsDue::<Test>::get(50, audit_id),
+            Some((schedule_id, 50))
+        );
+        let schedule = AuditSchedules::<Test>::get(schedule_id).unwrap();
+        assert_eq!(schedule.last_audit, Some((audit_id, 50)));
+        assert_eq!(schedule.next_due, 150);
+        assert!(AuditScheduleQueue::<Test>::contains_key(130, schedule_id));
+
+        //completed on time
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditing_org,
+        );
+        create_observation(auditing_org_member, auditing_org_group_id, audit_id);
+        propose(
+            auditing_org_member,
+            auditing_org_group_id,
+            super::Call::complete_audit(audit_id),
+        );
+        run_to_block(50);
+        assert!(AuditsModule::get_missed_audits(schedule_id).is_empty());
+
+        //not completed by its due date
+        run_to_block(130);
+        let audit_id = 2u32;
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::Requested
+        );
+        run_to_block(150);
+        assert_eq!(
+            AuditsModule::get_missed_audits(schedule_id),
+            vec![(audit_id, 150)]
+        );
+        assert_eq!(AuditSchedules::<Test>::get(schedule_id).unwrap().missed, 1);
+
+        //only the creator can pause
+        propose(
+            auditing_org_member,
+            auditing_org_group_id,
+            super::Call::pause_audit_schedule(schedule_id),
+        );
+        assert!(!last_proposal_succeeded());
+        propose(
+            audit_creator_member,
+            audit_creator_group_id,
+            super::Call::pause_audit_schedule(schedule_id),
+        );
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            AuditSchedules::<Test>::get(schedule_id).unwrap().status,
+            AuditScheduleStatus::Paused
+        );
+
+        //audits falling due while paused are skipped
+        run_to_block(230);
+        assert!(Audits::<Test>::get(3).is_none());
+        assert_eq!(
+            AuditSchedules::<Test>::get(schedule_id).unwrap().next_due,
+            350
+        );
+
+        propose(
+            audit_creator_member,
+            audit_creator_group_id,
+            super::Call::resume_audit_schedule(schedule_id),
+        );
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            AuditSchedules::<Test>::get(schedule_id).unwrap().status,
+            AuditScheduleStatus::Active
+        );
+
+        propose(
+            audit_creator_member,
+            audit_creator_group_id,
+            super::Call::cancel_audit_schedule(schedule_id),
+        );
+        assert!(last_proposal_succeeded());
+        assert!(!AuditScheduleQueue::<Test>::contains_key(330, schedule_id));
+        run_to_block(330);
+        assert!(Audits::<Test>::get(3).is_none());
+        assert!(AuditsModule::get_upcoming_audits_by_creator(audit_creator).is_empty());
+
+        propose(
+            audit_creator_member,
+            audit_creator_group_id,
+            super::Call::resume_audit_schedule(schedule_id),
+        );
+        assert!(!last_proposal_succeeded());
+    });
+}
+
+#[test]
+fn audit_schedule_limits_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator = create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+
+        let propose = |call: super::Call<Test>| {
+            assert_ok!(Groups::propose(
+                Origin::signed(audit_creator_member),
+                audit_creator_group_id,
+                Box::new(crate::mock::Call::AuditsModule(call)),
+                1,
+                100
+            ));
+        };
+        let create_schedule = |cadence: u64, lead_time: u64, first_due: u64| {
+            propose(super::Call::create_audit_schedule(
+                auditing_org,
+                None,
+                vec![AuditSubject::Did(FARM_DID)],
+                cadence,
+                lead_time,
+                first_due,
+            ));
+        };
+        let run_to_block = |block_number: u64| {
+            System::set_block_number(block_number);
+            <AuditsModule as frame_support::traits::OnInitialize<u64>>::on_initialize(block_number);
+        };
+
+        //the cadence cannot be shorter than the minimum
+        create_schedule(<Test as Config>::MinCadence::get() - 1, 1, 50);
+        assert!(!last_proposal_succeeded());
+
+        //audits beyond the limit of a block are requested and checked in the next block
+        let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+        for _ in 0..=max_per_block {
+            create_schedule(100, 20, 50);
+            assert!(last_proposal_succeeded());
+        }
+        let last_schedule_id = max_per_block + 1;
+        assert_eq!(AuditScheduleQueueLength::<Test>::get(30), max_per_block);
+        assert!(AuditScheduleQueue::<Test>::contains_key(
+            31,
+            last_schedule_id
+        ));
+
+        run_to_block(30);
+        assert_eq!(Audits::<Test>::iter().count(), max_per_block as usize);
+        assert!(!AuditScheduleQueueLength::<Test>::contains_key(30));
+        run_to_block(31);
+        let last_audit_id = max_per_block + 1;
+        assert!(Audits::<Test>::contains_key(last_audit_id));
+        assert_eq!(
+            ScheduledAuditsDue::<Test>::get(51, last_audit_id),
+            Some((last_schedule_id, 50))
+        );
+
+        run_to_block(50);
+        assert_eq!(MissedAudits::<Test>::iter().count(), max_per_block as usize);
+        run_to_block(51);
+        assert_eq!(
+            AuditsModule::get_missed_audits(last_schedule_id),
+            vec![(last_audit_id, 50)]
+        );
+
+        //an audit creator can only have so many schedules that are not cancelled
+        let schedule_limit = <Test as Config>::AuditScheduleLimit::get();
+        for _ in last_schedule_id..schedule_limit {
+            create_schedule(100, 20, 250);
+            assert!(last_proposal_succeeded());
+        }
+        assert_eq!(
+            AuditScheduleCount::<Test>::get(audit_creator),
+            schedule_limit
+        );
+        create_schedule(100, 20, 250);
+        assert!(!last_proposal_succeeded());
+
+        propose(super::Call::cancel_audit_schedule(1));
+        assert!(last_proposal_succeeded());
+        create_schedule(100, 20, 250);
+        assert!(last_proposal_succeeded());
+    });
+}
+
+#[test]
+fn reopen_audit_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+        let audit_id = create_audit(
+            audit_creator_member,
+            audit_creator_group_account,
+            auditing_org,
+        );
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        let auditors_group_id = 3;
+        let auditors_member = 3;
+        let auditors_group_account = create_group(auditors_member, auditors_group_id);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditors_group_account,
+        );
+        create_observation(auditors_member, auditors_group_id, audit_id);
+
+        let reopen = |member: u64, group_id: u32, reason: &[u8]| {
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(super::Call::reopen_audit(
+                    audit_id,
+                    reason.to_vec()
+                ))),
+                1,
+                100
+            ));
+        };
+
+        //only a completed audit can be reopened
+        reopen(audit_creator_member, audit_creator_group_id, b"wrong date");
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(audit_id)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::Completed
+        );
+
+        //only the audit creator or the auditing org
+        reopen(auditors_member, auditors_group_id, b"wrong date");
+        assert!(!last_proposal_succeeded());
+
+        reopen(audit_creator_member, audit_creator_group_id, b"wrong date");
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            AuditReopenRequests::<Test>::get(audit_id),
+            Some(audit_creator_group_account)
+        );
+
+        //only the requesting party can withdraw the request
+        let withdraw = |member: u64, group_id: u32| {
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::withdraw_reopen_request(audit_id)
+                )),
+                1,
+                100
+            ));
+        };
+        withdraw(auditing_org_member, auditing_org_group_id);
+        assert!(!last_proposal_succeeded());
+        withdraw(audit_creator_member, audit_creator_group_id);
+        assert!(last_proposal_succeeded());
+        assert!(AuditReopenRequests::<Test>::get(audit_id).is_none());
+        withdraw(audit_creator_member, audit_creator_group_id);
+        assert!(!last_proposal_succeeded());
+
+        reopen(audit_creator_member, audit_creator_group_id, b"wrong date");
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::Completed
+        );
+
+        //the other party must approve
+        reopen(audit_creator_member, audit_creator_group_id, b"wrong date");
+        assert!(!last_proposal_succeeded());
+
+        reopen(auditing_org_member, auditing_org_group_id, b"agreed");
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::InProgress
+        );
+        assert!(AuditReopenRequests::<Test>::get(audit_id).is_none());
+
+        let changes: Vec<_> = AuditsModule::get_audit_trail(audit_id)
+            .into_iter()
+            .map(|entry| entry.change)
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                AuditChange::Status(AuditStatus::Requested),
+                AuditChange::Status(AuditStatus::Accepted),
+                AuditChange::Status(AuditStatus::InProgress),
+                AuditChange::Status(AuditStatus::Completed),
+                AuditChange::ReopenRequested(b"wrong date".to_vec().try_into().unwrap()),
+                AuditChange::ReopenRequestWithdrawn,
+                AuditChange::ReopenRequested(b"wrong date".to_vec().try_into().unwrap()),
+                AuditChange::Reopened(b"agreed".to_vec().try_into().unwrap()),
+            ]
+        );
+        assert_eq!(AuditTrailLength::<Test>::get(audit_id), 7);
+        let reopened = AuditsModule::get_audit_trail(audit_id).pop().unwrap();
+        assert_eq!(reopened.proposal_id, Some(get_proposal_id()));
+        assert_eq!(reopened.account, auditing_org);
+        assert_eq!(reopened.block_number, System::block_number());
+    });
+}
+
+#[test]
+fn supersede_observation_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+        let audit_id = create_audit(
+            audit_creator_member,
+            audit_creator_group_account,
+            auditing_org,
+        );
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        let auditors_group_id = 3;
+        let auditors_member = 3;
+        let auditors_group_account = create_group(auditors_member, auditors_group_id);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditors_group_account,
+        );
+
+        let control_point_id = 1;
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_observation(
+                    audit_id,
+                    control_point_id,
+                    Some(Compliance::NonCompliant),
+                    None
+                )
+            )),
+            1,
+            100
+        ));
+        let observation_id = 1;
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    blake2_256(b"plan"),
+                    100,
+                    audit_creator_member
+                )
+            )),
+            1,
+            100
+        ));
+        assert_eq!(OpenFindings::<Test>::get(audit_id), 1);
+
+        let supersede = |member: u64, group_id: u32, observation_id: u32, compliance| {
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::supersede_observation(
+                        audit_id,
+                        control_point_id,
+                        observation_id,
+                        Some(compliance),
+                        None,
+                        b"misread".to_vec()
+                    )
+                )),
+                1,
+                100
+            ));
+        };
+
+        //only the auditors
+        supersede(
+            auditing_org_member,
+            auditing_org_group_id,
+            observation_id,
+            Compliance::Compliant,
+        );
+        assert!(!last_proposal_succeeded());
+
+        //a non-compliant replacement keeps the finding and its corrective action
+        supersede(
+            auditors_member,
+            auditors_group_id,
+            observation_id,
+            Compliance::NonCompliant,
+        );
+        assert!(last_proposal_succeeded());
+        let new_observation_id = 2;
+        assert_eq!(
+            SupersededObservations::<Test>::get(observation_id),
+            Some(new_observation_id)
+        );
+        assert!(Observations::<Test>::get((audit_id, control_point_id), observation_id).is_some());
+        assert!(
+            CorrectiveActions::<Test>::get(audit_id, (control_point_id, observation_id)).is_none()
+        );
+        assert!(
+            CorrectiveActions::<Test>::get(audit_id, (control_point_id, new_observation_id))
+                .is_some()
+        );
+        assert_eq!(OpenFindings::<Test>::get(audit_id), 1);
+
+        //an observation can only be superseded once
+        supersede(
+            auditors_member,
+            auditors_group_id,
+            observation_id,
+            Compliance::Compliant,
+        );
+        assert!(!last_proposal_succeeded());
+
+        //a compliant replacement withdraws the finding
+        supersede(
+            auditors_member,
+            auditors_group_id,
+            new_observation_id,
+            Compliance::Compliant,
+        );
+        assert!(last_proposal_succeeded());
+        assert!(
+            CorrectiveActions::<Test>::get(audit_id, (control_point_id, new_observation_id))
+                .is_none()
+        );
+        assert!(AuditsModule::get_corrective_actions(audit_id).is_empty());
+        assert_eq!(OpenFindings::<Test>::get(audit_id), 0);
+
+        //superseded observations are kept but not reported
+        assert_eq!(
+            AuditsModule::get_observation_by_control_point(audit_id, control_point_id).len(),
+            3
+        );
+        let report = AuditsModule::get_audit_report(audit_id).unwrap();
+        assert_eq!(report.totals.observations, 1);
+        assert_eq!(report.totals.compliant, 1);
+        assert_eq!(report.control_points[0].observations[0].0, 3);
+
+        assert_eq!(
+            AuditsModule::get_audit_trail(audit_id)
+                .pop()
+                .unwrap()
+                .change,
+            AuditChange::ObservationSuperseded(
+                control_point_id,
+                new_observation_id,
+                3,
+                b"misread".to_vec().try_into().unwrap()
+            )
+        );
+    });
+}
+
+#[test]
+fn confidential_notes_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+        let audit_id = create_audit(
+            audit_creator_member,
+            audit_creator_group_account,
+            auditing_org,
+        );
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        let auditors_group_id = 3;
+        let auditors_member = 3;
+        let auditors_group_account = create_group(auditors_member, auditors_group_id);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditors_group_account,
+        );
+        let outsider_member = 4;
+        let outsider_group_id = 4;
+        create_group(outsider_member, outsider_group_id);
+
+        //keys of small order are rejected, whatever their top bit
+        assert_noop!(
+            AuditsModule::register_note_key(Origin::signed(1), [0u8; 32]),
+            Error::<Test>::InvalidNoteKey
+        );
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        one[31] = 0x80;
+        assert_noop!(
+            AuditsModule::register_note_key(Origin::signed(1), one),
+            Error::<Test>::InvalidNoteKey
+        );
+
+        for member in 1..=4u64 {
+            assert_ok!(AuditsModule::register_note_key(
+                Origin::signed(member),
+                [member as u8; 32]
+            ));
+            assert_eq!(AuditsModule::get_note_key(member), Some([member as u8; 32]));
+        }
+        let envelope = |member: u64| {
+            (
+                member,
+                KeyEnvelope {
+                    recipient_key: [member as u8; 32],
+                    ephemeral_key: [42u8; 32],
+                    encrypted_key: [42u8; 48],
+                },
+            )
+        };
+        let ciphertext_hash = blake2_256(b"ciphertext");
+        let create_note = |member: u64, group_id: u32, envelopes: Vec<(u64, KeyEnvelope)>| {
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::create_confidential_note(
+                        audit_id,
+                        ciphertext_hash,
+                        None,
+                        envelopes
+                    )
+                )),
+                1,
+                100
+            ));
+        };
+
+        //only the parties to the audit
+        create_note(outsider_member, outsider_group_id, vec![envelope(1)]);
+        assert!(!last_proposal_succeeded());
+
+        //only members of the parties can be recipients
+        create_note(auditors_member, auditors_group_id, vec![envelope(4)]);
+        assert!(!last_proposal_succeeded());
+
+        //envelopes must be sealed to the registered key
+        let (_, mut stale) = envelope(1);
+        stale.recipient_key = [42u8; 32];
+        create_note(auditors_member, auditors_group_id, vec![(1, stale)]);
+        assert!(!last_proposal_succeeded());
+
+        create_note(
+            auditors_member,
+            auditors_group_id,
+            vec![envelope(1), envelope(3)],
+        );
+        assert!(last_proposal_succeeded());
+        let notes = AuditsModule::get_confidential_notes(audit_id);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].0, ciphertext_hash);
+        assert_eq!(notes[0].1.author, auditors_group_account);
+        assert_eq!(
+            AuditsModule::get_note_envelopes(audit_id, ciphertext_hash).len(),
+            2
+        );
+
+        create_note(auditors_member, auditors_group_id, vec![envelope(1)]);
+        assert!(!last_proposal_succeeded());
+
+        let grant = |member: u64, group_id: u32, recipient: u64| {
+            let (recipient, envelope) = envelope(recipient);
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::grant_note_access(audit_id, ciphertext_hash, recipient, envelope)
+                )),
+                1,
+                100
+            ));
+        };
+        let revoke = |member: u64, group_id: u32, recipient: u64| {
+            assert_ok!(Groups::propose(
+                Origin::signed(member),
+                group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::revoke_note_access(audit_id, ciphertext_hash, recipient)
+                )),
+                1,
+                100
+            ));
+        };
+
+        //only the author
+        grant(audit_creator_member, audit_creator_group_id, 2);
+        assert!(!last_proposal_succeeded());
+
+        grant(auditors_member, auditors_group_id, 2);
+        assert!(last_proposal_succeeded());
+        assert!(NoteEnvelopes::<Test>::get((audit_id, ciphertext_hash), 2).is_some());
+
+        grant(auditors_member, auditors_group_id, 4);
+        assert!(!last_proposal_succeeded());
+
+        revoke(auditing_org_member, auditing_org_group_id, 1);
+        assert!(!last_proposal_succeeded());
+
+        revoke(auditors_member, auditors_group_id, 1);
+        assert!(last_proposal_succeeded());
+        assert!(NoteEnvelopes::<Test>::get((audit_id, ciphertext_hash), 1).is_none());
+        assert_eq!(
+            AuditsModule::get_note_envelopes(audit_id, ciphertext_hash).len(),
+            2
+        );
+
+        revoke(auditors_member, auditors_group_id, 1);
+        assert!(!last_proposal_succeeded());
+    });
+}
+
+//Make sure weights cannot exceed 10% of total allowance for block.
+
+#[test]
+fn corrective_actions_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+        let audit_id = create_audit(
+            audit_creator_member,
+            audit_creator_group_account,
+            auditing_org,
+        );
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        let auditors_group_id = 3;
+        let auditors_member = 3;
+        let auditors_group_account = create_group(auditors_member, auditors_group_id);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditors_group_account,
+        );
+
+        let control_point_id = 1;
+        for _ in 0..2 {
+            assert_ok!(Groups::propose(
+                Origin::signed(auditors_member),
+                auditors_group_id,
+                Box::new(crate::mock::Call::AuditsModule(
+                    super::Call::create_observation(
+                        audit_id,
+                        control_point_id,
+                        Some(Compliance::NonCompliant),
+                        None
+                    )
+                )),
+                1,
+                100
+            ));
+        }
+        assert_eq!(OpenFindings::<Test>::get(audit_id), 2);
+        let observation_id = 1;
+
+        //only the audit creator can respond
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    blake2_256(b"plan"),
+                    100,
+                    audit_creator_member
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    blake2_256(b"plan"),
+                    100,
+                    audit_creator_member
+                )
+            )),
+            1,
+            100
+        ));
+        let proposal_id = get_proposal_id();
+        assert_eq!(
+            CorrectiveActions::<Test>::get(audit_id, (control_point_id, observation_id)),
+            Some(CorrectiveAction {
+                proposal_id,
+                description_hash: blake2_256(b"plan"),
+                due_date: 100,
+                responsible: audit_creator_member,
+                status: CorrectiveActionStatus::Open,
+                closing_evidence: None,
+            })
+        );
+        assert_eq!(
+            AuditsModule::get_open_corrective_actions_by_creator(audit_creator_group_account).len(),
+            1
+        );
+        assert_eq!(
+            AuditsModule::get_open_corrective_actions_by_auditing_org(auditing_org).len(),
+            1
+        );
+
+        //only one corrective action per observation
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    blake2_256(b"another plan"),
+                    100,
+                    audit_creator_member
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        create_evidence(auditors_member, auditors_group_id, audit_id);
+        let evidence_id = 1;
+
+        //evidence must be linked to the observation first
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::close_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    evidence_id
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::link_evidence(
+                audit_id,
+                control_point_id,
+                observation_id,
+                evidence_id
+            ))),
+            1,
+            100
+        ));
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::close_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    evidence_id
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        let corrective_action =
+            CorrectiveActions::<Test>::get(audit_id, (control_point_id, observation_id)).unwrap();
+        assert_eq!(corrective_action.status, CorrectiveActionStatus::Closed);
+        assert_eq!(corrective_action.closing_evidence, Some(evidence_id));
+        assert_eq!(OpenFindings::<Test>::get(audit_id), 1);
+        assert!(
+            AuditsModule::get_open_corrective_actions_by_creator(audit_creator_group_account)
+                .is_empty()
+        );
+        assert_eq!(AuditsModule::get_corrective_actions(audit_id).len(), 1);
+
+        //the second finding is still open when the audit is completed
+        let second_observation_id = 2;
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(audit_id)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::CompletedWithOpenFindings
+        );
+
+        //the open finding can still be responded to and evidenced without reopening the audit
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    second_observation_id,
+                    blake2_256(b"second plan"),
+                    100,
+                    audit_creator_member
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_evidence(
+                    audit_id,
+                    b"closure".to_vec(),
+                    b"image/png".to_vec(),
+                    None,
+                    [43u8; 32],
+                    4,
+                )
+            )),
+            1,
+            100
+        ));
+        let second_evidence_id = 2;
+        assert!(Evidences::<Test>::contains_key(
+            audit_id,
+            second_evidence_id
+        ));
+
+        //but closed findings cannot be worked on
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::link_evidence(
+                audit_id,
+                control_point_id,
+                observation_id,
+                second_evidence_id
+            ))),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::link_evidence(
+                audit_id,
+                control_point_id,
+                second_observation_id,
+                second_evidence_id
+            ))),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+
+        //closing the last open finding completes the audit
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::close_corrective_action(
+                    audit_id,
+                    control_point_id,
+                    second_observation_id,
+                    second_evidence_id
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        assert_eq!(OpenFindings::<Test>::get(audit_id), 0);
+        assert_eq!(
+            Audits::<Test>::get(audit_id).unwrap().status,
+            AuditStatus::Completed
+        );
+        assert_eq!(
+            AuditsModule::get_audit_trail(audit_id)
+                .pop()
+                .unwrap()
+                .change,
+            AuditChange::Status(AuditStatus::Completed)
+        );
+    });
+}
+
+#[test]
+fn certificates_should_work() {
+    new_test_ext().execute_with(|| {
+        let audit_creator_member = 1;
+        let audit_creator_group_id = 1;
+        let audit_creator_group_account =
+            create_group(audit_creator_member, audit_creator_group_id);
+        let auditing_org_member = 2;
+        let auditing_org_group_id = 2;
+        let auditing_org = create_group(auditing_org_member, auditing_org_group_id);
+        let audit_id = create_audit(
+            audit_creator_member,
+            audit_creator_group_account,
+            auditing_org,
+        );
+        accept_audit(auditing_org_group_id, audit_id, auditing_org_member);
+        let auditors_group_id = 3;
+        let auditors_member = 3;
+        let auditors_group_account = create_group(auditors_member, auditors_group_id);
+        assign_auditors(
+            audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditors_group_account,
+        );
+        create_observation(auditors_member, auditors_group_id, audit_id);
+
+        //the audit must be completed first
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::issue_certificate(
+                    audit_id,
+                    FARM_DID,
+                    b"Organic".to_vec(),
+                    b"A".to_vec(),
+                    1_000
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(audit_id)
+            )),
+            1,
+            100
+        ));
+
+        //only DIDs in scope of the audit can be certified
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::issue_certificate(
+                    audit_id,
+                    Did { id: [2u8; 32] },
+                    b"Organic".to_vec(),
+                    b"A".to_vec(),
+                    1_000
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::issue_certificate(
+                    audit_id,
+                    FARM_DID,
+                    b"Organic".to_vec(),
+                    b"A".to_vec(),
+                    1_000
+                )
+            )),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            Certificates::<Test>::get(FARM_DID, audit_id),
+            Some(Certificate {
+                claim_id: CLAIM_ID,
+                grade: b"A".to_vec().try_into().unwrap(),
+                valid_until: 1_000,
+                status: CertificateStatus::Valid,
+            })
+        );
+        assert_eq!(AuditsModule::get_certificates_by_did(FARM_DID).len(), 1);
+
+        //a second audit of the farm fails
+        assert_ok!(Groups::propose(
+            Origin::signed(audit_creator_member),
+            audit_creator_group_id,
+            Box::new(crate::mock::Call::AuditsModule(super::Call::create_audit(
+                auditing_org,
+                None,
+                vec![AuditSubject::Did(FARM_DID)],
+                None,
+                None,
+                2u32
+            ))),
+            1,
+            100
+        ));
+        let failed_audit_id = 2;
+        accept_audit(auditing_org_group_id, failed_audit_id, auditing_org_member);
+        assign_auditors(
+            failed_audit_id,
+            auditing_org_group_id,
+            auditing_org_member,
+            auditors_group_account,
+        );
+        assert_ok!(Groups::propose(
+            Origin::signed(auditors_member),
+            auditors_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::create_observation(
+                    failed_audit_id,
+                    1,
+                    Some(Compliance::NonCompliant),
+                    None
+                )
+            )),
+            1,
+            100
+        ));
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::complete_audit(failed_audit_id)
+            )),
+            1,
+            100
+        ));
+
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::revoke_certificate(audit_id, FARM_DID, Some(failed_audit_id))
+            )),
+            1,
+            100
+        ));
+        assert!(last_proposal_succeeded());
+        assert_eq!(
+            Certificates::<Test>::get(FARM_DID, audit_id)
+                .unwrap()
+                .status,
+            CertificateStatus::Revoked(Some(failed_audit_id))
+        );
+
+        //a certificate can only be revoked once
+        assert_ok!(Groups::propose(
+            Origin::signed(auditing_org_member),
+            auditing_org_group_id,
+            Box::new(crate::mock::Call::AuditsModule(
+                super::Call::revoke_certificate(audit_id, FARM_DID, None)
+            )),
+            1,
+            100
+        ));
+        assert!(!last_proposal_succeeded());
+    });
+}
+
+#[test]
+fn migrate_to_v3_should_work() {
+    new_test_ext().execute_with(|| {
+        use crate::migration::deprecated::OldEvidence;
+        use frame_support::storage::unhashed;
+
+        let audit_id = 1u32;
+        let hash = [42u8; 32];
+        let hex_hash = format!("0x{}", "2a".repeat(32)).into_bytes();
+        let url_hash = b"https://example.com/evidence.png".to_vec();
+
+        // seed evidence as it was encoded in V2
+        StorageVersion::<Test>::put(Releases::V2);
+        for (evidence_id, old_hash) in vec![hash.to_vec(), hex_hash, url_hash.clone(), vec![]]
+            .into_iter()
+            .enumerate()
+        {
+            unhashed::put(
+                &Evidences::<Test>::hashed_key_for(audit_id, evidence_id as u32 + 1),
+                &OldEvidence::<u32, Vec<u8>, Vec<u8>> {
+                    proposal_id: 1,
+                    name: b"name".to_vec(),
+                    content_type: b"image/png".to_vec(),
+                    url: None,
+                    hash: old_hash,
+                },
+            );
+        }
+
+        crate::migration::migrate_to_v3::<Test>();
+
+        assert_eq!(StorageVersion::<Test>::get(), Some(Releases::V3));
+        let evidence = |evidence_id: u32| Evidences::<Test>::get(audit_id, evidence_id).unwrap();
+        assert_eq!(evidence(1).hash, hash);
+        assert_eq!(evidence(1).legacy_hash, None);
+        // hex encoded hashes are decoded
+        assert_eq!(evidence(2).hash, hash);
+        assert_eq!(evidence(2).legacy_hash, None);
+        // anything else is kept as it was uploaded
+        assert_eq!(evidence(3).hash, [0u8; 32]);
+        assert_eq!(evidence(3).legacy_hash, Some(url_hash));
+        assert_eq!(evidence(4).hash, [0u8; 32]);
+        assert_eq!(evidence(4).legacy_hash, None);
+    });
+}
+
+#[test]
+fn weights_should_not_be_excessive() {
+    new_test_ext().execute_with(|| {
+        const MAXIMUM_ALLOWED_WEIGHT: Weight = 130_000_000_000;
+
+        let weight = <Test as Config>::WeightInfo::create_audit(
+            <Test as Config>::SubjectLimit::get(),
+            <Test as Config>::FeeMilestoneLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::delete_audit();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::link_audit();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::unlink_audit();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::accept_audit();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::assign_auditors_initial_assign();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::assign_auditors_replace();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::reject_audit();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::complete_audit();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_observation();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_evidence(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::NameLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::link_evidence();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::unlink_evidence();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight =
+            <Test as Config>::WeightInfo::delete_evidence(<Test as Config>::MaxLinkRemove::get());
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_framework(
+            <Test as Config>::ControlPointLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::publish_framework_version(
+            <Test as Config>::ControlPointLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_corrective_action();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::close_corrective_action();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::issue_certificate(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::NameLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::revoke_certificate();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::dispute_fee();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::resolve_fee_dispute();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_audit_schedule(
+            <Test as Config>::SubjectLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::pause_audit_schedule();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::resume_audit_schedule();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::cancel_audit_schedule();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::on_initialize(
+            <Test as Config>::MaxScheduledPerBlock::get(),
+            <Test as Config>::MaxScheduledPerBlock::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::reopen_audit(<Test as Config>::NameLimit::get());
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::withdraw_reopen_request();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight =
+            <Test as Config>::WeightInfo::supersede_observation(<Test as Config>::NameLimit::get());
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::register_note_key();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_confidential_note(
+            <Test as Config>::NoteRecipientLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::grant_note_access();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::revoke_note_access();
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
     });
 }
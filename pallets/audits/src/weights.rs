@@ -50,7 +50,7 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_audits.
 pub trait WeightInfo {
-    fn create_audit() -> Weight;
+    fn create_audit(a: u32, b: u32) -> Weight;
     fn delete_audit() -> Weight;
     fn link_audit() -> Weight;
     fn unlink_audit() -> Weight;
@@ -60,24 +60,49 @@ pub trait WeightInfo {
     fn reject_audit() -> Weight;
     fn complete_audit() -> Weight;
     fn create_observation() -> Weight;
-    fn create_evidence(a: u32, b: u32, c: u32, d: u32) -> Weight;
+    fn create_evidence(a: u32, b: u32, c: u32) -> Weight;
     fn link_evidence() -> Weight;
     fn unlink_evidence() -> Weight;
     fn delete_evidence(a: u32) -> Weight;
+    fn create_framework(a: u32) -> Weight;
+    fn publish_framework_version(a: u32) -> Weight;
+    fn create_corrective_action() -> Weight;
+    fn close_corrective_action() -> Weight;
+    fn issue_certificate(a: u32, b: u32) -> Weight;
+    fn revoke_certificate() -> Weight;
+    fn dispute_fee() -> Weight;
+    fn resolve_fee_dispute() -> Weight;
+    fn create_audit_schedule(a: u32) -> Weight;
+    fn pause_audit_schedule() -> Weight;
+    fn resume_audit_schedule() -> Weight;
+    fn cancel_audit_schedule() -> Weight;
+    fn on_initialize(a: u32, b: u32) -> Weight;
+    fn reopen_audit(a: u32) -> Weight;
+    fn supersede_observation(a: u32) -> Weight;
+    fn register_note_key() -> Weight;
+    fn create_confidential_note(a: u32) -> Weight;
+    fn grant_note_access() -> Weight;
+    fn revoke_note_access() -> Weight;
+    fn withdraw_reopen_request() -> Weight;
 }
 
 /// Weights for pallet_audits using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    fn create_audit() -> Weight {
+    fn create_audit(a: u32, b: u32) -> Weight {
         (121_647_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+            // Standard Error: 6_000
+            .saturating_add((9_215_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((346_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().writes(11 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
     }
     fn delete_audit() -> Weight {
-        (112_067_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+        (118_402_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
     }
     fn link_audit() -> Weight {
         (116_274_000 as Weight)
@@ -90,9 +115,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
     fn accept_audit() -> Weight {
-        (92_723_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+        (131_985_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
     }
     fn assign_auditors_initial_assign() -> Weight {
         (98_179_000 as Weight)
@@ -105,28 +130,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     fn reject_audit() -> Weight {
-        (100_823_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+        (107_311_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
     }
     fn complete_audit() -> Weight {
-        (101_715_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+        (158_062_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
     fn create_observation() -> Weight {
-        (160_908_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(3 as Weight))
-            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+        (214_570_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(8 as Weight))
+            .saturating_add(T::DbWeight::get().writes(11 as Weight))
     }
-    fn create_evidence(_a: u32, b: u32, c: u32, d: u32) -> Weight {
+    fn create_evidence(_a: u32, b: u32, c: u32) -> Weight {
         (137_157_000 as Weight)
             // Standard Error: 2_000
             .saturating_add((3_000 as Weight).saturating_mul(b as Weight))
             // Standard Error: 2_000
             .saturating_add((2_000 as Weight).saturating_mul(c as Weight))
-            // Standard Error: 2_000
-            .saturating_add((1_000 as Weight).saturating_mul(d as Weight))
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
@@ -149,19 +172,140 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(2 as Weight))
             .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
     }
+    fn create_framework(a: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn publish_framework_version(a: u32) -> Weight {
+        (96_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn create_corrective_action() -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn close_corrective_action() -> Weight {
+        (112_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn issue_certificate(a: u32, b: u32) -> Weight {
+        (180_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn revoke_certificate() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn dispute_fee() -> Weight {
+        (98_214_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn resolve_fee_dispute() -> Weight {
+        (152_387_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn create_audit_schedule(a: u32) -> Weight {
+        (121_873_000 as Weight)
+            .saturating_add((1_204_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn pause_audit_schedule() -> Weight {
+        (76_310_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn resume_audit_schedule() -> Weight {
+        (76_842_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_audit_schedule() -> Weight {
+        (81_529_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn on_initialize(a: u32, b: u32) -> Weight {
+        (3_214_000 as Weight)
+            .saturating_add((58_731_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((19_406_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(b as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((15 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+    }
+    fn reopen_audit(a: u32) -> Weight {
+        (84_215_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn supersede_observation(a: u32) -> Weight {
+        (131_472_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(9 as Weight))
+    }
+    fn register_note_key() -> Weight {
+        (42_113_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn create_confidential_note(a: u32) -> Weight {
+        (79_254_000 as Weight)
+            .saturating_add((24_637_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn grant_note_access() -> Weight {
+        (86_406_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn revoke_note_access() -> Weight {
+        (58_921_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn withdraw_reopen_request() -> Weight {
+        (58_214_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-    fn create_audit() -> Weight {
+    fn create_audit(a: u32, b: u32) -> Weight {
         (121_647_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+            // Standard Error: 6_000
+            .saturating_add((9_215_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((346_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(11 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
     }
     fn delete_audit() -> Weight {
-        (112_067_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+        (118_402_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(7 as Weight))
     }
     fn link_audit() -> Weight {
         (116_274_000 as Weight)
@@ -174,9 +318,9 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
     fn accept_audit() -> Weight {
-        (92_723_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+        (131_985_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
     }
     fn assign_auditors_initial_assign() -> Weight {
         (98_179_000 as Weight)
@@ -189,28 +333,26 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
     fn reject_audit() -> Weight {
-        (100_823_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+        (107_311_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
     }
     fn complete_audit() -> Weight {
-        (101_715_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+        (158_062_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
     }
     fn create_observation() -> Weight {
-        (160_908_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+        (214_570_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(8 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(11 as Weight))
     }
-    fn create_evidence(_a: u32, b: u32, c: u32, d: u32) -> Weight {
+    fn create_evidence(_a: u32, b: u32, c: u32) -> Weight {
         (137_157_000 as Weight)
             // Standard Error: 2_000
             .saturating_add((3_000 as Weight).saturating_mul(b as Weight))
             // Standard Error: 2_000
             .saturating_add((2_000 as Weight).saturating_mul(c as Weight))
-            // Standard Error: 2_000
-            .saturating_add((1_000 as Weight).saturating_mul(d as Weight))
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
@@ -233,4 +375,120 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(2 as Weight))
             .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
     }
+    fn create_framework(a: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn publish_framework_version(a: u32) -> Weight {
+        (96_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn create_corrective_action() -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn close_corrective_action() -> Weight {
+        (112_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+    }
+    fn issue_certificate(a: u32, b: u32) -> Weight {
+        (180_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn revoke_certificate() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn dispute_fee() -> Weight {
+        (98_214_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn resolve_fee_dispute() -> Weight {
+        (152_387_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn create_audit_schedule(a: u32) -> Weight {
+        (121_873_000 as Weight)
+            .saturating_add((1_204_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
+    }
+    fn pause_audit_schedule() -> Weight {
+        (76_310_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn resume_audit_schedule() -> Weight {
+        (76_842_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_audit_schedule() -> Weight {
+        (81_529_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn on_initialize(a: u32, b: u32) -> Weight {
+        (3_214_000 as Weight)
+            .saturating_add((58_731_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((19_406_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(b as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((15 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+    }
+    fn reopen_audit(a: u32) -> Weight {
+        (84_215_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn supersede_observation(a: u32) -> Weight {
+        (131_472_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(9 as Weight))
+    }
+    fn register_note_key() -> Weight {
+        (42_113_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn create_confidential_note(a: u32) -> Weight {
+        (79_254_000 as Weight)
+            .saturating_add((24_637_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn grant_note_access() -> Weight {
+        (86_406_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn revoke_note_access() -> Weight {
+        (58_921_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn withdraw_reopen_request() -> Weight {
+        (58_214_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
 }
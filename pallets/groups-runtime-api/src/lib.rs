@@ -4,12 +4,12 @@
 
 use codec::Codec;
 use frame_support::dispatch::Vec;
-use primitives::group::{Group, Votes};
+use primitives::group::{Budget, BudgetUsage, Group, ScheduledExecution, Votes};
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime amalgamator file (the `runtime/src/lib.rs`)
 sp_api::decl_runtime_apis! {
-    pub trait GroupsApi<AccountId,GroupId,MemberCount,ProposalId,Hash,BoundedString,Balance>
+    pub trait GroupsApi<AccountId,GroupId,MemberCount,ProposalId,Hash,BoundedString,Balance,BlockNumber>
     where
     AccountId: Codec,
     GroupId: Codec,
@@ -17,7 +17,8 @@ sp_api::decl_runtime_apis! {
     ProposalId: Codec,
     Hash: Codec + AsRef<[u8]>,
     BoundedString: Codec + Into<Vec<u8>>,
-    Balance: Codec
+    Balance: Codec,
+    BlockNumber: Codec
     {
         fn member_of(account:AccountId) -> Vec<(GroupId, Group<GroupId, AccountId, MemberCount, BoundedString>,Vec<(AccountId, MemberCount)>,Balance)> ;
         fn is_member(group:GroupId,account:AccountId) -> bool;
@@ -28,6 +29,9 @@ sp_api::decl_runtime_apis! {
         fn get_proposal(proposal_id:ProposalId) -> Option<(ProposalId, GroupId,Vec<(AccountId, MemberCount)>,Option<(Hash,u32)>,Votes<AccountId, MemberCount>)> ;
         fn get_proposals_by_group(group:GroupId) -> Vec<(ProposalId,GroupId, Vec<(AccountId, MemberCount)>,Option<(Hash,u32)>,Votes<AccountId, MemberCount>)>;
         fn get_proposals_by_account(account_id: AccountId) -> Vec<(GroupId, Vec<(ProposalId, GroupId,Vec<(AccountId, MemberCount)>,Option<(Hash,u32)>,Votes<AccountId, MemberCount>)>)>;
+        fn get_scheduled_proposals(group:GroupId) -> Vec<(ProposalId,ScheduledExecution<AccountId,BlockNumber,MemberCount>)>;
+        fn get_budget(group:GroupId) -> Option<(Budget<Balance,BlockNumber,MemberCount>,BudgetUsage<Balance,BlockNumber>)>;
+        fn get_invitations(account:AccountId) -> Vec<(GroupId,Group<GroupId, AccountId, MemberCount,BoundedString>,MemberCount)>;
 
     }
 }
@@ -32,6 +32,34 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+fn create_joint_groups<T: Config>(
+    caller: &T::AccountId,
+    g: u32,
+) -> Result<Vec<(T::GroupId, T::MemberCount)>, &'static str> {
+    let mut thresholds = vec![];
+    for i in 1..=g {
+        GroupPallet::<T>::create_group(
+            SystemOrigin::Signed(caller.clone()).into(),
+            vec![42u8; 2 as usize],
+            vec![(caller.clone(), 1u32.into())],
+            1u32.into(),
+            1_000_000u32.into(),
+        )?;
+        thresholds.push((i.into(), 1u32.into()));
+    }
+    Ok(thresholds)
+}
+
+fn accept_invitations<T: Config>(group_id: T::GroupId) -> Result<(), &'static str> {
+    let invited: Vec<T::AccountId> = PendingInvitations::<T>::iter_prefix(group_id)
+        .map(|(account, _)| account)
+        .collect();
+    for account in invited {
+        GroupPallet::<T>::accept_invitation(SystemOrigin::Signed(account).into(), group_id)?;
+    }
+    Ok(())
+}
+
 benchmarks! {
     create_group {
         let a in 1 .. <T as Config>::NameLimit::get();
@@ -54,7 +82,8 @@ benchmarks! {
         assert!(Groups::<T>::contains_key(group_id));
         let group=Groups::<T>::get(group_id).unwrap();
         assert_eq!(group.name.len(),a as usize);
-        assert_eq!(GroupMembers::<T>::iter_prefix(group_id).count(),m as usize);
+        assert_eq!(GroupMembers::<T>::iter_prefix(group_id).count(),1);
+        assert_eq!(PendingInvitations::<T>::iter_prefix(group_id).count(),(m - 1) as usize);
     }
 
     update_group {
@@ -77,6 +106,7 @@ benchmarks! {
         GroupPallet::<T>::create_group(origin,vec![42u8; 2 as usize],origional_members, 1u32.into(),1_000_000_000u32.into())?;
         let group_id:T::GroupId=1u32.into();
         assert!(Groups::<T>::contains_key(group_id));
+        accept_invitations::<T>(group_id)?;
 
         let mut new_members = vec![];
         //don't overlap members
@@ -96,7 +126,7 @@ benchmarks! {
         assert!(Groups::<T>::contains_key(group_id));
         let group=Groups::<T>::get(group_id).unwrap();
         assert_eq!(group.name.len(),a as usize);
-        assert_eq!(GroupMembers::<T>::iter_prefix(group_id).count(),n as usize);
+        assert_eq!(PendingInvitations::<T>::iter_prefix(group_id).count(),n as usize);
         assert_eq!(group.threshold,2u32.into());
     }
 
@@ -131,7 +161,7 @@ benchmarks! {
     verify {
         let sub_group_id:T::GroupId=2u32.into();
         assert!(Groups::<T>::contains_key(sub_group_id));
-        assert_eq!(GroupMembers::<T>::iter_prefix(sub_group_id).count(),m as usize);
+        assert_eq!(PendingInvitations::<T>::iter_prefix(sub_group_id).count(),m as usize);
     }
 
     update_sub_group {
@@ -160,6 +190,7 @@ benchmarks! {
         let origin=<T as Config>::GroupsOriginByGroupThreshold::successful_origin();
         let call = Call::<T>::create_sub_group(vec![42u8; 2 as usize],origional_members,1u32.into(),1_000u32.into());
         call.dispatch_bypass_filter(origin.clone())? ;
+        accept_invitations::<T>(2u32.into())?;
 
         let mut new_members = vec![];
         //don't overlap members
@@ -180,7 +211,7 @@ benchmarks! {
         assert!(Groups::<T>::contains_key(sub_group_id));
         let sub_group=Groups::<T>::get(sub_group_id).unwrap();
         assert_eq!(sub_group.name.len(),a as usize);
-        assert_eq!(GroupMembers::<T>::iter_prefix(sub_group_id).count(),n as usize);
+        assert_eq!(PendingInvitations::<T>::iter_prefix(sub_group_id).count(),n as usize);
         assert_eq!(sub_group.threshold,2u32.into());
     }
 
@@ -202,6 +233,7 @@ benchmarks! {
         GroupPallet::<T>::create_group(origin,vec![42u8; 2 as usize],members, 1u32.into(),1_000_000_000u32.into())?;
         let group_id:T::GroupId=1u32.into();
         assert!(Groups::<T>::contains_key(group_id));
+        accept_invitations::<T>(group_id)?;
 
         for i in 0 .. p {
             let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; T::MaxProposalLength::get()  as usize]).into();
@@ -249,6 +281,7 @@ benchmarks! {
 
         let sub_group_id:T::GroupId=2u32.into();
         assert!(Groups::<T>::contains_key(sub_group_id));
+        accept_invitations::<T>(sub_group_id)?;
 
         for i in 0 .. p {
             let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; T::MaxProposalLength::get()  as usize]).into();
@@ -376,6 +409,7 @@ benchmarks! {
         GroupPallet::<T>::create_group(SystemOrigin::Signed(voter.clone()).into(),vec![42u8; 2 as usize],members.clone(), m.into(),1_000_000u32.into())?;
         let group_id:T::GroupId=1u32.into();
         assert!(Groups::<T>::contains_key(group_id));
+        accept_invitations::<T>(group_id)?;
 
         // Threshold is 1 less than the number of members so that one person can vote nay
         let threshold:T::MemberCount = (m - 1).into();
@@ -456,6 +490,7 @@ benchmarks! {
         GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(),vec![42u8; 2 as usize],members.clone(), m.into(),1_000_000u32.into())?;
         let group_id:T::GroupId=1u32.into();
         assert!(Groups::<T>::contains_key(group_id));
+        accept_invitations::<T>(group_id)?;
 
         let threshold = m.into();
 
@@ -495,6 +530,43 @@ benchmarks! {
 
     }
 
+    expire_proposal {
+
+        let bytes = 100;
+        let bytes_in_storage = bytes + size_of::<u32>() as u32;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let member: T::AccountId = account("member", 1, SEED);
+        let members = vec![(caller.clone(),1u32.into()),(member,1u32.into())];
+
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(),vec![42u8; 2 as usize],members, 2u32.into(),1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        assert!(Groups::<T>::contains_key(group_id));
+        accept_invitations::<T>(group_id)?;
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; bytes as usize]).into();
+        GroupPallet::<T>::propose(
+            SystemOrigin::Signed(caller.clone()).into(),
+            group_id,
+            Box::new(proposal),
+            2u32.into(),
+            bytes_in_storage,
+        )?;
+
+        let proposal_id:T::ProposalId=1u32.into();
+        assert!(Proposals::<T>::contains_key(group_id,proposal_id));
+
+        let expiry = ProposalExpiries::<T>::get(group_id,proposal_id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(expiry);
+
+    }: _(SystemOrigin::Signed(caller), group_id, proposal_id)
+
+    verify {
+        assert!(!Proposals::<T>::contains_key(group_id,proposal_id));
+        assert_last_event::<T>(Event::ProposalExpired(group_id,proposal_id).into());
+    }
+
     close_approved {
         let a in 1 .. T::MaxProposalLength::get();
         //start at 2 so that a vote is always required
@@ -515,6 +587,7 @@ benchmarks! {
         GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(),vec![42u8; 2 as usize],members.clone(), m.into(),1_000_000u32.into())?;
         let group_id:T::GroupId=1u32.into();
         assert!(Groups::<T>::contains_key(group_id));
+        accept_invitations::<T>(group_id)?;
 
         // Threshold is two, so any two ayes will pass the vote
         let threshold = m.into();
@@ -581,6 +654,7 @@ benchmarks! {
         call.dispatch_bypass_filter(origin)? ;
         let sub_group_id:T::GroupId=2u32.into();
         assert!(Groups::<T>::contains_key(sub_group_id));
+        accept_invitations::<T>(sub_group_id)?;
 
         // Add proposal
 
@@ -634,6 +708,7 @@ benchmarks! {
         call.dispatch_bypass_filter(origin)? ;
         let sub_group_id:T::GroupId=2u32.into();
         assert!(Groups::<T>::contains_key(sub_group_id));
+        accept_invitations::<T>(sub_group_id)?;
 
         // Add proposal
 
@@ -666,6 +741,32 @@ benchmarks! {
         // assert_last_event::<T>(Event::ApprovedByVeto(admin,sub_group_id,proposal_id,false,None).into());
     }
 
+    propose_with_timelock {
+        let a in 1 .. T::MaxProposalLength::get();
+
+        let bytes_in_storage = a + size_of::<u32>() as u32;
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+        let members = vec![(caller.clone(),1u32.into()),(account("member", 1, SEED),1u32.into())];
+
+        GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(), vec![42u8; 2 as usize], members, 2u32.into(), 1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        assert!(Groups::<T>::contains_key(group_id));
+
+        let threshold = 2u32.into();
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![42u8 as u8; a as usize]).into();
+
+    }: propose_with_timelock(SystemOrigin::Signed(caller.clone()), group_id,Box::new(proposal.clone()),threshold,Timelock::Delay(10u32.into()),bytes_in_storage)
+
+    verify {
+        let proposal_id:T::ProposalId=1u32.into();
+        assert!(ProposalTimelocks::<T>::contains_key(group_id,proposal_id));
+        assert_last_event::<T>(Event::Proposed(caller, group_id, proposal_id,threshold).into());
+    }
+
     withdraw_funds_group {
 
         let caller = whitelisted_caller();
@@ -755,6 +856,296 @@ benchmarks! {
     }
 
 
+    set_budget {
+        let t in 0 .. T::MaxBudgetTiers::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        GroupPallet::<T>::create_group(origin,vec![42u8; 2 as usize],vec![(caller.clone(), 1u32.into())], 1u32.into(),1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        assert!(Groups::<T>::contains_key(group_id));
+
+        let tiers=(0..t).map(|i| ((i * 1_000u32).into(), (i + 1).into())).collect();
+        let budget=Budget {
+            period: 10u32.into(),
+            period_cap: 1_000_000u32.into(),
+            small_spend: 1_000u32.into(),
+            tiers,
+        };
+
+        let origin=<T as Config>::GroupsOriginByGroupThreshold::successful_origin();
+        let call = Call::<T>::set_budget(group_id,Some(budget));
+
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert!(Budgets::<T>::contains_key(group_id));
+        assert_last_event::<T>(Event::BudgetUpdated(group_id,group_id).into());
+    }
+
+    accept_invitation {
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+        let member: T::AccountId = account("member", 1, SEED);
+        let members = vec![(caller.clone(),1u32.into()),(member.clone(),1u32.into())];
+
+        GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(), vec![42u8; 2 as usize], members, 2u32.into(), 1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        assert!(PendingInvitations::<T>::contains_key(group_id,&member));
+
+    }: _(SystemOrigin::Signed(member.clone()), group_id)
+
+    verify {
+        assert!(GroupMembers::<T>::contains_key(group_id,&member));
+        assert!(!PendingInvitations::<T>::contains_key(group_id,&member));
+        assert_last_event::<T>(Event::InvitationAccepted(group_id,member).into());
+    }
+
+    decline_invitation {
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+        let member: T::AccountId = account("member", 1, SEED);
+        let members = vec![(caller.clone(),1u32.into()),(member.clone(),1u32.into())];
+
+        GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(), vec![42u8; 2 as usize], members, 2u32.into(), 1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        assert!(PendingInvitations::<T>::contains_key(group_id,&member));
+
+    }: _(SystemOrigin::Signed(member.clone()), group_id)
+
+    verify {
+        assert!(!GroupMembers::<T>::contains_key(group_id,&member));
+        assert!(!PendingInvitations::<T>::contains_key(group_id,&member));
+        assert_eq!(Groups::<T>::get(group_id).unwrap().threshold,1u32.into());
+        assert_last_event::<T>(Event::InvitationDeclined(group_id,member).into());
+    }
+
+    leave_group {
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+        let member: T::AccountId = account("member", 1, SEED);
+        let members = vec![(caller.clone(),1u32.into()),(member.clone(),1u32.into())];
+
+        GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(), vec![42u8; 2 as usize], members, 2u32.into(), 1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        accept_invitations::<T>(group_id)?;
+        assert!(GroupMembers::<T>::contains_key(group_id,&member));
+
+    }: _(SystemOrigin::Signed(member.clone()), group_id)
+
+    verify {
+        assert!(!GroupMembers::<T>::contains_key(group_id,&member));
+        let group=Groups::<T>::get(group_id).unwrap();
+        assert_eq!(group.total_vote_weight,1u32.into());
+        assert_eq!(group.threshold,1u32.into());
+        assert_last_event::<T>(Event::MemberLeft(group_id,member).into());
+    }
+
+    cancel_scheduled {
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+        let members = vec![(caller.clone(),1u32.into())];
+
+        GroupPallet::<T>::create_group(SystemOrigin::Signed(caller.clone()).into(), vec![42u8; 2 as usize], members, 1u32.into(), 1_000_000u32.into())?;
+        let group_id:T::GroupId=1u32.into();
+        assert!(Groups::<T>::contains_key(group_id));
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![42u8; 100]).into();
+        GroupPallet::<T>::propose_with_timelock(
+            SystemOrigin::Signed(caller).into(),
+            group_id,
+            Box::new(proposal),
+            1u32.into(),
+            Timelock::Delay(10u32.into()),
+            100 + size_of::<u32>() as u32,
+        )?;
+        let proposal_id:T::ProposalId=1u32.into();
+        assert!(ScheduledProposals::<T>::contains_key(group_id,proposal_id));
+
+        let origin=<T as Config>::GroupsOriginByGroupThreshold::successful_origin();
+        let call = Call::<T>::cancel_scheduled(proposal_id);
+
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    verify {
+        assert!(!ScheduledProposals::<T>::contains_key(group_id,proposal_id));
+        assert!(!Proposals::<T>::contains_key(group_id,proposal_id));
+        assert_last_event::<T>(Event::ScheduledProposalCancelled(group_id,proposal_id).into());
+    }
+
+    propose_joint {
+        let a in 1 .. T::MaxProposalLength::get();
+        let g in 2 .. T::MaxJointGroups::get();
+
+        let bytes_in_storage = a + size_of::<u32>() as u32;
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let thresholds = create_joint_groups::<T>(&caller, g)?;
+        let group_id:T::GroupId=1u32.into();
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![42u8 as u8; a as usize]).into();
+
+    }: _(SystemOrigin::Signed(caller.clone()), group_id, Box::new(proposal.clone()), thresholds, bytes_in_storage)
+
+    verify {
+        let proposal_id:T::ProposalId=1u32.into();
+        assert!(JointProposals::<T>::contains_key(proposal_id));
+        assert_eq!(JointVoting::<T>::iter_prefix(proposal_id).count(), g as usize);
+        assert_last_event::<T>(Event::JointProposed(caller, group_id, proposal_id).into());
+    }
+
+    vote_joint {
+        let m in 2 .. T::MaxMembers::get().unique_saturated_into();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let thresholds = create_joint_groups::<T>(&caller, 2)?;
+        let group_id:T::GroupId=1u32.into();
+        let voter: T::AccountId = account("member", 1, SEED);
+
+        let mut members = vec![];
+        for i in 1 .. m {
+            let member = account("member", i, SEED);
+            members.push((member,1u32.into()));
+        }
+        let origin=<T as Config>::GroupsOriginByGroupThreshold::successful_origin();
+        GroupPallet::<T>::update_group(origin, None, Some(members), None, None)?;
+        accept_invitations::<T>(group_id)?;
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![42u8; 100 as usize]).into();
+        GroupPallet::<T>::propose_joint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            group_id,
+            Box::new(proposal),
+            thresholds,
+            1_000,
+        )?;
+        let proposal_id:T::ProposalId=1u32.into();
+
+    }: _(SystemOrigin::Signed(voter.clone()), group_id, proposal_id, true)
+
+    verify {
+        let votes=JointVoting::<T>::get(proposal_id,group_id).unwrap();
+        assert_eq!(votes.ayes.len(),2);
+        assert_last_event::<T>(Event::Voted(voter, group_id, proposal_id, true).into());
+    }
+
+    close_joint_approved {
+        let a in 1 .. T::MaxProposalLength::get();
+        let g in 2 .. T::MaxJointGroups::get();
+
+        let bytes_in_storage = a + size_of::<u32>() as u32;
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let thresholds = create_joint_groups::<T>(&caller, g)?;
+        let group_id:T::GroupId=1u32.into();
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; a as usize]).into();
+        GroupPallet::<T>::propose_joint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            group_id,
+            Box::new(proposal),
+            thresholds,
+            bytes_in_storage,
+        )?;
+        let proposal_id:T::ProposalId=1u32.into();
+
+        for i in 2 ..= g {
+            GroupPallet::<T>::vote_joint(
+                SystemOrigin::Signed(caller.clone()).into(),
+                i.into(),
+                proposal_id,
+                true,
+            )?;
+        }
+
+    }: close_joint(SystemOrigin::Signed(caller), proposal_id, Weight::max_value(), bytes_in_storage)
+
+    verify {
+        assert!(!JointProposals::<T>::contains_key(proposal_id));
+        assert!(JointVoting::<T>::iter_prefix(proposal_id).next().is_none());
+    }
+
+    close_joint_disapproved {
+        let g in 2 .. T::MaxJointGroups::get();
+
+        let bytes_in_storage = 100 + size_of::<u32>() as u32;
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let thresholds = create_joint_groups::<T>(&caller, g)?;
+        let group_id:T::GroupId=1u32.into();
+        let last_group_id:T::GroupId=g.into();
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; 100 as usize]).into();
+        GroupPallet::<T>::propose_joint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            group_id,
+            Box::new(proposal),
+            thresholds,
+            bytes_in_storage,
+        )?;
+        let proposal_id:T::ProposalId=1u32.into();
+
+        for i in 2 .. g {
+            GroupPallet::<T>::vote_joint(
+                SystemOrigin::Signed(caller.clone()).into(),
+                i.into(),
+                proposal_id,
+                true,
+            )?;
+        }
+        GroupPallet::<T>::vote_joint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            last_group_id,
+            proposal_id,
+            false,
+        )?;
+
+    }: close_joint(SystemOrigin::Signed(caller), proposal_id, Weight::max_value(), bytes_in_storage)
+
+    verify {
+        assert!(!JointProposals::<T>::contains_key(proposal_id));
+        assert_last_event::<T>(Event::JointDisapproved(proposal_id, last_group_id).into());
+    }
+
+    expire_joint_proposal {
+        let g in 2 .. T::MaxJointGroups::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let thresholds = create_joint_groups::<T>(&caller, g)?;
+        let group_id:T::GroupId=1u32.into();
+
+        let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; 100 as usize]).into();
+        GroupPallet::<T>::propose_joint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            group_id,
+            Box::new(proposal),
+            thresholds,
+            1_000,
+        )?;
+        let proposal_id:T::ProposalId=1u32.into();
+
+        let expiry = ProposalExpiries::<T>::get(group_id,proposal_id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(expiry);
+
+    }: _(SystemOrigin::Signed(caller), proposal_id)
+
+    verify {
+        assert!(!JointProposals::<T>::contains_key(proposal_id));
+        assert_eq!(JointVoting::<T>::iter_prefix(proposal_id).count(), 0);
+        assert_last_event::<T>(Event::JointProposalExpired(proposal_id).into());
+    }
+
 }
 
 impl_benchmark_test_suite!(GroupPallet, crate::mock::new_test_ext(), crate::mock::Test,);
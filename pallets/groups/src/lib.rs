@@ -11,9 +11,15 @@
 //! #### For general users
 //! * `create_group` - Creates a new **Group**
 //!                    The creator transfers some funds to the **Group** account as part of creation.
+//!                    The creator joins immediately if listed as a member, all other members are invited.
+//!                    Proposals cannot be approved until the members that have joined carry the threshold.
+//! * `accept_invitation` - An invited account joins the **Group** with the weight it was invited with.
+//! * `decline_invitation` - An invited account declines to join the **Group**.
 //!
 //! #### For **Group** members
 //! * `update_group` - Members of a **Group** can update the group via a **Proposal**.
+//!                    New members are invited and must accept before they can vote.
+//! * `leave_group` - A member can leave a **Group**/**Sub-group** without a **Proposal**. The last member cannot leave.
 //! * `remove_group` - Members of a **Group** can remove the group via a **Proposal**.
 //!                    Funds remaining in the **Group** account are transfered to the specified account.
 //! * `create_sub_group` - A **Group** creates a **Sub-group**.
@@ -25,13 +31,30 @@
 //!               The caller specifies threshold and voting proceeds until that threshold is met or cannot be met.
 //!               The threshold is not checked at this stage but is instead checked upon extrinsic execution and depends on the requirements of the extrinsic called.
 //!               If the specified threshold is 1, the extrinsic is executed immediately.
+//!               Otherwise a refundable deposit (set in the settings pallet) is reserved from the proposer. It is returned once the **Proposal** is closed,
+//!               and paid to the **Group** account if the **Proposal** is disapproved by veto or expires without any other member voting.
+//! * `propose_with_timelock` - As `propose`, but once approved the extrinsic is queued and dispatched at a given block or after a delay.
+//!                             Members of the parent **Group** may cancel it during the delay using `veto`.
+//!                             At most `MaxScheduledPerBlock` proposals are dispatched in a block, later ones wait for the next block with room.
+//!                             Proposals that do not fit in the `MaximumScheduledWeight` of a block are carried over to the next block.
+//! * `propose_joint` - A member of a **Group** can propose an extrinsic that must be approved by several **Groups**, each with its own threshold.
+//!                     Once every participating **Group** has met its threshold the extrinsic is dispatched with the accounts of all of them.
+//!                     The proposer's deposit is reserved as for `propose`, and the **Proposal** counts towards the `MaxProposals` of every participating **Group**.
+//! * `vote_joint` - A member of a participating **Group** can vote on a joint **Proposal** on behalf of their **Group**.
+//! * `close_joint` - Dispatch a joint **Proposal** once every participating **Group** has approved it, or remove it if any **Group** cannot.
+//! * `expire_joint_proposal` - Anyone can remove a joint **Proposal** that has not been closed within `ProposalLifetime` blocks.
 //! * `vote` - A member of a **Group**/**Sub-group** can vote on pending **Proposals**
 //! *          A member may change thier vote while the **Proposal** is still in progress, but there is an extra charge.
 //! * `close` - After voting a caller should check the vote tallies and call `close` if the threshold is met or cannot be met.
 //! * `veto` - A member of a **Group** can veto an ongoing **Proposal** (override the existing votes with either yay or nay).
+//! * `expire_proposal` - Anyone can remove a **Proposal** that has not been closed within `ProposalLifetime` blocks.
+//! * `cancel_scheduled` - A **Group** can cancel one of its approved **Proposals** waiting in the execution queue via a **Proposal**.
 //! * `withdraw_funds_group` - A **Group** can choose to withdraw funds from the group account to a chosen account via a **Proposal**
 //! * `withdraw_funds_sub_group` - A **Group** can choose to withdraw funds from one of its sub_groups into its account via a **Proposal**
 //! * `send_funds_to_sub_group` - A **Group** can choose to send funds from its account to one of its sub_groups into  via a **Proposal**
+//! * `set_budget` - A **Group** can set spending limits on its own account, or on the account of one of its sub_groups, via a **Proposal**.
+//!                  A budget caps the amount spent per period, allows any single member to `execute` small spends and requires higher thresholds for larger spends.
+//!                  A **Sub-group** with a budget may use `withdraw_funds_group`.
 //! Note: adding funds to a group account from an individual account can be done using the built in substrate tranfer extrinsic
 
 //!
@@ -45,6 +68,9 @@
 //! * `get_proposal` - Get a **Proposal**
 //! * `get_proposals` - Get the collection of outstanding **Proposals** of a **Group**
 //! * `get_voting` - Get the current votes on a **Proposal**
+//! * `get_scheduled_proposals` - Get the approved **Proposals** of a **Group** that are waiting to be dispatched
+//! * `get_budget` - Get the budget of a **Group** and the amount spent in the current period
+//! * `get_invitations` - Get the **Groups** that an account has been invited to join
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -70,7 +96,9 @@ pub mod pallet {
         dispatch::{DispatchResultWithPostInfo, Dispatchable, Parameter, PostDispatchInfo, Vec},
         ensure,
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement::AllowDeath, Get, ReservableCurrency},
+        traits::{
+            BalanceStatus, Currency, ExistenceRequirement::AllowDeath, Get, ReservableCurrency,
+        },
         weights::{GetDispatchInfo, Weight},
     };
     use frame_system::{self as system, pallet_prelude::*};
@@ -101,6 +129,17 @@ pub mod pallet {
         V2,
     }
 
+    /// How a transfer out of a group account was authorised.
+    #[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+    enum SpendAuthority<MemberCount> {
+        /// Executed by a single member without a vote.
+        Member,
+        /// Approved by the given number of yes votes.
+        Votes(MemberCount),
+        /// Approved by a member of the parent group.
+        Veto,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
@@ -216,12 +255,31 @@ pub mod pallet {
             ModuleIndex = u8,
             ExtrinsicIndex = u8,
             AccountId = Self::AccountId,
+            Balance = <Self::Currency as Currency<Self::AccountId>>::Balance,
         >;
 
         /// The maximum length of strings.
         type NameLimit: Get<u32>;
         /// The maximum length of parent child relationships.
         type GroupChainLimit: Get<u32>;
+        /// The maximum number of spending tiers in a budget.
+        type MaxBudgetTiers: Get<u32>;
+        /// This allows extrinsics to be executed via a joint proposal once every participating group has met its threshold.
+        /// The co-signing groups and their accounts are returned so the called extrinsic can check who approved it.
+        type GroupsOriginJointApproved: EnsureOrigin<
+            <Self as frame_system::Config>::Origin,
+            Success = Vec<(Self::GroupId, Self::AccountId)>,
+        >;
+        /// The maximum number of groups that may participate in a joint proposal.
+        type MaxJointGroups: Get<u32>;
+        /// The number of blocks after which a proposal that has not been closed may be expired.
+        type ProposalLifetime: Get<Self::BlockNumber>;
+        /// The maximum number of scheduled proposals dispatched at the start of a block.
+        type MaxScheduledPerBlock: Get<u32>;
+        /// The maximum weight of scheduled proposals dispatched at the start of a block.
+        type MaximumScheduledWeight: Get<Weight>;
+        /// The maximum number of blocks a proposal is pushed back when the execution queue is full.
+        type MaxScheduleDeferral: Get<u32>;
     }
 
     /// Origin for groups module proposals.
@@ -237,6 +295,9 @@ pub mod pallet {
         /// It has been approved by veto.
         /// (group_id,veto_account,group_account)
         ProposalApprovedByVeto(GroupId, ProposalId, AccountId, AccountId),
+        /// It has been approved by every group participating in a joint proposal.
+        /// (group_id,group_account) of each co-signing group
+        JointApproved(Vec<(GroupId, AccountId)>),
     }
 
     /// Origin for the groups module.
@@ -256,6 +317,7 @@ pub mod pallet {
         T::Hash = "Hash",
         T::AccountId = "AccountId",
         T::MemberCount = "MemberCount",
+        T::BlockNumber = "BlockNumber",
         T::Currency = "Currency",
 
         <T::Currency as Currency<T::AccountId>>::Balance = "Balance",
@@ -361,6 +423,61 @@ pub mod pallet {
             <T::Currency as Currency<T::AccountId>>::Balance,
             bool,
         ),
+        /// An approved proposal was added to the execution queue
+        /// (group_id,proposal_id,when)
+        ProposalScheduled(T::GroupId, T::ProposalId, T::BlockNumber),
+        /// A budget was set or removed
+        /// (caller_group_id,group_id)
+        BudgetUpdated(T::GroupId, T::GroupId),
+        /// A scheduled proposal was dispatched from the execution queue
+        /// (group_id,proposal_id,success,error)
+        ProposalExecutedLater(T::GroupId, T::ProposalId, bool, Option<DispatchError>),
+        /// An account was invited to join a group
+        /// (group_id,account,weight)
+        MemberInvited(T::GroupId, T::AccountId, T::MemberCount),
+        /// An account accepted an invitation and joined a group
+        /// (group_id,account)
+        InvitationAccepted(T::GroupId, T::AccountId),
+        /// An account declined an invitation to join a group
+        /// (group_id,account)
+        InvitationDeclined(T::GroupId, T::AccountId),
+        /// A member left a group
+        /// (group_id,account)
+        MemberLeft(T::GroupId, T::AccountId),
+        /// A proposal requiring the approval of several groups was created
+        /// (proposer,group_id,proposal_id)
+        JointProposed(T::AccountId, T::GroupId, T::ProposalId),
+        /// A joint proposal was approved by every participating group and executed
+        /// (proposal_id,success,error)
+        JointApproved(T::ProposalId, bool, Option<DispatchError>),
+        /// A joint proposal can no longer be approved by one of the participating groups
+        /// (proposal_id,group_id)
+        JointDisapproved(T::ProposalId, T::GroupId),
+        /// A proposal deposit was returned to the proposer
+        /// (group_id,proposal_id,proposer,amount)
+        DepositReturned(
+            T::GroupId,
+            T::ProposalId,
+            T::AccountId,
+            <T::Currency as Currency<T::AccountId>>::Balance,
+        ),
+        /// A proposal deposit was paid to the group account
+        /// (group_id,proposal_id,proposer,amount)
+        DepositSlashed(
+            T::GroupId,
+            T::ProposalId,
+            T::AccountId,
+            <T::Currency as Currency<T::AccountId>>::Balance,
+        ),
+        /// A proposal was removed because it was not closed in time
+        /// (group_id,proposal_id)
+        ProposalExpired(T::GroupId, T::ProposalId),
+        /// A proposal waiting in the execution queue was cancelled by its group
+        /// (group_id,proposal_id)
+        ScheduledProposalCancelled(T::GroupId, T::ProposalId),
+        /// A joint proposal was removed because it was not closed in time
+        /// (proposal_id)
+        JointProposalExpired(T::ProposalId),
     }
 
     #[pallet::error]
@@ -410,6 +527,38 @@ pub mod pallet {
         NotGroupAccount,
         /// A chain generated Id has exceeded its capacity
         NoIdAvailable,
+        /// Proposal is already approved and waiting in the execution queue
+        ProposalAlreadyScheduled,
+        /// Budget period must be greater than zero
+        InvalidBudget,
+        /// A budget has too many spending tiers
+        TooManyBudgetTiers,
+        /// Transfer would exceed the spending cap for the current period
+        BudgetExceeded,
+        /// Transfer is larger than the small spend allowance for a single member
+        SmallSpendExceeded,
+        /// Transfer requires a higher threshold according to the spending tiers
+        SpendThresholdNotMet,
+        /// Account has not been invited to the group
+        InvitationMissing,
+        /// The last member of a group cannot leave it
+        LastMember,
+        /// A joint proposal requires at least two distinct groups
+        InvalidJointGroups,
+        /// There can only be a maximum of `MaxJointGroups` groups in a joint proposal
+        TooManyJointGroups,
+        /// Group is not participating in the joint proposal
+        NotJointGroup,
+        /// The proposal has not reached the end of its lifetime
+        ProposalNotExpired,
+        /// Proposal is not waiting in the execution queue
+        ProposalNotScheduled,
+        /// The execution queue is full for every block the proposal may be deferred to
+        ExecutionQueueFull,
+        /// Proposal is heavier than the weight available to scheduled proposals in a block
+        ProposalTooHeavy,
+        /// The members that have joined do not yet carry the weight of the group threshold
+        MembersPending,
     }
 
     #[pallet::type_value]
@@ -424,6 +573,38 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            // `schedule_proposal` queues at most `MaxScheduledPerBlock` proposals for a block
+            let base_weight: Weight = T::DbWeight::get().reads_writes(2, 2);
+            let max_weight = T::MaximumScheduledWeight::get();
+            let next = now.saturating_add(One::one());
+            let mut weight = base_weight;
+            let mut deferred = 0u32;
+            <ExecutionQueue<T>>::drain_prefix(now).for_each(
+                |((group_id, proposal_id), proposal_weight)| {
+                    // once the weight is used up the remaining proposals wait for the next block,
+                    // at least one proposal is dispatched so the queue always makes progress
+                    if deferred > 0
+                        || (weight > base_weight
+                            && weight.saturating_add(proposal_weight) > max_weight)
+                    {
+                        Self::defer_scheduled(next, group_id, proposal_id, proposal_weight);
+                        deferred += 1;
+                        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+                    } else {
+                        weight =
+                            weight.saturating_add(Self::dispatch_scheduled(group_id, proposal_id));
+                    }
+                },
+            );
+            <ExecutionQueueLength<T>>::remove(now);
+            if deferred > 0 {
+                <ExecutionQueueLength<T>>::mutate(next, |length| {
+                    *length = length.saturating_add(deferred)
+                });
+            }
+            weight
+        }
         // fn on_runtime_upgrade() -> frame_support::weights::Weight {
         //     let mut weight: Weight = 0;
         //     // weight += super::migration::migrate_to_v2::<T>();
@@ -520,6 +701,34 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Accounts invited to join a group and the weight they were invited with
+    /// GroupId,AccountId => MemberCount
+    #[pallet::storage]
+    #[pallet::getter(fn pending_invitations)]
+    pub(super) type PendingInvitations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::MemberCount,
+        OptionQuery,
+    >;
+
+    /// Store which groups an account has been invited to
+    /// AccountId,GroupId => ()
+    #[pallet::storage]
+    #[pallet::getter(fn invitations_by_account)]
+    pub(super) type InvitationsByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::GroupId,
+        (),
+        OptionQuery,
+    >;
+
     /// Groups may have child groups
     /// GroupId,GroupId => ()
     #[pallet::storage]
@@ -583,12 +792,155 @@ pub mod pallet {
     pub(super) type GroupByProposal<T: Config> =
         StorageMap<_, Blake2_128Concat, T::ProposalId, T::GroupId, OptionQuery>;
 
+    /// Proposals which will be dispatched once approved are time locked
+    /// GroupId,ProposalId => Timelock
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_timelocks)]
+    pub(super) type ProposalTimelocks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Blake2_128Concat,
+        T::ProposalId,
+        Timelock<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Deposits reserved from proposers until their proposal is resolved
+    /// GroupId,ProposalId => (AccountId,Balance)
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_deposits)]
+    pub(super) type ProposalDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Blake2_128Concat,
+        T::ProposalId,
+        (
+            T::AccountId,
+            <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+        ),
+        OptionQuery,
+    >;
+
+    /// Block after which a proposal that has not been closed may be expired
+    /// GroupId,ProposalId => BlockNumber
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_expiries)]
+    pub(super) type ProposalExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Blake2_128Concat,
+        T::ProposalId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Approved proposals waiting to be dispatched
+    /// GroupId,ProposalId => ScheduledExecution
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_proposals)]
+    pub(super) type ScheduledProposals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Blake2_128Concat,
+        T::ProposalId,
+        ScheduledExecution<T::AccountId, T::BlockNumber, T::MemberCount>,
+        OptionQuery,
+    >;
+
+    /// Queue of proposals to be dispatched at the start of a block, with the weight of each proposal when it was scheduled
+    /// BlockNumber,(GroupId,ProposalId) => Weight
+    #[pallet::storage]
+    #[pallet::getter(fn execution_queue)]
+    pub(super) type ExecutionQueue<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Blake2_128Concat,
+        (T::GroupId, T::ProposalId),
+        Weight,
+        OptionQuery,
+    >;
+
+    /// Number of proposals in the execution queue for a block
+    /// BlockNumber => u32
+    #[pallet::storage]
+    #[pallet::getter(fn execution_queue_length)]
+    pub(super) type ExecutionQueueLength<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::BlockNumber, u32, ValueQuery>;
+
+    /// Spending rules for a group account
+    /// GroupId => Budget
+    #[pallet::storage]
+    #[pallet::getter(fn budgets)]
+    pub(super) type Budgets<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Budget<
+            <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+            T::BlockNumber,
+            T::MemberCount,
+        >,
+        OptionQuery,
+    >;
+
+    /// Amount spent from a group account in the current budget period
+    /// GroupId => BudgetUsage
+    #[pallet::storage]
+    #[pallet::getter(fn budget_usages)]
+    pub(super) type BudgetUsages<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        BudgetUsage<<<T as Config>::Currency as Currency<T::AccountId>>::Balance, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Proposals that must be approved by several groups
+    /// ProposalId => Proposal
+    #[pallet::storage]
+    #[pallet::getter(fn joint_proposals)]
+    pub(super) type JointProposals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ProposalId, <T as Config>::Proposal, OptionQuery>;
+
+    /// Votes of each group participating in a joint proposal
+    /// ProposalId,GroupId => Votes
+    #[pallet::storage]
+    #[pallet::getter(fn joint_voting)]
+    pub(super) type JointVoting<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::ProposalId,
+        Blake2_128Concat,
+        T::GroupId,
+        Votes<T::AccountId, T::MemberCount>,
+        OptionQuery,
+    >;
+
+    /// Joint proposals that a group participates in
+    /// GroupId,ProposalId => ()
+    #[pallet::storage]
+    #[pallet::getter(fn joint_proposals_by_group)]
+    pub(super) type JointProposalsByGroup<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::GroupId,
+        Blake2_128Concat,
+        T::ProposalId,
+        (),
+        OptionQuery,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Create a new Group
         ///
         /// - `name`: The name of the group
-        /// - `members`: The members of the group. If there are duplicates the last one will be used.
+        /// - `members`: The members of the group. If there are duplicates the last one will be used. Members other than the sender are invited and must accept the invitation.
         /// - `threshold`: The threshold number of votes required to make modifications to the group.
         /// - `initial_balance`: The initial GRAMs to transfer from the sender to the group account to be used for calling extrinsics by the group.
         #[pallet::weight(
@@ -637,11 +989,12 @@ pub mod pallet {
                     parent: None,
                 };
 
-            Self::add_members(&mut group, group_id, members);
-
-            if group.threshold > group.total_vote_weight {
-                group.threshold = group.total_vote_weight;
-            }
+            let (creator, invited): (Vec<_>, Vec<_>) = members
+                .into_iter()
+                .partition(|(account, _)| *account == sender);
+            Self::add_members(&mut group, group_id, creator);
+            Self::invite_members(&mut group, group_id, invited);
+            Self::clamp_threshold(&mut group, group_id);
 
             <Groups<T>>::insert(group_id, group);
             <GroupByAccount<T>>::insert(&anonymous_account, group_id);
@@ -660,7 +1013,8 @@ pub mod pallet {
         ///
         /// - `group_id`: Group to be updated
         /// - `name`: New group name
-        /// - `add_members`: Add new members or overwrite existing member weights.
+        /// - `add_members`: Invite new members or overwrite existing member weights.
+        /// - `remove_members`: Remove members or cancel their invitations.
         /// - `threshold`: New threshold
 
         #[pallet::weight(T::WeightInfo::update_group(
@@ -691,7 +1045,7 @@ pub mod pallet {
                         Self::remove_members(group, caller_group_id, remove_members);
                     }
                     if let Some(add_members) = add_members {
-                        Self::add_members(group, caller_group_id, add_members);
+                        Self::invite_members(group, caller_group_id, add_members);
                     }
                     if let Some(bounded_name) = bounded_name {
                         group.name = bounded_name;
@@ -701,9 +1055,7 @@ pub mod pallet {
                             group.threshold = threshold;
                         }
                     }
-                    if group.threshold > group.total_vote_weight {
-                        group.threshold = group.total_vote_weight;
-                    }
+                    Self::clamp_threshold(group, caller_group_id);
                 }
             });
 
@@ -715,7 +1067,7 @@ pub mod pallet {
         /// Create a new SubGroup
         ///
         /// - `name`: The name of the group
-        /// - `members`: The members of the group. Sender is automatically added to members if not already included.
+        /// - `members`: The members of the group. Members are invited and must accept the invitation.
         /// - `threshold`: The threshold number of votes required to make modifications to the group.
         /// - `initial_balance`: The initial GRAMs to transfer from the sender to the group account to be used for calling extrinsics by the group.
 
@@ -764,11 +1116,8 @@ pub mod pallet {
                 parent: Some(caller_group_id),
             };
 
-            Self::add_members(&mut sub_group, sub_group_id, members);
-
-            if sub_group.threshold > sub_group.total_vote_weight {
-                sub_group.threshold = sub_group.total_vote_weight;
-            }
+            Self::invite_members(&mut sub_group, sub_group_id, members);
+            Self::clamp_threshold(&mut sub_group, sub_group_id);
 
             <Groups<T>>::insert(sub_group_id, sub_group);
             <GroupByAccount<T>>::insert(&anonymous_account, sub_group_id);
@@ -822,7 +1171,7 @@ pub mod pallet {
                         Self::remove_members(sub_group, sub_group_id, remove_members);
                     }
                     if let Some(add_members) = add_members {
-                        Self::add_members(sub_group, sub_group_id, add_members);
+                        Self::invite_members(sub_group, sub_group_id, add_members);
                     }
                     if let Some(bounded_name) = bounded_name {
                         sub_group.name = bounded_name;
@@ -832,9 +1181,7 @@ pub mod pallet {
                             sub_group.threshold = threshold;
                         }
                     }
-                    if sub_group.threshold > sub_group.total_vote_weight {
-                        sub_group.threshold = sub_group.total_vote_weight;
-                    }
+                    Self::clamp_threshold(sub_group, sub_group_id);
                 }
             });
 
@@ -889,6 +1236,19 @@ pub mod pallet {
                 proposal_count += 1;
             });
             <ProposalHashes<T>>::remove_prefix(group_id);
+            <ProposalTimelocks<T>>::remove_prefix(group_id);
+            <ProposalExpiries<T>>::remove_prefix(group_id);
+            <ProposalDeposits<T>>::drain_prefix(group_id).for_each(|(_, (proposer, amount))| {
+                <T as Config>::Currency::unreserve(&proposer, amount);
+            });
+            <ScheduledProposals<T>>::drain_prefix(group_id).for_each(|(proposal_id, scheduled)| {
+                Self::remove_from_queue(scheduled.when, group_id, proposal_id);
+            });
+            <PendingInvitations<T>>::drain_prefix(group_id).for_each(|(account_id, _)| {
+                <InvitationsByAccount<T>>::remove(&account_id, group_id);
+            });
+            <JointProposalsByGroup<T>>::drain_prefix(group_id)
+                .for_each(|(proposal_id, _)| Self::remove_joint_proposal(proposal_id));
 
             Self::deposit_event(Event::GroupRemoved(group_id, return_funds_too));
 
@@ -943,6 +1303,23 @@ pub mod pallet {
                 proposal_count += 1;
             });
             <ProposalHashes<T>>::remove_prefix(sub_group_id);
+            <ProposalTimelocks<T>>::remove_prefix(sub_group_id);
+            <ProposalExpiries<T>>::remove_prefix(sub_group_id);
+            <ProposalDeposits<T>>::drain_prefix(sub_group_id).for_each(
+                |(_, (proposer, amount))| {
+                    <T as Config>::Currency::unreserve(&proposer, amount);
+                },
+            );
+            <ScheduledProposals<T>>::drain_prefix(sub_group_id).for_each(
+                |(proposal_id, scheduled)| {
+                    Self::remove_from_queue(scheduled.when, sub_group_id, proposal_id);
+                },
+            );
+            <PendingInvitations<T>>::drain_prefix(sub_group_id).for_each(|(account_id, _)| {
+                <InvitationsByAccount<T>>::remove(&account_id, sub_group_id);
+            });
+            <JointProposalsByGroup<T>>::drain_prefix(sub_group_id)
+                .for_each(|(proposal_id, _)| Self::remove_joint_proposal(proposal_id));
 
             Self::deposit_event(Event::SubGroupRemoved(caller_group_id, sub_group_id));
 
@@ -1030,38 +1407,8 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
 
-            let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
-            let weight_maybe = <GroupMembers<T>>::get(group_id, &sender);
-            ensure!(weight_maybe.is_some(), Error::<T>::NotMember);
-            let weight = weight_maybe.unwrap();
-            let proposal_len = proposal.using_encoded(|x| x.len());
-            let proposal_hash = T::Hashing::hash_of(&proposal);
-            ensure!(
-                !<ProposalHashes<T>>::contains_key(group_id, proposal_hash),
-                Error::<T>::DuplicateProposal
-            );
-            ensure!(
-                proposal_len <= length_bound as usize,
-                Error::<T>::WrongProposalLength
-            );
-
-            T::GetExtrinsicExtraSource::charge_extrinsic_extra(
-                &MODULE_INDEX,
-                &(ExtrinsicIndex::Proposal as u8),
-                &sender,
-            );
-
-            let proposal_id = next_id!(NextProposalId<T>, T);
-
-            let votes = Votes {
-                threshold,
-                total_vote_weight: group.total_vote_weight,
-                ayes: vec![(sender.clone(), weight)],
-                nays: vec![],
-                veto: None,
-            };
-            <Voting<T>>::insert(group_id, proposal_id, votes);
-            <GroupByProposal<T>>::insert(proposal_id, group_id);
+            let (group_account, weight, proposal_len, proposal_hash, proposal_id, deposit) =
+                Self::open_proposal(&sender, group_id, &proposal, threshold, length_bound)?;
 
             if threshold == weight {
                 let result = proposal.dispatch(
@@ -1070,7 +1417,7 @@ pub mod pallet {
                         proposal_id,
                         weight,
                         0u32.into(),
-                        group.anonymous_account,
+                        group_account,
                     )
                     .into(),
                 );
@@ -1090,6 +1437,7 @@ pub mod pallet {
             } else {
                 <ProposalHashes<T>>::insert(group_id, proposal_hash, ());
                 <Proposals<T>>::insert(group_id, proposal_id, proposal);
+                Self::record_deposit_and_expiry(group_id, proposal_id, &sender, deposit);
 
                 Self::deposit_event(Event::Proposed(sender, group_id, proposal_id, threshold));
 
@@ -1097,6 +1445,59 @@ pub mod pallet {
             }
         }
 
+        /// Add a new proposal to be voted on. Once approved the proposal is placed in the execution queue rather than dispatched immediately.
+        /// While it is waiting, members of the parent group may cancel it with `veto`.
+        ///
+        /// Requires the sender to be member.
+        ///
+        /// - `group_id`: Group executing the extrinsic
+        /// - `proposal`: Proposal to be executed
+        /// - `threshold`: Declaration of the threshold required - will be checked by the extrinsic after approval.
+        /// - `timelock`: Block at which, or delay after approval after which, the proposal will be dispatched.
+        /// - `length_bound`: The length of the Proposal for weight estimation
+        #[pallet::weight(T::WeightInfo::propose_with_timelock(*length_bound))]
+        pub fn propose_with_timelock(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+            proposal: Box<<T as Config>::Proposal>,
+            threshold: T::MemberCount,
+            timelock: Timelock<T::BlockNumber>,
+            length_bound: u32,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            // a proposal the proposer approves alone is queued straight away, so find it a block before opening it
+            let proposal_weight = proposal.get_dispatch_info().weight;
+            let when = if <GroupMembers<T>>::get(group_id, &sender) == Some(threshold) {
+                Some(Self::execution_block(&timelock, proposal_weight)?)
+            } else {
+                None
+            };
+
+            let (_, weight, _, proposal_hash, proposal_id, deposit) =
+                Self::open_proposal(&sender, group_id, &proposal, threshold, length_bound)?;
+            <ProposalHashes<T>>::insert(group_id, proposal_hash, ());
+            <Proposals<T>>::insert(group_id, proposal_id, proposal);
+            <ProposalTimelocks<T>>::insert(group_id, proposal_id, timelock);
+            Self::record_deposit_and_expiry(group_id, proposal_id, &sender, deposit);
+
+            Self::deposit_event(Event::Proposed(sender, group_id, proposal_id, threshold));
+
+            if let Some(when) = when {
+                Self::schedule_proposal(
+                    group_id,
+                    proposal_id,
+                    weight,
+                    0u32.into(),
+                    None,
+                    when,
+                    proposal_weight,
+                );
+            }
+
+            Ok(().into())
+        }
+
         /// Vote on a Proposal
         ///
         /// - `group_id`: Group executing the extrinsic
@@ -1117,40 +1518,13 @@ pub mod pallet {
             ensure!(weight_maybe.is_some(), Error::<T>::NotMember);
             let weight = weight_maybe.unwrap();
             let mut voting = Self::voting(group_id, proposal_id).ok_or(Error::<T>::VoteMissing)?;
+            ensure!(
+                !<ScheduledProposals<T>>::contains_key(group_id, proposal_id),
+                Error::<T>::ProposalAlreadyScheduled
+            );
 
-            let position_yes = voting
-                .ayes
-                .iter()
-                .position(|(account, _)| account == &sender);
-            let position_no = voting
-                .nays
-                .iter()
-                .position(|(account, _)| account == &sender);
-
-            // Detects first vote of the member in the motion
-            let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
-
-            if approve {
-                if position_yes.is_none() {
-                    voting.ayes.push((sender.clone(), weight));
-                } else {
-                    #[allow(clippy::try_err)]
-                    Err(Error::<T>::DuplicateVote)?;
-                }
-                if let Some(pos) = position_no {
-                    voting.nays.swap_remove(pos);
-                }
-            } else {
-                if position_no.is_none() {
-                    voting.nays.push((sender.clone(), weight));
-                } else {
-                    #[allow(clippy::try_err)]
-                    Err(Error::<T>::DuplicateVote)?;
-                }
-                if let Some(pos) = position_yes {
-                    voting.ayes.swap_remove(pos);
-                }
-            }
+            let is_account_voting_first_time =
+                Self::record_vote(&mut voting, &sender, weight, approve)?;
 
             Self::deposit_event(Event::Voted(sender, group_id, proposal_id, approve));
 
@@ -1203,6 +1577,10 @@ pub mod pallet {
             let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
 
             let voting = Self::voting(group_id, proposal_id).ok_or(Error::<T>::VoteMissing)?;
+            ensure!(
+                !<ScheduledProposals<T>>::contains_key(group_id, proposal_id),
+                Error::<T>::ProposalAlreadyScheduled
+            );
 
             let yes_votes = voting.ayes.into_iter().map(|(_, w)| w).sum();
             let no_votes = voting.nays.into_iter().map(|(_, w)| w).sum();
@@ -1216,6 +1594,10 @@ pub mod pallet {
             let proposal_hash = T::Hashing::hash_of(&proposal);
 
             if approved {
+                ensure!(
+                    group.total_vote_weight >= group.threshold,
+                    Error::<T>::MembersPending
+                );
                 let proposal_len = proposal.using_encoded(|x| x.len());
                 ensure!(
                     proposal_len <= length_bound as usize,
@@ -1228,6 +1610,24 @@ pub mod pallet {
                     Error::<T>::WrongProposalWeight
                 );
 
+                if let Some(timelock) = <ProposalTimelocks<T>>::get(group_id, proposal_id) {
+                    let when = Self::execution_block(&timelock, dispatch_weight)?;
+                    Self::schedule_proposal(
+                        group_id,
+                        proposal_id,
+                        yes_votes,
+                        no_votes,
+                        None,
+                        when,
+                        dispatch_weight,
+                    );
+                    return Ok((
+                        Some(T::WeightInfo::close_scheduled(T::MaxMembers::get().into())),
+                        Pays::Yes,
+                    )
+                        .into());
+                }
+
                 let result = proposal.dispatch(
                     RawOrigin::ProposalApproved(
                         group_id,
@@ -1250,6 +1650,8 @@ pub mod pallet {
 
                 <Proposals<T>>::remove(group_id, proposal_id);
                 <ProposalHashes<T>>::remove(group_id, proposal_hash);
+                <ProposalExpiries<T>>::remove(group_id, proposal_id);
+                Self::return_deposit(group_id, proposal_id);
 
                 let proposal_weight = Self::get_result_weight(result).unwrap_or(dispatch_weight);
 
@@ -1274,6 +1676,9 @@ pub mod pallet {
 
                 <Proposals<T>>::remove(group_id, proposal_id);
                 <ProposalHashes<T>>::remove(group_id, proposal_hash);
+                <ProposalTimelocks<T>>::remove(group_id, proposal_id);
+                <ProposalExpiries<T>>::remove(group_id, proposal_id);
+                Self::return_deposit(group_id, proposal_id);
                 return Ok((
                     Some(T::WeightInfo::close_disapproved(
                         T::MaxMembers::get().into(),
@@ -1324,6 +1729,11 @@ pub mod pallet {
             let proposal =
                 Self::proposals(group_id, proposal_id).ok_or(Error::<T>::ProposalMissing)?;
             let proposal_hash = T::Hashing::hash_of(&proposal);
+            let scheduled_maybe = <ScheduledProposals<T>>::get(group_id, proposal_id);
+            ensure!(
+                !(approve && scheduled_maybe.is_some()),
+                Error::<T>::ProposalAlreadyScheduled
+            );
             <Voting<T>>::mutate(group_id, proposal_id, |votes_option| {
                 if let Some(votes) = votes_option {
                     votes.veto = Some(approve);
@@ -1336,12 +1746,27 @@ pub mod pallet {
                     proposal_len <= length_bound as usize,
                     Error::<T>::WrongProposalLength
                 );
+
                 let dispatch_weight = proposal.get_dispatch_info().weight;
                 ensure!(
                     dispatch_weight <= proposal_weight_bound,
                     Error::<T>::WrongProposalWeight
                 );
 
+                if let Some(timelock) = <ProposalTimelocks<T>>::get(group_id, proposal_id) {
+                    let when = Self::execution_block(&timelock, dispatch_weight)?;
+                    Self::schedule_proposal(
+                        group_id,
+                        proposal_id,
+                        0u32.into(),
+                        0u32.into(),
+                        Some(sender),
+                        when,
+                        dispatch_weight,
+                    );
+                    return Ok((Some(T::WeightInfo::veto_scheduled()), Pays::Yes).into());
+                }
+
                 let result = proposal.dispatch(
                     RawOrigin::ProposalApprovedByVeto(
                         group_id,
@@ -1362,6 +1787,8 @@ pub mod pallet {
 
                 <Proposals<T>>::remove(group_id, proposal_id);
                 <ProposalHashes<T>>::remove(group_id, proposal_hash);
+                <ProposalExpiries<T>>::remove(group_id, proposal_id);
+                Self::return_deposit(group_id, proposal_id);
 
                 let proposal_weight = Self::get_result_weight(result).unwrap_or(dispatch_weight);
 
@@ -1375,28 +1802,85 @@ pub mod pallet {
                     .into());
             } else {
                 Self::deposit_event(Event::DisapprovedByVeto(sender, group_id, proposal_id));
+                if let Some(scheduled) = scheduled_maybe {
+                    Self::remove_from_queue(scheduled.when, group_id, proposal_id);
+                    <ScheduledProposals<T>>::remove(group_id, proposal_id);
+                }
                 <Proposals<T>>::remove(group_id, proposal_id);
                 <ProposalHashes<T>>::remove(group_id, proposal_hash);
+                <ProposalTimelocks<T>>::remove(group_id, proposal_id);
+                <ProposalExpiries<T>>::remove(group_id, proposal_id);
+                Self::slash_deposit(group_id, proposal_id, &group.anonymous_account);
                 return Ok((Some(T::WeightInfo::veto_disapproved()), Pays::Yes).into());
             };
         }
 
-        /// Withdraw funds from the group account
+        /// Remove a Proposal that has not been closed within `ProposalLifetime` blocks.
+        /// Anyone can trigger an expiry. If no member other than the proposer has voted, the proposal deposit is paid to the group account, otherwise it is returned.
         ///
-        /// - `target_account`: account to withdraw the funds to
-        /// - `amount`: amount to withdraw
-
-        #[pallet::weight(T::WeightInfo::withdraw_funds_group())]
-
-        pub fn withdraw_funds_group(
+        /// - `group_id`: Group of the proposal
+        /// - `proposal_id`: Proposal to be expired
+        #[pallet::weight(T::WeightInfo::expire_proposal())]
+        pub fn expire_proposal(
             origin: OriginFor<T>,
-            target_account: T::AccountId,
-            amount: <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
         ) -> DispatchResultWithPostInfo {
-            let (group_id, _, _, _, group_account) =
-                T::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+            let _sender = ensure_signed(origin)?;
             let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
-            ensure!(group.parent.is_none(), Error::<T>::NotGroup);
+            let proposal =
+                Self::proposals(group_id, proposal_id).ok_or(Error::<T>::ProposalMissing)?;
+            ensure!(
+                !<ScheduledProposals<T>>::contains_key(group_id, proposal_id),
+                Error::<T>::ProposalAlreadyScheduled
+            );
+            let expiry = <ProposalExpiries<T>>::get(group_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotExpired)?;
+            ensure!(
+                system::Module::<T>::block_number() >= expiry,
+                Error::<T>::ProposalNotExpired
+            );
+            let voting = Self::voting(group_id, proposal_id).ok_or(Error::<T>::VoteMissing)?;
+            // the proposer's own approval is recorded when proposing
+            let has_votes = voting.ayes.len() > 1 || !voting.nays.is_empty();
+
+            let proposal_hash = T::Hashing::hash_of(&proposal);
+            <Proposals<T>>::remove(group_id, proposal_id);
+            <ProposalHashes<T>>::remove(group_id, proposal_hash);
+            <ProposalTimelocks<T>>::remove(group_id, proposal_id);
+            <ProposalExpiries<T>>::remove(group_id, proposal_id);
+            if has_votes {
+                Self::return_deposit(group_id, proposal_id);
+            } else {
+                Self::slash_deposit(group_id, proposal_id, &group.anonymous_account);
+            }
+
+            Self::deposit_event(Event::ProposalExpired(group_id, proposal_id));
+
+            Ok(().into())
+        }
+
+        /// Withdraw funds from the group account
+        /// If the group has a budget, a single member may `execute` a withdrawal up to the small spend allowance.
+        /// A sub_group may only withdraw funds if its parent has set a budget for it.
+        ///
+        /// - `target_account`: account to withdraw the funds to
+        /// - `amount`: amount to withdraw
+
+        #[pallet::weight(T::WeightInfo::withdraw_funds_group())]
+
+        pub fn withdraw_funds_group(
+            origin: OriginFor<T>,
+            target_account: T::AccountId,
+            amount: <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+        ) -> DispatchResultWithPostInfo {
+            let (group_id, authority, group_account) = Self::ensure_spend_origin(origin)?;
+            let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            ensure!(
+                group.parent.is_none() || <Budgets<T>>::contains_key(group_id),
+                Error::<T>::NotGroup
+            );
+            let usage_maybe = Self::check_budget(group_id, &group, authority, amount)?;
 
             let result = <T as Config>::Currency::transfer(
                 &group_account,
@@ -1404,6 +1888,9 @@ pub mod pallet {
                 amount,
                 AllowDeath,
             );
+            if let (true, Some(usage)) = (result.is_ok(), usage_maybe) {
+                <BudgetUsages<T>>::insert(group_id, usage);
+            }
 
             Self::deposit_event(Event::GroupFundsWithdrawn(
                 group_id,
@@ -1456,6 +1943,7 @@ pub mod pallet {
         }
 
         /// Deposit funds to a subgroup account from the group account
+        /// If the group has a budget, a single member may `execute` a deposit up to the small spend allowance.
         ///
         /// - `sub_group_id`: Subgroup to deposit funds to
 
@@ -1467,8 +1955,8 @@ pub mod pallet {
             sub_group_id: T::GroupId,
             amount: <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
         ) -> DispatchResultWithPostInfo {
-            let (caller_group_id, _, _, _, caller_group_account) =
-                T::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+            let (caller_group_id, authority, caller_group_account) =
+                Self::ensure_spend_origin(origin)?;
 
             let sub_group = Self::groups(sub_group_id).ok_or(Error::<T>::GroupMissing)?;
             ensure!(sub_group.parent.is_some(), Error::<T>::NotSubGroup);
@@ -1477,6 +1965,8 @@ pub mod pallet {
                 caller_group_id == parent_group_id,
                 Error::<T>::NotParentGroup
             );
+            let group = Self::groups(caller_group_id).ok_or(Error::<T>::GroupMissing)?;
+            let usage_maybe = Self::check_budget(caller_group_id, &group, authority, amount)?;
 
             let result = <T as Config>::Currency::transfer(
                 &caller_group_account,
@@ -1484,6 +1974,9 @@ pub mod pallet {
                 amount,
                 AllowDeath,
             );
+            if let (true, Some(usage)) = (result.is_ok(), usage_maybe) {
+                <BudgetUsages<T>>::insert(caller_group_id, usage);
+            }
 
             Self::deposit_event(Event::SubGroupFundsDeposited(
                 parent_group_id,
@@ -1494,6 +1987,422 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Set or remove the budget of a group account. A group may set its own budget if it is not a sub_group, or the budget of one of its sub_groups.
+        ///
+        /// - `group_id`: Group whose spending is limited
+        /// - `budget`: The spending rules, or `None` to remove them
+        #[pallet::weight(T::WeightInfo::set_budget(T::MaxBudgetTiers::get()))]
+        pub fn set_budget(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+            budget: Option<
+                Budget<
+                    <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+                    T::BlockNumber,
+                    T::MemberCount,
+                >,
+            >,
+        ) -> DispatchResultWithPostInfo {
+            let (caller_group_id, _, _, _, _) =
+                T::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            match group.parent {
+                Some(parent_group_id) => ensure!(
+                    caller_group_id == parent_group_id,
+                    Error::<T>::NotParentGroup
+                ),
+                None => ensure!(caller_group_id == group_id, Error::<T>::NotGroupAccount),
+            }
+
+            match budget {
+                Some(budget) => {
+                    ensure!(!budget.period.is_zero(), Error::<T>::InvalidBudget);
+                    ensure!(
+                        budget.tiers.len() <= T::MaxBudgetTiers::get() as usize,
+                        Error::<T>::TooManyBudgetTiers
+                    );
+                    <Budgets<T>>::insert(group_id, budget);
+                }
+                None => {
+                    <Budgets<T>>::remove(group_id);
+                    <BudgetUsages<T>>::remove(group_id);
+                }
+            }
+
+            Self::deposit_event(Event::BudgetUpdated(caller_group_id, group_id));
+
+            Ok(().into())
+        }
+
+        /// Add a new proposal that must be approved by several groups. Each participating group votes separately with `vote_joint`
+        /// and the proposal is dispatched by `close_joint` once every group has met its threshold.
+        ///
+        /// Requires the sender to be member of `group_id`.
+        ///
+        /// - `group_id`: Group of the proposer. Must be one of the participating groups.
+        /// - `proposal`: Proposal to be executed
+        /// - `thresholds`: The participating groups and the threshold each of them must meet. A threshold may not be below the threshold of the group.
+        /// - `length_bound`: The length of the Proposal for weight estimation
+        ///
+        /// A deposit is reserved from the proposer as for `propose`. The proposal counts towards the `MaxProposals` of every participating group.
+        #[pallet::weight(T::WeightInfo::propose_joint(*length_bound, thresholds.len() as u32))]
+        pub fn propose_joint(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+            proposal: Box<<T as Config>::Proposal>,
+            thresholds: Vec<(T::GroupId, T::MemberCount)>,
+            length_bound: u32,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(
+                thresholds.len() <= T::MaxJointGroups::get() as usize,
+                Error::<T>::TooManyJointGroups
+            );
+            let mut group_ids: Vec<T::GroupId> =
+                thresholds.iter().map(|(group_id, _)| *group_id).collect();
+            group_ids.sort();
+            group_ids.dedup();
+            ensure!(
+                group_ids.len() >= 2 && group_ids.len() == thresholds.len(),
+                Error::<T>::InvalidJointGroups
+            );
+            ensure!(
+                group_ids.binary_search(&group_id).is_ok(),
+                Error::<T>::NotJointGroup
+            );
+
+            let weight = <GroupMembers<T>>::get(group_id, &sender).ok_or(Error::<T>::NotMember)?;
+            let proposal_len = proposal.using_encoded(|x| x.len());
+            ensure!(
+                proposal_len <= length_bound as usize,
+                Error::<T>::WrongProposalLength
+            );
+
+            let mut joint_voting = Vec::new();
+            for (joint_group_id, threshold) in thresholds {
+                let group = Self::groups(joint_group_id).ok_or(Error::<T>::GroupMissing)?;
+                ensure!(
+                    threshold > Zero::zero() && threshold >= group.threshold,
+                    Error::<T>::InvalidThreshold
+                );
+                ensure!(
+                    Self::active_proposal_count(joint_group_id) < T::MaxProposals::get() as usize,
+                    Error::<T>::TooManyProposals
+                );
+                let ayes = if joint_group_id == group_id {
+                    vec![(sender.clone(), weight)]
+                } else {
+                    vec![]
+                };
+                joint_voting.push((
+                    joint_group_id,
+                    Votes {
+                        threshold,
+                        total_vote_weight: group.total_vote_weight,
+                        ayes,
+                        nays: vec![],
+                        veto: None,
+                    },
+                ));
+            }
+
+            let deposit = Self::reserve_deposit(&sender)?;
+
+            T::GetExtrinsicExtraSource::charge_extrinsic_extra(
+                &MODULE_INDEX,
+                &(ExtrinsicIndex::Proposal as u8),
+                &sender,
+            );
+
+            let proposal_id = next_id!(NextProposalId<T>, T);
+
+            for (joint_group_id, votes) in joint_voting {
+                <JointVoting<T>>::insert(proposal_id, joint_group_id, votes);
+                <JointProposalsByGroup<T>>::insert(joint_group_id, proposal_id, ());
+            }
+            <JointProposals<T>>::insert(proposal_id, proposal);
+            // the deposit and expiry are kept under the group of the proposer
+            Self::record_deposit_and_expiry(group_id, proposal_id, &sender, deposit);
+
+            Self::deposit_event(Event::JointProposed(sender, group_id, proposal_id));
+
+            Ok(().into())
+        }
+
+        /// Vote on a joint proposal on behalf of a participating group.
+        ///
+        /// Requires the sender to be member of `group_id`.
+        ///
+        /// - `group_id`: Participating group the sender is voting for
+        /// - `proposal_id`: Joint proposal to vote on
+        /// - `approve`: approval
+        #[pallet::weight(T::WeightInfo::vote_joint(T::MaxMembers::get().into()))]
+        pub fn vote_joint(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
+            approve: bool,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            let weight = <GroupMembers<T>>::get(group_id, &sender).ok_or(Error::<T>::NotMember)?;
+            let mut voting =
+                Self::joint_voting(proposal_id, group_id).ok_or(Error::<T>::NotJointGroup)?;
+
+            let is_account_voting_first_time =
+                Self::record_vote(&mut voting, &sender, weight, approve)?;
+
+            Self::deposit_event(Event::Voted(sender, group_id, proposal_id, approve));
+
+            <JointVoting<T>>::insert(proposal_id, group_id, voting);
+
+            let pays = if is_account_voting_first_time {
+                Pays::No
+            } else {
+                Pays::Yes
+            };
+            Ok((
+                Some(<T as Config>::WeightInfo::vote_joint(
+                    group.total_vote_weight.unique_saturated_into(),
+                )),
+                pays,
+            )
+                .into())
+        }
+
+        /// Close a joint Proposal. It is dispatched if every participating group has met its threshold and removed if any group no longer can.
+        /// Anyone can trigger a close, they don't have to be a member.
+        ///
+        /// - `proposal_id`: Joint proposal to be closed
+        /// - `proposal_weight_bound`: maximum expected weight of the proposal.
+        /// - `length_bound`: length of the proposal.
+        #[pallet::weight({
+            let a = *length_bound;
+            let g = T::MaxJointGroups::get();
+            T::WeightInfo::close_joint_approved(a, g)
+                .max(T::WeightInfo::close_joint_disapproved(g))
+                .saturating_add(*proposal_weight_bound)
+        })]
+        pub fn close_joint(
+            origin: OriginFor<T>,
+            proposal_id: T::ProposalId,
+            proposal_weight_bound: Weight,
+            length_bound: u32,
+        ) -> DispatchResultWithPostInfo {
+            let _sender = ensure_signed(origin)?;
+            let proposal = Self::joint_proposals(proposal_id).ok_or(Error::<T>::ProposalMissing)?;
+
+            let mut approved = true;
+            let mut disapproved_by = None;
+            let mut co_signers = Vec::new();
+            for (group_id, voting) in <JointVoting<T>>::iter_prefix(proposal_id) {
+                let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+                // the group may have raised its threshold since the proposal was made
+                let threshold = voting.threshold.max(group.threshold);
+                let yes_votes: T::MemberCount = voting.ayes.into_iter().map(|(_, w)| w).sum();
+                let no_votes: T::MemberCount = voting.nays.into_iter().map(|(_, w)| w).sum();
+                if voting.total_vote_weight.saturating_sub(no_votes) < threshold {
+                    disapproved_by = Some(group_id);
+                    break;
+                }
+                if yes_votes < threshold {
+                    approved = false;
+                }
+                co_signers.push((group_id, group.anonymous_account));
+            }
+            let group_count = co_signers.len() as u32;
+
+            if let Some(group_id) = disapproved_by {
+                Self::remove_joint_proposal(proposal_id);
+                Self::deposit_event(Event::JointDisapproved(proposal_id, group_id));
+                return Ok((
+                    Some(T::WeightInfo::close_joint_disapproved(group_count)),
+                    Pays::Yes,
+                )
+                    .into());
+            }
+            ensure!(approved, Error::<T>::VotingIncomplete);
+
+            let proposal_len = proposal.using_encoded(|x| x.len());
+            ensure!(
+                proposal_len <= length_bound as usize,
+                Error::<T>::WrongProposalLength
+            );
+            let dispatch_weight = proposal.get_dispatch_info().weight;
+            ensure!(
+                dispatch_weight <= proposal_weight_bound,
+                Error::<T>::WrongProposalWeight
+            );
+
+            co_signers.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let result = proposal.dispatch(RawOrigin::JointApproved(co_signers).into());
+
+            Self::deposit_event(Event::JointApproved(
+                proposal_id,
+                result.is_ok(),
+                result.err().map(|err| err.error),
+            ));
+
+            Self::remove_joint_proposal(proposal_id);
+
+            let proposal_weight = Self::get_result_weight(result).unwrap_or(dispatch_weight);
+
+            Ok((
+                Some(
+                    T::WeightInfo::close_joint_approved(proposal_len as u32, group_count)
+                        .saturating_add(proposal_weight),
+                ),
+                Pays::Yes,
+            )
+                .into())
+        }
+
+        /// Accept an invitation to join a group. The sender joins with the weight they were invited with.
+        ///
+        /// - `group_id`: Group that invited the sender
+        #[pallet::weight(T::WeightInfo::accept_invitation())]
+        pub fn accept_invitation(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let mut group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            let weight = <PendingInvitations<T>>::take(group_id, &sender)
+                .ok_or(Error::<T>::InvitationMissing)?;
+            <InvitationsByAccount<T>>::remove(&sender, group_id);
+
+            Self::add_members(&mut group, group_id, vec![(sender.clone(), weight)]);
+            Self::clamp_threshold(&mut group, group_id);
+            <Groups<T>>::insert(group_id, group);
+
+            Self::deposit_event(Event::InvitationAccepted(group_id, sender));
+
+            Ok(().into())
+        }
+
+        /// Decline an invitation to join a group.
+        ///
+        /// - `group_id`: Group that invited the sender
+        #[pallet::weight(T::WeightInfo::decline_invitation())]
+        pub fn decline_invitation(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let mut group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            ensure!(
+                <PendingInvitations<T>>::contains_key(group_id, &sender),
+                Error::<T>::InvitationMissing
+            );
+            <PendingInvitations<T>>::remove(group_id, &sender);
+            <InvitationsByAccount<T>>::remove(&sender, group_id);
+
+            Self::clamp_threshold(&mut group, group_id);
+            <Groups<T>>::insert(group_id, group);
+
+            Self::deposit_event(Event::InvitationDeclined(group_id, sender));
+
+            Ok(().into())
+        }
+
+        /// Leave a group. The threshold is reduced if it can no longer be met. The last member of a group cannot leave.
+        ///
+        /// - `group_id`: Group to leave
+        #[pallet::weight(T::WeightInfo::leave_group())]
+        pub fn leave_group(
+            origin: OriginFor<T>,
+            group_id: T::GroupId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let mut group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            let weight = <GroupMembers<T>>::get(group_id, &sender).ok_or(Error::<T>::NotMember)?;
+            ensure!(group.total_vote_weight > weight, Error::<T>::LastMember);
+
+            Self::remove_members(&mut group, group_id, vec![sender.clone()]);
+            Self::clamp_threshold(&mut group, group_id);
+            <Groups<T>>::insert(group_id, group);
+
+            Self::deposit_event(Event::MemberLeft(group_id, sender));
+
+            Ok(().into())
+        }
+
+        /// Cancel a proposal of the group that is waiting in the execution queue. Can only be called via a proposal from the group.
+        ///
+        /// - `proposal_id`: Proposal to be cancelled
+        #[pallet::weight(T::WeightInfo::cancel_scheduled())]
+        pub fn cancel_scheduled(
+            origin: OriginFor<T>,
+            proposal_id: T::ProposalId,
+        ) -> DispatchResultWithPostInfo {
+            let (group_id, _, _, _, _) = T::GroupsOriginByGroupThreshold::ensure_origin(origin)?;
+
+            let scheduled = <ScheduledProposals<T>>::get(group_id, proposal_id)
+                .ok_or(Error::<T>::ProposalNotScheduled)?;
+            let proposal =
+                Self::proposals(group_id, proposal_id).ok_or(Error::<T>::ProposalMissing)?;
+            let proposal_hash = T::Hashing::hash_of(&proposal);
+
+            Self::remove_from_queue(scheduled.when, group_id, proposal_id);
+            <ScheduledProposals<T>>::remove(group_id, proposal_id);
+            <Proposals<T>>::remove(group_id, proposal_id);
+            <ProposalHashes<T>>::remove(group_id, proposal_hash);
+            <ProposalTimelocks<T>>::remove(group_id, proposal_id);
+
+            Self::deposit_event(Event::ScheduledProposalCancelled(group_id, proposal_id));
+
+            Ok(().into())
+        }
+
+        /// Remove a joint Proposal that has not been closed within `ProposalLifetime` blocks.
+        /// Anyone can trigger an expiry. If no member other than the proposer has voted, the proposal deposit is paid to the account of the proposer's group, otherwise it is returned.
+        ///
+        /// - `proposal_id`: Joint proposal to be expired
+        #[pallet::weight(T::WeightInfo::expire_joint_proposal(T::MaxJointGroups::get()))]
+        pub fn expire_joint_proposal(
+            origin: OriginFor<T>,
+            proposal_id: T::ProposalId,
+        ) -> DispatchResultWithPostInfo {
+            let _sender = ensure_signed(origin)?;
+            ensure!(
+                <JointProposals<T>>::contains_key(proposal_id),
+                Error::<T>::ProposalMissing
+            );
+
+            let mut expiry_maybe = None;
+            let mut vote_count = 0;
+            let mut group_count = 0;
+            for (group_id, voting) in <JointVoting<T>>::iter_prefix(proposal_id) {
+                if let Some(expiry) = <ProposalExpiries<T>>::get(group_id, proposal_id) {
+                    expiry_maybe = Some((group_id, expiry));
+                }
+                vote_count += voting.ayes.len() + voting.nays.len();
+                group_count += 1;
+            }
+            let (group_id, expiry) = expiry_maybe.ok_or(Error::<T>::ProposalNotExpired)?;
+            ensure!(
+                system::Module::<T>::block_number() >= expiry,
+                Error::<T>::ProposalNotExpired
+            );
+
+            // the proposer's own approval is recorded when proposing
+            if vote_count > 1 {
+                Self::return_deposit(group_id, proposal_id);
+            } else if let Some(group) = Self::groups(group_id) {
+                Self::slash_deposit(group_id, proposal_id, &group.anonymous_account);
+            }
+            Self::remove_joint_proposal(proposal_id);
+
+            Self::deposit_event(Event::JointProposalExpired(proposal_id));
+
+            Ok(Some(T::WeightInfo::expire_joint_proposal(group_count)).into())
+        }
     }
 
     impl<T: Config> Module<T> {
@@ -1647,8 +2556,147 @@ pub mod pallet {
                 .collect()
         }
 
+        pub fn get_scheduled_proposals(
+            group_id: T::GroupId,
+        ) -> Vec<(
+            T::ProposalId,
+            ScheduledExecution<T::AccountId, T::BlockNumber, T::MemberCount>,
+        )> {
+            <ScheduledProposals<T>>::iter_prefix(group_id).collect()
+        }
+
+        pub fn get_budget(
+            group_id: T::GroupId,
+        ) -> Option<(
+            Budget<
+                <T::Currency as Currency<T::AccountId>>::Balance,
+                T::BlockNumber,
+                T::MemberCount,
+            >,
+            BudgetUsage<<T::Currency as Currency<T::AccountId>>::Balance, T::BlockNumber>,
+        )> {
+            <Budgets<T>>::get(group_id).map(|budget| {
+                let usage = Self::current_budget_usage(group_id, &budget);
+                (budget, usage)
+            })
+        }
+
+        pub fn get_invitations(
+            account_id: T::AccountId,
+        ) -> Vec<(
+            T::GroupId,
+            Group<T::GroupId, T::AccountId, T::MemberCount, BoundedVec<u8, T::NameLimit>>,
+            T::MemberCount,
+        )> {
+            <InvitationsByAccount<T>>::iter_prefix(&account_id)
+                .filter_map(|(group_id, ())| {
+                    let weight = <PendingInvitations<T>>::get(group_id, &account_id)?;
+                    <Groups<T>>::get(group_id).map(|group| (group_id, group, weight))
+                })
+                .collect()
+        }
+
         // -- private functions --
 
+        /// Accepts either an approved proposal or a proposal executed by a single member.
+        fn ensure_spend_origin(
+            origin: OriginFor<T>,
+        ) -> Result<(T::GroupId, SpendAuthority<T::MemberCount>, T::AccountId), DispatchError>
+        {
+            match T::GroupsOriginByCallerThreshold::try_origin(origin) {
+                Ok((group_id, _, yes_votes, _, group_account)) => Ok((
+                    group_id,
+                    yes_votes.map_or(SpendAuthority::Veto, SpendAuthority::Votes),
+                    group_account,
+                )),
+                Err(origin) => {
+                    let (group_id, _, group_account) =
+                        T::GroupsOriginExecuted::ensure_origin(origin)?;
+                    Ok((group_id, SpendAuthority::Member, group_account))
+                }
+            }
+        }
+
+        /// Check that a transfer from a group account is permitted, returning the budget usage to record if the transfer succeeds.
+        /// Without a budget a transfer requires the group threshold and single members may not spend.
+        fn check_budget(
+            group_id: T::GroupId,
+            group: &Group<T::GroupId, T::AccountId, T::MemberCount, BoundedVec<u8, T::NameLimit>>,
+            authority: SpendAuthority<T::MemberCount>,
+            amount: <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+        ) -> Result<
+            Option<
+                BudgetUsage<
+                    <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+                    T::BlockNumber,
+                >,
+            >,
+            DispatchError,
+        > {
+            if let SpendAuthority::Votes(yes_votes) = authority {
+                ensure!(yes_votes >= group.threshold, DispatchError::BadOrigin);
+            }
+            let budget_maybe = <Budgets<T>>::get(group_id);
+            if budget_maybe.is_none() {
+                ensure!(
+                    authority != SpendAuthority::Member,
+                    Error::<T>::SmallSpendExceeded
+                );
+                return Ok(None);
+            }
+            let budget = budget_maybe.unwrap();
+
+            match authority {
+                SpendAuthority::Member => {
+                    ensure!(amount <= budget.small_spend, Error::<T>::SmallSpendExceeded)
+                }
+                SpendAuthority::Votes(yes_votes) => {
+                    let required_threshold = budget
+                        .tiers
+                        .iter()
+                        .filter(|(tier_amount, _)| amount > *tier_amount)
+                        .map(|(_, threshold)| *threshold)
+                        .max();
+                    if let Some(required_threshold) = required_threshold {
+                        ensure!(
+                            yes_votes >= required_threshold,
+                            Error::<T>::SpendThresholdNotMet
+                        );
+                    }
+                }
+                SpendAuthority::Veto => (),
+            }
+
+            let mut usage = Self::current_budget_usage(group_id, &budget);
+            usage.spent = usage
+                .spent
+                .checked_add(&amount)
+                .ok_or(Error::<T>::BudgetExceeded)?;
+            ensure!(usage.spent <= budget.period_cap, Error::<T>::BudgetExceeded);
+
+            Ok(Some(usage))
+        }
+
+        /// Budget usage for the current period. A new period starts with the first spend after the previous one has elapsed.
+        fn current_budget_usage(
+            group_id: T::GroupId,
+            budget: &Budget<
+                <<T as Config>::Currency as Currency<T::AccountId>>::Balance,
+                T::BlockNumber,
+                T::MemberCount,
+            >,
+        ) -> BudgetUsage<<<T as Config>::Currency as Currency<T::AccountId>>::Balance, T::BlockNumber>
+        {
+            let now = system::Module::<T>::block_number();
+            match <BudgetUsages<T>>::get(group_id) {
+                Some(usage) if now < usage.period_start.saturating_add(budget.period) => usage,
+                _ => BudgetUsage {
+                    period_start: now,
+                    spent: Zero::zero(),
+                },
+            }
+        }
+
         fn add_members(
             group: &mut Group<
                 T::GroupId,
@@ -1697,9 +2745,388 @@ pub mod pallet {
                 }
                 <GroupMembers<T>>::remove(group_id, &account);
                 <MemberOf<T>>::remove(&account, group_id);
+                <PendingInvitations<T>>::remove(group_id, &account);
+                <InvitationsByAccount<T>>::remove(&account, group_id);
             });
         }
 
+        /// Record a member's vote, replacing an opposite vote. Returns true if this is the member's first vote on the proposal.
+        fn record_vote(
+            voting: &mut Votes<T::AccountId, T::MemberCount>,
+            sender: &T::AccountId,
+            weight: T::MemberCount,
+            approve: bool,
+        ) -> Result<bool, DispatchError> {
+            let position_yes = voting
+                .ayes
+                .iter()
+                .position(|(account, _)| account == sender);
+            let position_no = voting
+                .nays
+                .iter()
+                .position(|(account, _)| account == sender);
+
+            // Detects first vote of the member in the motion
+            let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
+
+            if approve {
+                ensure!(position_yes.is_none(), Error::<T>::DuplicateVote);
+                voting.ayes.push((sender.clone(), weight));
+                if let Some(pos) = position_no {
+                    voting.nays.swap_remove(pos);
+                }
+            } else {
+                ensure!(position_no.is_none(), Error::<T>::DuplicateVote);
+                voting.nays.push((sender.clone(), weight));
+                if let Some(pos) = position_yes {
+                    voting.ayes.swap_remove(pos);
+                }
+            }
+
+            Ok(is_account_voting_first_time)
+        }
+
+        /// Remove a joint proposal, returning the deposit of the proposer if it is still held.
+        fn remove_joint_proposal(proposal_id: T::ProposalId) {
+            <JointProposals<T>>::remove(proposal_id);
+            <JointVoting<T>>::drain_prefix(proposal_id).for_each(|(group_id, _)| {
+                <JointProposalsByGroup<T>>::remove(group_id, proposal_id);
+                <ProposalExpiries<T>>::remove(group_id, proposal_id);
+                Self::return_deposit(group_id, proposal_id);
+            });
+        }
+
+        /// Proposals waiting on a group, including the joint proposals it participates in.
+        fn active_proposal_count(group_id: T::GroupId) -> usize {
+            <ProposalHashes<T>>::iter_prefix(group_id)
+                .count()
+                .saturating_add(<JointProposalsByGroup<T>>::iter_prefix(group_id).count())
+        }
+
+        /// Existing members have their weight updated, other accounts are invited to join.
+        fn invite_members(
+            group: &mut Group<
+                T::GroupId,
+                T::AccountId,
+                T::MemberCount,
+                BoundedVec<u8, T::NameLimit>,
+            >,
+            group_id: T::GroupId,
+            members: Vec<(T::AccountId, T::MemberCount)>,
+        ) {
+            let (existing, invited): (Vec<_>, Vec<_>) = members
+                .into_iter()
+                .partition(|(account, _)| <GroupMembers<T>>::contains_key(group_id, account));
+            Self::add_members(group, group_id, existing);
+            for (account, mut weight) in invited {
+                if weight == Zero::zero() {
+                    weight = 1u32.into();
+                }
+                <PendingInvitations<T>>::insert(group_id, &account, weight);
+                <InvitationsByAccount<T>>::insert(&account, group_id, ());
+                Self::deposit_event(Event::MemberInvited(group_id, account, weight));
+            }
+        }
+
+        /// The threshold may not exceed the weight of the members plus the weight of outstanding invitations.
+        fn clamp_threshold(
+            group: &mut Group<
+                T::GroupId,
+                T::AccountId,
+                T::MemberCount,
+                BoundedVec<u8, T::NameLimit>,
+            >,
+            group_id: T::GroupId,
+        ) {
+            let invited_weight: T::MemberCount = <PendingInvitations<T>>::iter_prefix(group_id)
+                .map(|(_, weight)| weight)
+                .sum();
+            let max_threshold = group.total_vote_weight.saturating_add(invited_weight);
+            if group.threshold > max_threshold {
+                group.threshold = max_threshold;
+            }
+        }
+
+        /// Place an approved proposal in the execution queue.
+        fn schedule_proposal(
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
+            yes_votes: T::MemberCount,
+            no_votes: T::MemberCount,
+            vetoed_by: Option<T::AccountId>,
+            when: T::BlockNumber,
+            proposal_weight: Weight,
+        ) {
+            <ExecutionQueueLength<T>>::mutate(when, |length| *length = length.saturating_add(1));
+            <ScheduledProposals<T>>::insert(
+                group_id,
+                proposal_id,
+                ScheduledExecution {
+                    when,
+                    yes_votes,
+                    no_votes,
+                    vetoed_by,
+                },
+            );
+            <ExecutionQueue<T>>::insert(when, (group_id, proposal_id), proposal_weight);
+            <ProposalExpiries<T>>::remove(group_id, proposal_id);
+            Self::return_deposit(group_id, proposal_id);
+
+            Self::deposit_event(Event::ProposalScheduled(group_id, proposal_id, when));
+        }
+
+        /// Find the block in which a proposal with the given timelock will be dispatched.
+        /// A full block defers the proposal to the next block with room in the queue, at most `MaxScheduleDeferral` blocks later.
+        fn execution_block(
+            timelock: &Timelock<T::BlockNumber>,
+            proposal_weight: Weight,
+        ) -> Result<T::BlockNumber, DispatchError> {
+            ensure!(
+                proposal_weight <= T::MaximumScheduledWeight::get(),
+                Error::<T>::ProposalTooHeavy
+            );
+            let now = system::Module::<T>::block_number();
+            // on_initialize has already run for the current block
+            let mut when = match *timelock {
+                Timelock::AtBlock(block_number) => block_number,
+                Timelock::Delay(delay) => now.saturating_add(delay),
+            }
+            .max(now.saturating_add(One::one()));
+            for _ in 0..T::MaxScheduleDeferral::get() {
+                if <ExecutionQueueLength<T>>::get(when) < T::MaxScheduledPerBlock::get() {
+                    return Ok(when);
+                }
+                when = when.saturating_add(One::one());
+            }
+            Err(Error::<T>::ExecutionQueueFull.into())
+        }
+
+        /// Move a queued proposal that did not fit in the weight of its block to the next block.
+        fn defer_scheduled(
+            next: T::BlockNumber,
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
+            proposal_weight: Weight,
+        ) {
+            <ExecutionQueue<T>>::insert(next, (group_id, proposal_id), proposal_weight);
+            <ScheduledProposals<T>>::mutate(group_id, proposal_id, |scheduled_maybe| {
+                if let Some(scheduled) = scheduled_maybe {
+                    scheduled.when = next;
+                }
+            });
+            Self::deposit_event(Event::ProposalScheduled(group_id, proposal_id, next));
+        }
+
+        /// Take a proposal out of the execution queue without dispatching it.
+        fn remove_from_queue(
+            when: T::BlockNumber,
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
+        ) {
+            <ExecutionQueue<T>>::remove(when, (group_id, proposal_id));
+            <ExecutionQueueLength<T>>::mutate(when, |length| *length = length.saturating_sub(1));
+        }
+
+        /// Dispatch a proposal taken from the execution queue.
+        fn dispatch_scheduled(group_id: T::GroupId, proposal_id: T::ProposalId) -> Weight {
+            let weight = T::DbWeight::get().reads_writes(3, 1);
+            let scheduled_maybe = <ScheduledProposals<T>>::take(group_id, proposal_id);
+            let proposal_maybe = <Proposals<T>>::get(group_id, proposal_id);
+            let group_maybe = <Groups<T>>::get(group_id);
+            if scheduled_maybe.is_none() || proposal_maybe.is_none() || group_maybe.is_none() {
+                return weight;
+            }
+            let scheduled = scheduled_maybe.unwrap();
+            let proposal = proposal_maybe.unwrap();
+            let group = group_maybe.unwrap();
+
+            let proposal_len = proposal.using_encoded(|x| x.len());
+            let proposal_hash = T::Hashing::hash_of(&proposal);
+            let dispatch_weight = proposal.get_dispatch_info().weight;
+
+            let origin = match scheduled.vetoed_by {
+                Some(vetoed_by) => RawOrigin::ProposalApprovedByVeto(
+                    group_id,
+                    proposal_id,
+                    vetoed_by,
+                    group.anonymous_account,
+                ),
+                None => RawOrigin::ProposalApproved(
+                    group_id,
+                    proposal_id,
+                    scheduled.yes_votes,
+                    scheduled.no_votes,
+                    group.anonymous_account,
+                ),
+            };
+            let result = proposal.dispatch(origin.into());
+
+            Self::deposit_event(Event::ProposalExecutedLater(
+                group_id,
+                proposal_id,
+                result.is_ok(),
+                result.err().map(|err| err.error),
+            ));
+
+            <Proposals<T>>::remove(group_id, proposal_id);
+            <ProposalHashes<T>>::remove(group_id, proposal_hash);
+            <ProposalTimelocks<T>>::remove(group_id, proposal_id);
+
+            let proposal_weight = Self::get_result_weight(result).unwrap_or(dispatch_weight);
+            weight
+                .saturating_add(T::WeightInfo::execute_scheduled(proposal_len as u32))
+                .saturating_add(proposal_weight)
+        }
+
+        /// Open a proposal with the approval of the proposer, shared by `propose` and `propose_with_timelock`.
+        /// No deposit is reserved if the proposer can meet the threshold alone.
+        /// Returns (group_account,proposer_weight,proposal_len,proposal_hash,proposal_id,deposit)
+        #[allow(clippy::type_complexity)]
+        fn open_proposal(
+            sender: &T::AccountId,
+            group_id: T::GroupId,
+            proposal: &<T as Config>::Proposal,
+            threshold: T::MemberCount,
+            length_bound: u32,
+        ) -> Result<
+            (
+                T::AccountId,
+                T::MemberCount,
+                usize,
+                T::Hash,
+                T::ProposalId,
+                Option<<<T as Config>::Currency as Currency<T::AccountId>>::Balance>,
+            ),
+            DispatchError,
+        > {
+            let group = Self::groups(group_id).ok_or(Error::<T>::GroupMissing)?;
+            let weight_maybe = <GroupMembers<T>>::get(group_id, sender);
+            ensure!(weight_maybe.is_some(), Error::<T>::NotMember);
+            let weight = weight_maybe.unwrap();
+            ensure!(
+                Self::active_proposal_count(group_id) < T::MaxProposals::get() as usize,
+                Error::<T>::TooManyProposals
+            );
+            let proposal_len = proposal.using_encoded(|x| x.len());
+            let proposal_hash = T::Hashing::hash_of(proposal);
+            ensure!(
+                !<ProposalHashes<T>>::contains_key(group_id, proposal_hash),
+                Error::<T>::DuplicateProposal
+            );
+            ensure!(
+                proposal_len <= length_bound as usize,
+                Error::<T>::WrongProposalLength
+            );
+
+            // a proposal the proposer approves alone must wait until invited members have joined
+            if threshold == weight {
+                ensure!(
+                    group.total_vote_weight >= group.threshold,
+                    Error::<T>::MembersPending
+                );
+            }
+
+            let deposit = if threshold == weight {
+                None
+            } else {
+                Self::reserve_deposit(sender)?
+            };
+
+            T::GetExtrinsicExtraSource::charge_extrinsic_extra(
+                &MODULE_INDEX,
+                &(ExtrinsicIndex::Proposal as u8),
+                sender,
+            );
+
+            let proposal_id = next_id!(NextProposalId<T>, T);
+
+            let votes = Votes {
+                threshold,
+                total_vote_weight: group.total_vote_weight,
+                ayes: vec![(sender.clone(), weight)],
+                nays: vec![],
+                veto: None,
+            };
+            <Voting<T>>::insert(group_id, proposal_id, votes);
+            <GroupByProposal<T>>::insert(proposal_id, group_id);
+
+            Ok((
+                group.anonymous_account,
+                weight,
+                proposal_len,
+                proposal_hash,
+                proposal_id,
+                deposit,
+            ))
+        }
+
+        /// Reserve the proposal deposit set in the settings pallet, if any.
+        fn reserve_deposit(
+            proposer: &T::AccountId,
+        ) -> Result<
+            Option<<<T as Config>::Currency as Currency<T::AccountId>>::Balance>,
+            DispatchError,
+        > {
+            let deposit = T::GetExtrinsicExtraSource::get_extrinsic_deposit(
+                &MODULE_INDEX,
+                &(ExtrinsicIndex::Proposal as u8),
+            )
+            .filter(|amount| !amount.is_zero());
+            if let Some(amount) = deposit {
+                <T as Config>::Currency::reserve(proposer, amount)?;
+            }
+            Ok(deposit)
+        }
+
+        fn record_deposit_and_expiry(
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
+            proposer: &T::AccountId,
+            deposit: Option<<<T as Config>::Currency as Currency<T::AccountId>>::Balance>,
+        ) {
+            if let Some(amount) = deposit {
+                <ProposalDeposits<T>>::insert(group_id, proposal_id, (proposer.clone(), amount));
+            }
+            let expiry =
+                system::Module::<T>::block_number().saturating_add(T::ProposalLifetime::get());
+            <ProposalExpiries<T>>::insert(group_id, proposal_id, expiry);
+        }
+
+        /// Unreserve the proposal deposit once a proposal has been resolved.
+        fn return_deposit(group_id: T::GroupId, proposal_id: T::ProposalId) {
+            if let Some((proposer, amount)) = <ProposalDeposits<T>>::take(group_id, proposal_id) {
+                <T as Config>::Currency::unreserve(&proposer, amount);
+                Self::deposit_event(Event::DepositReturned(
+                    group_id,
+                    proposal_id,
+                    proposer,
+                    amount,
+                ));
+            }
+        }
+
+        /// Pay the proposal deposit to the group account.
+        fn slash_deposit(
+            group_id: T::GroupId,
+            proposal_id: T::ProposalId,
+            group_account: &T::AccountId,
+        ) {
+            if let Some((proposer, amount)) = <ProposalDeposits<T>>::take(group_id, proposal_id) {
+                let _ = <T as Config>::Currency::repatriate_reserved(
+                    &proposer,
+                    group_account,
+                    amount,
+                    BalanceStatus::Free,
+                );
+                Self::deposit_event(Event::DepositSlashed(
+                    group_id,
+                    proposal_id,
+                    proposer,
+                    amount,
+                ));
+            }
+        }
+
         fn is_group_account(group_id: T::GroupId, account_id: &T::AccountId) -> bool {
             let group = <Groups<T>>::get(group_id);
             if group.is_none() {
@@ -1922,4 +3349,31 @@ pub mod pallet {
             ))
         }
     }
+
+    /// This verifies that the origin came from a joint proposal that every participating group approved. It returns the co-signing groups so the called extrinsic can check that the groups it requires took part.
+    pub struct EnsureJointApproved<T>(sp_std::marker::PhantomData<T>);
+    impl<
+            O: Into<Result<RawOrigin<T::AccountId, T::GroupId, T::ProposalId, T::MemberCount>, O>>
+                + From<RawOrigin<T::AccountId, T::GroupId, T::ProposalId, T::MemberCount>>,
+            T: Config,
+        > EnsureOrigin<O> for EnsureJointApproved<T>
+    {
+        type Success = Vec<(T::GroupId, T::AccountId)>;
+        fn try_origin(o: O) -> Result<Self::Success, O> {
+            o.into().and_then(|o| match o {
+                RawOrigin::JointApproved(co_signers) => Ok(co_signers),
+                r => Err(O::from(r)),
+            })
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn successful_origin() -> O {
+            let group_id: T::GroupId = 1u32.into();
+            let group = Groups::<T>::get(group_id).unwrap();
+            O::from(RawOrigin::JointApproved(vec![(
+                group_id,
+                group.anonymous_account,
+            )]))
+        }
+    }
 }
@@ -4,8 +4,9 @@ use crate as pallet_groups;
 use frame_support::{ord_parameter_types, parameter_types};
 use frame_system::{self as system, EnsureOneOf, EnsureSigned};
 use runtime::{
-    primitives::NameLimit, GroupChainLimit, GroupMaxMembers, GroupMaxProposalLength,
-    GroupMaxProposals,
+    primitives::NameLimit, GroupChainLimit, GroupMaxBudgetTiers, GroupMaxJointGroups,
+    GroupMaxMembers, GroupMaxProposalLength, GroupMaxProposals, GroupMaxScheduleDeferral,
+    GroupMaxScheduledPerBlock, GroupMaximumScheduledWeight,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -33,13 +34,14 @@ frame_support::construct_runtime!(
 type AccountId = u64;
 type ModuleIndex = u8;
 type ExtrinsicIndex = u8;
-type Balance = u64;
+type Balance = u128;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
     pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
     pub const ExistentialDeposit: u128 = 1;
+    pub const GroupProposalLifetime: u64 = 100;
 }
 
 impl system::Config for Test {
@@ -104,6 +106,13 @@ impl pallet_groups::Config for Test {
     type GetExtrinsicExtraSource = Settings;
     type NameLimit = NameLimit;
     type GroupChainLimit = GroupChainLimit;
+    type MaxBudgetTiers = GroupMaxBudgetTiers;
+    type GroupsOriginJointApproved = pallet_groups::EnsureJointApproved<Test>;
+    type MaxJointGroups = GroupMaxJointGroups;
+    type ProposalLifetime = GroupProposalLifetime;
+    type MaxScheduledPerBlock = GroupMaxScheduledPerBlock;
+    type MaximumScheduledWeight = GroupMaximumScheduledWeight;
+    type MaxScheduleDeferral = GroupMaxScheduleDeferral;
 }
 
 ord_parameter_types! {
@@ -3,7 +3,10 @@
 use super::*;
 use crate::{mock::*, GroupMembers};
 use core::convert::TryInto;
-use frame_support::{assert_ok, dispatch::Weight};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchError, Weight},
+};
 use primitives::*;
 
 const MINIMUM_BALANCE: u128 = 1;
@@ -63,6 +66,10 @@ fn update_group_should_work() {
 
         // verify group was created
         assert!(super::Groups::<Test>::contains_key(group_id));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
 
         assert_ok!(mock::Groups::propose(
             mock::Origin::signed(caller),
@@ -76,6 +83,10 @@ fn update_group_should_work() {
             1,
             100
         ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_3),
+            group_id
+        ));
 
         assert!(super::Groups::<Test>::contains_key(group_id));
         let group = super::Groups::<Test>::get(group_id).unwrap();
@@ -138,6 +149,18 @@ fn creating_new_sub_group_should_work() {
             group_id,
             sub_group_id
         ));
+        assert!(super::PendingInvitations::<Test>::contains_key(
+            sub_group_id,
+            member_2
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            sub_group_id
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_3),
+            sub_group_id
+        ));
 
         assert!(super::Groups::<Test>::contains_key(sub_group_id));
         let sub_group = super::Groups::<Test>::get(sub_group_id).unwrap();
@@ -199,6 +222,14 @@ fn update_sub_group_should_work() {
         ));
 
         let sub_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            sub_group_id
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_3),
+            sub_group_id
+        ));
 
         assert!(super::Groups::<Test>::contains_key(sub_group_id));
         let sub_group = super::Groups::<Test>::get(sub_group_id).unwrap();
@@ -230,6 +261,14 @@ fn update_sub_group_should_work() {
             1,
             100
         ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_4),
+            sub_group_id
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_5),
+            sub_group_id
+        ));
 
         assert!(super::Groups::<Test>::contains_key(sub_group_id));
         let sub_group = super::Groups::<Test>::get(sub_group_id).unwrap();
@@ -324,6 +363,12 @@ fn remove_group_should_work() {
         assert!(!super::GroupMembers::<Test>::contains_key(
             group_id, member_2
         ));
+        assert!(!super::PendingInvitations::<Test>::contains_key(
+            group_id, member_2
+        ));
+        assert!(!super::InvitationsByAccount::<Test>::contains_key(
+            member_2, group_id
+        ));
         assert!(super::Proposals::<Test>::iter_prefix(&group_id)
             .next()
             .is_none());
@@ -377,6 +422,10 @@ fn remove_sub_group_should_work() {
         ));
 
         let sub_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            sub_group_id
+        ));
 
         assert!(super::Groups::<Test>::contains_key(sub_group_id));
         let sub_group = super::Groups::<Test>::get(sub_group_id).unwrap();
@@ -499,6 +548,10 @@ fn vote_with_close_and_approve_should_work() {
         let group_id = 1u32;
         // verify group was created
         assert!(super::Groups::<Test>::contains_key(group_id));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
 
         // Create a proposal
         assert_ok!(mock::Groups::propose(
@@ -602,6 +655,10 @@ fn vote_with_close_and_disapprove_should_work() {
         let group_id = 1u32;
         // verify group was created
         assert!(super::Groups::<Test>::contains_key(group_id));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
 
         // Create a proposal
         assert_ok!(mock::Groups::propose(
@@ -719,6 +776,14 @@ fn veto_yes_should_work() {
             100
         ));
         let sub_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            sub_group_id
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_3),
+            sub_group_id
+        ));
 
         //subgroup proposes creating another subgroup
         let member_4 = 2u64;
@@ -806,6 +871,14 @@ fn veto_no_should_work() {
             100
         ));
         let sub_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            sub_group_id
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_3),
+            sub_group_id
+        ));
 
         //subgroup proposes creating another subgroup
         let member_4 = 2u64;
@@ -1046,40 +1119,1272 @@ fn send_funds_to_sub_group_should_work() {
 //Make sure weights cannot exceed 10% of total allowance for block.
 
 #[test]
-fn weights_should_not_be_excessive() {
+fn propose_with_timelock_should_work() {
     new_test_ext().execute_with(|| {
-        const MAXIMUM_ALLOWED_WEIGHT: Weight = 130_000_000_000;
+        let caller = 1u64;
 
-        let weight = <Test as Config>::WeightInfo::create_group(
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::MaxMembers::get(),
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            2_000_000u128
+        ));
+        let group_id = 1u32;
+        // verify group was created
+        assert!(super::Groups::<Test>::contains_key(group_id));
+
+        assert_ok!(mock::Groups::propose_with_timelock(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 1_000_000u128)
+            )),
+            1,
+            Timelock::Delay(10),
+            100
+        ));
+        let proposal_id = 1u32;
+
+        // threshold was met so the proposal is waiting in the execution queue
+        let scheduled = super::ScheduledProposals::<Test>::get(group_id, proposal_id).unwrap();
+        assert_eq!(scheduled.when, 11);
+        assert!(super::ExecutionQueue::<Test>::contains_key(
+            11,
+            (group_id, proposal_id)
+        ));
+        assert_eq!(
+            crate::mock::Groups::get_scheduled_proposals(group_id),
+            vec![(proposal_id, scheduled)]
         );
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::update_group(
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::MaxMembers::get(),
-            <Test as Config>::MaxMembers::get(),
+
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            2_000_000u128 + MINIMUM_BALANCE
         );
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::create_sub_group(
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::MaxMembers::get(),
+
+        // nothing happens before the scheduled block
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(10);
+        assert!(super::ScheduledProposals::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(11);
+
+        // verify funds were withdrawn and storage cleaned up
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            1_000_000u128 + MINIMUM_BALANCE
         );
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::update_sub_group(
-            <Test as Config>::NameLimit::get(),
-            <Test as Config>::MaxMembers::get(),
-            <Test as Config>::MaxMembers::get(),
+        assert!(!super::ScheduledProposals::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+        assert!(!super::ProposalTimelocks::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+        assert!(!super::Proposals::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+    });
+}
+
+#[test]
+fn veto_scheduled_proposal_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            3_000_000u128
+        ));
+        let group_id = 1u32;
+
+        let member_2 = 2u64;
+        let member_3 = 3u64;
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::create_sub_group(
+                "Test".to_string().into(),
+                vec![(member_2, 1), (member_3, 1)],
+                2,
+                2_000_000u128
+            ))),
+            1,
+            100
+        ));
+        let sub_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            sub_group_id
+        ));
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_3),
+            sub_group_id
+        ));
+
+        //subgroup proposes creating another subgroup at block 5
+        assert_ok!(mock::Groups::propose_with_timelock(
+            mock::Origin::signed(member_2),
+            sub_group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::create_sub_group(
+                "SubSubGroup".to_string().into(),
+                vec![(member_2, 1)],
+                1,
+                1_000_000u128
+            ))),
+            2,
+            Timelock::AtBlock(5),
+            100
+        ));
+        let sub_sub_group_id = 3u32;
+        let proposal_id = 2u32;
+
+        assert_ok!(mock::Groups::vote(
+            mock::Origin::signed(member_3),
+            sub_group_id,
+            proposal_id,
+            true
+        ));
+        assert_ok!(mock::Groups::close(
+            mock::Origin::signed(member_3),
+            sub_group_id,
+            proposal_id,
+            1_000_000_000,
+            100
+        ));
+
+        // approved but not yet executed
+        assert!(!super::Groups::<Test>::contains_key(sub_sub_group_id));
+        assert!(super::ExecutionQueue::<Test>::contains_key(
+            5,
+            (sub_group_id, proposal_id)
+        ));
+
+        // parent group member cancels it
+        assert_ok!(mock::Groups::veto(
+            mock::Origin::signed(caller),
+            sub_group_id,
+            proposal_id,
+            false,
+            100_000_000,
+            100
+        ));
+        assert!(!super::ScheduledProposals::<Test>::contains_key(
+            sub_group_id,
+            proposal_id
+        ));
+        assert!(!super::ExecutionQueue::<Test>::contains_key(
+            5,
+            (sub_group_id, proposal_id)
+        ));
+
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(5);
+
+        //subsubgroup was NOT created
+        assert!(!super::Groups::<Test>::contains_key(sub_sub_group_id));
+    });
+}
+
+#[test]
+fn budget_should_limit_spending() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1), (member_2, 1)],
+            1u32,
+            2_000_000u128
+        ));
+        let group_id = 1u32;
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::set_budget(
+                group_id,
+                Some(Budget {
+                    period: 10,
+                    period_cap: 500_000u128,
+                    small_spend: 100_000u128,
+                    tiers: vec![(200_000u128, 2)],
+                })
+            ))),
+            1,
+            100
+        ));
+        assert!(super::Budgets::<Test>::contains_key(group_id));
+
+        // a single member can spend up to the small spend allowance
+        assert_ok!(mock::Groups::execute(
+            mock::Origin::signed(member_2),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(member_2, 100_000u128)
+            )),
+            100
+        ));
+        assert_ok!(mock::Groups::execute(
+            mock::Origin::signed(member_2),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(member_2, 150_000u128)
+            )),
+            100
+        ));
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            1_900_000u128 + MINIMUM_BALANCE
         );
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::remove_group(
-            <Test as Config>::MaxMembers::get(),
-            <Test as Config>::MaxProposals::get(),
+
+        // spending above a tier requires a higher threshold
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 300_000u128)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            1_900_000u128 + MINIMUM_BALANCE
         );
-        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::remove_sub_group(
-            <Test as Config>::MaxMembers::get(),
-            <Test as Config>::MaxProposals::get(),
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 200_000u128)
+            )),
+            1,
+            100
+        ));
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 150_000u128)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            1_550_000u128 + MINIMUM_BALANCE
+        );
+
+        // the period cap has been reached
+        let (_, usage) = crate::mock::Groups::get_budget(group_id).unwrap();
+        assert_eq!(usage.spent, 450_000u128);
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 100_000u128)
+            )),
+            1,
+            100
+        ));
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            1_550_000u128 + MINIMUM_BALANCE
+        );
+
+        // a new period starts
+        System::set_block_number(12);
+        let (_, usage) = crate::mock::Groups::get_budget(group_id).unwrap();
+        assert_eq!(usage.spent, 0u128);
+        assert_ok!(mock::Groups::execute(
+            mock::Origin::signed(member_2),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(member_2, 100_000u128)
+            )),
+            100
+        ));
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            1_450_000u128 + MINIMUM_BALANCE
+        );
+    });
+}
+
+#[test]
+fn execute_withdraw_without_budget_should_fail() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            2_000_000u128
+        ));
+        let group_id = 1u32;
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+
+        assert_ok!(mock::Groups::execute(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 1u128)
+            )),
+            100
+        ));
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            2_000_000u128 + MINIMUM_BALANCE
+        );
+    });
+}
+
+#[test]
+fn accept_invitation_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+        let member_3 = 3u64;
+
+        // caller creates a Group and invites member_2
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1), (member_2, 2)],
+            3u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+
+        // member_2 is invited but is not yet a member
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_eq!(group.total_vote_weight, 1);
+        assert_eq!(group.threshold, 3);
+        assert!(GroupMembers::<Test>::contains_key(group_id, caller));
+        assert!(!GroupMembers::<Test>::contains_key(group_id, member_2));
+        assert_eq!(
+            super::PendingInvitations::<Test>::get(group_id, member_2),
+            Some(2)
+        );
+        assert_eq!(
+            crate::mock::Groups::get_invitations(member_2),
+            vec![(group_id, group, 2)]
+        );
+
+        assert_noop!(
+            mock::Groups::accept_invitation(mock::Origin::signed(member_3), group_id),
+            Error::<Test>::InvitationMissing
+        );
+
+        // caller cannot pass a proposal alone while member_2 has not joined
+        let proposal = Box::new(crate::mock::Call::Groups(super::Call::update_group(
+            Some(b"Updated".to_vec()),
+            None,
+            None,
+            None,
+        )));
+        assert_noop!(
+            mock::Groups::propose(
+                mock::Origin::signed(caller),
+                group_id,
+                proposal.clone(),
+                1,
+                100
+            ),
+            Error::<Test>::MembersPending
+        );
+
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_eq!(group.total_vote_weight, 3);
+        assert_eq!(group.threshold, 3);
+        assert_eq!(GroupMembers::<Test>::get(group_id, member_2), Some(2));
+        assert!(super::MemberOf::<Test>::contains_key(member_2, group_id));
+        assert!(!super::PendingInvitations::<Test>::contains_key(
+            group_id, member_2
+        ));
+        assert!(crate::mock::Groups::get_invitations(member_2).is_empty());
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            proposal,
+            1,
+            100
+        ));
+    });
+}
+
+#[test]
+fn decline_invitation_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1), (member_2, 1)],
+            2u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+
+        assert_ok!(mock::Groups::decline_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+
+        // the threshold can no longer be met so it is reduced
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_eq!(group.total_vote_weight, 1);
+        assert_eq!(group.threshold, 1);
+        assert!(!GroupMembers::<Test>::contains_key(group_id, member_2));
+        assert!(!super::PendingInvitations::<Test>::contains_key(
+            group_id, member_2
+        ));
+        assert!(!super::InvitationsByAccount::<Test>::contains_key(
+            member_2, group_id
+        ));
+
+        assert_noop!(
+            mock::Groups::accept_invitation(mock::Origin::signed(member_2), group_id),
+            Error::<Test>::InvitationMissing
+        );
+    });
+}
+
+#[test]
+fn leave_group_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+        let member_3 = 3u64;
+
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1), (member_2, 2)],
+            3u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+
+        assert_noop!(
+            mock::Groups::leave_group(mock::Origin::signed(member_3), group_id),
+            Error::<Test>::NotMember
+        );
+
+        assert_ok!(mock::Groups::leave_group(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_eq!(group.total_vote_weight, 1);
+        assert_eq!(group.threshold, 1);
+        assert!(!GroupMembers::<Test>::contains_key(group_id, member_2));
+        assert!(!super::MemberOf::<Test>::contains_key(member_2, group_id));
+
+        // the last member cannot leave
+        assert_noop!(
+            mock::Groups::leave_group(mock::Origin::signed(caller), group_id),
+            Error::<Test>::LastMember
+        );
+    });
+}
+
+#[test]
+fn joint_proposal_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+
+        // caller creates two Groups, the second is run by member_2
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Other".to_vec(),
+            vec![(member_2, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+        let other_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            other_group_id
+        ));
+
+        assert_ok!(mock::Groups::propose_joint(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::update_group(
+                Some(b"Joint".to_vec()),
+                None,
+                None,
+                None,
+            ))),
+            vec![(group_id, 1), (other_group_id, 1)],
+            100
+        ));
+        let proposal_id = 1u32;
+
+        assert!(super::JointProposals::<Test>::contains_key(proposal_id));
+        let voting = super::JointVoting::<Test>::get(proposal_id, group_id).unwrap();
+        assert_eq!(voting.ayes, vec![(caller, 1)]);
+        assert!(super::JointProposalsByGroup::<Test>::contains_key(
+            other_group_id,
+            proposal_id
+        ));
+
+        // member_2 can only vote for their own group
+        assert_noop!(
+            mock::Groups::vote_joint(mock::Origin::signed(member_2), group_id, proposal_id, true),
+            Error::<Test>::NotMember
+        );
+        // the other group has not approved yet
+        assert_noop!(
+            mock::Groups::close_joint(
+                mock::Origin::signed(caller),
+                proposal_id,
+                1_000_000_000,
+                100
+            ),
+            Error::<Test>::VotingIncomplete
+        );
+
+        assert_ok!(mock::Groups::vote_joint(
+            mock::Origin::signed(member_2),
+            other_group_id,
+            proposal_id,
+            true
+        ));
+        assert_ok!(mock::Groups::close_joint(
+            mock::Origin::signed(caller),
+            proposal_id,
+            1_000_000_000,
+            100
+        ));
+
+        // proposal storage cleaned up
+        assert!(!super::JointProposals::<Test>::contains_key(proposal_id));
+        assert!(super::JointVoting::<Test>::iter_prefix(proposal_id)
+            .next()
+            .is_none());
+        assert!(!super::JointProposalsByGroup::<Test>::contains_key(
+            other_group_id,
+            proposal_id
+        ));
+
+        // update_group requires the approval of a single group so the joint origin was rejected
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+        assert_eq!(group.name, b"Test".to_vec());
+    });
+}
+
+#[test]
+fn joint_proposal_disapproved_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Other".to_vec(),
+            vec![(member_2, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+        let other_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            other_group_id
+        ));
+
+        let proposal = Box::new(crate::mock::Call::Groups(super::Call::update_group(
+            Some(b"Joint".to_vec()),
+            None,
+            None,
+            None,
+        )));
+
+        assert_noop!(
+            mock::Groups::propose_joint(
+                mock::Origin::signed(caller),
+                group_id,
+                proposal.clone(),
+                vec![(group_id, 1), (group_id, 1)],
+                100
+            ),
+            Error::<Test>::InvalidJointGroups
+        );
+        assert_noop!(
+            mock::Groups::propose_joint(
+                mock::Origin::signed(caller),
+                group_id,
+                proposal.clone(),
+                vec![(other_group_id, 1), (3, 1)],
+                100
+            ),
+            Error::<Test>::NotJointGroup
+        );
+
+        // thresholds may not be below the threshold of a group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Third".to_vec(),
+            vec![(caller, 1), (member_2, 1)],
+            2u32,
+            1_000_000u128
+        ));
+        let third_group_id = 3u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            third_group_id
+        ));
+        assert_noop!(
+            mock::Groups::propose_joint(
+                mock::Origin::signed(caller),
+                group_id,
+                proposal.clone(),
+                vec![(group_id, 1), (third_group_id, 1)],
+                100
+            ),
+            Error::<Test>::InvalidThreshold
+        );
+
+        assert_ok!(mock::Groups::propose_joint(
+            mock::Origin::signed(caller),
+            group_id,
+            proposal,
+            vec![(group_id, 1), (other_group_id, 1)],
+            100
+        ));
+        let proposal_id = 1u32;
+
+        assert_ok!(mock::Groups::vote_joint(
+            mock::Origin::signed(member_2),
+            other_group_id,
+            proposal_id,
+            false
+        ));
+        assert_ok!(mock::Groups::close_joint(
+            mock::Origin::signed(caller),
+            proposal_id,
+            1_000_000_000,
+            100
+        ));
+
+        assert!(!super::JointProposals::<Test>::contains_key(proposal_id));
+        assert!(!super::JointProposalsByGroup::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+    });
+}
+
+#[test]
+fn proposal_deposit_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+        let deposit = 100u128;
+
+        assert_ok!(mock::Settings::set_extrinsic_deposit(
+            mock::Origin::root(),
+            1,
+            3,
+            deposit
+        ));
+
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1), (member_2, 1)],
+            1u32,
+            3_000_000u128
+        ));
+        let group_id = 1u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+
+        // proposals executed immediately do not need a deposit
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::create_sub_group(
+                "Test".to_string().into(),
+                vec![(caller, 1), (member_2, 1)],
+                2,
+                1_000_000u128
+            ))),
+            1,
+            100
+        ));
+        let sub_group_id = 2u32;
+        assert_eq!(mock::Balances::reserved_balance(&caller), 0);
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::update_group(
+                Some(b"Updated".to_vec()),
+                None,
+                None,
+                None,
+            ))),
+            2,
+            100
+        ));
+        let proposal_id = 2u32;
+        assert_eq!(mock::Balances::reserved_balance(&caller), deposit);
+        assert_eq!(
+            super::ProposalDeposits::<Test>::get(group_id, proposal_id),
+            Some((caller, deposit))
+        );
+
+        // deposit is returned when the proposal is closed
+        assert_ok!(mock::Groups::vote(
+            mock::Origin::signed(member_2),
+            group_id,
+            proposal_id,
+            true
+        ));
+        assert_ok!(mock::Groups::close(
+            mock::Origin::signed(caller),
+            group_id,
+            proposal_id,
+            1_000_000_000,
+            100
+        ));
+        assert_eq!(mock::Balances::reserved_balance(&caller), 0);
+        assert!(!super::ProposalDeposits::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+
+        // deposit is paid to the group account when the parent vetoes the proposal
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(caller),
+            sub_group_id
+        ));
+        let sub_group_account = super::Groups::<Test>::get(sub_group_id)
+            .unwrap()
+            .anonymous_account;
+        let sub_group_balance = mock::Balances::free_balance(&sub_group_account);
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            sub_group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::update_group(
+                Some(b"Vetoed".to_vec()),
+                None,
+                None,
+                None,
+            ))),
+            2,
+            100
+        ));
+        let proposal_id = 3u32;
+        assert_eq!(mock::Balances::reserved_balance(&caller), deposit);
+        assert_ok!(mock::Groups::veto(
+            mock::Origin::signed(caller),
+            sub_group_id,
+            proposal_id,
+            false,
+            100_000_000,
+            100
+        ));
+        assert_eq!(mock::Balances::reserved_balance(&caller), 0);
+        assert_eq!(
+            mock::Balances::free_balance(&sub_group_account),
+            sub_group_balance + deposit
+        );
+    });
+}
+
+#[test]
+fn expire_proposal_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+        let deposit = 100u128;
+
+        assert_ok!(mock::Settings::set_extrinsic_deposit(
+            mock::Origin::root(),
+            1,
+            3,
+            deposit
+        ));
+
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1), (member_2, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            group_id
+        ));
+        let group_account = super::Groups::<Test>::get(group_id)
+            .unwrap()
+            .anonymous_account;
+        let group_balance = mock::Balances::free_balance(&group_account);
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::update_group(
+                Some(b"Ignored".to_vec()),
+                None,
+                None,
+                None,
+            ))),
+            2,
+            100
+        ));
+        let ignored_proposal_id = 1u32;
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::update_group(
+                Some(b"Voted".to_vec()),
+                None,
+                None,
+                None,
+            ))),
+            2,
+            100
+        ));
+        let voted_proposal_id = 2u32;
+        assert_ok!(mock::Groups::vote(
+            mock::Origin::signed(member_2),
+            group_id,
+            voted_proposal_id,
+            false
+        ));
+        assert_eq!(mock::Balances::reserved_balance(&caller), 2 * deposit);
+
+        assert_noop!(
+            mock::Groups::expire_proposal(
+                mock::Origin::signed(member_2),
+                group_id,
+                ignored_proposal_id
+            ),
+            Error::<Test>::ProposalNotExpired
+        );
+
+        mock::System::set_block_number(1 + GroupProposalLifetime::get());
+
+        // nobody else voted so the deposit is paid to the group
+        assert_ok!(mock::Groups::expire_proposal(
+            mock::Origin::signed(member_2),
+            group_id,
+            ignored_proposal_id
+        ));
+        assert!(!super::Proposals::<Test>::contains_key(
+            group_id,
+            ignored_proposal_id
+        ));
+        assert_eq!(mock::Balances::reserved_balance(&caller), deposit);
+        assert_eq!(
+            mock::Balances::free_balance(&group_account),
+            group_balance + deposit
+        );
+
+        // a member voted so the deposit is returned
+        assert_ok!(mock::Groups::expire_proposal(
+            mock::Origin::signed(member_2),
+            group_id,
+            voted_proposal_id
+        ));
+        assert!(!super::Proposals::<Test>::contains_key(
+            group_id,
+            voted_proposal_id
+        ));
+        assert_eq!(mock::Balances::reserved_balance(&caller), 0);
+        assert_eq!(
+            mock::Balances::free_balance(&group_account),
+            group_balance + deposit
+        );
+    });
+}
+
+#[test]
+fn expire_joint_proposal_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+        let member_2 = 2u64;
+        let deposit = 100u128;
+
+        assert_ok!(mock::Settings::set_extrinsic_deposit(
+            mock::Origin::root(),
+            1,
+            3,
+            deposit
+        ));
+
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Other".to_vec(),
+            vec![(member_2, 1)],
+            1u32,
+            1_000_000u128
+        ));
+        let group_id = 1u32;
+        let other_group_id = 2u32;
+        assert_ok!(mock::Groups::accept_invitation(
+            mock::Origin::signed(member_2),
+            other_group_id
+        ));
+        let group_account = super::Groups::<Test>::get(group_id)
+            .unwrap()
+            .anonymous_account;
+        let group_balance = mock::Balances::free_balance(&group_account);
+
+        assert_ok!(mock::Groups::propose_joint(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::update_group(
+                Some(b"Joint".to_vec()),
+                None,
+                None,
+                None,
+            ))),
+            vec![(group_id, 1), (other_group_id, 1)],
+            100
+        ));
+        let proposal_id = 1u32;
+        assert_eq!(mock::Balances::reserved_balance(&caller), deposit);
+
+        assert_noop!(
+            mock::Groups::expire_joint_proposal(mock::Origin::signed(member_2), proposal_id),
+            Error::<Test>::ProposalNotExpired
+        );
+
+        mock::System::set_block_number(1 + GroupProposalLifetime::get());
+
+        // nobody else voted so the deposit is paid to the group of the proposer
+        assert_ok!(mock::Groups::expire_joint_proposal(
+            mock::Origin::signed(member_2),
+            proposal_id
+        ));
+        assert!(!super::JointProposals::<Test>::contains_key(proposal_id));
+        assert!(super::JointVoting::<Test>::iter_prefix(proposal_id)
+            .next()
+            .is_none());
+        assert!(!super::JointProposalsByGroup::<Test>::contains_key(
+            other_group_id,
+            proposal_id
+        ));
+        assert!(!super::ProposalExpiries::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+        assert_eq!(mock::Balances::reserved_balance(&caller), 0);
+        assert_eq!(
+            mock::Balances::free_balance(&group_account),
+            group_balance + deposit
+        );
+        assert_noop!(
+            mock::Groups::expire_joint_proposal(mock::Origin::signed(member_2), proposal_id),
+            Error::<Test>::ProposalMissing
+        );
+    });
+}
+
+#[test]
+fn cancel_scheduled_should_work() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            2_000_000u128
+        ));
+        let group_id = 1u32;
+        let group = super::Groups::<Test>::get(group_id).unwrap();
+
+        assert_ok!(mock::Groups::propose_with_timelock(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(
+                super::Call::withdraw_funds_group(caller, 1_000_000u128)
+            )),
+            1,
+            Timelock::Delay(10),
+            100
+        ));
+        let proposal_id = 1u32;
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(11), 1);
+
+        // only the group can cancel it
+        assert_noop!(
+            mock::Groups::cancel_scheduled(mock::Origin::signed(caller), proposal_id),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(mock::Groups::propose(
+            mock::Origin::signed(caller),
+            group_id,
+            Box::new(crate::mock::Call::Groups(super::Call::cancel_scheduled(
+                proposal_id
+            ))),
+            1,
+            100
+        ));
+
+        assert!(!super::ScheduledProposals::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+        assert!(!super::ExecutionQueue::<Test>::contains_key(
+            11,
+            (group_id, proposal_id)
+        ));
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(11), 0);
+        assert!(!super::Proposals::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+        assert!(!super::ProposalTimelocks::<Test>::contains_key(
+            group_id,
+            proposal_id
+        ));
+
+        // the funds are not withdrawn at the scheduled block
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(11);
+        assert_eq!(
+            crate::mock::Balances::free_balance(&group.anonymous_account),
+            2_000_000u128 + MINIMUM_BALANCE
+        );
+    });
+}
+
+#[test]
+fn execution_queue_should_be_limited_per_block() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            2_000_000u128
+        ));
+        let group_id = 1u32;
+
+        let max_scheduled = <Test as Config>::MaxScheduledPerBlock::get();
+        for amount in 1..=max_scheduled + 1 {
+            assert_ok!(mock::Groups::propose_with_timelock(
+                mock::Origin::signed(caller),
+                group_id,
+                Box::new(crate::mock::Call::Groups(
+                    super::Call::withdraw_funds_group(caller, amount.into())
+                )),
+                1,
+                Timelock::AtBlock(20),
+                100
+            ));
+        }
+
+        // the last proposal waits for the next block
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(20), max_scheduled);
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(21), 1);
+        let last_proposal_id = max_scheduled + 1;
+        assert_eq!(
+            super::ScheduledProposals::<Test>::get(group_id, last_proposal_id)
+                .unwrap()
+                .when,
+            21
+        );
+
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(20);
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(20), 0);
+        assert!(super::ScheduledProposals::<Test>::contains_key(
+            group_id,
+            last_proposal_id
+        ));
+
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(21);
+        assert!(!super::ScheduledProposals::<Test>::contains_key(
+            group_id,
+            last_proposal_id
+        ));
+
+        // a proposal is not deferred further than `MaxScheduleDeferral` blocks
+        let max_deferral = <Test as Config>::MaxScheduleDeferral::get() as u64;
+        for block in 30..30 + max_deferral {
+            super::ExecutionQueueLength::<Test>::insert(block, max_scheduled);
+        }
+        assert_noop!(
+            mock::Groups::propose_with_timelock(
+                mock::Origin::signed(caller),
+                group_id,
+                Box::new(crate::mock::Call::Groups(
+                    super::Call::withdraw_funds_group(caller, 1u128)
+                )),
+                1,
+                Timelock::AtBlock(30),
+                100
+            ),
+            Error::<Test>::ExecutionQueueFull
+        );
+    });
+}
+
+#[test]
+fn execution_queue_should_be_limited_by_weight() {
+    new_test_ext().execute_with(|| {
+        let caller = 1u64;
+
+        // caller creates a Group
+        assert_ok!(crate::mock::Groups::create_group(
+            mock::Origin::signed(caller),
+            b"Test".to_vec(),
+            vec![(caller, 1)],
+            1u32,
+            2_000_000u128
+        ));
+        let group_id = 1u32;
+
+        for amount in 1..=2u128 {
+            assert_ok!(mock::Groups::propose_with_timelock(
+                mock::Origin::signed(caller),
+                group_id,
+                Box::new(crate::mock::Call::Groups(
+                    super::Call::withdraw_funds_group(caller, amount)
+                )),
+                1,
+                Timelock::AtBlock(20),
+                100
+            ));
+        }
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(20), 2);
+
+        // each proposal takes all of the weight available to a block
+        let max_weight = <Test as Config>::MaximumScheduledWeight::get();
+        for proposal_id in 1..=2u32 {
+            super::ExecutionQueue::<Test>::insert(20, (group_id, proposal_id), max_weight);
+        }
+
+        // one proposal is dispatched and the other is carried over to the next block
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(20);
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(20), 0);
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(21), 1);
+        let scheduled = crate::mock::Groups::get_scheduled_proposals(group_id);
+        assert_eq!(scheduled.len(), 1);
+        let (proposal_id, execution) = scheduled[0].clone();
+        assert_eq!(execution.when, 21);
+        assert_eq!(
+            super::ExecutionQueue::<Test>::get(21, (group_id, proposal_id)),
+            Some(max_weight)
+        );
+
+        <crate::mock::Groups as frame_support::traits::OnInitialize<u64>>::on_initialize(21);
+        assert!(crate::mock::Groups::get_scheduled_proposals(group_id).is_empty());
+        assert_eq!(super::ExecutionQueueLength::<Test>::get(21), 0);
+    });
+}
+
+#[test]
+fn weights_should_not_be_excessive() {
+    new_test_ext().execute_with(|| {
+        const MAXIMUM_ALLOWED_WEIGHT: Weight = 130_000_000_000;
+
+        let weight = <Test as Config>::WeightInfo::create_group(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::MaxMembers::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::update_group(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::MaxMembers::get(),
+            <Test as Config>::MaxMembers::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_sub_group(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::MaxMembers::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::update_sub_group(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::MaxMembers::get(),
+            <Test as Config>::MaxMembers::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::remove_group(
+            <Test as Config>::MaxMembers::get(),
+            <Test as Config>::MaxProposals::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::remove_sub_group(
+            <Test as Config>::MaxMembers::get(),
+            <Test as Config>::MaxProposals::get(),
         );
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
         let weight =
@@ -1114,5 +2419,51 @@ fn weights_should_not_be_excessive() {
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
         let weight = <Test as Config>::WeightInfo::send_funds_to_sub_group();
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::propose_with_timelock(
+            <Test as Config>::MaxProposalLength::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight =
+            <Test as Config>::WeightInfo::close_scheduled(<Test as Config>::MaxMembers::get());
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::veto_scheduled();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight =
+            <Test as Config>::WeightInfo::set_budget(<Test as Config>::MaxBudgetTiers::get());
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::accept_invitation();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::decline_invitation();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::leave_group();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::propose_joint(
+            <Test as Config>::MaxProposalLength::get(),
+            <Test as Config>::MaxJointGroups::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::vote_joint(<Test as Config>::MaxMembers::get());
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::close_joint_approved(
+            <Test as Config>::MaxProposalLength::get(),
+            <Test as Config>::MaxJointGroups::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::close_joint_disapproved(
+            <Test as Config>::MaxJointGroups::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::expire_proposal();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::cancel_scheduled();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::expire_joint_proposal(
+            <Test as Config>::MaxJointGroups::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::execute_scheduled(
+            <Test as Config>::MaxProposalLength::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
     });
 }
@@ -67,6 +67,21 @@ pub trait WeightInfo {
     fn withdraw_funds_group() -> Weight;
     fn withdraw_funds_sub_group() -> Weight;
     fn send_funds_to_sub_group() -> Weight;
+    fn propose_with_timelock(a: u32) -> Weight;
+    fn close_scheduled(m: u32) -> Weight;
+    fn veto_scheduled() -> Weight;
+    fn execute_scheduled(a: u32) -> Weight;
+    fn set_budget(t: u32) -> Weight;
+    fn accept_invitation() -> Weight;
+    fn decline_invitation() -> Weight;
+    fn leave_group() -> Weight;
+    fn propose_joint(a: u32, g: u32) -> Weight;
+    fn vote_joint(m: u32) -> Weight;
+    fn close_joint_approved(a: u32, g: u32) -> Weight;
+    fn close_joint_disapproved(g: u32) -> Weight;
+    fn expire_proposal() -> Weight;
+    fn cancel_scheduled() -> Weight;
+    fn expire_joint_proposal(g: u32) -> Weight;
 }
 
 /// Weights for pallet_groups using the Substrate node and recommended hardware.
@@ -215,6 +230,100 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
+    fn propose_with_timelock(a: u32) -> Weight {
+        (194_000_000 as Weight)
+            .saturating_add((11_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn close_scheduled(m: u32) -> Weight {
+        (162_000_000 as Weight)
+            .saturating_add((400_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn veto_scheduled() -> Weight {
+        (186_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn execute_scheduled(a: u32) -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add((8_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn set_budget(t: u32) -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add((120_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_invitation() -> Weight {
+        (61_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn decline_invitation() -> Weight {
+        (43_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn leave_group() -> Weight {
+        (58_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn propose_joint(a: u32, g: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add((9_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((6_400_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
+    }
+    fn vote_joint(m: u32) -> Weight {
+        (151_000_000 as Weight)
+            .saturating_add((614_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn close_joint_approved(a: u32, g: u32) -> Weight {
+        (112_000_000 as Weight)
+            .saturating_add((15_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((7_100_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
+    }
+    fn close_joint_disapproved(g: u32) -> Weight {
+        (84_000_000 as Weight)
+            .saturating_add((6_800_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
+    }
+    fn expire_proposal() -> Weight {
+        (64_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+    fn cancel_scheduled() -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn expire_joint_proposal(g: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(g as Weight)))
+    }
 }
 
 // For backwards compatibility and tests
@@ -362,4 +471,98 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes(2 as Weight))
     }
+    fn propose_with_timelock(a: u32) -> Weight {
+        (194_000_000 as Weight)
+            .saturating_add((11_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+    }
+    fn close_scheduled(m: u32) -> Weight {
+        (162_000_000 as Weight)
+            .saturating_add((400_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn veto_scheduled() -> Weight {
+        (186_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn execute_scheduled(a: u32) -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add((8_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn set_budget(t: u32) -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add((120_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn accept_invitation() -> Weight {
+        (61_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+    }
+    fn decline_invitation() -> Weight {
+        (43_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn leave_group() -> Weight {
+        (58_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+    }
+    fn propose_joint(a: u32, g: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add((9_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((6_400_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
+    }
+    fn vote_joint(m: u32) -> Weight {
+        (151_000_000 as Weight)
+            .saturating_add((614_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn close_joint_approved(a: u32, g: u32) -> Weight {
+        (112_000_000 as Weight)
+            .saturating_add((15_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((7_100_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
+    }
+    fn close_joint_disapproved(g: u32) -> Weight {
+        (84_000_000 as Weight)
+            .saturating_add((6_800_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
+    }
+    fn expire_proposal() -> Weight {
+        (64_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(7 as Weight))
+    }
+    fn cancel_scheduled() -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+    }
+    fn expire_joint_proposal(g: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(g as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(g as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(g as Weight)))
+    }
 }
@@ -84,11 +84,15 @@ pub mod pallet {
     pub use super::weights::WeightInfo;
     use codec::Encode;
     use core::convert::TryInto;
+    use did_info::DidInfo;
     use extrinsic_extra::GetExtrinsicExtra;
     use frame_support::{
-        dispatch::DispatchResultWithPostInfo, pallet_prelude::*, traits::Randomness,
+        dispatch::{DispatchResult, DispatchResultWithPostInfo},
+        pallet_prelude::*,
+        traits::Randomness,
     };
     use frame_system::pallet_prelude::*;
+    use issue_claim::IssueClaim;
     use primitives::{bounded_vec::BoundedVec, *};
     use sp_runtime::{
         traits::{AtLeast32Bit, CheckedAdd, Hash, One, Saturating, UniqueSaturatedFrom},
@@ -1291,6 +1295,101 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> DidInfo for Module<T> {
+        type Did = Did;
+
+        fn did_exists(did: &Self::Did) -> bool {
+            <DidDocuments<T>>::contains_key(did)
+        }
+    }
+
+    impl<T: Config> IssueClaim for Module<T> {
+        type AccountId = T::AccountId;
+        type Did = Did;
+        type ClaimId = T::ClaimId;
+        type Moment = T::Moment;
+        type Statement = Statement<Vec<u8>, Vec<u8>>;
+
+        fn issue_claim(
+            issuer: &T::AccountId,
+            did: &Did,
+            description: Vec<u8>,
+            statements: Vec<Self::Statement>,
+            valid_until: T::Moment,
+        ) -> Result<T::ClaimId, DispatchError> {
+            ensure!(
+                <DidDocuments<T>>::contains_key(did),
+                Error::<T>::DidDocumentNotFound
+            );
+            ensure!(
+                Self::is_valid_issuer(did, issuer),
+                Error::<T>::NotAuthorized
+            );
+            ensure!(
+                statements.len() < T::StatementLimit::get() as usize,
+                Error::<T>::StatementLimitExceeded
+            );
+
+            let statements = statements
+                .into_iter()
+                .map(|statement| {
+                    Ok(Statement {
+                        name: enforce_limit!(statement.name),
+                        fact: enforce_limit_fact!(statement.fact),
+                        for_issuer: statement.for_issuer,
+                    })
+                })
+                .collect::<Result<Vec<_>, Error<T>>>()?;
+
+            let claim = Claim {
+                description: enforce_limit!(description),
+                statements,
+                created_by: issuer.clone(),
+                attestation: Some(Attestation {
+                    attested_by: issuer.clone(),
+                    issued: <timestamp::Module<T>>::get(),
+                    valid_until,
+                }),
+                threshold: One::one(),
+            };
+
+            let claim_id = next_id!(NextClaimId<T>, T);
+
+            T::GetExtrinsicExtraSource::charge_extrinsic_extra(
+                &MODULE_INDEX,
+                &(ExtrinsicIndex::Claim as u8),
+                issuer,
+            );
+
+            <Claims<T>>::insert(did, claim_id, claim);
+
+            Self::deposit_event(Event::ClaimMade(
+                issuer.clone(),
+                issuer.clone(),
+                *did,
+                claim_id,
+            ));
+            Self::deposit_event(Event::ClaimAttested(issuer.clone(), *did, claim_id));
+            Ok(claim_id)
+        }
+
+        fn revoke_claim(issuer: &T::AccountId, did: &Did, claim_id: T::ClaimId) -> DispatchResult {
+            <Claims<T>>::try_mutate_exists(did, claim_id, |maybe_claim| -> DispatchResult {
+                let claim = maybe_claim.as_mut().ok_or(Error::<T>::NotFound)?;
+                ensure!(claim.created_by == *issuer, Error::<T>::NotAuthorized);
+                claim.attestation = None;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ClaimAttestationRevoked(
+                issuer.clone(),
+                *did,
+                claim_id,
+            ));
+            Ok(())
+        }
+    }
+
     impl<T: Config> Module<T> {
         // -- rpc api functions --
 
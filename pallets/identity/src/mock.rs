@@ -6,8 +6,9 @@ use frame_system::{self as system, EnsureOneOf, EnsureSigned};
 use runtime::{
     primitives::{FactStringLimit, NameLimit},
     BulkDidLimit, BulkDidPropertyLimit, CatalogDidLimit, ClaimConsumerLimit, ClaimIssuerLimit,
-    ControllerLimit, GroupChainLimit, GroupMaxMembers, GroupMaxProposalLength, GroupMaxProposals,
-    PropertyLimit, StatementLimit,
+    ControllerLimit, GroupChainLimit, GroupMaxBudgetTiers, GroupMaxJointGroups, GroupMaxMembers,
+    GroupMaxProposalLength, GroupMaxProposals, GroupMaxScheduleDeferral, GroupMaxScheduledPerBlock,
+    GroupMaximumScheduledWeight, PropertyLimit, StatementLimit,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -37,6 +38,7 @@ frame_support::construct_runtime!(
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub const GroupProposalLifetime: u64 = 100;
 }
 
 type AccountId = u64;
@@ -144,6 +146,13 @@ impl groups::Config for Test {
     type WeightInfo = ();
     type NameLimit = NameLimit;
     type GroupChainLimit = GroupChainLimit;
+    type MaxBudgetTiers = GroupMaxBudgetTiers;
+    type GroupsOriginJointApproved = groups::EnsureJointApproved<Test>;
+    type MaxJointGroups = GroupMaxJointGroups;
+    type ProposalLifetime = GroupProposalLifetime;
+    type MaxScheduledPerBlock = GroupMaxScheduledPerBlock;
+    type MaximumScheduledWeight = GroupMaximumScheduledWeight;
+    type MaxScheduleDeferral = GroupMaxScheduleDeferral;
 }
 
 // Build genesis storage according to the mock runtime.
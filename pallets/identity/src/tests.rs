@@ -3,7 +3,8 @@ use super::*;
 use crate::mock::*;
 use chrono::Utc;
 use core::convert::TryInto;
-use frame_support::{assert_ok, dispatch::Weight};
+use frame_support::{assert_noop, assert_ok, dispatch::Weight};
+use issue_claim::IssueClaim;
 use primitives::*;
 
 #[test]
@@ -555,6 +556,69 @@ fn attest_claim_without_proposal_should_work() {
     });
 }
 
+#[test]
+fn issue_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        //required for randomness_collective_flip module
+        System::set_block_number(1);
+
+        assert_ok!(Identity::register_did(Origin::signed(1), None));
+
+        let mut dids_by_controller = Vec::new();
+        DidByController::<Test>::iter_prefix(&1).for_each(|(did, _)| {
+            dids_by_controller.push(did);
+        });
+        let did = dids_by_controller[0];
+
+        //the issuer must be authorized by the DID controller
+        let now = Utc::now().timestamp() as u64;
+        assert_noop!(
+            <Identity as IssueClaim>::issue_claim(
+                &3,
+                &did,
+                b"Audit Certificate".to_vec(),
+                vec![],
+                now + 8640000,
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Identity::authorize_claim_issuers(
+            Origin::signed(1),
+            did,
+            vec![ClaimIssuer {
+                issuer: 3u64,
+                expiration: now + 8640000
+            }]
+        ));
+        let claim_id = <Identity as IssueClaim>::issue_claim(
+            &3,
+            &did,
+            b"Audit Certificate".to_vec(),
+            vec![Statement {
+                name: b"grade".to_vec(),
+                fact: Fact::Text(b"A".to_vec()),
+                for_issuer: true,
+            }],
+            now + 8640000,
+        );
+        assert_ok!(claim_id);
+        let claim_id = claim_id.unwrap();
+
+        let claim = Claims::<Test>::get(&did, claim_id).unwrap();
+        assert_eq!(claim.created_by, 3);
+        assert!(claim.attestation.is_some());
+        assert_eq!(claim.attestation.unwrap().attested_by, 3);
+
+        //only the issuer can revoke
+        assert!(<Identity as IssueClaim>::revoke_claim(&2, &did, claim_id).is_err());
+        assert_ok!(<Identity as IssueClaim>::revoke_claim(&3, &did, claim_id));
+        assert!(Claims::<Test>::get(&did, claim_id)
+            .unwrap()
+            .attestation
+            .is_none());
+    });
+}
+
 //Make sure weights cannot exceed 10% of total allowance for block.
 
 #[test]
@@ -0,0 +1,94 @@
+use crate::Fact;
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+
+/// Describes an attribute that an attestor is expected to supply for a definition step.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AttributeSchema<BoundedString> {
+    pub name: BoundedString,
+    pub fact_type: FactType,
+    /// Attestation of the step is rejected if a required attribute is missing.
+    pub required: bool,
+    /// Unit the value is expressed in, e.g. "%" or "kg". For display only.
+    pub unit: Option<BoundedString>,
+    /// Inclusive lower bound for numeric facts
+    pub min: Option<AttributeBound>,
+    /// Inclusive upper bound for numeric facts
+    pub max: Option<AttributeBound>,
+}
+
+/// A bound of the range of a numeric attribute, of the same kind as the facts it constrains
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum AttributeBound {
+    /// A bound for `Fact::Float`, a float stored as le bytes
+    Float([u8; 8]),
+    /// A bound for the integer facts, compared exactly
+    Integer(u128),
+}
+
+impl<BoundedString> AttributeSchema<BoundedString> {
+    /// Check that a fact has the declared type and lies within the declared range.
+    pub fn accepts<BoundedStringFact>(&self, fact: &Fact<BoundedStringFact>) -> bool {
+        if fact.fact_type() != self.fact_type {
+            return false;
+        }
+        let above_min = self.min.map_or(true, |min| match (fact, min) {
+            (Fact::Float(value), AttributeBound::Float(min)) => {
+                f64::from_le_bytes(*value) >= f64::from_le_bytes(min)
+            }
+            (fact, AttributeBound::Integer(min)) => {
+                fact.integer_value().map_or(false, |value| value >= min)
+            }
+            _ => false,
+        });
+        let below_max = self.max.map_or(true, |max| match (fact, max) {
+            (Fact::Float(value), AttributeBound::Float(max)) => {
+                f64::from_le_bytes(*value) <= f64::from_le_bytes(max)
+            }
+            (fact, AttributeBound::Integer(max)) => {
+                fact.integer_value().map_or(false, |value| value <= max)
+            }
+            _ => false,
+        });
+        above_min && below_max
+    }
+}
+
+/// The variant of `Fact` an attribute is expected to hold
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum FactType {
+    Bool,
+    Text,
+    Attachment,
+    Location,
+    Did,
+    Float,
+    U8,
+    U16,
+    U32,
+    U128,
+    Date,
+    Iso8601,
+}
+
+impl FactType {
+    /// Whether facts of this type can be constrained to a range
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            FactType::Float | FactType::U8 | FactType::U16 | FactType::U32 | FactType::U128
+        )
+    }
+
+    /// Whether a bound is of the kind and within the values of facts of this type
+    pub fn accepts_bound(&self, bound: &AttributeBound) -> bool {
+        match (self, bound) {
+            (FactType::Float, AttributeBound::Float(bytes)) => !f64::from_le_bytes(*bytes).is_nan(),
+            (FactType::U8, AttributeBound::Integer(value)) => *value <= u8::MAX as u128,
+            (FactType::U16, AttributeBound::Integer(value)) => *value <= u16::MAX as u128,
+            (FactType::U32, AttributeBound::Integer(value)) => *value <= u32::MAX as u128,
+            (FactType::U128, AttributeBound::Integer(_)) => true,
+            _ => false,
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use super::Did;
 use codec::{Decode, Encode};
+use frame_support::dispatch::Vec;
 use sp_runtime::RuntimeDebug;
 
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
@@ -15,6 +17,20 @@ pub struct Audit<AccountId, ProposalId> {
     pub auditors: Option<AccountId>,
 }
 
+/// What is being audited.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum AuditSubject<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId, BoundedString>
+{
+    /// an identity e.g. a farm or a company
+    Did(Did),
+    /// an asset in the asset registry (registry_id, asset_id)
+    Asset(AssetRegistryId, AssetId),
+    /// a provenance process (registry_id, definition_id, process_id)
+    Process(RegistryId, DefinitionId, ProcessId),
+    /// a free-text reference to a site that is not on chain
+    Site(BoundedString),
+}
+
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
 pub enum AuditStatus {
     Requested,
@@ -22,4 +38,140 @@ pub enum AuditStatus {
     Rejected,
     InProgress,
     Completed,
+    /// completed while corrective actions for non-compliant observations were still open.
+    CompletedWithOpenFindings,
+}
+
+/// A certificate issued by the auditing org against a DID in scope of a completed audit.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct Certificate<ClaimId, Moment, AuditId, BoundedString> {
+    /// the attested claim in the identity pallet that carries the certificate.
+    pub claim_id: ClaimId,
+    pub grade: BoundedString,
+    pub valid_until: Moment,
+    pub status: CertificateStatus<AuditId>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum CertificateStatus<AuditId> {
+    Valid,
+    /// revoked by the issuer, or because of the later audit that failed.
+    Revoked(Option<AuditId>),
+}
+
+/// A fee held in escrow for the auditing org.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AuditFee<Balance> {
+    /// reserved from the audit creator when the audit is accepted.
+    pub amount: Balance,
+    /// partial releases (observation count, amount). The rest is released when the audit is completed.
+    pub milestones: Vec<(u32, Balance)>,
+    /// the part of the amount already released to the auditing org.
+    pub released: Balance,
+    /// observations made so far, for the milestones.
+    pub observations: u32,
+    pub status: AuditFeeStatus,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum AuditFeeStatus {
+    /// waiting for the auditing org to accept the audit.
+    Pending,
+    Reserved,
+    /// released in full to the auditing org.
+    Released,
+    /// returned to the audit creator.
+    Refunded,
+    /// frozen until the dispute is resolved.
+    Disputed,
+    /// the dispute was resolved, splitting the remainder between the parties.
+    Resolved,
+}
+
+/// A recurring audit. Each audit is requested `lead_time` blocks before it is due.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AuditSchedule<AccountId, ProposalId, FrameworkId, BlockNumber, AuditId, Subject> {
+    /// The proposal that created the schedule.
+    pub proposal_id: ProposalId,
+    /// the creator of the audits requested by the schedule.
+    pub audit_creator: AccountId,
+    pub auditing_org: AccountId,
+    /// audits are carried out against the latest version of the framework at the time they are requested.
+    pub framework: Option<FrameworkId>,
+    pub subjects: Vec<Subject>,
+    /// blocks between the due dates of consecutive audits.
+    pub cadence: BlockNumber,
+    /// blocks before the due date that an audit is requested. Greater than zero and no longer than the cadence.
+    pub lead_time: BlockNumber,
+    pub next_due: BlockNumber,
+    /// the audit most recently requested by the schedule (audit_id, due).
+    pub last_audit: Option<(AuditId, BlockNumber)>,
+    /// audits that were not completed by their due date.
+    pub missed: u32,
+    pub status: AuditScheduleStatus,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum AuditScheduleStatus {
+    Active,
+    /// no audits are requested. Audits falling due while paused are skipped, not requested late.
+    Paused,
+    Cancelled,
+}
+
+/// A change to an audit, kept in its audit trail.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AuditTrailEntry<
+    AccountId,
+    ProposalId,
+    BlockNumber,
+    ControlPointId,
+    ObservationId,
+    BoundedString,
+> {
+    /// The proposal that made the change. None for audits requested by a schedule or created by a joint proposal.
+    pub proposal_id: Option<ProposalId>,
+    /// the party that made the change.
+    pub account: AccountId,
+    pub block_number: BlockNumber,
+    pub change: AuditChange<ControlPointId, ObservationId, BoundedString>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum AuditChange<ControlPointId, ObservationId, BoundedString> {
+    /// the audit moved to the status.
+    Status(AuditStatus),
+    /// one party asked to reopen the completed audit (reason). The other party must approve.
+    ReopenRequested(BoundedString),
+    /// the other party approved and the audit is in progress again (reason).
+    Reopened(BoundedString),
+    /// an observation was replaced by a new one, which is kept for history (control_point_id, superseded, superseded_by, reason).
+    ObservationSuperseded(ControlPointId, ObservationId, ObservationId, BoundedString),
+    /// the party that asked to reopen the audit withdrew the request.
+    ReopenRequestWithdrawn,
+}
+
+/// A note encrypted client-side with a symmetric key, identified by the blake2-256 hash of its ciphertext.
+/// The ciphertext is kept off chain. The key is shared with each recipient in a `KeyEnvelope`.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ConfidentialNote<AccountId, ProposalId, ControlPointId, ObservationId> {
+    /// The proposal that created the note.
+    pub proposal_id: ProposalId,
+    /// the party that created the note. Only they may grant or revoke access.
+    pub author: AccountId,
+    /// the observation the note is about, if any.
+    pub observation: Option<(ControlPointId, ObservationId)>,
+}
+
+/// The symmetric key of a confidential note encrypted to the X25519 key of one recipient.
+/// The key is encrypted with ChaCha20-Poly1305 under the blake2-256 hash of the Diffie-Hellman shared secret of an ephemeral key and the recipient key,
+/// followed by the ephemeral and recipient public keys.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct KeyEnvelope {
+    /// the X25519 public key of the recipient the envelope was sealed to.
+    pub recipient_key: [u8; 32],
+    /// the X25519 public key of the ephemeral key of the sender.
+    pub ephemeral_key: [u8; 32],
+    /// the symmetric key and its authentication tag.
+    pub encrypted_key: [u8; 48],
 }
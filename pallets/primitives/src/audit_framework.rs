@@ -0,0 +1,53 @@
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AuditFramework<AccountId, BoundedString> {
+    /// the standards body (account or group) that publishes versions of the framework.
+    pub publisher: AccountId,
+    /// the name of the framework, usually the standard it catalogs.
+    pub name: BoundedString,
+    /// the latest published version. Versions are numbered from 1 and are never changed once published.
+    pub latest_version: u32,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ControlPoint<ControlPointId, BoundedString> {
+    /// the reference of the control point within the standard e.g. `4.2.1`
+    pub code: BoundedString,
+    pub title: BoundedString,
+    /// hash of the full description, which is kept off chain.
+    pub description_hash: [u8; 32],
+    pub severity: ControlPointSeverity,
+    /// the section this control point belongs to. It must precede the control point in the same framework version.
+    pub parent: Option<ControlPointId>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum ControlPointSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl ControlPointSeverity {
+    /// relative weight of the control point when scoring an audit.
+    pub fn weight(&self) -> u32 {
+        match self {
+            ControlPointSeverity::Low => 1,
+            ControlPointSeverity::Medium => 2,
+            ControlPointSeverity::High => 3,
+            ControlPointSeverity::Critical => 4,
+        }
+    }
+}
+
+/// Observations made against one control point of an audit.
+#[derive(Encode, Decode, Default, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ControlPointCoverage {
+    pub observations: u32,
+    pub compliant: u32,
+    pub non_compliant: u32,
+    pub not_applicable: u32,
+}
@@ -0,0 +1,62 @@
+use crate::{Audit, ControlPointCoverage, ControlPointSeverity, Evidence, Observation};
+use codec::{Decode, Encode};
+use frame_support::dispatch::Vec;
+use sp_runtime::{Permill, RuntimeDebug};
+
+/// A summary of an audit, computed on request.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AuditReport<
+    AccountId,
+    ProposalId,
+    AuditId,
+    ControlPointId,
+    ObservationId,
+    EvidenceId,
+    FrameworkId,
+    BoundedString,
+    BoundedStringUrl,
+> {
+    pub audit: Audit<AccountId, ProposalId>,
+    /// the framework version the audit references, if any.
+    pub framework: Option<(FrameworkId, u32)>,
+    /// sorted by control point id.
+    pub control_points: Vec<
+        ControlPointReport<
+            ControlPointId,
+            ObservationId,
+            ProposalId,
+            EvidenceId,
+            BoundedString,
+            BoundedStringUrl,
+        >,
+    >,
+    /// observation counts over all control points.
+    pub totals: ControlPointCoverage,
+    /// compliant observations as a share of all compliant and non-compliant observations, each weighted by the severity of its control point.
+    /// `None` when there is nothing to score.
+    pub score: Option<Permill>,
+    pub linked_audits: Vec<AuditId>,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ControlPointReport<
+    ControlPointId,
+    ObservationId,
+    ProposalId,
+    EvidenceId,
+    BoundedString,
+    BoundedStringUrl,
+> {
+    pub control_point_id: ControlPointId,
+    /// only known for audits that reference a framework.
+    pub severity: Option<ControlPointSeverity>,
+    pub coverage: ControlPointCoverage,
+    pub observations: Vec<(
+        ObservationId,
+        Observation<ProposalId>,
+        Vec<(
+            EvidenceId,
+            Evidence<ProposalId, BoundedString, BoundedStringUrl>,
+        )>,
+    )>,
+}
@@ -1,4 +1,5 @@
 use codec::{Decode, Encode};
+use frame_support::dispatch::Vec;
 use sp_runtime::RuntimeDebug;
 
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
@@ -11,4 +12,15 @@ pub struct Definition<BoundedString> {
 pub enum DefinitionStatus {
     Active,
     Inactive,
+    /// A new version of a definition that can still be edited. Becomes the current version once set active.
+    Draft,
+}
+
+/// What consumers may see of the processes of a definition without an account.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct DefinitionVisibility<BoundedString> {
+    /// Names of the step attributes that are shown publicly
+    pub public_attributes: Vec<BoundedString>,
+    /// Whether the location of a process is shown
+    pub show_location: bool,
 }
@@ -1,9 +1,27 @@
+use crate::AttributeSchema;
 use codec::{Decode, Encode};
+use frame_support::dispatch::Vec;
 use sp_runtime::RuntimeDebug;
 
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
-pub struct DefinitionStep<AccountId, MemberCount, BoundedString> {
+pub struct DefinitionStep<AccountId, MemberCount, BoundedString, DefinitionStepIndex, BlockNumber> {
     pub name: BoundedString,
     pub attestor: AccountId,
     pub threshold: MemberCount,
+    /// Steps leading into this step. A step without predecessors is eligible as soon as a process is created.
+    pub predecessors: Vec<DefinitionStepIndex>,
+    /// Whether all or any of the predecessors must be attested before this step is eligible.
+    pub join: JoinRule,
+    /// Attributes an attestor is expected to supply when attesting this step.
+    pub attributes: Vec<AttributeSchema<BoundedString>>,
+    /// Blocks a process may stay eligible at this step before the step is reported overdue.
+    pub max_duration: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum JoinRule {
+    /// Every predecessor must be attested (parallel steps).
+    All,
+    /// A single attested predecessor is enough (alternative branches).
+    Any,
 }
@@ -1,4 +1,5 @@
 use codec::{Decode, Encode};
+use frame_support::dispatch::Vec;
 use sp_runtime::RuntimeDebug;
 
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
@@ -7,5 +8,10 @@ pub struct Evidence<ProposalId, BoundedString, BoundedStringUrl> {
     pub name: BoundedString,
     pub content_type: BoundedString,
     pub url: Option<BoundedStringUrl>,
-    pub hash: BoundedString,
+    /// blake2-256 hash of the content. Nodes serve the content by this hash and verify it on receipt.
+    pub hash: [u8; 32],
+    /// declared size of the content in bytes. Zero for evidence created before sizes were declared.
+    pub size: u64,
+    /// the hash of evidence created before hashes were fixed to blake2-256, as it was uploaded, when it could not be decoded. `hash` is zeroed for such evidence.
+    pub legacy_hash: Option<Vec<u8>>,
 }
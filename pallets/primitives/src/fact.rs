@@ -1,4 +1,4 @@
-use crate::Did;
+use crate::{Did, FactType};
 use codec::{Decode, Encode};
 use frame_support::dispatch::Vec;
 use sp_core::H256;
@@ -31,3 +31,33 @@ pub enum Fact<BoundedString> {
     /// (Year, Month, Day, Hour, Minute, Second, Time Zone Offset)
     Iso8601(u16, u8, u8, u8, u8, u8, Vec<u8>),
 }
+
+impl<BoundedString> Fact<BoundedString> {
+    pub fn fact_type(&self) -> FactType {
+        match self {
+            Fact::Bool(_) => FactType::Bool,
+            Fact::Text(_) => FactType::Text,
+            Fact::Attachment(_, _) => FactType::Attachment,
+            Fact::Location(_, _) => FactType::Location,
+            Fact::Did(_) => FactType::Did,
+            Fact::Float(_) => FactType::Float,
+            Fact::U8(_) => FactType::U8,
+            Fact::U16(_) => FactType::U16,
+            Fact::U32(_) => FactType::U32,
+            Fact::U128(_) => FactType::U128,
+            Fact::Date(_, _, _) => FactType::Date,
+            Fact::Iso8601(_, _, _, _, _, _, _) => FactType::Iso8601,
+        }
+    }
+
+    /// The value of an integer fact, used for range checks
+    pub fn integer_value(&self) -> Option<u128> {
+        match self {
+            Fact::U8(v) => Some(*v as u128),
+            Fact::U16(v) => Some(*v as u128),
+            Fact::U32(v) => Some(*v as u128),
+            Fact::U128(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
@@ -29,3 +29,43 @@ pub struct Votes<AccountId, MemberCount> {
     /// whether or not the vote was vetoed
     pub veto: Option<bool>,
 }
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+/// Delays the dispatch of an approved proposal.
+pub enum Timelock<BlockNumber> {
+    /// Dispatch at the given block (or the next block if it has already passed).
+    AtBlock(BlockNumber),
+    /// Dispatch the given number of blocks after approval.
+    Delay(BlockNumber),
+}
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// An approved proposal waiting in the execution queue.
+pub struct ScheduledExecution<AccountId, BlockNumber, MemberCount> {
+    /// The block in which the proposal will be dispatched.
+    pub when: BlockNumber,
+    /// Approval votes at the time the proposal was closed.
+    pub yes_votes: MemberCount,
+    /// Rejection votes at the time the proposal was closed.
+    pub no_votes: MemberCount,
+    /// If the proposal was approved by veto, the account of the parent group member.
+    pub vetoed_by: Option<AccountId>,
+}
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Spending rules for transfers out of a group account.
+pub struct Budget<Balance, BlockNumber, MemberCount> {
+    /// Length of a budget period in blocks.
+    pub period: BlockNumber,
+    /// Total amount that may leave the group account within one period.
+    pub period_cap: Balance,
+    /// Amount any single member may spend in one transfer using `execute`, without a vote.
+    pub small_spend: Balance,
+    /// Spending tiers as (amount,threshold). Transfers larger than `amount` require at least `threshold` yes votes.
+    pub tiers: Vec<(Balance, MemberCount)>,
+}
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Amount spent from a group account in the current budget period.
+pub struct BudgetUsage<Balance, BlockNumber> {
+    /// The block in which the current period started.
+    pub period_start: BlockNumber,
+    /// Total spent so far in the current period.
+    pub spent: Balance,
+}
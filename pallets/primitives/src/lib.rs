@@ -3,7 +3,10 @@ pub mod asset;
 pub mod asset_property;
 pub mod attestation;
 pub mod attribute;
+pub mod attribute_schema;
 pub mod audit;
+pub mod audit_framework;
+pub mod audit_report;
 pub mod bounded_vec;
 pub mod claim;
 pub mod definition;
@@ -17,13 +20,15 @@ pub mod group;
 pub mod lease_agreement;
 pub mod observation;
 pub mod process;
+pub mod process_link;
 pub mod process_step;
 pub mod registry;
 
 pub use self::{
-    asset::*, asset_property::*, attestation::*, attribute::*, audit::*, claim::*, definition::*,
-    definition_step::*, did::*, did_document::*, did_property::*, evidence::*, fact::*, group::*,
-    lease_agreement::*, observation::*, process::*, process_step::*, registry::*,
+    asset::*, asset_property::*, attestation::*, attribute::*, attribute_schema::*, audit::*,
+    audit_framework::*, audit_report::*, claim::*, definition::*, definition_step::*, did::*,
+    did_document::*, did_property::*, evidence::*, fact::*, group::*, lease_agreement::*,
+    observation::*, process::*, process_link::*, process_step::*, registry::*,
 };
 pub use codec::Encode;
 
@@ -156,6 +161,10 @@ macro_rules! enforce_limit_definition_steps_option {
                             name: enforce_limit!(definition_step.name),
                             attestor: definition_step.attestor,
                             threshold: definition_step.threshold,
+                            predecessors: definition_step.predecessors,
+                            join: definition_step.join,
+                            attributes: definition_step.attributes,
+                            max_duration: definition_step.max_duration,
                         })
                     })
                     .collect::<Result<Vec<_>, Error<T>>>()
@@ -174,6 +183,10 @@ macro_rules! enforce_limit_definition_steps {
                     name: enforce_limit!(definition_step.name),
                     attestor: definition_step.attestor,
                     threshold: definition_step.threshold,
+                    predecessors: definition_step.predecessors,
+                    join: definition_step.join,
+                    attributes: definition_step.attributes,
+                    max_duration: definition_step.max_duration,
                 })
             })
             .collect::<Result<Vec<_>, Error<T>>>()?
@@ -15,3 +15,25 @@ pub enum Compliance {
     Compliant,
     NonCompliant,
 }
+
+/// The response of the audit creator to a non-compliant observation.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct CorrectiveAction<AccountId, ProposalId, BlockNumber, EvidenceId> {
+    /// The proposal that created the corrective action.
+    pub proposal_id: ProposalId,
+    /// hash of the plan, which is kept off chain.
+    pub description_hash: [u8; 32],
+    /// the block by which the action is due to be closed.
+    pub due_date: BlockNumber,
+    /// the account responsible for carrying out the plan.
+    pub responsible: AccountId,
+    pub status: CorrectiveActionStatus,
+    /// the evidence the auditors verified closure with.
+    pub closing_evidence: Option<EvidenceId>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub enum CorrectiveActionStatus {
+    Open,
+    Closed,
+}
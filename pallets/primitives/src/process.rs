@@ -1,14 +1,26 @@
+use super::{Did, Fact};
 use codec::{Decode, Encode};
+use frame_support::dispatch::Vec;
 use sp_runtime::RuntimeDebug;
 
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
-pub struct Process<BoundedString> {
+pub struct Process<BoundedString, AssetRegistryId, AssetId> {
     pub name: BoundedString,
     pub status: ProcessStatus,
+    /// The asset the process concerns, e.g. a silo or field (registry_id, asset_id)
+    pub asset: Option<(AssetRegistryId, AssetId)>,
+    /// DIDs of those taking part, e.g. the farmer
+    pub participants: Vec<Did>,
+    /// Where the process takes place, always a `Fact::Location`
+    pub location: Option<Fact<BoundedString>>,
 }
 
 #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
 pub enum ProcessStatus {
     InProgress,
     Completed,
+    /// An attestor rejected a step. Only the definition creator can resume the process.
+    Rejected,
+    /// An attestor put the process on hold pending re-inspection.
+    OnHold,
 }
@@ -0,0 +1,23 @@
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+
+/// Records that a lot tracked by one process went into the lot tracked by another.
+/// Links are stored against the downstream process.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ProcessLink<BoundedString> {
+    pub kind: ProcessLinkKind,
+    /// Quantity of the upstream lot that went into the downstream lot
+    pub quantity: u128,
+    /// Unit the quantity is expressed in, e.g. "kg"
+    pub unit: BoundedString,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum ProcessLinkKind {
+    /// The upstream lot is an input that was transformed into the downstream lot (e.g. wheat milled into flour)
+    Input,
+    /// The downstream lot is a part of the upstream lot (e.g. a harvest split into shipments)
+    Split,
+    /// The upstream lot was combined with others into the downstream lot without being transformed
+    Merge,
+}
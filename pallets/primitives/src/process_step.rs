@@ -8,3 +8,67 @@ pub struct ProcessStep<ProposalId, BoundedStringName, BoundedStringFact> {
     pub proposal_id: Option<ProposalId>,
     pub attributes: Vec<Attribute<BoundedStringName, BoundedStringFact>>,
 }
+
+/// Where and when a step was attested, so off-chain exports can point back to the extrinsic.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub struct AttestationEvidence<BlockNumber, Moment> {
+    pub block_number: BlockNumber,
+    pub extrinsic_index: u32,
+    pub timestamp: Moment,
+}
+
+/// A correction to an attested step, filed by its attestor. The attestation itself is kept unchanged,
+/// and the latest amendment holds the current attributes of the step.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ProcessStepAmendment<
+    ProposalId,
+    BlockNumber,
+    Moment,
+    BoundedStringName,
+    BoundedStringFact,
+> {
+    pub proposal_id: Option<ProposalId>,
+    pub reason: BoundedStringFact,
+    pub attributes: Vec<Attribute<BoundedStringName, BoundedStringFact>>,
+    pub evidence: AttestationEvidence<BlockNumber, Moment>,
+}
+
+/// An attested step as published to consumers, with only the attributes its definition makes public.
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct PublicProcessStep<
+    DefinitionStepIndex,
+    BlockNumber,
+    Moment,
+    BoundedStringName,
+    BoundedStringFact,
+> {
+    pub definition_step_index: DefinitionStepIndex,
+    /// Name of the definition step
+    pub name: BoundedStringName,
+    /// Name of the group that attested the step, if the attestor is a group
+    pub attestor_name: Option<Vec<u8>>,
+    pub attributes: Vec<Attribute<BoundedStringName, BoundedStringFact>>,
+    pub evidence: AttestationEvidence<BlockNumber, Moment>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct ProcessStepRejection<ProposalId, BoundedStringName, BoundedStringFact> {
+    pub proposal_id: Option<ProposalId>,
+    pub reason: BoundedStringFact,
+    pub attributes: Vec<Attribute<BoundedStringName, BoundedStringFact>>,
+}
+
+#[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum ProcessStepStatus {
+    /// Waiting for its predecessors to be attested.
+    Pending,
+    /// May be attested now.
+    Eligible,
+    Attested,
+    /// Bypassed because a later step was attested through another branch.
+    Skipped,
+    /// Rejected by its attestor; the process is rejected or on hold.
+    Rejected,
+    /// Eligible for longer than the maximum duration of its definition step.
+    Overdue,
+}
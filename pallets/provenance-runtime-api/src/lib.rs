@@ -4,12 +4,15 @@
 
 use codec::Codec;
 use frame_support::dispatch::Vec;
-use primitives::{Definition, DefinitionStep, Process, ProcessStatus, ProcessStep, Registry};
+use primitives::{
+    AttestationEvidence, Definition, DefinitionStep, DefinitionVisibility, Did, Fact, Process, ProcessLink, ProcessStatus, ProcessStep,
+    ProcessStepAmendment, ProcessStepRejection, ProcessStepStatus, PublicProcessStep, Registry,
+};
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime amalgamator file (the `runtime/src/lib.rs`)
 sp_api::decl_runtime_apis! {
-    pub trait ProvenanceApi<AccountId,RegistryId,DefinitionId,ProcessId,ProposalId,MemberCount,DefinitionStepIndex,BoundedStringName, BoundedStringFact>
+    pub trait ProvenanceApi<AccountId,RegistryId,DefinitionId,ProcessId,ProposalId,MemberCount,DefinitionStepIndex,AssetRegistryId,AssetId,BlockNumber,Moment,BoundedStringName, BoundedStringFact>
     where
     AccountId: Codec,
     RegistryId: Codec,
@@ -18,6 +21,10 @@ sp_api::decl_runtime_apis! {
     ProposalId: Codec,
     MemberCount: Codec,
     DefinitionStepIndex: Codec,
+    AssetRegistryId: Codec,
+    AssetId: Codec,
+    BlockNumber: Codec,
+    Moment: Codec,
     BoundedStringName: Codec + Into<Vec<u8>>,
     BoundedStringFact: Codec + Into<Vec<u8>>
      {
@@ -29,27 +36,51 @@ sp_api::decl_runtime_apis! {
 
         fn get_definition(registry_id:RegistryId,definition_id:DefinitionId) -> Option<Definition<BoundedStringName>>;
 
-        fn get_definition_step(registry_id:RegistryId,definition_id:DefinitionId,step_index: DefinitionStepIndex) -> Option<DefinitionStep<AccountId,MemberCount,BoundedStringName>>;
+        fn get_definition_step(registry_id:RegistryId,definition_id:DefinitionId,step_index: DefinitionStepIndex) -> Option<DefinitionStep<AccountId,MemberCount,BoundedStringName,DefinitionStepIndex,BlockNumber>>;
 
-        fn get_definition_steps(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionStepIndex,DefinitionStep<AccountId,MemberCount,BoundedStringName>)>;
+        fn get_definition_steps(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionStepIndex,DefinitionStep<AccountId,MemberCount,BoundedStringName,DefinitionStepIndex,BlockNumber>)>;
+
+        fn get_definition_versions(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionId,Definition<BoundedStringName>)>;
 
         fn get_available_definitions(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,Definition<BoundedStringName>)>;
 
-        fn get_processes(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(ProcessId,Process<BoundedStringName>)>;
+        fn get_processes(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
+
+        fn get_processes_by_status(registry_id:RegistryId,definition_id:DefinitionId,status: ProcessStatus) -> Vec<(ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
+
+        fn get_processes_all_versions(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionId,ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
+
+        fn get_processes_for_asset(asset_registry_id:AssetRegistryId,asset_id:AssetId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
+
+        fn get_processes_for_did(did:Did) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
+
+        fn get_process(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Option<Process<BoundedStringName,AssetRegistryId,AssetId>>;
 
-        fn get_process(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Option<Process<BoundedStringName>>;
+        fn get_processes_for_attestor_by_status(account_id: AccountId,status: ProcessStatus) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
 
-        fn get_processes_for_attestor_by_status(account_id: AccountId,status: ProcessStatus) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName>)>;
+        fn get_processes_for_attestor_pending(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
 
-        fn get_processes_for_attestor_pending(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName>)>;
+        fn get_overdue_processes(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,AssetRegistryId,AssetId>)>;
 
-        fn get_process_steps(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Vec<ProcessStep<ProposalId,BoundedStringName, BoundedStringFact>>;
+        fn get_process_steps(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Vec<(DefinitionStepIndex,ProcessStepStatus,Option<ProcessStep<ProposalId,BoundedStringName, BoundedStringFact>>)>;
 
-        fn get_process_step(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId,definition_step_index:DefinitionStepIndex) -> Option<ProcessStep<ProposalId,BoundedStringName, BoundedStringFact>>;
+        fn get_process_step(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId,definition_step_index:DefinitionStepIndex) -> Option<(ProcessStep<ProposalId,BoundedStringName, BoundedStringFact>,Vec<ProcessStepAmendment<ProposalId,BlockNumber,Moment,BoundedStringName,BoundedStringFact>>)>;
+
+        fn get_process_step_rejections(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Vec<(DefinitionStepIndex,ProcessStepRejection<ProposalId,BoundedStringName, BoundedStringFact>)>;
+
+        fn get_process_step_evidence(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Vec<(DefinitionStepIndex,AttestationEvidence<BlockNumber,Moment>)>;
 
         fn can_view_definition( account_id: AccountId,registry_id:RegistryId,definition_id:DefinitionId) -> bool;
 
         fn is_attestor( account_id: AccountId,registry_id:RegistryId,definition_id:DefinitionId,definition_step_index: DefinitionStepIndex ) -> bool;
 
+        fn trace_back(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId,depth: u32) -> Vec<(u32,(RegistryId,DefinitionId,ProcessId),(RegistryId,DefinitionId,ProcessId),ProcessLink<BoundedStringName>)>;
+
+        fn trace_forward(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId,depth: u32) -> Vec<(u32,(RegistryId,DefinitionId,ProcessId),(RegistryId,DefinitionId,ProcessId),ProcessLink<BoundedStringName>)>;
+
+        fn get_definition_visibility(registry_id:RegistryId,definition_id:DefinitionId) -> Option<DefinitionVisibility<BoundedStringName>>;
+
+        fn get_public_trace(registry_id:RegistryId,definition_id:DefinitionId,process_id: ProcessId) -> Option<(BoundedStringName,ProcessStatus,Option<Fact<BoundedStringName>>,Vec<PublicProcessStep<DefinitionStepIndex,BlockNumber,Moment,BoundedStringName,BoundedStringFact>>)>;
+
     }
 }
@@ -4,10 +4,10 @@
 
 use super::*;
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
     dispatch::Vec,
-    traits::{Currency, Get},
+    traits::{Currency, Get, Hooks},
 };
 use frame_system::{self, RawOrigin as SystemOrigin};
 use primitives::*;
@@ -82,6 +82,7 @@ benchmarks! {
         let a in 1 .. <T as Config>::NameLimit::get(); //definition name
         let b in 1 .. <T as Config>::NameLimit::get(); //step name
         let c in 1 .. <T as Config>::DefinitionStepLimit::get();
+        let d in 0 .. <T as Config>::AttributeLimit::get(); //attribute schemas
 
         let caller = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
@@ -92,12 +93,26 @@ benchmarks! {
 
         let name = vec![42u8; a as usize];
 
+        let mut attributes=Vec::new();
+        for i in 0..d {
+            attributes.push(AttributeSchema{
+                name:i.to_le_bytes().to_vec(),
+                fact_type:FactType::U32,
+                required:true,
+                unit:Some(vec![42u8; <T as Config>::NameLimit::get() as usize]),
+                min:Some(AttributeBound::Float(0f64.to_le_bytes())),
+                max:Some(AttributeBound::Float(100f64.to_le_bytes())),
+            })
+        }
+
         let mut steps=vec![];
         for i in 0..c {
                 let name = vec![42u8; b as usize];
                 let attestor = whitelisted_caller();
                 let threshold=T::MemberCount::unique_saturated_from(1u32);
-                steps.push((name,attestor,threshold));
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                let attributes = if i == 0 { attributes.clone() } else { vec![] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,attributes,None));
         }
 
     }: _(SystemOrigin::Signed(caller.clone()),registry_id,name,steps)
@@ -124,7 +139,7 @@ benchmarks! {
         let attestor = whitelisted_caller();
         let threshold=T::MemberCount::unique_saturated_from(1u32);
 
-        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold)])?;
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
         let definition_id=T::DefinitionId::unique_saturated_from(1u32);
 
     }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id)
@@ -149,7 +164,7 @@ benchmarks! {
         let attestor = whitelisted_caller();
         let threshold=T::MemberCount::unique_saturated_from(1u32);
 
-        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold)])?;
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
         let definition_id=T::DefinitionId::unique_saturated_from(1u32);
 
     }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id)
@@ -178,7 +193,8 @@ benchmarks! {
                 let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
                 let attestor = whitelisted_caller();
                 let threshold=T::MemberCount::unique_saturated_from(1u32);
-                steps.push((name,attestor,threshold));
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
         }
 
         ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
@@ -193,6 +209,7 @@ benchmarks! {
     }
 
     update_definition_step {
+        let a in 0 .. <T as Config>::AttributeLimit::get();
 
         let caller = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
@@ -206,14 +223,26 @@ benchmarks! {
         let attestor = whitelisted_caller();
         let threshold=T::MemberCount::unique_saturated_from(1u32);
 
-        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold)])?;
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
         let definition_id=T::DefinitionId::unique_saturated_from(1u32);
         let definition_step_index=T::DefinitionStepIndex::unique_saturated_from(0u32);
 
         let attestor:T::AccountId = whitelisted_caller();
         let threshold=T::MemberCount::unique_saturated_from(1u32);
 
-    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,definition_step_index,Some(attestor.clone()),Some(threshold))
+        let mut attributes=Vec::new();
+        for i in 0..a {
+            attributes.push(AttributeSchema{
+                name:i.to_le_bytes().to_vec(),
+                fact_type:FactType::U32,
+                required:true,
+                unit:Some(vec![42u8; <T as Config>::NameLimit::get() as usize]),
+                min:Some(AttributeBound::Float(0f64.to_le_bytes())),
+                max:Some(AttributeBound::Float(100f64.to_le_bytes())),
+            })
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,definition_step_index,Some(attestor.clone()),Some(threshold),Some(attributes))
 
     verify {
         let definition_step=<DefinitionSteps<T>>::get((registry_id, definition_id),definition_step_index);
@@ -221,6 +250,7 @@ benchmarks! {
         let definition_step=definition_step.unwrap();
         assert_eq!(definition_step.attestor,attestor);
         assert_eq!(definition_step.threshold,threshold);
+        assert_eq!(definition_step.attributes.len(),a as usize);
     }
 
     create_process {
@@ -238,7 +268,8 @@ benchmarks! {
                 let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
                 let attestor = whitelisted_caller();
                 let threshold=T::MemberCount::unique_saturated_from(1u32);
-                steps.push((name,attestor,threshold));
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
         }
         ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
         let definition_id=T::DefinitionId::unique_saturated_from(1u32);
@@ -246,7 +277,7 @@ benchmarks! {
 
         let name = vec![42u8; a as usize];
 
-    }: _(SystemOrigin::Signed(attestor.clone()),registry_id,definition_id,name.clone())
+    }: _(SystemOrigin::Signed(attestor.clone()),registry_id,definition_id,name.clone(),None,vec![],None)
 
     verify {
         let process_id=T::ProcessId::unique_saturated_from(1u32);
@@ -273,7 +304,8 @@ benchmarks! {
                 let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
                 let attestor = whitelisted_caller();
                 let threshold=T::MemberCount::unique_saturated_from(1u32);
-                steps.push((name,attestor,threshold));
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
         }
 
         ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
@@ -283,7 +315,7 @@ benchmarks! {
         let attestor_origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(attestor.clone()).into();
 
 
-        ProvenancePallet::<T>::create_process(attestor_origin,registry_id,definition_id,vec![42u8])?;
+        ProvenancePallet::<T>::create_process(attestor_origin,registry_id,definition_id,vec![42u8],None,vec![],None)?;
 
         let process_id=T::ProcessId::unique_saturated_from(1u32);
 
@@ -313,7 +345,8 @@ benchmarks! {
                 let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
                 let attestor = whitelisted_caller();
                 let threshold=T::MemberCount::unique_saturated_from(1u32);
-                steps.push((name,attestor,threshold));
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
         }
 
         ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
@@ -326,7 +359,7 @@ benchmarks! {
 
         ProvenancePallet::<T>::set_definition_active(origin.clone(),registry_id,definition_id)?;
 
-        ProvenancePallet::<T>::create_process(attestor_origin,registry_id,definition_id,vec![42u8])?;
+        ProvenancePallet::<T>::create_process(attestor_origin,registry_id,definition_id,vec![42u8],None,vec![],None)?;
 
         let process_id=T::ProcessId::unique_saturated_from(1u32);
 
@@ -361,7 +394,8 @@ benchmarks! {
                 let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
                 let attestor = whitelisted_caller();
                 let threshold=T::MemberCount::unique_saturated_from(1u32);
-                steps.push((name,attestor,threshold));
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
         }
 
         ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
@@ -369,7 +403,7 @@ benchmarks! {
         let attestor:T::AccountId = whitelisted_caller();
         let attestor_origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(attestor.clone()).into();
 
-        ProvenancePallet::<T>::create_process(attestor_origin,registry_id,definition_id,vec![42u8])?;
+        ProvenancePallet::<T>::create_process(attestor_origin,registry_id,definition_id,vec![42u8],None,vec![],None)?;
 
         let process_id=T::ProcessId::unique_saturated_from(1u32);
 
@@ -392,6 +426,328 @@ benchmarks! {
         assert_eq!(process_step.attributes.len(),a as usize);
     }
 
+    new_definition_version {
+        let a in 1 .. <T as Config>::DefinitionStepLimit::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+        assert!(<Registries<T>>::contains_key(caller.clone(),registry_id));
+
+        let mut steps=vec![];
+        for i in 0..a {
+                let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+                let attestor = whitelisted_caller();
+                let threshold=T::MemberCount::unique_saturated_from(1u32);
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
+        }
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id)
+
+    verify {
+        let new_definition_id=T::DefinitionId::unique_saturated_from(2u32);
+        let definition=<Definitions<T>>::get(registry_id, new_definition_id);
+        assert!(definition.is_some());
+        assert_eq!(definition.unwrap().status,DefinitionStatus::Draft);
+        assert_eq!(<DefinitionSteps<T>>::iter_prefix((registry_id, new_definition_id)).count(),a as usize);
+        assert_eq!(<DefinitionPredecessors<T>>::get(registry_id, new_definition_id),Some(definition_id));
+    }
+
+    update_definition_draft {
+        let a in 1 .. <T as Config>::DefinitionStepLimit::get();
+        let b in 1 .. <T as Config>::NameLimit::get();
+        let c in 1 .. <T as Config>::DefinitionStepLimit::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+        assert!(<Registries<T>>::contains_key(caller.clone(),registry_id));
+
+        let mut steps=vec![];
+        for i in 0..c {
+                let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+                let attestor = whitelisted_caller();
+                let threshold=T::MemberCount::unique_saturated_from(1u32);
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
+        }
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],steps)?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+        ProvenancePallet::<T>::new_definition_version(origin.clone(),registry_id,definition_id)?;
+        let new_definition_id=T::DefinitionId::unique_saturated_from(2u32);
+
+        let mut steps=vec![];
+        for i in 0..a {
+                let name = vec![42u8; b as usize];
+                let attestor = whitelisted_caller();
+                let threshold=T::MemberCount::unique_saturated_from(1u32);
+                let predecessors = if i == 0 { vec![] } else { vec![T::DefinitionStepIndex::unique_saturated_from(i - 1)] };
+                steps.push((name,attestor,threshold,predecessors,JoinRule::All,vec![],None));
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,new_definition_id,steps)
+
+    verify {
+        assert_eq!(<DefinitionSteps<T>>::iter_prefix((registry_id, new_definition_id)).count(),a as usize);
+    }
+
+    link_processes {
+        let a in 1 .. 100;
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+        let attestor = whitelisted_caller();
+        let threshold=T::MemberCount::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        for _ in 0..a+1 {
+            ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        }
+        let process_id=T::ProcessId::unique_saturated_from(a+1);
+
+        let mut links=Vec::new();
+        for i in 1..a+1 {
+            links.push((
+                (registry_id,definition_id,T::ProcessId::unique_saturated_from(i)),
+                ProcessLink{
+                    kind:ProcessLinkKind::Input,
+                    quantity:1000,
+                    unit:vec![42u8; <T as Config>::NameLimit::get() as usize],
+                },
+            ));
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,process_id,links)
+
+    verify {
+        assert_eq!(<ProcessLinks<T>>::iter_prefix((registry_id,definition_id,process_id)).count(),a as usize);
+    }
+
+    unlink_process {
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+        let attestor = whitelisted_caller();
+        let threshold=T::MemberCount::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        let upstream=(registry_id,definition_id,T::ProcessId::unique_saturated_from(1u32));
+        let process_id=T::ProcessId::unique_saturated_from(2u32);
+
+        ProvenancePallet::<T>::link_processes(origin.clone(),registry_id,definition_id,process_id,vec![(upstream,ProcessLink{
+            kind:ProcessLinkKind::Input,
+            quantity:1000,
+            unit:vec![42u8],
+        })])?;
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,process_id,upstream)
+
+    verify {
+        assert!(!<ProcessLinks<T>>::contains_key((registry_id,definition_id,process_id),upstream));
+    }
+
+    reject_process_step {
+        let a in 1 .. <T as Config>::AttributeLimit::get();
+        let b in 1 .. <T as Config>::NameLimit::get();
+        let c in 1 .. <T as Config>::FactStringLimit::get();
+        let d in 1 .. <T as Config>::FactStringLimit::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+        let attestor = whitelisted_caller();
+        let threshold=T::MemberCount::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        let process_id=T::ProcessId::unique_saturated_from(1u32);
+
+        let definition_step_index=T::DefinitionStepIndex::unique_saturated_from(0u32);
+
+        let mut attributes=Vec::new();
+        for _ in 0..a {
+            attributes.push(Attribute{
+                name:vec![42u8; b as usize],
+                fact:Fact::Text(vec![42u8; c as usize]),
+            })
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,process_id,definition_step_index,vec![42u8; d as usize],attributes,ProcessStatus::OnHold)
+
+    verify {
+        let rejection=<ProcessStepRejections<T>>::get((registry_id, definition_id,process_id),definition_step_index);
+        assert!(rejection.is_some());
+        assert_eq!(rejection.unwrap().attributes.len(),a as usize);
+    }
+
+    resume_process {
+        let a in 1 .. <T as Config>::DefinitionStepLimit::get();
+
+        let owner:T::AccountId = account("owner", 0, 0);
+        T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value());
+        let owner_origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(owner.clone()).into();
+        ProvenancePallet::<T>::create_registry(owner_origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let caller:T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+
+        let mut steps=vec![];
+        for _ in 0..a {
+            let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+            let threshold=T::MemberCount::unique_saturated_from(1u32);
+            steps.push((name,caller.clone(),threshold,vec![],JoinRule::All,vec![],None));
+        }
+        ProvenancePallet::<T>::create_definition(owner_origin.clone(),registry_id,vec![42u8],steps)?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        let process_id=T::ProcessId::unique_saturated_from(1u32);
+
+        for i in 0..a {
+            if i > 0 {
+                ProvenancePallet::<T>::resume_process(owner_origin.clone(),registry_id,definition_id,process_id)?;
+            }
+            ProvenancePallet::<T>::reject_process_step(origin.clone(),registry_id,definition_id,process_id,T::DefinitionStepIndex::unique_saturated_from(i),vec![42u8],vec![],ProcessStatus::OnHold)?;
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,process_id)
+
+    verify {
+        let process=<Processes<T>>::get((registry_id, definition_id),process_id).unwrap();
+        assert_eq!(process.status,ProcessStatus::InProgress);
+    }
+
+    set_definition_visibility {
+        let a in 1 .. <T as Config>::AttributeLimit::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+        let attestor = whitelisted_caller();
+        let threshold=T::MemberCount::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        let mut public_attributes=Vec::new();
+        for i in 0..a {
+            let mut name=vec![42u8; <T as Config>::NameLimit::get() as usize];
+            name[0]=i as u8;
+            name[1]=(i >> 8) as u8;
+            public_attributes.push(name);
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,Some(DefinitionVisibility{public_attributes,show_location:true}))
+
+    verify {
+        let visibility=<DefinitionVisibilities<T>>::get(registry_id, definition_id);
+        assert!(visibility.is_some());
+        assert_eq!(visibility.unwrap().public_attributes.len(),a as usize);
+    }
+
+    amend_process_step {
+        let a in 1 .. <T as Config>::AttributeLimit::get();
+        let b in 1 .. <T as Config>::NameLimit::get();
+        let c in 1 .. <T as Config>::FactStringLimit::get();
+        let d in 1 .. <T as Config>::FactStringLimit::get();
+
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+        let attestor = whitelisted_caller();
+        let threshold=T::MemberCount::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,attestor,threshold,vec![],JoinRule::All,vec![],None)])?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        let process_id=T::ProcessId::unique_saturated_from(1u32);
+
+        let definition_step_index=T::DefinitionStepIndex::unique_saturated_from(0u32);
+        ProvenancePallet::<T>::attest_process_step(origin.clone(),registry_id,definition_id,process_id,definition_step_index,vec![])?;
+
+        let mut attributes=Vec::new();
+        for _ in 0..a {
+            attributes.push(Attribute{
+                name:vec![42u8; b as usize],
+                fact:Fact::Text(vec![42u8; c as usize]),
+            })
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()),registry_id,definition_id,process_id,definition_step_index,vec![42u8; d as usize],attributes)
+
+    verify {
+        let amendment=<ProcessStepAmendments<T>>::get((registry_id, definition_id,process_id),(definition_step_index,0));
+        assert!(amendment.is_some());
+        assert_eq!(amendment.unwrap().attributes.len(),a as usize);
+    }
+
+    on_initialize {
+        let a in 1 .. <T as Config>::MaxDeadlinesPerBlock::get();
+
+        let caller:T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let origin:<T as frame_system::Config>::Origin=SystemOrigin::Signed(caller.clone()).into();
+        ProvenancePallet::<T>::create_registry(origin.clone(), vec![42u8])?;
+        let registry_id=T::RegistryId::unique_saturated_from(1u32);
+
+        let name = vec![42u8; <T as Config>::NameLimit::get() as usize];
+        let threshold=T::MemberCount::unique_saturated_from(1u32);
+        let max_duration=T::BlockNumber::unique_saturated_from(1u32);
+        ProvenancePallet::<T>::create_definition(origin.clone(),registry_id,vec![42u8],vec![(name,caller.clone(),threshold,vec![],JoinRule::All,vec![],Some(max_duration))])?;
+        let definition_id=T::DefinitionId::unique_saturated_from(1u32);
+
+        for _ in 0..a {
+            ProvenancePallet::<T>::create_process(origin.clone(),registry_id,definition_id,vec![42u8],None,vec![],None)?;
+        }
+        let deadline=<frame_system::Module<T>>::block_number()+max_duration;
+
+    }: { ProvenancePallet::<T>::on_initialize(deadline); }
+
+    verify {
+        assert_eq!(<ProcessStepDeadlines<T>>::iter_prefix(deadline).count(),0 as usize);
+    }
+
 }
 
 impl_benchmark_test_suite!(
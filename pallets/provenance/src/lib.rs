@@ -12,6 +12,15 @@
 //! * `update_registry` - Rename a **Registry**
 //! * `remove_registry` - Remove a **Registry** - **Registry** must be empty.
 //! * `create_definition` - Create a new **Process Definition**
+//!                         Each step lists its predecessor steps and whether all or any of them must be attested first,
+//!                         so steps may run in parallel or on alternative branches.
+//!                         Steps may also declare the attributes an attestor must or may supply, with their expected type, unit and range,
+//!                         and a maximum number of blocks a process may wait at the step before a `ProcessStepOverdue` event is emitted.
+//!                         At most `MaxDeadlinesPerBlock` steps become overdue in a block, later ones in the next block with room.
+//! * `new_definition_version` - Clone the latest version of a **Process Definition** into an editable draft linked to it.
+//!                              Setting the draft active makes it the current version and sets the previous version inactive.
+//!                              Processes already created on a previous version finish on that version.
+//! * `update_definition_draft` - Replace the steps of a draft **Process Definition**.
 //! * `set_definition_inactive` - Set a **Process Definition** to 'inactive'.
 //!                             Once a **Process Definition** is made inactive, new processes cannot be created against it.
 //! * `set_definition_active` - Set a **Process Definition** to 'active'.
@@ -20,14 +29,27 @@
 //! * `remove_definition` - Remove a **Process Definition**. It must not have any related Processes.
 //! * `update_definition_step` - Update a step of a **Process Definition**.
 //!                              You can change attestors or threshold.
+//! * `set_definition_visibility` - Make the **Processes** of a **Process Definition** public, choosing which step attributes and whether their location are shown,
+//!                                 or make them private again.
 //! * `update_process` - A **Process Definition** creator is allowed to rename **Processes** (attestors cannot).
 //! * `remove_process` - A **Process Definition** creator is allowed to remove a **Processes** (attestors cannot).
+//! * `resume_process` - A **Process Definition** creator may resume a rejected or on hold **Process** for re-inspection.
 //!
 //! #### For Attestors
 //! * `create_process` - An attestor of the first step of a **Process Definition** may create a new Process.
-//!                           A Process Step can only be updated after the previous step has been attested and before the step itself is attested.
-//! * `attest_process_step` - Attest that the attributes for a process step are accurate.
-//!                           This is done once for each step in order and the process moves on to the next or completes if it is the last step.
+//!                           Every step without predecessors becomes eligible.
+//!                           The process may reference the asset it concerns, the DIDs of its participants and its location.
+//! * `link_processes` - Record the lots (processes) that went into a process, as inputs, splits or merges, with quantities.
+//!                      May be called by the registry owner or an attestor on the definition of the downstream process.
+//! * `unlink_process` - Remove a link recorded in error.
+//! * `attest_process_step` - Attest that the attributes for an eligible process step are accurate.
+//!                           Attestation is rejected if a required attribute is missing or an attribute does not match the step's schema.
+//!                           Steps whose predecessors are then satisfied become eligible, and unattested steps on other branches leading into the attested step are skipped.
+//!                           The process completes once no steps remain eligible.
+//! * `amend_process_step` - Correct the attributes of an attested process step, giving a reason. The step's attestor must meet the step's threshold again.
+//!                          The attestation is kept and amendments are appended to its history, at most `AmendmentLimit` per step.
+//! * `reject_process_step` - Reject an eligible process step, with a reason and the attributes found, putting the **Process** on hold or rejecting it.
+//! * `resume_process` - The attestor who put a **Process** on hold may resume it once the problem is resolved.
 //!
 //! ### RPC Methods
 //!
@@ -36,10 +58,22 @@
 //! * `get_definitions` - Get the collection of **Process Definitions** in a **Registry**.
 //! * `get_definition` - Get a specific **Process Definition**.
 //! * `get_definition_steps` - Get the collection of steps in a **Process Definition**.
+//! * `get_definition_versions` - Get all versions of a **Process Definition**, oldest first.
 //! * `get_processes` - Get the collection of **Processes** based on a specific **Process Definition**.
 //! * `get_process` - Get a specific **Process**.
-//! * `get_process_steps` - Get the collection of steps of a **Process**.
-//! * `get_process_step` - Get a specific step of a **Process**.
+//! * `get_processes_by_status` - Get the **Processes** based on a specific **Process Definition** with a given status, e.g. rejected.
+//! * `get_process_step_rejections` - Get the rejections recorded against the steps of a **Process**.
+//! * `get_processes_all_versions` - Get the collection of **Processes** across all versions of a **Process Definition**.
+//! * `get_processes_for_asset` - Get the **Processes** that concern an asset in the asset registry.
+//! * `get_processes_for_did` - Get the **Processes** a DID takes part in.
+//! * `get_process_steps` - Get the status (pending, eligible, overdue, attested, skipped or rejected) of every step of a **Process**, with the attested data as amended.
+//! * `get_overdue_processes` - Get the in progress **Processes** where an account is the attestor of a step that has waited longer than its maximum duration.
+//! * `get_process_step` - Get the current attributes of a specific step of a **Process**, with the amendments made to it since it was attested.
+//! * `get_process_step_evidence` - Get the block, extrinsic index and time at which each step of a **Process** was attested.
+//! * `trace_back` - Walk the lineage of a **Process** upstream, up to a given depth.
+//! * `trace_forward` - Walk the lineage of a **Process** downstream, up to a given depth.
+//! * `get_definition_visibility` - Get what consumers may see of the **Processes** of a **Process Definition**.
+//! * `get_public_trace` - Get the attested steps of a **Process** of a public **Process Definition**, with only the public attributes, the names of the attesting groups and the block of each attestation.
 //! * `can_view_definition` - Is the account the creator of the definition or an attestor on any step.
 //! * `is_attestor` - Is the account the attestor for the step. (Attestors may be an individual account or a group, check if a user is a member of the group seperately.)
 
@@ -60,19 +94,25 @@ pub mod migration;
 #[frame_support::pallet]
 pub mod pallet {
     pub use super::weights::WeightInfo;
+    use asset_info::AssetInfo;
     use core::convert::TryInto;
+    use did_info::DidInfo;
     use extrinsic_extra::GetExtrinsicExtra;
     use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
     use frame_system::pallet_prelude::*;
     use primitives::{bounded_vec::BoundedVec, *};
+    use process_info::ProcessInfo;
     use sp_runtime::{
-        traits::{AtLeast32Bit, CheckedAdd, One, Saturating, UniqueSaturatedFrom},
+        traits::{AtLeast32Bit, CheckedAdd, One, UniqueSaturatedFrom, Zero},
         Either,
     };
-    use sp_std::prelude::*;
+    use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec};
 
     const MODULE_INDEX: u8 = 3;
 
+    /// The deepest a lineage trace may walk, whatever depth the caller asks for
+    pub const MAX_TRACE_DEPTH: u32 = 100;
+
     #[repr(u8)]
     pub enum ExtrinsicIndex {
         Registry = 31,
@@ -84,10 +124,11 @@ pub mod pallet {
     pub enum Releases {
         V1,
         V2,
+        V3,
     }
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + groups::Config {
+    pub trait Config: frame_system::Config + timestamp::Config + groups::Config {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
@@ -116,6 +157,32 @@ pub mod pallet {
 
         /// The maximum number of attributes allowed for a given process step
         type AttributeLimit: Get<u32>;
+
+        /// The maximum number of predecessors of a definition step
+        type PredecessorLimit: Get<u32>;
+
+        /// The maximum number of upstream processes a process may be linked to, and of downstream processes linked to it
+        type ProcessLinkLimit: Get<u32>;
+
+        /// The maximum number of step deadlines checked at the start of a block
+        type MaxDeadlinesPerBlock: Get<u32>;
+
+        /// The maximum number of amendments to an attested step
+        type AmendmentLimit: Get<u32>;
+
+        /// The asset registry's id for a registry, used to reference the asset a process concerns
+        type AssetRegistryId: Parameter + Member + Copy + PartialEq;
+        /// The asset registry's id for an asset
+        type AssetId: Parameter + Member + Copy + PartialEq;
+
+        /// Checks that an asset referenced by a process is registered
+        type AssetInfoSource: AssetInfo<RegistryId = Self::AssetRegistryId, AssetId = Self::AssetId>;
+
+        /// Checks that the DIDs of a process's participants have been created
+        type DidInfoSource: DidInfo<Did = Did>;
+
+        /// The maximum number of participant DIDs a process may reference
+        type ParticipantLimit: Get<u32>;
     }
 
     #[pallet::event]
@@ -144,6 +211,18 @@ pub mod pallet {
         DefinitionSetInactive(T::AccountId, T::AccountId, T::RegistryId, T::DefinitionId),
         /// A Definition was Removed (account_id,group_account_id,registry_id, definition_id)
         DefinitionRemoved(T::AccountId, T::AccountId, T::RegistryId, T::DefinitionId),
+        /// A new version of a Definition was created as a draft (account_id,group_account_id,registry_id, definition_id, previous_definition_id)
+        DefinitionVersionCreated(
+            T::AccountId,
+            T::AccountId,
+            T::RegistryId,
+            T::DefinitionId,
+            T::DefinitionId,
+        ),
+        /// The steps of a draft Definition were replaced (account_id,group_account_id,registry_id, definition_id)
+        DefinitionDraftUpdated(T::AccountId, T::AccountId, T::RegistryId, T::DefinitionId),
+        /// The public visibility of a Definition was set or cleared (account_id,group_account_id,registry_id, definition_id)
+        DefinitionVisibilityUpdated(T::AccountId, T::AccountId, T::RegistryId, T::DefinitionId),
 
         /// A DefinitionStep was Updated (account_id,group_account_id,registry_id, definition_id, definition_step_index)
         DefinitionStepUpdated(
@@ -196,8 +275,58 @@ pub mod pallet {
             T::ProcessId,
             T::DefinitionStepIndex,
         ),
+        /// An attested ProcessStep was amended (account_id,registry_id, definition_id, process_id, definition_step_index)
+        ProcessStepAmended(
+            T::AccountId,
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            T::DefinitionStepIndex,
+        ),
         /// A new Process was completed (account_id,registry_id, definition_id, process_id)
         ProcessCompleted(T::AccountId, T::RegistryId, T::DefinitionId, T::ProcessId),
+        /// A ProcessStep was rejected, leaving the Process rejected or on hold (account_id,registry_id, definition_id, process_id, definition_step_index, status)
+        ProcessStepRejected(
+            T::AccountId,
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            T::DefinitionStepIndex,
+            ProcessStatus,
+        ),
+        /// A rejected or on hold Process was resumed (account_id,group_account_id,registry_id, definition_id, process_id)
+        ProcessResumed(
+            T::AccountId,
+            T::AccountId,
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+        ),
+        /// An eligible ProcessStep was not attested within the maximum duration of its definition step (registry_id, definition_id, process_id, definition_step_index)
+        ProcessStepOverdue(
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            T::DefinitionStepIndex,
+        ),
+        /// A Process was linked to a Process whose lot went into it (account_id,group_account_id,registry_id, definition_id, process_id, (upstream registry_id, definition_id, process_id))
+        ProcessLinked(
+            T::AccountId,
+            T::AccountId,
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+        ),
+        /// A link between Processes was removed (account_id,group_account_id,registry_id, definition_id, process_id, (upstream registry_id, definition_id, process_id))
+        ProcessUnlinked(
+            T::AccountId,
+            T::AccountId,
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+        ),
     }
 
     #[pallet::error]
@@ -218,6 +347,10 @@ pub mod pallet {
         IncorrectStatus,
         /// User tried to attest a step that is not the current step of a Process
         ProcessStepNotCurrent,
+        /// Only attested steps can be amended
+        ProcessStepNotAttested,
+        /// A step may be amended at most `AmendmentLimit` times
+        TooManyAmendments,
         /// No id was found (either user is not owner, or entity does not exist)
         NotFound,
         /// Cannot delete non-empty registry
@@ -230,6 +363,34 @@ pub mod pallet {
         NoIdAvailable,
         /// A definition can only be set active if all steps have attestor groups assigned
         AttestorNotSet,
+        /// A step may only list distinct earlier steps as predecessors
+        InvalidPredecessor,
+        /// A step may have at most `PredecessorLimit` predecessors
+        TooManyPredecessors,
+        /// A process may have at most `ProcessLinkLimit` upstream or downstream links
+        TooManyProcessLinks,
+        /// A new version can only be created from the latest version of a definition
+        NotLatestVersion,
+        /// A process cannot be linked to itself
+        InvalidProcessLink,
+        /// Attribute names must be unique and ranges may only be set on numeric attributes, with float bounds for floats and integer bounds for integers
+        InvalidAttributeSchema,
+        /// The asset referenced by a process is not registered
+        AssetNotFound,
+        /// A DID referenced by a process has not been created
+        DidNotFound,
+        /// A process may reference at most `ParticipantLimit` distinct DIDs
+        InvalidParticipants,
+        /// A process location must be a `Fact::Location`
+        InvalidLocation,
+        /// A step's maximum duration must be at least one block
+        InvalidMaxDuration,
+        /// An attribute required by the definition step was not supplied
+        RequiredAttributeMissing,
+        /// An attribute is not of the type or within the range declared by the definition step
+        AttributeNotValid,
+        /// Public attribute names must be unique, and at most `AttributeLimit` for each step a definition may have
+        InvalidVisibility,
     }
 
     #[pallet::type_value]
@@ -252,11 +413,39 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        // fn on_runtime_upgrade() -> frame_support::weights::Weight {
-        //     let mut weight: Weight = 0;
-        //     // weight += super::migration::migrate_to_v2::<T>();
-        //     weight
-        // }
+        fn on_initialize(block_number: T::BlockNumber) -> frame_support::weights::Weight {
+            // `make_eligible` sets at most `MaxDeadlinesPerBlock` deadlines for a block
+            let mut overdue_count = 0;
+            <ProcessStepDeadlines<T>>::drain_prefix(block_number).for_each(
+                |((registry_id, definition_id, process_id, step_index), eligible_since)| {
+                    overdue_count += 1;
+                    // The step may have been attested, or made eligible again by a resume, since the deadline was set
+                    let still_eligible = <EligibleSteps<T>>::get(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    ) == Some(eligible_since);
+                    let in_progress = <Processes<T>>::get((registry_id, definition_id), process_id)
+                        .map_or(false, |process| process.status == ProcessStatus::InProgress);
+                    if still_eligible && in_progress {
+                        Self::deposit_event(Event::ProcessStepOverdue(
+                            registry_id,
+                            definition_id,
+                            process_id,
+                            step_index,
+                        ));
+                    }
+                },
+            );
+            <ProcessStepDeadlineCount<T>>::remove(block_number);
+            <T as Config>::WeightInfo::on_initialize(overdue_count)
+        }
+
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            let mut weight: Weight = 0;
+            // weight += super::migration::migrate_to_v2::<T>();
+            weight += super::migration::migrate_to_v3::<T>();
+            weight
+        }
     }
 
     #[pallet::genesis_config]
@@ -275,7 +464,7 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
-            <StorageVersion<T>>::put(Releases::V2);
+            <StorageVersion<T>>::put(Releases::V3);
         }
     }
 
@@ -287,6 +476,7 @@ pub mod pallet {
     /// Storage version of the pallet.
     ///
     /// V2 - added proposal_id to observation struct
+    /// V3 - definition steps list their predecessors, attribute schemas and maximum durations, processes reference their asset, participants and location
     pub type StorageVersion<T> = StorageValue<_, Releases, OptionQuery>;
 
     #[pallet::storage]
@@ -327,7 +517,13 @@ pub mod pallet {
         (T::RegistryId, T::DefinitionId),
         Blake2_128Concat,
         T::DefinitionStepIndex,
-        DefinitionStep<T::AccountId, T::MemberCount, BoundedVec<u8, <T as Config>::NameLimit>>,
+        DefinitionStep<
+            T::AccountId,
+            T::MemberCount,
+            BoundedVec<u8, <T as Config>::NameLimit>,
+            T::DefinitionStepIndex,
+            T::BlockNumber,
+        >,
         OptionQuery,
     >;
 
@@ -355,7 +551,7 @@ pub mod pallet {
         (T::RegistryId, T::DefinitionId),
         Blake2_128Concat,
         T::ProcessId,
-        Process<BoundedVec<u8, <T as Config>::NameLimit>>,
+        Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
         OptionQuery,
     >;
 
@@ -377,6 +573,210 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn definition_predecessors)]
+    /// The version a Definition was created from
+    /// T::RegistryId, T::DefinitionId => T::DefinitionId
+    pub(super) type DefinitionPredecessors<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::RegistryId,
+        Blake2_128Concat,
+        T::DefinitionId,
+        T::DefinitionId,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn definition_successors)]
+    /// The version created from a Definition
+    /// T::RegistryId, T::DefinitionId => T::DefinitionId
+    pub(super) type DefinitionSuccessors<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::RegistryId,
+        Blake2_128Concat,
+        T::DefinitionId,
+        T::DefinitionId,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn eligible_steps)]
+    /// Steps of a Process that may currently be attested, with the block they became eligible at
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), DefinitionStepIndex => T::BlockNumber
+    pub(super) type EligibleSteps<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        T::DefinitionStepIndex,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_step_deadlines)]
+    /// Eligible steps with a maximum duration, by the block at which they become overdue, with the block they became eligible at
+    /// T::BlockNumber, (T::RegistryId,T::DefinitionId,T::ProcessId,T::DefinitionStepIndex) => T::BlockNumber
+    pub(super) type ProcessStepDeadlines<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Blake2_128Concat,
+        (
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            T::DefinitionStepIndex,
+        ),
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_step_amendment_count)]
+    /// Number of amendments made to an attested step of a Process
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), DefinitionStepIndex => u32
+    pub(super) type ProcessStepAmendmentCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        T::DefinitionStepIndex,
+        u32,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_step_deadline_count)]
+    /// Number of step deadlines set for a block
+    /// T::BlockNumber => u32
+    pub(super) type ProcessStepDeadlineCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::BlockNumber, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_step_rejections)]
+    /// Steps of a Process that an attestor rejected, with the reason and the attributes found
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), DefinitionStepIndex => ProcessStepRejection
+    pub(super) type ProcessStepRejections<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        T::DefinitionStepIndex,
+        ProcessStepRejection<
+            T::ProposalId,
+            BoundedVec<u8, <T as Config>::NameLimit>,
+            BoundedVec<u8, <T as Config>::FactStringLimit>,
+        >,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_step_evidence)]
+    /// The block, extrinsic index and time at which a step of a Process was attested
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), DefinitionStepIndex => AttestationEvidence
+    pub(super) type ProcessStepEvidence<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        T::DefinitionStepIndex,
+        AttestationEvidence<T::BlockNumber, T::Moment>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_step_amendments)]
+    /// Corrections to the attested steps of a Process, numbered in the order they were made
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), (DefinitionStepIndex, amendment number) => ProcessStepAmendment
+    pub(super) type ProcessStepAmendments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        (T::DefinitionStepIndex, u32),
+        ProcessStepAmendment<
+            T::ProposalId,
+            T::BlockNumber,
+            T::Moment,
+            BoundedVec<u8, <T as Config>::NameLimit>,
+            BoundedVec<u8, <T as Config>::FactStringLimit>,
+        >,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_links)]
+    /// The lots that went into a Process
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), (upstream T::RegistryId,T::DefinitionId,T::ProcessId) => ProcessLink
+    pub(super) type ProcessLinks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        ProcessLink<BoundedVec<u8, <T as Config>::NameLimit>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn process_links_by_upstream)]
+    /// The Processes a Process's lot went into
+    /// (T::RegistryId,T::DefinitionId,T::ProcessId), (downstream T::RegistryId,T::DefinitionId,T::ProcessId) => ()
+    pub(super) type ProcessLinksByUpstream<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn processes_by_asset)]
+    /// The Processes that concern an asset
+    /// (asset registry_id, asset_id), (T::RegistryId,T::DefinitionId,T::ProcessId) => ()
+    pub(super) type ProcessesByAsset<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::AssetRegistryId, T::AssetId),
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn processes_by_did)]
+    /// The Processes a DID takes part in
+    /// Did, (T::RegistryId,T::DefinitionId,T::ProcessId) => ()
+    pub(super) type ProcessesByDid<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Did,
+        Blake2_128Concat,
+        (T::RegistryId, T::DefinitionId, T::ProcessId),
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn definition_visibilities)]
+    /// What consumers may see of the Processes of a public Definition
+    /// T::RegistryId, T::DefinitionId => DefinitionVisibility
+    pub(super) type DefinitionVisibilities<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::RegistryId,
+        Blake2_128Concat,
+        T::DefinitionId,
+        DefinitionVisibility<BoundedVec<u8, <T as Config>::NameLimit>>,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn next_registry_id)]
     /// The next available registry index
@@ -503,16 +903,27 @@ pub mod pallet {
         /// Arguments:
         /// - `registry_id` Registry to put definition in
         /// - `name` name of the definition
+        /// - `steps` (name, attestor, threshold, predecessors, join, attributes, max_duration) of each step. Predecessors must be earlier steps, at most `PredecessorLimit` of them. The first step has no predecessors.
+        ///   `max_duration` is the number of blocks a process may stay eligible at the step before it is reported overdue.
         #[pallet::weight(<T as Config>::WeightInfo::create_definition(
             name.len() as u32,
             steps.len() as u32,
-            get_max_step_name::<T::AccountId,T::MemberCount>(steps) as u32
+            get_max_step_name::<T::AccountId,T::MemberCount,T::DefinitionStepIndex,T::BlockNumber>(steps) as u32,
+            get_schema_count::<T::AccountId,T::MemberCount,T::DefinitionStepIndex,T::BlockNumber>(steps)
         ))]
         pub fn create_definition(
             origin: OriginFor<T>,
             registry_id: T::RegistryId,
             name: Vec<u8>,
-            steps: Vec<(Vec<u8>, T::AccountId, T::MemberCount)>,
+            steps: Vec<(
+                Vec<u8>,
+                T::AccountId,
+                T::MemberCount,
+                Vec<T::DefinitionStepIndex>,
+                JoinRule,
+                Vec<AttributeSchema<Vec<u8>>>,
+                Option<T::BlockNumber>,
+            )>,
         ) -> DispatchResultWithPostInfo {
             let (account_id, group_account) = ensure_account_or_executed!(origin);
 
@@ -521,22 +932,16 @@ pub mod pallet {
                 Error::<T>::NotAuthorized
             );
 
-            ensure!(!steps.is_empty(), Error::<T>::DefinitionStepsRequired);
-
             let bounded_name = enforce_limit!(name);
 
+            let definition_steps = Self::enforce_definition_steps(steps)?;
+
             <T as Config>::GetExtrinsicExtraSource::charge_extrinsic_extra(
                 &MODULE_INDEX,
                 &(ExtrinsicIndex::Definition as u8),
                 &group_account,
             );
 
-            let mut new_steps = vec![];
-            for (name, attestor, threshold) in steps {
-                let bounded_name = enforce_limit!(name);
-                new_steps.push((bounded_name, attestor, threshold));
-            }
-
             let definition_id = next_id!(NextDefinitionId<T>, T);
 
             let definition = Definition {
@@ -546,31 +951,7 @@ pub mod pallet {
 
             <Definitions<T>>::insert(registry_id, definition_id, definition);
 
-            new_steps
-                .into_iter()
-                .enumerate()
-                .for_each(|(index, (name, attestor, threshold))| {
-                    let definition_step = DefinitionStep {
-                        name,
-                        attestor: attestor.clone(),
-                        threshold,
-                    };
-
-                    let definition_step_index =
-                        T::DefinitionStepIndex::unique_saturated_from(index);
-
-                    <DefinitionSteps<T>>::insert(
-                        (registry_id, definition_id),
-                        definition_step_index,
-                        definition_step,
-                    );
-
-                    <DefinitionStepsByAttestor<T>>::insert(
-                        attestor,
-                        (registry_id, definition_id, definition_step_index),
-                        (),
-                    );
-                });
+            Self::insert_definition_steps(registry_id, definition_id, definition_steps);
 
             Self::deposit_event(Event::DefinitionCreated(
                 account_id,
@@ -605,6 +986,30 @@ pub mod pallet {
                 }
             });
 
+            if let Some(previous_definition_id) =
+                <DefinitionPredecessors<T>>::get(registry_id, definition_id)
+            {
+                let mut was_active = false;
+                <Definitions<T>>::mutate_exists(
+                    registry_id,
+                    previous_definition_id,
+                    |maybe_definition| {
+                        if let Some(ref mut definition) = maybe_definition {
+                            was_active = definition.status == DefinitionStatus::Active;
+                            definition.status = DefinitionStatus::Inactive;
+                        }
+                    },
+                );
+                if was_active {
+                    Self::deposit_event(Event::DefinitionSetInactive(
+                        account_id.clone(),
+                        group_account.clone(),
+                        registry_id,
+                        previous_definition_id,
+                    ));
+                }
+            }
+
             Self::deposit_event(Event::DefinitionSetActive(
                 account_id,
                 group_account,
@@ -631,6 +1036,13 @@ pub mod pallet {
                 Error::<T>::NotAuthorized
             );
 
+            let definition =
+                <Definitions<T>>::get(registry_id, definition_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(
+                definition.status != DefinitionStatus::Draft,
+                Error::<T>::IncorrectStatus
+            );
+
             <Definitions<T>>::mutate_exists(registry_id, definition_id, |maybe_definition| {
                 if let Some(ref mut definition) = maybe_definition {
                     definition.status = DefinitionStatus::Inactive;
@@ -675,10 +1087,35 @@ pub mod pallet {
                 Error::<T>::ProcessesExist
             );
 
-            let step_count =
-                <DefinitionSteps<T>>::drain_prefix((registry_id, definition_id)).count() as u32;
+            let step_count = Self::remove_definition_steps(registry_id, definition_id);
 
             <Definitions<T>>::remove(registry_id, definition_id);
+            <DefinitionVisibilities<T>>::remove(registry_id, definition_id);
+
+            let previous_definition_id =
+                <DefinitionPredecessors<T>>::take(registry_id, definition_id);
+            let next_definition_id = <DefinitionSuccessors<T>>::take(registry_id, definition_id);
+            match (previous_definition_id, next_definition_id) {
+                (Some(previous_definition_id), Some(next_definition_id)) => {
+                    <DefinitionSuccessors<T>>::insert(
+                        registry_id,
+                        previous_definition_id,
+                        next_definition_id,
+                    );
+                    <DefinitionPredecessors<T>>::insert(
+                        registry_id,
+                        next_definition_id,
+                        previous_definition_id,
+                    );
+                }
+                (Some(previous_definition_id), None) => {
+                    <DefinitionSuccessors<T>>::remove(registry_id, previous_definition_id);
+                }
+                (None, Some(next_definition_id)) => {
+                    <DefinitionPredecessors<T>>::remove(registry_id, next_definition_id);
+                }
+                (None, None) => (),
+            }
 
             Self::deposit_event(Event::DefinitionRemoved(
                 account_id,
@@ -697,8 +1134,11 @@ pub mod pallet {
         /// - `definition_step_index` index of definition step to be updated
         /// - `attestor` Attestor for the step.
         /// - `threshold` Required threshold if Attestor is a group account else set to 1
+        /// - `attributes` Attributes the attestor is expected to supply for the step
 
-        #[pallet::weight(<T as Config>::WeightInfo::update_definition_step(   ))]
+        #[pallet::weight(<T as Config>::WeightInfo::update_definition_step(
+            attributes.as_ref().map_or(0, |attributes| attributes.len() as u32)
+        ))]
         pub fn update_definition_step(
             origin: OriginFor<T>,
             registry_id: T::RegistryId,
@@ -706,9 +1146,14 @@ pub mod pallet {
             definition_step_index: T::DefinitionStepIndex,
             attestor: Option<T::AccountId>,
             threshold: Option<T::MemberCount>,
+            attributes: Option<Vec<AttributeSchema<Vec<u8>>>>,
         ) -> DispatchResultWithPostInfo {
             let (account_id, group_account) = ensure_account_or_executed!(origin);
 
+            let attributes = attributes
+                .map(Self::enforce_attribute_schemas)
+                .transpose()?;
+
             ensure!(
                 <Registries<T>>::contains_key(&group_account, registry_id),
                 Error::<T>::NotAuthorized
@@ -746,6 +1191,9 @@ pub mod pallet {
                         if let Some(threshold) = threshold {
                             definition_step.threshold = threshold;
                         }
+                        if let Some(attributes) = attributes {
+                            definition_step.attributes = attributes;
+                        }
                     }
                 },
             );
@@ -766,19 +1214,49 @@ pub mod pallet {
         /// - `registry_id` Registry the Definition is in
         /// - `definition_id` Definition the process is related to
         /// - `name` name of the Process
+        /// - `asset` optional asset (asset registry, asset) the Process is about
+        /// - `participants` DIDs of the parties taking part in the Process
+        /// - `location` optional `Fact::Location` where the Process takes place
         #[pallet::weight(<T as Config>::WeightInfo::create_process(
-            name.len() as u32
+            name.len() as u32,
+            participants.len() as u32
         ))]
         pub fn create_process(
             origin: OriginFor<T>,
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
             name: Vec<u8>,
+            asset: Option<(T::AssetRegistryId, T::AssetId)>,
+            participants: Vec<Did>,
+            location: Option<Fact<Vec<u8>>>,
         ) -> DispatchResultWithPostInfo {
             let (account_id, group_account) = ensure_account_or_executed!(origin);
 
             let bounded_name = enforce_limit!(name);
 
+            if let Some((asset_registry_id, asset_id)) = asset {
+                ensure!(
+                    T::AssetInfoSource::asset_exists(asset_registry_id, asset_id),
+                    Error::<T>::AssetNotFound
+                );
+            }
+            let mut sorted = participants.clone();
+            sorted.sort();
+            sorted.dedup();
+            ensure!(
+                sorted.len() == participants.len()
+                    && participants.len() as u32 <= T::ParticipantLimit::get(),
+                Error::<T>::InvalidParticipants
+            );
+            for did in participants.iter() {
+                ensure!(T::DidInfoSource::did_exists(did), Error::<T>::DidNotFound);
+            }
+            let location = match location {
+                Some(Fact::Location(lat, lng)) => Some(Fact::Location(lat, lng)),
+                Some(_) => return Err(Error::<T>::InvalidLocation.into()),
+                None => None,
+            };
+
             let definition = <Definitions<T>>::get(registry_id, definition_id);
             ensure!(definition.is_some(), Error::<T>::NotFound);
 
@@ -806,13 +1284,33 @@ pub mod pallet {
 
             let process_id = next_id!(NextProcessId<T>, T);
 
+            if let Some(asset) = asset {
+                <ProcessesByAsset<T>>::insert(asset, (registry_id, definition_id, process_id), ());
+            }
+            participants.iter().for_each(|did| {
+                <ProcessesByDid<T>>::insert(did, (registry_id, definition_id, process_id), ());
+            });
+
             let process = Process {
                 name: bounded_name,
                 status: ProcessStatus::InProgress,
+                asset,
+                participants,
+                location,
             };
 
             <Processes<T>>::insert((registry_id, definition_id), process_id, process);
 
+            <DefinitionSteps<T>>::iter_prefix((registry_id, definition_id))
+                .filter(|(_, definition_step)| definition_step.predecessors.is_empty())
+                .for_each(|(definition_step_index, definition_step)| {
+                    Self::make_eligible(
+                        (registry_id, definition_id, process_id),
+                        definition_step_index,
+                        definition_step.max_duration,
+                    );
+                });
+
             Self::deposit_event(Event::ProcessCreated(
                 account_id,
                 group_account,
@@ -881,7 +1379,8 @@ pub mod pallet {
         /// - `definition_id` Definition the process is related to
         /// - `process_id` Process to be removed
         #[pallet::weight(<T as Config>::WeightInfo::remove_process(
-            <T as Config>::DefinitionStepLimit::get()
+            <T as Config>::DefinitionStepLimit::get(),
+            <T as Config>::ParticipantLimit::get()
         ))]
         pub fn remove_process(
             origin: OriginFor<T>,
@@ -895,15 +1394,33 @@ pub mod pallet {
                 <Registries<T>>::contains_key(&group_account, registry_id),
                 Error::<T>::NotAuthorized
             );
-            ensure!(
-                <Processes<T>>::contains_key((registry_id, definition_id), process_id),
-                Error::<T>::NotFound
-            );
+            let process = <Processes<T>>::take((registry_id, definition_id), process_id)
+                .ok_or(Error::<T>::NotFound)?;
+
+            if let Some(asset) = process.asset {
+                <ProcessesByAsset<T>>::remove(asset, (registry_id, definition_id, process_id));
+            }
+            let participant_count = process.participants.len() as u32;
+            process.participants.iter().for_each(|did| {
+                <ProcessesByDid<T>>::remove(did, (registry_id, definition_id, process_id));
+            });
 
-            <Processes<T>>::remove((registry_id, definition_id), process_id);
             let step_count =
                 <ProcessSteps<T>>::drain_prefix((registry_id, definition_id, process_id)).count()
                     as u32;
+            <EligibleSteps<T>>::remove_prefix((registry_id, definition_id, process_id));
+            <ProcessStepRejections<T>>::remove_prefix((registry_id, definition_id, process_id));
+            <ProcessStepEvidence<T>>::remove_prefix((registry_id, definition_id, process_id));
+            <ProcessStepAmendments<T>>::remove_prefix((registry_id, definition_id, process_id));
+            <ProcessStepAmendmentCount<T>>::remove_prefix((registry_id, definition_id, process_id));
+
+            let process = (registry_id, definition_id, process_id);
+            <ProcessLinks<T>>::drain_prefix(process).for_each(|(upstream, _)| {
+                <ProcessLinksByUpstream<T>>::remove(upstream, process);
+            });
+            <ProcessLinksByUpstream<T>>::drain_prefix(process).for_each(|(downstream, _)| {
+                <ProcessLinks<T>>::remove(downstream, process);
+            });
 
             Self::deposit_event(Event::ProcessRemoved(
                 account_id,
@@ -912,7 +1429,11 @@ pub mod pallet {
                 definition_id,
                 process_id,
             ));
-            Ok(Some(<T as Config>::WeightInfo::remove_process(step_count)).into())
+            Ok(Some(<T as Config>::WeightInfo::remove_process(
+                step_count,
+                participant_count,
+            ))
+            .into())
         }
 
         /// Attest a process_step - attestors on the step must propose and vote up to the required threshold
@@ -956,19 +1477,15 @@ pub mod pallet {
                 Error::<T>::IncorrectThreshold
             );
 
-            let one = T::DefinitionStepIndex::unique_saturated_from(1u32);
-
+            let process = <Processes<T>>::get((registry_id, definition_id), process_id)
+                .ok_or(Error::<T>::NotFound)?;
             ensure!(
-                definition_step_index == T::DefinitionStepIndex::unique_saturated_from(0u32)
-                    || <ProcessSteps<T>>::contains_key(
-                        (registry_id, definition_id, process_id),
-                        definition_step_index.saturating_sub(one)
-                    ),
-                Error::<T>::ProcessStepNotCurrent
+                process.status == ProcessStatus::InProgress,
+                Error::<T>::IncorrectStatus
             );
 
             ensure!(
-                !<ProcessSteps<T>>::contains_key(
+                <EligibleSteps<T>>::contains_key(
                     (registry_id, definition_id, process_id),
                     definition_step_index
                 ),
@@ -977,6 +1494,8 @@ pub mod pallet {
 
             let attributes = enforce_limit_attributes!(attributes);
 
+            Self::validate_attributes(&definition_step.attributes, &attributes)?;
+
             let process_step = ProcessStep {
                 proposal_id,
                 attributes,
@@ -987,13 +1506,29 @@ pub mod pallet {
                 definition_step_index,
                 process_step,
             );
+            <ProcessStepEvidence<T>>::insert(
+                (registry_id, definition_id, process_id),
+                definition_step_index,
+                AttestationEvidence {
+                    block_number: <frame_system::Module<T>>::block_number(),
+                    extrinsic_index: <frame_system::Module<T>>::extrinsic_index()
+                        .unwrap_or_default(),
+                    timestamp: <timestamp::Module<T>>::get(),
+                },
+            );
 
-            let one = T::DefinitionStepIndex::unique_saturated_from(1u32);
+            Self::update_eligible_steps(
+                registry_id,
+                definition_id,
+                process_id,
+                definition_step_index,
+                &definition_step.predecessors,
+            );
 
-            if !<DefinitionSteps<T>>::contains_key(
-                (registry_id, definition_id),
-                definition_step_index.saturating_add(one),
-            ) {
+            if <EligibleSteps<T>>::iter_prefix((registry_id, definition_id, process_id))
+                .next()
+                .is_none()
+            {
                 <Processes<T>>::mutate_exists(
                     (registry_id, definition_id),
                     process_id,
@@ -1022,142 +1557,925 @@ pub mod pallet {
 
             Ok(().into())
         }
-    }
 
-    impl<T: Config> Module<T> {
-        // -- rpc api functions --
-        pub fn get_registries(
-            account_id: T::AccountId,
-        ) -> Vec<(
-            T::RegistryId,
-            Registry<BoundedVec<u8, <T as Config>::NameLimit>>,
-        )> {
-            let mut registries = Vec::new();
+        /// Create a new version of a definition as an editable draft. The draft is set active with `set_definition_active`.
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` the latest version of the Definition
+        #[pallet::weight(<T as Config>::WeightInfo::new_definition_version(
+            <T as Config>::DefinitionStepLimit::get()
+        ))]
+        pub fn new_definition_version(
+            origin: OriginFor<T>,
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> DispatchResultWithPostInfo {
+            let (account_id, group_account) = ensure_account_or_executed!(origin);
 
-            <Registries<T>>::iter_prefix(account_id)
-                .for_each(|(registry_id, registry)| registries.push((registry_id, registry)));
+            ensure!(
+                <Registries<T>>::contains_key(&group_account, registry_id),
+                Error::<T>::NotAuthorized
+            );
+            let definition =
+                <Definitions<T>>::get(registry_id, definition_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(
+                definition.status != DefinitionStatus::Draft,
+                Error::<T>::IncorrectStatus
+            );
+            ensure!(
+                !<DefinitionSuccessors<T>>::contains_key(registry_id, definition_id),
+                Error::<T>::NotLatestVersion
+            );
 
-            registries
-        }
-        pub fn get_registry(
-            account_id: T::AccountId,
-            registry_id: T::RegistryId,
-        ) -> Option<Registry<BoundedVec<u8, <T as Config>::NameLimit>>> {
-            <Registries<T>>::get(account_id, registry_id)
-        }
+            <T as Config>::GetExtrinsicExtraSource::charge_extrinsic_extra(
+                &MODULE_INDEX,
+                &(ExtrinsicIndex::Definition as u8),
+                &group_account,
+            );
 
-        pub fn get_definitions(
-            registry_id: T::RegistryId,
-        ) -> Vec<(
-            T::DefinitionId,
-            Definition<BoundedVec<u8, <T as Config>::NameLimit>>,
-        )> {
-            let mut definitions = Vec::new();
+            let new_definition_id = next_id!(NextDefinitionId<T>, T);
 
-            <Definitions<T>>::iter_prefix(registry_id).for_each(|(definition_id, definition)| {
-                definitions.push((definition_id, definition))
-            });
+            <Definitions<T>>::insert(
+                registry_id,
+                new_definition_id,
+                Definition {
+                    name: definition.name,
+                    status: DefinitionStatus::Draft,
+                },
+            );
 
-            definitions
-        }
-        pub fn get_definition(
-            registry_id: T::RegistryId,
-            definition_id: T::DefinitionId,
-        ) -> Option<Definition<BoundedVec<u8, <T as Config>::NameLimit>>> {
-            <Definitions<T>>::get(registry_id, definition_id)
+            let definition_steps = Self::get_definition_steps(registry_id, definition_id)
+                .into_iter()
+                .map(|(_, definition_step)| definition_step)
+                .collect::<Vec<_>>();
+            let step_count = definition_steps.len() as u32;
+            Self::insert_definition_steps(registry_id, new_definition_id, definition_steps);
+
+            if let Some(visibility) = <DefinitionVisibilities<T>>::get(registry_id, definition_id) {
+                <DefinitionVisibilities<T>>::insert(registry_id, new_definition_id, visibility);
+            }
+
+            <DefinitionPredecessors<T>>::insert(registry_id, new_definition_id, definition_id);
+            <DefinitionSuccessors<T>>::insert(registry_id, definition_id, new_definition_id);
+
+            Self::deposit_event(Event::DefinitionVersionCreated(
+                account_id,
+                group_account,
+                registry_id,
+                new_definition_id,
+                definition_id,
+            ));
+            Ok(Some(<T as Config>::WeightInfo::new_definition_version(
+                step_count,
+            ))
+            .into())
         }
 
-        pub fn get_definition_step(
+        /// Replace the steps of a draft definition
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` the draft Definition
+        /// - `steps` (name, attestor, threshold, predecessors, join, attributes, max_duration) of each step, as for `create_definition`
+        #[pallet::weight(<T as Config>::WeightInfo::update_definition_draft(
+            steps.len() as u32,
+            get_max_step_name::<T::AccountId,T::MemberCount,T::DefinitionStepIndex,T::BlockNumber>(steps) as u32,
+            <T as Config>::DefinitionStepLimit::get()
+        ))]
+        pub fn update_definition_draft(
+            origin: OriginFor<T>,
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
-            step_index: T::DefinitionStepIndex,
-        ) -> Option<
-            DefinitionStep<T::AccountId, T::MemberCount, BoundedVec<u8, <T as Config>::NameLimit>>,
-        > {
-            <DefinitionSteps<T>>::get((registry_id, definition_id), step_index)
+            steps: Vec<(
+                Vec<u8>,
+                T::AccountId,
+                T::MemberCount,
+                Vec<T::DefinitionStepIndex>,
+                JoinRule,
+                Vec<AttributeSchema<Vec<u8>>>,
+                Option<T::BlockNumber>,
+            )>,
+        ) -> DispatchResultWithPostInfo {
+            let (account_id, group_account) = ensure_account_or_executed!(origin);
+
+            ensure!(
+                <Registries<T>>::contains_key(&group_account, registry_id),
+                Error::<T>::NotAuthorized
+            );
+            let definition =
+                <Definitions<T>>::get(registry_id, definition_id).ok_or(Error::<T>::NotFound)?;
+            ensure!(
+                definition.status == DefinitionStatus::Draft,
+                Error::<T>::IncorrectStatus
+            );
+
+            let step_count = steps.len() as u32;
+            let max_step_name = get_max_step_name::<
+                T::AccountId,
+                T::MemberCount,
+                T::DefinitionStepIndex,
+                T::BlockNumber,
+            >(&steps);
+            let definition_steps = Self::enforce_definition_steps(steps)?;
+
+            let removed_step_count = Self::remove_definition_steps(registry_id, definition_id);
+            Self::insert_definition_steps(registry_id, definition_id, definition_steps);
+
+            Self::deposit_event(Event::DefinitionDraftUpdated(
+                account_id,
+                group_account,
+                registry_id,
+                definition_id,
+            ));
+            Ok(Some(<T as Config>::WeightInfo::update_definition_draft(
+                step_count,
+                max_step_name,
+                removed_step_count,
+            ))
+            .into())
         }
 
-        pub fn get_definition_steps(
+        /// Link a process to the processes whose lots went into it
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` Definition the process is related to
+        /// - `process_id` the downstream Process
+        /// - `links` the upstream Processes (registry_id, definition_id, process_id) with the kind of link and the quantity used.
+        ///   A process may be linked to at most `ProcessLinkLimit` upstream processes, and be upstream of at most as many.
+        ///   The caller must be the registry owner or an attestor of the definitions of both the downstream and the upstream processes.
+        #[pallet::weight(<T as Config>::WeightInfo::link_processes(links.len() as u32))]
+        pub fn link_processes(
+            origin: OriginFor<T>,
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
-        ) -> Vec<(
-            T::DefinitionStepIndex,
-            DefinitionStep<T::AccountId, T::MemberCount, BoundedVec<u8, <T as Config>::NameLimit>>,
-        )> {
-            let mut definition_steps = Vec::new();
-            <DefinitionSteps<T>>::iter_prefix((registry_id, definition_id)).for_each(
-                |(step_index, definition_step)| {
-                    definition_steps.push((step_index, definition_step))
-                },
+            process_id: T::ProcessId,
+            links: Vec<(
+                (T::RegistryId, T::DefinitionId, T::ProcessId),
+                ProcessLink<Vec<u8>>,
+            )>,
+        ) -> DispatchResultWithPostInfo {
+            let (account_id, group_account) = ensure_account_or_executed!(origin);
+
+            ensure!(
+                Self::can_view_definition(group_account.clone(), registry_id, definition_id),
+                Error::<T>::NotAuthorized
+            );
+            ensure!(
+                <Processes<T>>::contains_key((registry_id, definition_id), process_id),
+                Error::<T>::NotFound
             );
-            definition_steps.sort_by(|(step_index_a, _), (step_index_b, _)| {
-                step_index_a.partial_cmp(step_index_b).unwrap()
-            });
 
-            definition_steps
-        }
+            let downstream = (registry_id, definition_id, process_id);
+            let link_limit = T::ProcessLinkLimit::get() as usize;
+            let mut upstream_count = <ProcessLinks<T>>::iter_prefix(downstream).count();
+            let mut bounded_links = Vec::new();
+            for (upstream, link) in links {
+                ensure!(upstream != downstream, Error::<T>::InvalidProcessLink);
+                let (upstream_registry_id, upstream_definition_id, upstream_process_id) = upstream;
+                // the upstream side must also agree to its lots being used downstream
+                ensure!(
+                    Self::can_view_definition(
+                        group_account.clone(),
+                        upstream_registry_id,
+                        upstream_definition_id
+                    ),
+                    Error::<T>::NotAuthorized
+                );
+                ensure!(
+                    <Processes<T>>::contains_key(
+                        (upstream_registry_id, upstream_definition_id),
+                        upstream_process_id
+                    ),
+                    Error::<T>::NotFound
+                );
+                if !<ProcessLinks<T>>::contains_key(downstream, upstream) {
+                    upstream_count += 1;
+                    ensure!(
+                        upstream_count <= link_limit
+                            && <ProcessLinksByUpstream<T>>::iter_prefix(upstream).count()
+                                < link_limit,
+                        Error::<T>::TooManyProcessLinks
+                    );
+                }
+                bounded_links.push((
+                    upstream,
+                    ProcessLink {
+                        kind: link.kind,
+                        quantity: link.quantity,
+                        unit: enforce_limit!(link.unit),
+                    },
+                ));
+            }
 
-        pub fn get_available_definitions(
-            account_id: T::AccountId,
-        ) -> Vec<(
-            T::RegistryId,
-            T::DefinitionId,
-            Definition<BoundedVec<u8, <T as Config>::NameLimit>>,
-        )> {
-            let mut definitions = Vec::new();
-            <DefinitionStepsByAttestor<T>>::iter_prefix(account_id).for_each(
-                |((registry_id, definition_id, step_index), _)| {
-                    if step_index == T::DefinitionStepIndex::unique_saturated_from(0u32) {
-                        let maybe_definition = <Definitions<T>>::get(registry_id, definition_id);
-                        if let Some(definition) = maybe_definition {
-                            if !definitions.iter().any(|(r_id, d_id, _)| {
-                                *r_id == registry_id && *d_id == definition_id
-                            }) {
-                                definitions.push((registry_id, definition_id, definition));
-                            }
-                        }
-                    }
-                },
-            );
-            definitions
+            for (upstream, link) in bounded_links {
+                <ProcessLinks<T>>::insert(downstream, upstream, link);
+                <ProcessLinksByUpstream<T>>::insert(upstream, downstream, ());
+
+                Self::deposit_event(Event::ProcessLinked(
+                    account_id.clone(),
+                    group_account.clone(),
+                    registry_id,
+                    definition_id,
+                    process_id,
+                    upstream,
+                ));
+            }
+
+            Ok(().into())
         }
 
-        pub fn get_processes(
+        /// Remove a link between a process and an upstream process
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` Definition the process is related to
+        /// - `process_id` the downstream Process
+        /// - `upstream` the upstream Process (registry_id, definition_id, process_id)
+        #[pallet::weight(<T as Config>::WeightInfo::unlink_process())]
+        pub fn unlink_process(
+            origin: OriginFor<T>,
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
-        ) -> Vec<(
-            T::ProcessId,
-            Process<BoundedVec<u8, <T as Config>::NameLimit>>,
-        )> {
-            let mut processes = Vec::new();
+            process_id: T::ProcessId,
+            upstream: (T::RegistryId, T::DefinitionId, T::ProcessId),
+        ) -> DispatchResultWithPostInfo {
+            let (account_id, group_account) = ensure_account_or_executed!(origin);
 
-            <Processes<T>>::iter_prefix((registry_id, definition_id))
-                .for_each(|(process_id, process)| processes.push((process_id, process)));
+            ensure!(
+                Self::can_view_definition(group_account.clone(), registry_id, definition_id),
+                Error::<T>::NotAuthorized
+            );
+            let downstream = (registry_id, definition_id, process_id);
+            ensure!(
+                <ProcessLinks<T>>::contains_key(downstream, upstream),
+                Error::<T>::NotFound
+            );
 
-            processes
+            <ProcessLinks<T>>::remove(downstream, upstream);
+            <ProcessLinksByUpstream<T>>::remove(upstream, downstream);
+
+            Self::deposit_event(Event::ProcessUnlinked(
+                account_id,
+                group_account,
+                registry_id,
+                definition_id,
+                process_id,
+                upstream,
+            ));
+            Ok(().into())
         }
-        pub fn get_process(
+
+        /// Reject a process_step - attestors on the step must propose and vote up to the required threshold
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` Definition the process is related to
+        /// - `process_id` the Process
+        /// - `definition_step_index` index of step to be rejected
+        /// - `reason` why the step was rejected
+        /// - `attributes` the attributes found, e.g. the failed lab results
+        /// - `status` `Rejected`, or `OnHold` if the process may be resumed after re-inspection
+        #[pallet::weight(<T as Config>::WeightInfo::reject_process_step(
+            attributes.len() as u32,
+            get_max_attribute_name_len(attributes),
+            get_max_attribute_fact_len(attributes),
+            reason.len() as u32,
+        ))]
+        pub fn reject_process_step(
+            origin: OriginFor<T>,
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
             process_id: T::ProcessId,
-        ) -> Option<Process<BoundedVec<u8, <T as Config>::NameLimit>>> {
-            <Processes<T>>::get((registry_id, definition_id), process_id)
-        }
-        /// Processes where an account is the attestor on at least one step and the specified status
-        pub fn get_processes_for_attestor_by_status(
-            account_id: T::AccountId,
+            definition_step_index: T::DefinitionStepIndex,
+            reason: Vec<u8>,
+            attributes: Vec<Attribute<Vec<u8>, Vec<u8>>>,
             status: ProcessStatus,
-        ) -> Vec<(
-            T::RegistryId,
-            T::DefinitionId,
-            T::ProcessId,
-            Process<BoundedVec<u8, <T as Config>::NameLimit>>,
-        )> {
-            let mut definitions = Vec::new();
-            <DefinitionStepsByAttestor<T>>::iter_prefix(account_id).for_each(
-                |((registry_id, definition_id, _), _)| {
-                    if !definitions
-                        .iter()
+        ) -> DispatchResultWithPostInfo {
+            let either = T::GroupsOriginAccountOrApproved::ensure_origin(origin)?;
+            let (sender, yes_votes, proposal_id) = match either {
+                Either::Left(account_id) => (account_id, None, None),
+                Either::Right((_, proposal_id, yes_votes, _, group_account)) => {
+                    (group_account, yes_votes, Some(proposal_id))
+                }
+            };
+
+            ensure!(
+                status == ProcessStatus::Rejected || status == ProcessStatus::OnHold,
+                Error::<T>::IncorrectStatus
+            );
+
+            let definition_step =
+                <DefinitionSteps<T>>::get((registry_id, definition_id), definition_step_index)
+                    .ok_or(Error::<T>::NotFound)?;
+
+            ensure!(definition_step.attestor == sender, Error::<T>::NotAttestor);
+
+            ensure!(
+                yes_votes.is_none() || yes_votes.unwrap() >= definition_step.threshold,
+                Error::<T>::IncorrectThreshold
+            );
+
+            let process = <Processes<T>>::get((registry_id, definition_id), process_id)
+                .ok_or(Error::<T>::NotFound)?;
+            ensure!(
+                process.status == ProcessStatus::InProgress,
+                Error::<T>::IncorrectStatus
+            );
+
+            ensure!(
+                <EligibleSteps<T>>::contains_key(
+                    (registry_id, definition_id, process_id),
+                    definition_step_index
+                ),
+                Error::<T>::ProcessStepNotCurrent
+            );
+
+            let reason: BoundedVec<u8, <T as Config>::FactStringLimit> = reason
+                .try_into()
+                .map_err(|_| Error::<T>::StringLengthLimitExceeded)?;
+            let attributes = enforce_limit_attributes!(attributes);
+
+            <ProcessStepRejections<T>>::insert(
+                (registry_id, definition_id, process_id),
+                definition_step_index,
+                ProcessStepRejection {
+                    proposal_id,
+                    reason,
+                    attributes,
+                },
+            );
+
+            <Processes<T>>::mutate_exists(
+                (registry_id, definition_id),
+                process_id,
+                |maybe_process| {
+                    if let Some(ref mut process) = maybe_process {
+                        process.status = status.clone();
+                    }
+                },
+            );
+
+            Self::deposit_event(Event::ProcessStepRejected(
+                sender,
+                registry_id,
+                definition_id,
+                process_id,
+                definition_step_index,
+                status,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Resume a rejected or on hold process so that its eligible steps can be attested again.
+        /// The definition creator may resume any rejected or on hold process,
+        /// the attestor of a rejected step only a process it put on hold, with the threshold of that step when proposed by its group.
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` Definition the process is related to
+        /// - `process_id` the Process
+        #[pallet::weight(<T as Config>::WeightInfo::resume_process(
+            <T as Config>::DefinitionStepLimit::get()
+        ))]
+        pub fn resume_process(
+            origin: OriginFor<T>,
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+        ) -> DispatchResultWithPostInfo {
+            let either = T::GroupsOriginAccountOrApproved::ensure_origin(origin)?;
+            let (group_account, yes_votes) = match either {
+                Either::Left(account_id) => (account_id, None),
+                Either::Right((_, _, yes_votes, _, group_account)) => (group_account, yes_votes),
+            };
+
+            let process = <Processes<T>>::get((registry_id, definition_id), process_id)
+                .ok_or(Error::<T>::NotFound)?;
+
+            let mut rejection_count = 0;
+            if !<Registries<T>>::contains_key(&group_account, registry_id) {
+                ensure!(
+                    process.status == ProcessStatus::OnHold,
+                    Error::<T>::NotAuthorized
+                );
+                let mut is_attestor = false;
+                let mut meets_threshold = false;
+                <ProcessStepRejections<T>>::iter_prefix((registry_id, definition_id, process_id))
+                    .filter(|(step_index, _)| {
+                        !<ProcessSteps<T>>::contains_key(
+                            (registry_id, definition_id, process_id),
+                            step_index,
+                        )
+                    })
+                    .for_each(|(step_index, _)| {
+                        rejection_count += 1;
+                        if let Some(definition_step) =
+                            <DefinitionSteps<T>>::get((registry_id, definition_id), step_index)
+                        {
+                            if definition_step.attestor == group_account {
+                                is_attestor = true;
+                                meets_threshold = meets_threshold
+                                    || yes_votes.map_or(true, |yes_votes| {
+                                        yes_votes >= definition_step.threshold
+                                    });
+                            }
+                        }
+                    });
+                ensure!(is_attestor, Error::<T>::NotAuthorized);
+                ensure!(meets_threshold, Error::<T>::IncorrectThreshold);
+            }
+
+            ensure!(
+                process.status == ProcessStatus::Rejected
+                    || process.status == ProcessStatus::OnHold,
+                Error::<T>::IncorrectStatus
+            );
+
+            <Processes<T>>::mutate_exists(
+                (registry_id, definition_id),
+                process_id,
+                |maybe_process| {
+                    if let Some(ref mut process) = maybe_process {
+                        process.status = ProcessStatus::InProgress;
+                    }
+                },
+            );
+
+            // The clock on eligible steps restarts once the process is back in progress
+            <EligibleSteps<T>>::iter_prefix((registry_id, definition_id, process_id))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .for_each(|(step_index, _)| {
+                    let max_duration =
+                        <DefinitionSteps<T>>::get((registry_id, definition_id), step_index)
+                            .and_then(|definition_step| definition_step.max_duration);
+                    Self::make_eligible(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                        max_duration,
+                    );
+                });
+
+            Self::deposit_event(Event::ProcessResumed(
+                group_account.clone(),
+                group_account,
+                registry_id,
+                definition_id,
+                process_id,
+            ));
+            Ok(Some(<T as Config>::WeightInfo::resume_process(rejection_count)).into())
+        }
+
+        /// Set what consumers may see of the processes of a definition without an account
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` the Definition
+        /// - `visibility` the step attributes shown publicly and whether the location of a process is shown, or `None` to make the definition private
+        #[pallet::weight(<T as Config>::WeightInfo::set_definition_visibility(
+            visibility.as_ref().map_or(0, |visibility| visibility.public_attributes.len() as u32)
+        ))]
+        pub fn set_definition_visibility(
+            origin: OriginFor<T>,
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            visibility: Option<DefinitionVisibility<Vec<u8>>>,
+        ) -> DispatchResultWithPostInfo {
+            let (account_id, group_account) = ensure_account_or_executed!(origin);
+
+            ensure!(
+                <Registries<T>>::contains_key(&group_account, registry_id),
+                Error::<T>::NotAuthorized
+            );
+            ensure!(
+                <Definitions<T>>::contains_key(registry_id, definition_id),
+                Error::<T>::NotFound
+            );
+
+            match visibility {
+                Some(visibility) => {
+                    ensure!(
+                        visibility.public_attributes.len() as u32
+                            <= <T as Config>::AttributeLimit::get()
+                                * <T as Config>::DefinitionStepLimit::get(),
+                        Error::<T>::InvalidVisibility
+                    );
+                    let mut public_attributes = Vec::new();
+                    for name in visibility.public_attributes.into_iter() {
+                        let name = enforce_limit!(name);
+                        ensure!(
+                            !public_attributes.contains(&name),
+                            Error::<T>::InvalidVisibility
+                        );
+                        public_attributes.push(name);
+                    }
+                    <DefinitionVisibilities<T>>::insert(
+                        registry_id,
+                        definition_id,
+                        DefinitionVisibility {
+                            public_attributes,
+                            show_location: visibility.show_location,
+                        },
+                    );
+                }
+                None => <DefinitionVisibilities<T>>::remove(registry_id, definition_id),
+            }
+
+            Self::deposit_event(Event::DefinitionVisibilityUpdated(
+                account_id,
+                group_account,
+                registry_id,
+                definition_id,
+            ));
+            Ok(().into())
+        }
+
+        /// Amend an attested process_step - attestors on the step must propose and vote up to the required threshold
+        ///
+        /// Arguments:
+        /// - `registry_id` Registry the Definition is in
+        /// - `definition_id` Definition the process is related to
+        /// - `process_id` the Process
+        /// - `definition_step_index` index of the attested step
+        /// - `reason` why the step is amended, e.g. a weight was mistyped
+        /// - `attributes` the corrected attributes, replacing those currently recorded for the step. A step may be amended at most `AmendmentLimit` times.
+        #[pallet::weight(<T as Config>::WeightInfo::amend_process_step(
+            attributes.len() as u32,
+            get_max_attribute_name_len(attributes),
+            get_max_attribute_fact_len(attributes),
+            reason.len() as u32,
+        ))]
+        pub fn amend_process_step(
+            origin: OriginFor<T>,
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+            definition_step_index: T::DefinitionStepIndex,
+            reason: Vec<u8>,
+            attributes: Vec<Attribute<Vec<u8>, Vec<u8>>>,
+        ) -> DispatchResultWithPostInfo {
+            let either = T::GroupsOriginAccountOrApproved::ensure_origin(origin)?;
+            let (sender, yes_votes, proposal_id) = match either {
+                Either::Left(account_id) => (account_id, None, None),
+                Either::Right((_, proposal_id, yes_votes, _, group_account)) => {
+                    (group_account, yes_votes, Some(proposal_id))
+                }
+            };
+
+            let definition_step =
+                <DefinitionSteps<T>>::get((registry_id, definition_id), definition_step_index)
+                    .ok_or(Error::<T>::NotFound)?;
+
+            ensure!(definition_step.attestor == sender, Error::<T>::NotAttestor);
+
+            ensure!(
+                yes_votes.is_none() || yes_votes.unwrap() >= definition_step.threshold,
+                Error::<T>::IncorrectThreshold
+            );
+
+            ensure!(
+                <Processes<T>>::contains_key((registry_id, definition_id), process_id),
+                Error::<T>::NotFound
+            );
+            ensure!(
+                <ProcessSteps<T>>::contains_key(
+                    (registry_id, definition_id, process_id),
+                    definition_step_index
+                ),
+                Error::<T>::ProcessStepNotAttested
+            );
+
+            let reason: BoundedVec<u8, <T as Config>::FactStringLimit> = reason
+                .try_into()
+                .map_err(|_| Error::<T>::StringLengthLimitExceeded)?;
+            let attributes = enforce_limit_attributes!(attributes);
+
+            Self::validate_attributes(&definition_step.attributes, &attributes)?;
+
+            let amendment_number = <ProcessStepAmendmentCount<T>>::get(
+                (registry_id, definition_id, process_id),
+                definition_step_index,
+            );
+            ensure!(
+                amendment_number < T::AmendmentLimit::get(),
+                Error::<T>::TooManyAmendments
+            );
+
+            <ProcessStepAmendmentCount<T>>::insert(
+                (registry_id, definition_id, process_id),
+                definition_step_index,
+                amendment_number + 1,
+            );
+            <ProcessStepAmendments<T>>::insert(
+                (registry_id, definition_id, process_id),
+                (definition_step_index, amendment_number),
+                ProcessStepAmendment {
+                    proposal_id,
+                    reason,
+                    attributes,
+                    evidence: AttestationEvidence {
+                        block_number: <frame_system::Module<T>>::block_number(),
+                        extrinsic_index: <frame_system::Module<T>>::extrinsic_index()
+                            .unwrap_or_default(),
+                        timestamp: <timestamp::Module<T>>::get(),
+                    },
+                },
+            );
+
+            Self::deposit_event(Event::ProcessStepAmended(
+                sender,
+                registry_id,
+                definition_id,
+                process_id,
+                definition_step_index,
+            ));
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> ProcessInfo for Module<T> {
+        type RegistryId = T::RegistryId;
+        type DefinitionId = T::DefinitionId;
+        type ProcessId = T::ProcessId;
+
+        fn process_exists(
+            registry_id: Self::RegistryId,
+            definition_id: Self::DefinitionId,
+            process_id: Self::ProcessId,
+        ) -> bool {
+            <Processes<T>>::contains_key((registry_id, definition_id), process_id)
+        }
+    }
+
+    impl<T: Config> Module<T> {
+        // -- rpc api functions --
+        pub fn get_registries(
+            account_id: T::AccountId,
+        ) -> Vec<(
+            T::RegistryId,
+            Registry<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            let mut registries = Vec::new();
+
+            <Registries<T>>::iter_prefix(account_id)
+                .for_each(|(registry_id, registry)| registries.push((registry_id, registry)));
+
+            registries
+        }
+        pub fn get_registry(
+            account_id: T::AccountId,
+            registry_id: T::RegistryId,
+        ) -> Option<Registry<BoundedVec<u8, <T as Config>::NameLimit>>> {
+            <Registries<T>>::get(account_id, registry_id)
+        }
+
+        pub fn get_definitions(
+            registry_id: T::RegistryId,
+        ) -> Vec<(
+            T::DefinitionId,
+            Definition<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            let mut definitions = Vec::new();
+
+            <Definitions<T>>::iter_prefix(registry_id).for_each(|(definition_id, definition)| {
+                definitions.push((definition_id, definition))
+            });
+
+            definitions
+        }
+        pub fn get_definition(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> Option<Definition<BoundedVec<u8, <T as Config>::NameLimit>>> {
+            <Definitions<T>>::get(registry_id, definition_id)
+        }
+
+        pub fn get_definition_step(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            step_index: T::DefinitionStepIndex,
+        ) -> Option<
+            DefinitionStep<
+                T::AccountId,
+                T::MemberCount,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                T::DefinitionStepIndex,
+                T::BlockNumber,
+            >,
+        > {
+            <DefinitionSteps<T>>::get((registry_id, definition_id), step_index)
+        }
+
+        pub fn get_definition_steps(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> Vec<(
+            T::DefinitionStepIndex,
+            DefinitionStep<
+                T::AccountId,
+                T::MemberCount,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                T::DefinitionStepIndex,
+                T::BlockNumber,
+            >,
+        )> {
+            let mut definition_steps = Vec::new();
+            <DefinitionSteps<T>>::iter_prefix((registry_id, definition_id)).for_each(
+                |(step_index, definition_step)| {
+                    definition_steps.push((step_index, definition_step))
+                },
+            );
+            definition_steps.sort_by(|(step_index_a, _), (step_index_b, _)| {
+                step_index_a.partial_cmp(step_index_b).unwrap()
+            });
+
+            definition_steps
+        }
+
+        /// All versions of a definition, oldest first
+        pub fn get_definition_versions(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> Vec<(
+            T::DefinitionId,
+            Definition<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            let mut first_definition_id = definition_id;
+            while let Some(previous_definition_id) =
+                <DefinitionPredecessors<T>>::get(registry_id, first_definition_id)
+            {
+                first_definition_id = previous_definition_id;
+            }
+
+            let mut definitions = Vec::new();
+            let mut next_definition_id = Some(first_definition_id);
+            while let Some(definition_id) = next_definition_id {
+                if let Some(definition) = <Definitions<T>>::get(registry_id, definition_id) {
+                    definitions.push((definition_id, definition));
+                }
+                next_definition_id = <DefinitionSuccessors<T>>::get(registry_id, definition_id);
+            }
+            definitions
+        }
+
+        pub fn get_available_definitions(
+            account_id: T::AccountId,
+        ) -> Vec<(
+            T::RegistryId,
+            T::DefinitionId,
+            Definition<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            let mut definitions = Vec::new();
+            <DefinitionStepsByAttestor<T>>::iter_prefix(account_id).for_each(
+                |((registry_id, definition_id, step_index), _)| {
+                    if step_index == T::DefinitionStepIndex::unique_saturated_from(0u32) {
+                        let maybe_definition = <Definitions<T>>::get(registry_id, definition_id);
+                        if let Some(definition) = maybe_definition {
+                            if !definitions.iter().any(|(r_id, d_id, _)| {
+                                *r_id == registry_id && *d_id == definition_id
+                            }) {
+                                definitions.push((registry_id, definition_id, definition));
+                            }
+                        }
+                    }
+                },
+            );
+            definitions
+        }
+
+        pub fn get_processes(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> Vec<(
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut processes = Vec::new();
+
+            <Processes<T>>::iter_prefix((registry_id, definition_id))
+                .for_each(|(process_id, process)| processes.push((process_id, process)));
+
+            processes
+        }
+
+        /// Processes based on a definition with the specified status
+        pub fn get_processes_by_status(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            status: ProcessStatus,
+        ) -> Vec<(
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut processes = Vec::new();
+
+            <Processes<T>>::iter_prefix((registry_id, definition_id))
+                .filter(|(_, process)| process.status == status)
+                .for_each(|(process_id, process)| processes.push((process_id, process)));
+
+            processes
+        }
+
+        /// Processes created on any version of a definition
+        pub fn get_processes_all_versions(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> Vec<(
+            T::DefinitionId,
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut processes = Vec::new();
+
+            Self::get_definition_versions(registry_id, definition_id)
+                .into_iter()
+                .for_each(|(definition_id, _)| {
+                    <Processes<T>>::iter_prefix((registry_id, definition_id)).for_each(
+                        |(process_id, process)| {
+                            processes.push((definition_id, process_id, process))
+                        },
+                    );
+                });
+
+            processes
+        }
+
+        /// Processes that concern an asset
+        pub fn get_processes_for_asset(
+            asset_registry_id: T::AssetRegistryId,
+            asset_id: T::AssetId,
+        ) -> Vec<(
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut processes = Vec::new();
+
+            <ProcessesByAsset<T>>::iter_prefix((asset_registry_id, asset_id)).for_each(
+                |((registry_id, definition_id, process_id), _)| {
+                    if let Some(process) =
+                        <Processes<T>>::get((registry_id, definition_id), process_id)
+                    {
+                        processes.push((registry_id, definition_id, process_id, process));
+                    }
+                },
+            );
+
+            processes
+        }
+
+        /// Processes a DID takes part in
+        pub fn get_processes_for_did(
+            did: Did,
+        ) -> Vec<(
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut processes = Vec::new();
+
+            <ProcessesByDid<T>>::iter_prefix(did).for_each(
+                |((registry_id, definition_id, process_id), _)| {
+                    if let Some(process) =
+                        <Processes<T>>::get((registry_id, definition_id), process_id)
+                    {
+                        processes.push((registry_id, definition_id, process_id, process));
+                    }
+                },
+            );
+
+            processes
+        }
+
+        pub fn get_process(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+        ) -> Option<Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>>
+        {
+            <Processes<T>>::get((registry_id, definition_id), process_id)
+        }
+        /// Processes where an account is the attestor on at least one step and the specified status
+        pub fn get_processes_for_attestor_by_status(
+            account_id: T::AccountId,
+            status: ProcessStatus,
+        ) -> Vec<(
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut definitions = Vec::new();
+            <DefinitionStepsByAttestor<T>>::iter_prefix(account_id).for_each(
+                |((registry_id, definition_id, _), _)| {
+                    if !definitions
+                        .iter()
                         .any(|(r_id, d_id)| *r_id == registry_id && *d_id == definition_id)
                     {
                         definitions.push((registry_id, definition_id));
@@ -1180,19 +2498,70 @@ pub mod pallet {
             processes
         }
 
-        /// Processes where an account is the attestor on the step that is pending
-        pub fn get_processes_for_attestor_pending(
+        /// Processes where an account is the attestor on the step that is pending
+        pub fn get_processes_for_attestor_pending(
+            account_id: T::AccountId,
+        ) -> Vec<(
+            T::RegistryId,
+            T::DefinitionId,
+            T::ProcessId,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
+        )> {
+            let mut definition_steps = Vec::new();
+            <DefinitionStepsByAttestor<T>>::iter_prefix(account_id).for_each(
+                |((registry_id, definition_id, step_index), _)| {
+                    definition_steps.push((registry_id, definition_id, step_index));
+                },
+            );
+
+            let mut processes = Vec::new();
+
+            definition_steps
+                .iter()
+                .for_each(|(registry_id, definition_id, step_index)| {
+                    <Processes<T>>::iter_prefix((registry_id, definition_id)).for_each(
+                        |(process_id, process)| {
+                            if process.status == ProcessStatus::InProgress
+                                && <EligibleSteps<T>>::contains_key(
+                                    (registry_id, definition_id, process_id),
+                                    step_index,
+                                )
+                            {
+                                processes.push((*registry_id, *definition_id, process_id, process))
+                            }
+                        },
+                    );
+                });
+
+            processes
+        }
+
+        /// Processes where an account is the attestor on an eligible step that has been eligible for longer than the step's maximum duration
+        pub fn get_overdue_processes(
             account_id: T::AccountId,
         ) -> Vec<(
             T::RegistryId,
             T::DefinitionId,
             T::ProcessId,
-            Process<BoundedVec<u8, <T as Config>::NameLimit>>,
+            Process<BoundedVec<u8, <T as Config>::NameLimit>, T::AssetRegistryId, T::AssetId>,
         )> {
-            let mut definition_steps = Vec::new();
+            let now = <frame_system::Module<T>>::block_number();
+            // Steps with a maximum duration by definition, so each process is visited once
+            let mut definition_steps: BTreeMap<
+                (T::RegistryId, T::DefinitionId),
+                Vec<(T::DefinitionStepIndex, T::BlockNumber)>,
+            > = BTreeMap::new();
             <DefinitionStepsByAttestor<T>>::iter_prefix(account_id).for_each(
                 |((registry_id, definition_id, step_index), _)| {
-                    definition_steps.push((registry_id, definition_id, step_index));
+                    if let Some(max_duration) =
+                        <DefinitionSteps<T>>::get((registry_id, definition_id), step_index)
+                            .and_then(|definition_step| definition_step.max_duration)
+                    {
+                        definition_steps
+                            .entry((registry_id, definition_id))
+                            .or_default()
+                            .push((step_index, max_duration));
+                    }
                 },
             );
 
@@ -1200,79 +2569,310 @@ pub mod pallet {
 
             definition_steps
                 .iter()
-                .for_each(|(registry_id, definition_id, step_index)| {
-                    <Processes<T>>::iter_prefix((registry_id, definition_id)).for_each(
-                        |(process_id, process)| {
-                            #[allow(clippy::collapsible_if)]
-                            if process.status == ProcessStatus::InProgress {
-                                if !<ProcessSteps<T>>::contains_key(
+                .for_each(|((registry_id, definition_id), steps)| {
+                    <Processes<T>>::iter_prefix((registry_id, definition_id))
+                        .filter(|(_, process)| process.status == ProcessStatus::InProgress)
+                        .for_each(|(process_id, process)| {
+                            let is_overdue = steps.iter().any(|(step_index, max_duration)| {
+                                <EligibleSteps<T>>::get(
                                     (registry_id, definition_id, process_id),
                                     step_index,
-                                ) && (*step_index
-                                    == T::DefinitionStepIndex::unique_saturated_from(0u32)
-                                    || <ProcessSteps<T>>::contains_key(
-                                        (registry_id, definition_id, process_id),
-                                        step_index.saturating_sub(
-                                            T::DefinitionStepIndex::unique_saturated_from(1u32),
-                                        ),
-                                    ))
-                                {
-                                    processes.push((
-                                        *registry_id,
-                                        *definition_id,
-                                        process_id,
-                                        process,
-                                    ))
-                                }
+                                )
+                                .map_or(false, |eligible_since| {
+                                    Self::is_overdue(eligible_since, Some(*max_duration), now)
+                                })
+                            });
+                            if is_overdue {
+                                processes.push((*registry_id, *definition_id, process_id, process))
                             }
-                        },
-                    );
+                        });
                 });
 
             processes
         }
 
+        /// The status of every step of the definition for a process, with the data of attested steps as corrected by their latest amendment
         pub fn get_process_steps(
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
             process_id: T::ProcessId,
-        ) -> Vec<
-            ProcessStep<
-                T::ProposalId,
-                BoundedVec<u8, <T as Config>::NameLimit>,
-                BoundedVec<u8, <T as Config>::FactStringLimit>,
+        ) -> Vec<(
+            T::DefinitionStepIndex,
+            ProcessStepStatus,
+            Option<
+                ProcessStep<
+                    T::ProposalId,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    BoundedVec<u8, <T as Config>::FactStringLimit>,
+                >,
             >,
-        > {
-            let mut process_steps = Vec::new();
-            <ProcessSteps<T>>::iter_prefix((registry_id, definition_id, process_id)).for_each(
-                |(step_index, definition_step)| process_steps.push((step_index, definition_step)),
+        )> {
+            let definition_steps = Self::get_definition_steps(registry_id, definition_id);
+            let is_stopped = <Processes<T>>::get((registry_id, definition_id), process_id).map_or(
+                false,
+                |process| {
+                    process.status == ProcessStatus::Rejected
+                        || process.status == ProcessStatus::OnHold
+                },
             );
-            process_steps.sort_by(|(step_index_a, _), (step_index_b, _)| {
-                step_index_a.partial_cmp(step_index_b).unwrap()
-            });
 
-            process_steps
+            let mut skipped = Vec::new();
+            definition_steps
+                .iter()
+                .filter(|(step_index, _)| {
+                    <ProcessSteps<T>>::contains_key(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    )
+                })
+                .for_each(|(_, definition_step)| {
+                    Self::unattested_ancestors(
+                        registry_id,
+                        definition_id,
+                        process_id,
+                        &definition_step.predecessors,
+                    )
+                    .into_iter()
+                    .for_each(|step_index| {
+                        if !skipped.contains(&step_index) {
+                            skipped.push(step_index);
+                        }
+                    });
+                });
+
+            let now = <frame_system::Module<T>>::block_number();
+            definition_steps
                 .into_iter()
-                .map(|(_, process_step)| process_step)
+                .map(|(step_index, definition_step)| {
+                    let process_step = <ProcessSteps<T>>::get(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    )
+                    .map(|process_step| {
+                        Self::current_process_step(
+                            (registry_id, definition_id, process_id),
+                            step_index,
+                            process_step,
+                        )
+                    });
+                    let status = if process_step.is_some() {
+                        ProcessStepStatus::Attested
+                    } else if is_stopped
+                        && <ProcessStepRejections<T>>::contains_key(
+                            (registry_id, definition_id, process_id),
+                            step_index,
+                        )
+                    {
+                        ProcessStepStatus::Rejected
+                    } else if let Some(eligible_since) = <EligibleSteps<T>>::get(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    ) {
+                        if !is_stopped
+                            && Self::is_overdue(eligible_since, definition_step.max_duration, now)
+                        {
+                            ProcessStepStatus::Overdue
+                        } else {
+                            ProcessStepStatus::Eligible
+                        }
+                    } else if skipped.contains(&step_index) {
+                        ProcessStepStatus::Skipped
+                    } else {
+                        ProcessStepStatus::Pending
+                    };
+                    (step_index, status, process_step)
+                })
                 .collect()
         }
 
+        /// The current view of an attested step, with the amendments made to it oldest first.
+        #[allow(clippy::type_complexity)]
         pub fn get_process_step(
             registry_id: T::RegistryId,
             definition_id: T::DefinitionId,
             process_id: T::ProcessId,
             definition_step_index: T::DefinitionStepIndex,
-        ) -> Option<
+        ) -> Option<(
             ProcessStep<
                 T::ProposalId,
                 BoundedVec<u8, <T as Config>::NameLimit>,
                 BoundedVec<u8, <T as Config>::FactStringLimit>,
             >,
-        > {
-            <ProcessSteps<T>>::get(
+            Vec<
+                ProcessStepAmendment<
+                    T::ProposalId,
+                    T::BlockNumber,
+                    T::Moment,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    BoundedVec<u8, <T as Config>::FactStringLimit>,
+                >,
+            >,
+        )> {
+            let process_step = <ProcessSteps<T>>::get(
                 (registry_id, definition_id, process_id),
                 definition_step_index,
-            )
+            )?;
+            let amendments = Self::process_step_amendment_history(
+                registry_id,
+                definition_id,
+                process_id,
+                definition_step_index,
+            );
+            Some((
+                Self::current_process_step(
+                    (registry_id, definition_id, process_id),
+                    definition_step_index,
+                    process_step,
+                ),
+                amendments,
+            ))
+        }
+
+        /// The rejections recorded against the steps of a process
+        pub fn get_process_step_rejections(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+        ) -> Vec<(
+            T::DefinitionStepIndex,
+            ProcessStepRejection<
+                T::ProposalId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::FactStringLimit>,
+            >,
+        )> {
+            let mut rejections = Vec::new();
+
+            <ProcessStepRejections<T>>::iter_prefix((registry_id, definition_id, process_id))
+                .for_each(|(step_index, rejection)| rejections.push((step_index, rejection)));
+
+            rejections
+        }
+
+        /// The block, extrinsic index and time at which each attested step of a process was attested
+        pub fn get_process_step_evidence(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+        ) -> Vec<(
+            T::DefinitionStepIndex,
+            AttestationEvidence<T::BlockNumber, T::Moment>,
+        )> {
+            let mut evidence = Vec::new();
+
+            <ProcessStepEvidence<T>>::iter_prefix((registry_id, definition_id, process_id))
+                .for_each(|(step_index, step_evidence)| evidence.push((step_index, step_evidence)));
+
+            evidence
+        }
+
+        pub fn get_definition_visibility(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> Option<DefinitionVisibility<BoundedVec<u8, <T as Config>::NameLimit>>> {
+            <DefinitionVisibilities<T>>::get(registry_id, definition_id)
+        }
+
+        /// The name, status, location and attested steps of a process as shown to consumers, if its definition is public.
+        /// Each step has only its public attributes, as corrected by its latest amendment. Steps attested before attestation evidence was recorded are left out,
+        /// as they cannot be verified against a block.
+        #[allow(clippy::type_complexity)]
+        pub fn get_public_trace(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+        ) -> Option<(
+            BoundedVec<u8, <T as Config>::NameLimit>,
+            ProcessStatus,
+            Option<Fact<BoundedVec<u8, <T as Config>::NameLimit>>>,
+            Vec<
+                PublicProcessStep<
+                    T::DefinitionStepIndex,
+                    T::BlockNumber,
+                    T::Moment,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    BoundedVec<u8, <T as Config>::FactStringLimit>,
+                >,
+            >,
+        )> {
+            let visibility = <DefinitionVisibilities<T>>::get(registry_id, definition_id)?;
+            let process = <Processes<T>>::get((registry_id, definition_id), process_id)?;
+
+            let steps = Self::get_definition_steps(registry_id, definition_id)
+                .into_iter()
+                .filter_map(|(step_index, definition_step)| {
+                    let process_step = <ProcessSteps<T>>::get(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    )?;
+                    let process_step = Self::current_process_step(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                        process_step,
+                    );
+                    let evidence = <ProcessStepEvidence<T>>::get(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    )?;
+                    let attestor_name =
+                        <groups::Module<T>>::group_by_account(&definition_step.attestor)
+                            .and_then(|group_id| <groups::Module<T>>::groups(group_id))
+                            .map(|group| group.name.into_inner());
+                    let attributes = process_step
+                        .attributes
+                        .into_iter()
+                        .filter(|attribute| visibility.public_attributes.contains(&attribute.name))
+                        .collect();
+                    Some(PublicProcessStep {
+                        definition_step_index: step_index,
+                        name: definition_step.name,
+                        attestor_name,
+                        attributes,
+                        evidence,
+                    })
+                })
+                .collect();
+
+            let location = if visibility.show_location {
+                process.location
+            } else {
+                None
+            };
+            Some((process.name, process.status, location, steps))
+        }
+
+        /// Links leading into a process, walking upstream up to `depth` levels.
+        /// Each entry is (level, downstream process, upstream process, link).
+        #[allow(clippy::type_complexity)]
+        pub fn trace_back(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+            depth: u32,
+        ) -> Vec<(
+            u32,
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+            ProcessLink<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            Self::trace((registry_id, definition_id, process_id), depth, true)
+        }
+
+        /// Links leading out of a process, walking downstream up to `depth` levels.
+        /// Each entry is (level, downstream process, upstream process, link).
+        #[allow(clippy::type_complexity)]
+        pub fn trace_forward(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+            depth: u32,
+        ) -> Vec<(
+            u32,
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+            ProcessLink<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            Self::trace((registry_id, definition_id, process_id), depth, false)
         }
 
         // Definition creators and attestors can view definitions and processes derived from them.
@@ -1306,6 +2906,423 @@ pub mod pallet {
         }
 
         // -- private functions --
+
+        #[allow(clippy::type_complexity)]
+        fn process_step_amendment_history(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+            definition_step_index: T::DefinitionStepIndex,
+        ) -> Vec<
+            ProcessStepAmendment<
+                T::ProposalId,
+                T::BlockNumber,
+                T::Moment,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::FactStringLimit>,
+            >,
+        > {
+            let process = (registry_id, definition_id, process_id);
+            (0..<ProcessStepAmendmentCount<T>>::get(process, definition_step_index))
+                .filter_map(|amendment_number| {
+                    <ProcessStepAmendments<T>>::get(
+                        process,
+                        (definition_step_index, amendment_number),
+                    )
+                })
+                .collect()
+        }
+
+        /// The attested step as corrected by its latest amendment, if any
+        #[allow(clippy::type_complexity)]
+        fn current_process_step(
+            process: (T::RegistryId, T::DefinitionId, T::ProcessId),
+            definition_step_index: T::DefinitionStepIndex,
+            process_step: ProcessStep<
+                T::ProposalId,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::FactStringLimit>,
+            >,
+        ) -> ProcessStep<
+            T::ProposalId,
+            BoundedVec<u8, <T as Config>::NameLimit>,
+            BoundedVec<u8, <T as Config>::FactStringLimit>,
+        > {
+            let latest_amendment =
+                match <ProcessStepAmendmentCount<T>>::get(process, definition_step_index) {
+                    0 => None,
+                    count => {
+                        <ProcessStepAmendments<T>>::get(process, (definition_step_index, count - 1))
+                    }
+                };
+            match latest_amendment {
+                Some(amendment) => ProcessStep {
+                    proposal_id: amendment.proposal_id,
+                    attributes: amendment.attributes.clone(),
+                },
+                None => process_step,
+            }
+        }
+
+        /// Breadth first walk of the lineage graph. Each process is expanded once, so cycles terminate.
+        #[allow(clippy::type_complexity)]
+        fn trace(
+            start: (T::RegistryId, T::DefinitionId, T::ProcessId),
+            depth: u32,
+            upstream: bool,
+        ) -> Vec<(
+            u32,
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+            (T::RegistryId, T::DefinitionId, T::ProcessId),
+            ProcessLink<BoundedVec<u8, <T as Config>::NameLimit>>,
+        )> {
+            let depth = depth.min(MAX_TRACE_DEPTH);
+            let mut links = Vec::new();
+            let mut visited = vec![start];
+            let mut frontier = vec![start];
+            let mut level = 1;
+            while level <= depth && !frontier.is_empty() {
+                let mut next_frontier = Vec::new();
+                for process in frontier {
+                    let mut neighbours = Vec::new();
+                    if upstream {
+                        <ProcessLinks<T>>::iter_prefix(process).for_each(|(upstream, link)| {
+                            links.push((level, process, upstream, link));
+                            neighbours.push(upstream);
+                        });
+                    } else {
+                        <ProcessLinksByUpstream<T>>::iter_prefix(process).for_each(
+                            |(downstream, _)| {
+                                if let Some(link) = <ProcessLinks<T>>::get(downstream, process) {
+                                    links.push((level, downstream, process, link));
+                                    neighbours.push(downstream);
+                                }
+                            },
+                        );
+                    }
+                    for neighbour in neighbours {
+                        if !visited.contains(&neighbour) {
+                            visited.push(neighbour);
+                            next_frontier.push(neighbour);
+                        }
+                    }
+                }
+                frontier = next_frontier;
+                level += 1;
+            }
+            links
+        }
+
+        #[allow(clippy::type_complexity)]
+        fn enforce_definition_steps(
+            steps: Vec<(
+                Vec<u8>,
+                T::AccountId,
+                T::MemberCount,
+                Vec<T::DefinitionStepIndex>,
+                JoinRule,
+                Vec<AttributeSchema<Vec<u8>>>,
+                Option<T::BlockNumber>,
+            )>,
+        ) -> Result<
+            Vec<
+                DefinitionStep<
+                    T::AccountId,
+                    T::MemberCount,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    T::DefinitionStepIndex,
+                    T::BlockNumber,
+                >,
+            >,
+            Error<T>,
+        > {
+            ensure!(!steps.is_empty(), Error::<T>::DefinitionStepsRequired);
+
+            let mut definition_steps = Vec::new();
+            for (
+                index,
+                (name, attestor, threshold, predecessors, join, attributes, max_duration),
+            ) in steps.into_iter().enumerate()
+            {
+                let index = T::DefinitionStepIndex::unique_saturated_from(index);
+                ensure!(
+                    predecessors.len() as u32 <= T::PredecessorLimit::get(),
+                    Error::<T>::TooManyPredecessors
+                );
+                ensure!(
+                    predecessors.iter().all(|predecessor| *predecessor < index),
+                    Error::<T>::InvalidPredecessor
+                );
+                let mut sorted = predecessors.clone();
+                sorted.sort();
+                sorted.dedup();
+                ensure!(
+                    sorted.len() == predecessors.len(),
+                    Error::<T>::InvalidPredecessor
+                );
+                ensure!(
+                    max_duration.map_or(true, |max_duration| !max_duration.is_zero()),
+                    Error::<T>::InvalidMaxDuration
+                );
+                definition_steps.push(DefinitionStep {
+                    name: enforce_limit!(name),
+                    attestor,
+                    threshold,
+                    predecessors,
+                    join,
+                    attributes: Self::enforce_attribute_schemas(attributes)?,
+                    max_duration,
+                });
+            }
+            Ok(definition_steps)
+        }
+
+        fn insert_definition_steps(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            definition_steps: Vec<
+                DefinitionStep<
+                    T::AccountId,
+                    T::MemberCount,
+                    BoundedVec<u8, <T as Config>::NameLimit>,
+                    T::DefinitionStepIndex,
+                    T::BlockNumber,
+                >,
+            >,
+        ) {
+            definition_steps
+                .into_iter()
+                .enumerate()
+                .for_each(|(index, definition_step)| {
+                    let definition_step_index =
+                        T::DefinitionStepIndex::unique_saturated_from(index);
+
+                    <DefinitionStepsByAttestor<T>>::insert(
+                        &definition_step.attestor,
+                        (registry_id, definition_id, definition_step_index),
+                        (),
+                    );
+
+                    <DefinitionSteps<T>>::insert(
+                        (registry_id, definition_id),
+                        definition_step_index,
+                        definition_step,
+                    );
+                });
+        }
+
+        /// Remove the steps of a definition, returning how many were removed
+        fn remove_definition_steps(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+        ) -> u32 {
+            let mut step_count = 0;
+            <DefinitionSteps<T>>::drain_prefix((registry_id, definition_id)).for_each(
+                |(definition_step_index, definition_step)| {
+                    <DefinitionStepsByAttestor<T>>::remove(
+                        definition_step.attestor,
+                        (registry_id, definition_id, definition_step_index),
+                    );
+                    step_count += 1;
+                },
+            );
+            step_count
+        }
+
+        fn enforce_attribute_schemas(
+            attributes: Vec<AttributeSchema<Vec<u8>>>,
+        ) -> Result<Vec<AttributeSchema<BoundedVec<u8, <T as Config>::NameLimit>>>, Error<T>>
+        {
+            ensure!(
+                attributes.len() as u32 <= T::AttributeLimit::get(),
+                Error::<T>::InvalidAttributeSchema
+            );
+            let mut bounded_attributes: Vec<
+                AttributeSchema<BoundedVec<u8, <T as Config>::NameLimit>>,
+            > = Vec::new();
+            for attribute in attributes {
+                //integer facts are bounded by integers so large values are compared exactly
+                ensure!(
+                    attribute
+                        .min
+                        .iter()
+                        .chain(attribute.max.iter())
+                        .all(|bound| attribute.fact_type.accepts_bound(bound)),
+                    Error::<T>::InvalidAttributeSchema
+                );
+                match (attribute.min, attribute.max) {
+                    (Some(AttributeBound::Float(min)), Some(AttributeBound::Float(max))) => {
+                        ensure!(
+                            f64::from_le_bytes(min) <= f64::from_le_bytes(max),
+                            Error::<T>::InvalidAttributeSchema
+                        )
+                    }
+                    (Some(AttributeBound::Integer(min)), Some(AttributeBound::Integer(max))) => {
+                        ensure!(min <= max, Error::<T>::InvalidAttributeSchema)
+                    }
+                    _ => (),
+                }
+                let name = enforce_limit!(attribute.name);
+                ensure!(
+                    !bounded_attributes
+                        .iter()
+                        .any(|existing| existing.name == name),
+                    Error::<T>::InvalidAttributeSchema
+                );
+                bounded_attributes.push(AttributeSchema {
+                    name,
+                    fact_type: attribute.fact_type,
+                    required: attribute.required,
+                    unit: enforce_limit_option!(attribute.unit),
+                    min: attribute.min,
+                    max: attribute.max,
+                });
+            }
+            Ok(bounded_attributes)
+        }
+
+        /// Check attested attributes against the schema of the definition step.
+        /// Attributes not mentioned in the schema are accepted as is.
+        fn validate_attributes(
+            schema: &[AttributeSchema<BoundedVec<u8, <T as Config>::NameLimit>>],
+            attributes: &[Attribute<
+                BoundedVec<u8, <T as Config>::NameLimit>,
+                BoundedVec<u8, <T as Config>::FactStringLimit>,
+            >],
+        ) -> Result<(), Error<T>> {
+            for attribute_schema in schema {
+                match attributes
+                    .iter()
+                    .find(|attribute| attribute.name == attribute_schema.name)
+                {
+                    Some(attribute) => ensure!(
+                        attribute_schema.accepts(&attribute.fact),
+                        Error::<T>::AttributeNotValid
+                    ),
+                    None => ensure!(
+                        !attribute_schema.required,
+                        Error::<T>::RequiredAttributeMissing
+                    ),
+                }
+            }
+            Ok(())
+        }
+
+        /// Update the eligible steps of a process after a step was attested.
+        /// Successors whose join rule is now satisfied become eligible and any unattested steps leading into the attested step are skipped.
+        fn update_eligible_steps(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+            attested_step_index: T::DefinitionStepIndex,
+            predecessors: &[T::DefinitionStepIndex],
+        ) {
+            <EligibleSteps<T>>::remove(
+                (registry_id, definition_id, process_id),
+                attested_step_index,
+            );
+
+            Self::unattested_ancestors(registry_id, definition_id, process_id, predecessors)
+                .into_iter()
+                .for_each(|step_index| {
+                    <EligibleSteps<T>>::remove(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    );
+                });
+
+            <DefinitionSteps<T>>::iter_prefix((registry_id, definition_id))
+                .filter(|(step_index, definition_step)| {
+                    definition_step.predecessors.contains(&attested_step_index)
+                        && !<ProcessSteps<T>>::contains_key(
+                            (registry_id, definition_id, process_id),
+                            step_index,
+                        )
+                })
+                .for_each(|(step_index, definition_step)| {
+                    let is_attested = |predecessor: &T::DefinitionStepIndex| {
+                        <ProcessSteps<T>>::contains_key(
+                            (registry_id, definition_id, process_id),
+                            predecessor,
+                        )
+                    };
+                    let ready = match definition_step.join {
+                        JoinRule::All => definition_step.predecessors.iter().all(is_attested),
+                        JoinRule::Any => true,
+                    };
+                    if ready {
+                        Self::make_eligible(
+                            (registry_id, definition_id, process_id),
+                            step_index,
+                            definition_step.max_duration,
+                        );
+                    }
+                });
+        }
+
+        fn is_overdue(
+            eligible_since: T::BlockNumber,
+            max_duration: Option<T::BlockNumber>,
+            now: T::BlockNumber,
+        ) -> bool {
+            max_duration.map_or(false, |max_duration| {
+                now >= eligible_since.saturating_add(max_duration)
+            })
+        }
+
+        /// Make a step of a process eligible as of the current block, scheduling the block at which it becomes overdue.
+        fn make_eligible(
+            process: (T::RegistryId, T::DefinitionId, T::ProcessId),
+            step_index: T::DefinitionStepIndex,
+            max_duration: Option<T::BlockNumber>,
+        ) {
+            let now = <frame_system::Module<T>>::block_number();
+            <EligibleSteps<T>>::insert(process, step_index, now);
+            if let Some(max_duration) = max_duration {
+                let mut deadline = now.saturating_add(max_duration);
+                // a full block defers the deadline to the next block with room
+                while <ProcessStepDeadlineCount<T>>::get(deadline) >= T::MaxDeadlinesPerBlock::get()
+                {
+                    deadline = deadline.saturating_add(One::one());
+                }
+                <ProcessStepDeadlineCount<T>>::mutate(deadline, |count| {
+                    *count = count.saturating_add(1)
+                });
+                <ProcessStepDeadlines<T>>::insert(
+                    deadline,
+                    (process.0, process.1, process.2, step_index),
+                    now,
+                );
+            }
+        }
+
+        /// Steps that lead into the given predecessors and have not been attested.
+        fn unattested_ancestors(
+            registry_id: T::RegistryId,
+            definition_id: T::DefinitionId,
+            process_id: T::ProcessId,
+            predecessors: &[T::DefinitionStepIndex],
+        ) -> Vec<T::DefinitionStepIndex> {
+            let mut ancestors = Vec::new();
+            let mut to_visit = predecessors.to_vec();
+            while let Some(step_index) = to_visit.pop() {
+                if ancestors.contains(&step_index)
+                    || <ProcessSteps<T>>::contains_key(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                    )
+                {
+                    continue;
+                }
+                ancestors.push(step_index);
+                if let Some(definition_step) =
+                    <DefinitionSteps<T>>::get((registry_id, definition_id), step_index)
+                {
+                    to_visit.extend(definition_step.predecessors);
+                }
+            }
+            ancestors
+        }
     }
 
     // -- for use in weights --
@@ -1332,17 +3349,41 @@ pub mod pallet {
         }};
     }
 
-    fn get_max_step_name<AccountId, MemberCount>(
-        steps: &[(Vec<u8>, AccountId, MemberCount)],
+    fn get_max_step_name<AccountId, MemberCount, DefinitionStepIndex, BlockNumber>(
+        steps: &[(
+            Vec<u8>,
+            AccountId,
+            MemberCount,
+            Vec<DefinitionStepIndex>,
+            JoinRule,
+            Vec<AttributeSchema<Vec<u8>>>,
+            Option<BlockNumber>,
+        )],
     ) -> u32 {
         let mut max_step_name_len = 0;
-        steps.iter().for_each(|(name, _, _)| {
+        steps.iter().for_each(|(name, _, _, _, _, _, _)| {
             if name.len() as u32 > max_step_name_len {
                 max_step_name_len = name.len() as u32;
             };
         });
         max_step_name_len
     }
+    fn get_schema_count<AccountId, MemberCount, DefinitionStepIndex, BlockNumber>(
+        steps: &[(
+            Vec<u8>,
+            AccountId,
+            MemberCount,
+            Vec<DefinitionStepIndex>,
+            JoinRule,
+            Vec<AttributeSchema<Vec<u8>>>,
+            Option<BlockNumber>,
+        )],
+    ) -> u32 {
+        steps
+            .iter()
+            .map(|(_, _, _, _, _, attributes, _)| attributes.len() as u32)
+            .sum()
+    }
     fn get_max_attribute_name_len(attributes: &[Attribute<Vec<u8>, Vec<u8>>]) -> u32 {
         let mut max_attribute_name_len = 0;
         attributes.iter().for_each(|attribute| {
@@ -1,18 +1,34 @@
 use super::*;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use primitives::{bounded_vec::BoundedVec, ProcessStep};
+use primitives::{
+    bounded_vec::BoundedVec, DefinitionStep, JoinRule, Process, ProcessStatus, ProcessStep,
+};
+use sp_runtime::traits::{Saturating, UniqueSaturatedFrom};
 
 pub mod deprecated {
     use codec::{Decode, Encode};
     use frame_support::dispatch::Vec;
-    use primitives::Attribute;
+    use primitives::{Attribute, ProcessStatus};
     use sp_runtime::RuntimeDebug;
 
+    #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+    pub struct OldProcess<BoundedString> {
+        pub name: BoundedString,
+        pub status: ProcessStatus,
+    }
+
     #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
     pub struct OldProcessStep<BoundedStringName, BoundedStringFact> {
         pub attributes: Vec<Attribute<BoundedStringName, BoundedStringFact>>,
     }
+
+    #[derive(Encode, Decode, PartialOrd, Ord, PartialEq, Eq, Clone, RuntimeDebug)]
+    pub struct OldDefinitionStep<AccountId, MemberCount, BoundedString> {
+        pub name: BoundedString,
+        pub attestor: AccountId,
+        pub threshold: MemberCount,
+    }
 }
 
 #[allow(clippy::unnecessary_cast)]
@@ -44,3 +60,89 @@ pub fn migrate_to_v2<T: Config>() -> Weight {
 
     weight
 }
+
+#[allow(clippy::unnecessary_cast)]
+pub fn migrate_to_v3<T: Config>() -> Weight {
+    let mut weight: Weight = T::DbWeight::get().reads(1 as Weight);
+
+    let storage_version_maybe = <StorageVersion<T>>::get();
+
+    // chains that never ran the V2 migration are translated to V2 first
+    if storage_version_maybe.is_none() || storage_version_maybe == Some(Releases::V1) {
+        weight += migrate_to_v2::<T>();
+    }
+
+    if <StorageVersion<T>>::get() == Some(Releases::V2) {
+        let one = T::DefinitionStepIndex::unique_saturated_from(1u32);
+
+        <DefinitionSteps<T>>::translate::<
+            deprecated::OldDefinitionStep<
+                T::AccountId,
+                T::MemberCount,
+                BoundedVec<u8, <T as Config>::NameLimit>,
+            >,
+            _,
+        >(|(_, _), step_index, old| {
+            weight += T::DbWeight::get().reads_writes(1 as Weight, 1 as Weight);
+            let predecessors = if step_index == T::DefinitionStepIndex::unique_saturated_from(0u32)
+            {
+                sp_std::vec![]
+            } else {
+                sp_std::vec![step_index.saturating_sub(one)]
+            };
+            let new = DefinitionStep {
+                name: old.name,
+                attestor: old.attestor,
+                threshold: old.threshold,
+                predecessors,
+                join: JoinRule::All,
+                attributes: sp_std::vec![],
+                max_duration: None,
+            };
+            Some(new)
+        });
+
+        <Processes<T>>::translate::<
+            deprecated::OldProcess<BoundedVec<u8, <T as Config>::NameLimit>>,
+            _,
+        >(|(_, _), _, old| {
+            weight += T::DbWeight::get().reads_writes(1 as Weight, 1 as Weight);
+            let new = Process {
+                name: old.name,
+                status: old.status,
+                asset: None,
+                participants: sp_std::vec![],
+                location: None,
+            };
+            Some(new)
+        });
+
+        <Processes<T>>::iter()
+            .filter(|(_, _, process)| process.status == ProcessStatus::InProgress)
+            .for_each(|((registry_id, definition_id), process_id, _)| {
+                weight += T::DbWeight::get().reads_writes(1 as Weight, 0 as Weight);
+                let mut step_index = T::DefinitionStepIndex::unique_saturated_from(0u32);
+                while <ProcessSteps<T>>::contains_key(
+                    (registry_id, definition_id, process_id),
+                    step_index,
+                ) {
+                    weight += T::DbWeight::get().reads_writes(1 as Weight, 0 as Weight);
+                    step_index = step_index.saturating_add(one);
+                }
+                if <DefinitionSteps<T>>::contains_key((registry_id, definition_id), step_index) {
+                    weight += T::DbWeight::get().reads_writes(1 as Weight, 1 as Weight);
+                    <EligibleSteps<T>>::insert(
+                        (registry_id, definition_id, process_id),
+                        step_index,
+                        <frame_system::Module<T>>::block_number(),
+                    );
+                }
+            });
+
+        <StorageVersion<T>>::set(Some(Releases::V3));
+    } else {
+        frame_support::debug::info!(" >>> Unused migration!");
+    }
+
+    weight
+}
@@ -1,11 +1,16 @@
 //! Mocks for the module.
 use crate as pallet_provenance;
+use asset_info::AssetInfo;
+use did_info::DidInfo;
 use frame_support::parameter_types;
 use frame_system::{self as system, EnsureOneOf, EnsureSigned};
 use runtime::{
     primitives::{FactStringLimit, NameLimit},
-    AttributeLimit, DefinitionStepLimit, GroupChainLimit, GroupMaxMembers, GroupMaxProposalLength,
-    GroupMaxProposals,
+    AttributeLimit, DefinitionPredecessorLimit, DefinitionStepLimit, GroupChainLimit,
+    GroupMaxBudgetTiers, GroupMaxJointGroups, GroupMaxMembers, GroupMaxProposalLength,
+    GroupMaxProposals, GroupMaxScheduleDeferral, GroupMaxScheduledPerBlock,
+    GroupMaximumScheduledWeight, ProcessLinkLimit, ProcessMaxDeadlinesPerBlock,
+    ProcessParticipantLimit, ProcessStepAmendmentLimit,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -26,6 +31,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
         Settings: settings::{Module, Call, Config<T>,Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+        Timestamp: timestamp::{Module, Call, Storage, Inherent},
         Groups: groups::{Module, Call, Storage, Event<T>, Origin<T>},
         Provenance: pallet_provenance::{Module, Call, Storage, Event<T>},
     }
@@ -34,6 +40,7 @@ frame_support::construct_runtime!(
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub const GroupProposalLifetime: u64 = 100;
 }
 
 type AccountId = u64;
@@ -115,6 +122,37 @@ impl groups::Config for Test {
     type WeightInfo = ();
     type NameLimit = NameLimit;
     type GroupChainLimit = GroupChainLimit;
+    type MaxBudgetTiers = GroupMaxBudgetTiers;
+    type GroupsOriginJointApproved = groups::EnsureJointApproved<Test>;
+    type MaxJointGroups = GroupMaxJointGroups;
+    type ProposalLifetime = GroupProposalLifetime;
+    type MaxScheduledPerBlock = GroupMaxScheduledPerBlock;
+    type MaximumScheduledWeight = GroupMaximumScheduledWeight;
+    type MaxScheduleDeferral = GroupMaxScheduleDeferral;
+}
+
+/// The only asset registered in the mock asset registry (registry_id, asset_id)
+pub const ASSET: (u32, u32) = (1, 1);
+/// The only DID created in the mock identity pallet
+pub const FARMER_DID: primitives::Did = primitives::Did { id: [1u8; 32] };
+
+pub struct MockAssetRegistry;
+impl AssetInfo for MockAssetRegistry {
+    type RegistryId = u32;
+    type AssetId = u32;
+
+    fn asset_exists(registry_id: u32, asset_id: u32) -> bool {
+        (registry_id, asset_id) == ASSET
+    }
+}
+
+pub struct MockIdentity;
+impl DidInfo for MockIdentity {
+    type Did = primitives::Did;
+
+    fn did_exists(did: &primitives::Did) -> bool {
+        *did == FARMER_DID
+    }
 }
 
 impl pallet_provenance::Config for Test {
@@ -129,6 +167,15 @@ impl pallet_provenance::Config for Test {
     type FactStringLimit = FactStringLimit;
     type DefinitionStepLimit = DefinitionStepLimit;
     type AttributeLimit = AttributeLimit;
+    type PredecessorLimit = DefinitionPredecessorLimit;
+    type ProcessLinkLimit = ProcessLinkLimit;
+    type MaxDeadlinesPerBlock = ProcessMaxDeadlinesPerBlock;
+    type AmendmentLimit = ProcessStepAmendmentLimit;
+    type AssetRegistryId = u32;
+    type AssetId = u32;
+    type AssetInfoSource = MockAssetRegistry;
+    type DidInfoSource = MockIdentity;
+    type ParticipantLimit = ProcessParticipantLimit;
 }
 
 impl settings::Config for Test {
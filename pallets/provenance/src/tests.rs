@@ -1,7 +1,7 @@
 //! Tests for the module.
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_ok, dispatch::Weight};
+use frame_support::{assert_noop, assert_ok, dispatch::Weight};
 use primitives::*;
 use std::convert::TryInto;
 
@@ -92,7 +92,15 @@ fn create_definition_should_work() {
             Origin::signed(DEFINITION_OWNER),
             registry_id,
             b"TestDefinition".to_vec(),
-            vec![(b"TestStep".to_vec(), ATTESTOR, threshold)]
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
         ));
         let definition_id = 1u32;
         assert!(Definitions::<Test>::contains_key(
@@ -119,7 +127,11 @@ fn create_definition_should_work() {
             DefinitionStep {
                 name: b"TestStep".to_vec().try_into().unwrap(),
                 attestor: ATTESTOR,
-                threshold
+                threshold,
+                predecessors: vec![],
+                join: JoinRule::All,
+                attributes: vec![],
+                max_duration: None
             },
             definition_step
         );
@@ -139,7 +151,15 @@ fn remove_definition_should_work() {
             Origin::signed(DEFINITION_OWNER),
             registry_id,
             b"TestDefinition".to_vec(),
-            vec![(b"TestStep".to_vec(), ATTESTOR, threshold)]
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
         ));
         let definition_id = 1u32;
         assert!(Definitions::<Test>::contains_key(
@@ -174,7 +194,15 @@ fn set_definition_inactive_and_active_should_work() {
             Origin::signed(DEFINITION_OWNER),
             registry_id,
             b"TestDefinition".to_vec(),
-            vec![(b"TestStep".to_vec(), ATTESTOR, threshold)]
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
         ));
         let definition_id = 1u32;
         assert!(Definitions::<Test>::contains_key(
@@ -223,7 +251,15 @@ fn create_process_should_work() {
             Origin::signed(DEFINITION_OWNER),
             registry_id,
             b"TestDefinition".to_vec(),
-            vec![(b"TestStep".to_vec(), ATTESTOR, threshold)]
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
         ));
         let definition_id = 1u32;
         assert!(Definitions::<Test>::contains_key(
@@ -236,6 +272,9 @@ fn create_process_should_work() {
             registry_id,
             definition_id,
             b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
         ));
         let process_id = 1u32;
         assert!(Processes::<Test>::contains_key(
@@ -248,7 +287,10 @@ fn create_process_should_work() {
             process,
             Process {
                 name: b"TestProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::InProgress
+                status: ProcessStatus::InProgress,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
     });
@@ -267,7 +309,15 @@ fn update_process_should_work() {
             Origin::signed(DEFINITION_OWNER),
             registry_id,
             b"TestDefinition".to_vec(),
-            vec![(b"TestStep".to_vec(), ATTESTOR, threshold)]
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
         ));
         let definition_id = 1u32;
         assert!(Definitions::<Test>::contains_key(
@@ -280,6 +330,9 @@ fn update_process_should_work() {
             registry_id,
             definition_id,
             b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
         ));
         let process_id = 1u32;
         assert!(Processes::<Test>::contains_key(
@@ -292,7 +345,10 @@ fn update_process_should_work() {
             process,
             Process {
                 name: b"TestProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::InProgress
+                status: ProcessStatus::InProgress,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
 
@@ -309,7 +365,10 @@ fn update_process_should_work() {
             process,
             Process {
                 name: b"RenamedProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::InProgress
+                status: ProcessStatus::InProgress,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
     });
@@ -328,7 +387,15 @@ fn remove_process_should_work() {
             Origin::signed(DEFINITION_OWNER),
             registry_id,
             b"TestDefinition".to_vec(),
-            vec![(b"TestStep".to_vec(), ATTESTOR, threshold)]
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
         ));
         let definition_id = 1u32;
         assert!(Definitions::<Test>::contains_key(
@@ -341,6 +408,9 @@ fn remove_process_should_work() {
             registry_id,
             definition_id,
             b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
         ));
         let process_id = 1u32;
         assert!(Processes::<Test>::contains_key(
@@ -353,7 +423,10 @@ fn remove_process_should_work() {
             process,
             Process {
                 name: b"TestProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::InProgress
+                status: ProcessStatus::InProgress,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
         assert_ok!(Provenance::remove_process(
@@ -383,8 +456,24 @@ fn attest_process_step_should_work() {
             registry_id,
             b"TestDefinition".to_vec(),
             vec![
-                (b"TestStep_1".to_vec(), ATTESTOR, threshold),
-                (b"TestStep_2".to_vec(), ATTESTOR, threshold)
+                (
+                    b"TestStep_1".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![],
+                    None
+                ),
+                (
+                    b"TestStep_2".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![0],
+                    JoinRule::All,
+                    vec![],
+                    None
+                )
             ]
         ));
         let definition_id = 1u32;
@@ -398,6 +487,9 @@ fn attest_process_step_should_work() {
             registry_id,
             definition_id,
             b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
         ));
         let process_id = 1u32;
         assert!(Processes::<Test>::contains_key(
@@ -410,7 +502,10 @@ fn attest_process_step_should_work() {
             process,
             Process {
                 name: b"TestProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::InProgress
+                status: ProcessStatus::InProgress,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
         let definition_step_index = 0;
@@ -450,7 +545,10 @@ fn attest_process_step_should_work() {
             process,
             Process {
                 name: b"TestProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::InProgress
+                status: ProcessStatus::InProgress,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
 
@@ -491,12 +589,1798 @@ fn attest_process_step_should_work() {
             process,
             Process {
                 name: b"TestProcess".to_vec().try_into().unwrap(),
-                status: ProcessStatus::Completed
+                status: ProcessStatus::Completed,
+                asset: None,
+                participants: vec![],
+                location: None,
             }
         );
     });
 }
 
+#[test]
+fn create_definition_with_invalid_predecessor_should_fail() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+        assert_noop!(
+            Provenance::create_definition(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                b"TestDefinition".to_vec(),
+                vec![
+                    (
+                        b"TestStep_1".to_vec(),
+                        ATTESTOR,
+                        threshold,
+                        vec![1],
+                        JoinRule::All,
+                        vec![],
+                        None
+                    ),
+                    (
+                        b"TestStep_2".to_vec(),
+                        ATTESTOR,
+                        threshold,
+                        vec![0],
+                        JoinRule::All,
+                        vec![],
+                        None
+                    )
+                ]
+            ),
+            Error::<Test>::InvalidPredecessor
+        );
+        assert_noop!(
+            Provenance::create_definition(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                b"TestDefinition".to_vec(),
+                vec![
+                    (
+                        b"TestStep_1".to_vec(),
+                        ATTESTOR,
+                        threshold,
+                        vec![],
+                        JoinRule::All,
+                        vec![],
+                        None
+                    ),
+                    (
+                        b"TestStep_2".to_vec(),
+                        ATTESTOR,
+                        threshold,
+                        vec![0, 0],
+                        JoinRule::All,
+                        vec![],
+                        None
+                    )
+                ]
+            ),
+            Error::<Test>::InvalidPredecessor
+        );
+
+        // a step may have at most `PredecessorLimit` predecessors
+        let predecessor_limit = <Test as Config>::PredecessorLimit::get();
+        let mut steps: Vec<_> = (0..=predecessor_limit)
+            .map(|_| {
+                (
+                    b"TestStep".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![],
+                    None,
+                )
+            })
+            .collect();
+        steps.push((
+            b"TestStep_Last".to_vec(),
+            ATTESTOR,
+            threshold,
+            (0..=predecessor_limit).collect(),
+            JoinRule::All,
+            vec![],
+            None,
+        ));
+        assert_noop!(
+            Provenance::create_definition(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                b"TestDefinition".to_vec(),
+                steps
+            ),
+            Error::<Test>::TooManyPredecessors
+        );
+    });
+}
+
+#[test]
+fn step_graph_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+        // harvest -> (organic | conventional) -> (lab test & packaging) -> shipping
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![
+                (
+                    b"Harvest".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![],
+                    None
+                ),
+                (
+                    b"Organic".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![0],
+                    JoinRule::All,
+                    vec![],
+                    None
+                ),
+                (
+                    b"Conventional".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![0],
+                    JoinRule::All,
+                    vec![],
+                    None
+                ),
+                (
+                    b"LabTest".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![1, 2],
+                    JoinRule::Any,
+                    vec![],
+                    None
+                ),
+                (
+                    b"Packaging".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![1, 2],
+                    JoinRule::Any,
+                    vec![],
+                    None
+                ),
+                (
+                    b"Shipping".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![3, 4],
+                    JoinRule::All,
+                    vec![],
+                    None
+                )
+            ]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+
+        let eligible_steps = || {
+            let mut steps: Vec<u32> =
+                EligibleSteps::<Test>::iter_prefix((registry_id, definition_id, process_id))
+                    .map(|(step_index, _)| step_index)
+                    .collect();
+            steps.sort_unstable();
+            steps
+        };
+        let attest = |definition_step_index: u32| {
+            Provenance::attest_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                definition_step_index,
+                vec![],
+            )
+        };
+
+        assert_eq!(eligible_steps(), vec![0]);
+        assert_noop!(attest(1), Error::<Test>::ProcessStepNotCurrent);
+
+        assert_ok!(attest(0));
+        assert_eq!(eligible_steps(), vec![1, 2]);
+
+        assert_ok!(attest(1));
+        assert_eq!(eligible_steps(), vec![2, 3, 4]);
+
+        assert_ok!(attest(3));
+        assert_eq!(eligible_steps(), vec![4]);
+        assert_noop!(attest(2), Error::<Test>::ProcessStepNotCurrent);
+
+        let statuses: Vec<(u32, ProcessStepStatus)> =
+            Provenance::get_process_steps(registry_id, definition_id, process_id)
+                .into_iter()
+                .map(|(step_index, status, _)| (step_index, status))
+                .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (0, ProcessStepStatus::Attested),
+                (1, ProcessStepStatus::Attested),
+                (2, ProcessStepStatus::Skipped),
+                (3, ProcessStepStatus::Attested),
+                (4, ProcessStepStatus::Eligible),
+                (5, ProcessStepStatus::Pending),
+            ]
+        );
+
+        assert_ok!(attest(4));
+        assert_eq!(eligible_steps(), vec![5]);
+        let process = Processes::<Test>::get((registry_id, definition_id), process_id).unwrap();
+        assert_eq!(process.status, ProcessStatus::InProgress);
+
+        assert_ok!(attest(5));
+        assert!(eligible_steps().is_empty());
+        let process = Processes::<Test>::get((registry_id, definition_id), process_id).unwrap();
+        assert_eq!(process.status, ProcessStatus::Completed);
+    });
+}
+
+#[test]
+fn attribute_schema_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+        let moisture = AttributeSchema {
+            name: b"Moisture".to_vec(),
+            fact_type: FactType::Float,
+            required: true,
+            unit: Some(b"%".to_vec()),
+            min: Some(AttributeBound::Float(0f64.to_le_bytes())),
+            max: Some(AttributeBound::Float(100f64.to_le_bytes())),
+        };
+        let serial = AttributeSchema {
+            name: b"Serial".to_vec(),
+            fact_type: FactType::U128,
+            required: false,
+            unit: None,
+            min: Some(AttributeBound::Integer(u128::MAX - 1)),
+            max: None,
+        };
+        let notes = AttributeSchema {
+            name: b"Notes".to_vec(),
+            fact_type: FactType::Text,
+            required: false,
+            unit: None,
+            min: None,
+            max: None,
+        };
+        assert_noop!(
+            Provenance::create_definition(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                b"TestDefinition".to_vec(),
+                vec![(
+                    b"TestStep".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![AttributeSchema {
+                        min: Some(AttributeBound::Float(0f64.to_le_bytes())),
+                        ..notes.clone()
+                    }],
+                    None
+                )]
+            ),
+            Error::<Test>::InvalidAttributeSchema
+        );
+        //integer attributes take integer bounds within the range of their type
+        for bound in vec![
+            AttributeBound::Float(0f64.to_le_bytes()),
+            AttributeBound::Integer(256),
+        ] {
+            assert_noop!(
+                Provenance::create_definition(
+                    Origin::signed(DEFINITION_OWNER),
+                    registry_id,
+                    b"TestDefinition".to_vec(),
+                    vec![(
+                        b"TestStep".to_vec(),
+                        ATTESTOR,
+                        threshold,
+                        vec![],
+                        JoinRule::All,
+                        vec![AttributeSchema {
+                            fact_type: FactType::U8,
+                            max: Some(bound),
+                            ..serial.clone()
+                        }],
+                        None
+                    )]
+                ),
+                Error::<Test>::InvalidAttributeSchema
+            );
+        }
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![moisture, notes, serial],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+        let definition_step =
+            Provenance::get_definition_step(registry_id, definition_id, 0).unwrap();
+        assert_eq!(definition_step.attributes.len(), 3);
+        assert_eq!(
+            definition_step.attributes[0].unit,
+            Some(b"%".to_vec().try_into().unwrap())
+        );
+
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+        let attest = |attributes: Vec<Attribute<Vec<u8>, Vec<u8>>>| {
+            Provenance::attest_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                attributes,
+            )
+        };
+
+        assert_noop!(
+            attest(vec![Attribute {
+                name: b"Notes".to_vec(),
+                fact: Fact::Text(b"Dry".to_vec())
+            }]),
+            Error::<Test>::RequiredAttributeMissing
+        );
+        assert_noop!(
+            attest(vec![Attribute {
+                name: b"Moisture".to_vec(),
+                fact: Fact::Text(b"12.5".to_vec())
+            }]),
+            Error::<Test>::AttributeNotValid
+        );
+        assert_noop!(
+            attest(vec![Attribute {
+                name: b"Moisture".to_vec(),
+                fact: Fact::Float(120f64.to_le_bytes())
+            }]),
+            Error::<Test>::AttributeNotValid
+        );
+        //as floats both values would round to the bound
+        assert_noop!(
+            attest(vec![
+                Attribute {
+                    name: b"Moisture".to_vec(),
+                    fact: Fact::Float(12.5f64.to_le_bytes())
+                },
+                Attribute {
+                    name: b"Serial".to_vec(),
+                    fact: Fact::U128(u128::MAX - 2)
+                }
+            ]),
+            Error::<Test>::AttributeNotValid
+        );
+        assert_ok!(attest(vec![
+            Attribute {
+                name: b"Moisture".to_vec(),
+                fact: Fact::Float(12.5f64.to_le_bytes())
+            },
+            Attribute {
+                name: b"Serial".to_vec(),
+                fact: Fact::U128(u128::MAX - 1)
+            }
+        ]));
+        assert!(ProcessSteps::<Test>::contains_key(
+            (registry_id, definition_id, process_id),
+            0
+        ));
+    });
+}
+
+#[test]
+fn definition_versioning_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+
+        assert_ok!(Provenance::new_definition_version(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id
+        ));
+        let new_definition_id = 2u32;
+        let definition = Definitions::<Test>::get(registry_id, new_definition_id).unwrap();
+        assert_eq!(definition.status, DefinitionStatus::Draft);
+        assert_eq!(
+            Provenance::get_definition_steps(registry_id, new_definition_id),
+            Provenance::get_definition_steps(registry_id, definition_id)
+        );
+        assert_eq!(
+            DefinitionPredecessors::<Test>::get(registry_id, new_definition_id),
+            Some(definition_id)
+        );
+        assert_noop!(
+            Provenance::new_definition_version(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                definition_id
+            ),
+            Error::<Test>::NotLatestVersion
+        );
+        assert_noop!(
+            Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                new_definition_id,
+                b"TestProcess".to_vec(),
+                None,
+                vec![],
+                None
+            ),
+            Error::<Test>::IncorrectStatus
+        );
+
+        let steps = vec![
+            (
+                b"TestStep_1".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None,
+            ),
+            (
+                b"TestStep_2".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![0],
+                JoinRule::All,
+                vec![],
+                None,
+            ),
+        ];
+        assert_noop!(
+            Provenance::update_definition_draft(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                definition_id,
+                steps.clone()
+            ),
+            Error::<Test>::IncorrectStatus
+        );
+        assert_ok!(Provenance::update_definition_draft(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            new_definition_id,
+            steps
+        ));
+        assert_eq!(
+            Provenance::get_definition_steps(registry_id, new_definition_id).len(),
+            2
+        );
+
+        assert_ok!(Provenance::set_definition_active(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            new_definition_id
+        ));
+        let definition = Definitions::<Test>::get(registry_id, definition_id).unwrap();
+        assert_eq!(definition.status, DefinitionStatus::Inactive);
+        let definition = Definitions::<Test>::get(registry_id, new_definition_id).unwrap();
+        assert_eq!(definition.status, DefinitionStatus::Active);
+
+        // the in-flight process finishes on the original version
+        assert_ok!(Provenance::attest_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            vec![]
+        ));
+        let process = Processes::<Test>::get((registry_id, definition_id), process_id).unwrap();
+        assert_eq!(process.status, ProcessStatus::Completed);
+
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            new_definition_id,
+            b"TestProcess_2".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let new_process_id = 2u32;
+
+        let versions: Vec<u32> = Provenance::get_definition_versions(registry_id, definition_id)
+            .into_iter()
+            .map(|(definition_id, _)| definition_id)
+            .collect();
+        assert_eq!(versions, vec![definition_id, new_definition_id]);
+        let processes: Vec<(u32, u32)> =
+            Provenance::get_processes_all_versions(registry_id, new_definition_id)
+                .into_iter()
+                .map(|(definition_id, process_id, _)| (definition_id, process_id))
+                .collect();
+        assert_eq!(
+            processes,
+            vec![
+                (definition_id, process_id),
+                (new_definition_id, new_process_id)
+            ]
+        );
+
+        // removing a draft unlinks it from the lineage
+        assert_ok!(Provenance::new_definition_version(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            new_definition_id
+        ));
+        let draft_definition_id = 3u32;
+        assert_ok!(Provenance::remove_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            draft_definition_id
+        ));
+        assert!(!DefinitionSuccessors::<Test>::contains_key(
+            registry_id,
+            new_definition_id
+        ));
+        assert_eq!(
+            Provenance::get_definition_versions(registry_id, definition_id).len(),
+            2
+        );
+    });
+}
+
+#[test]
+fn process_lineage_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"TestStep".to_vec(),
+                ATTESTOR,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+        for name in [b"WheatA", b"WheatB", b"FlourC", b"ShipmD"].iter() {
+            assert_ok!(Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                name.to_vec(),
+                None,
+                vec![],
+                None
+            ));
+        }
+        let wheat_a = (registry_id, definition_id, 1u32);
+        let wheat_b = (registry_id, definition_id, 2u32);
+        let flour = (registry_id, definition_id, 3u32);
+        let shipment = (registry_id, definition_id, 4u32);
+        let link = |kind: ProcessLinkKind, quantity: u128| ProcessLink {
+            kind,
+            quantity,
+            unit: b"kg".to_vec(),
+        };
+
+        assert_noop!(
+            Provenance::link_processes(
+                Origin::signed(3),
+                registry_id,
+                definition_id,
+                flour.2,
+                vec![(wheat_a, link(ProcessLinkKind::Input, 600))]
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Provenance::link_processes(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                flour.2,
+                vec![(flour, link(ProcessLinkKind::Input, 600))]
+            ),
+            Error::<Test>::InvalidProcessLink
+        );
+        assert_noop!(
+            Provenance::link_processes(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                flour.2,
+                vec![(
+                    (registry_id, definition_id, 99u32),
+                    link(ProcessLinkKind::Input, 600)
+                )]
+            ),
+            Error::<Test>::NotFound
+        );
+
+        // the caller must also be authorized on the upstream definition
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"Other".to_vec()
+        ));
+        let other_registry_id = 2u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            other_registry_id,
+            b"OtherDefinition".to_vec(),
+            vec![(
+                b"TestStep".to_vec(),
+                3,
+                threshold,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let other_definition_id = 2u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(3),
+            other_registry_id,
+            other_definition_id,
+            b"WheatE".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let other_process_id = 5u32;
+        assert_noop!(
+            Provenance::link_processes(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                flour.2,
+                vec![(
+                    (other_registry_id, other_definition_id, other_process_id),
+                    link(ProcessLinkKind::Input, 600)
+                )]
+            ),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(Provenance::link_processes(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            flour.2,
+            vec![
+                (wheat_a, link(ProcessLinkKind::Input, 600)),
+                (wheat_b, link(ProcessLinkKind::Input, 400))
+            ]
+        ));
+        assert_ok!(Provenance::link_processes(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            shipment.2,
+            vec![(flour, link(ProcessLinkKind::Split, 250))]
+        ));
+        assert_eq!(
+            ProcessLinks::<Test>::get(flour, wheat_a),
+            Some(ProcessLink {
+                kind: ProcessLinkKind::Input,
+                quantity: 600,
+                unit: b"kg".to_vec().try_into().unwrap()
+            })
+        );
+
+        let edges = |links: Vec<(u32, (u32, u32, u32), (u32, u32, u32), _)>| {
+            let mut edges: Vec<(u32, u32, u32)> = links
+                .into_iter()
+                .map(|(depth, downstream, upstream, _)| (depth, downstream.2, upstream.2))
+                .collect();
+            edges.sort_unstable();
+            edges
+        };
+
+        assert_eq!(
+            edges(Provenance::trace_back(
+                registry_id,
+                definition_id,
+                shipment.2,
+                10
+            )),
+            vec![(1, 4, 3), (2, 3, 1), (2, 3, 2)]
+        );
+        assert_eq!(
+            edges(Provenance::trace_back(
+                registry_id,
+                definition_id,
+                shipment.2,
+                1
+            )),
+            vec![(1, 4, 3)]
+        );
+        assert_eq!(
+            edges(Provenance::trace_forward(
+                registry_id,
+                definition_id,
+                wheat_a.2,
+                10
+            )),
+            vec![(1, 3, 1), (2, 4, 3)]
+        );
+
+        assert_ok!(Provenance::unlink_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            flour.2,
+            wheat_b
+        ));
+        assert_eq!(
+            edges(Provenance::trace_back(
+                registry_id,
+                definition_id,
+                flour.2,
+                10
+            )),
+            vec![(1, 3, 1)]
+        );
+
+        assert_ok!(Provenance::remove_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            shipment.2
+        ));
+        assert!(Provenance::trace_forward(registry_id, definition_id, flour.2, 10).is_empty());
+
+        // a process may be linked to at most `ProcessLinkLimit` upstream processes
+        let link_limit = <Test as Config>::ProcessLinkLimit::get();
+        for i in 0..link_limit {
+            assert_ok!(Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                i.to_le_bytes().to_vec(),
+                None,
+                vec![],
+                None
+            ));
+        }
+        let first_new_process_id = 5u32;
+        let too_many_links = (first_new_process_id..first_new_process_id + link_limit)
+            .map(|process_id| {
+                (
+                    (registry_id, definition_id, process_id),
+                    link(ProcessLinkKind::Input, 1),
+                )
+            })
+            .collect();
+        // flour is already linked to wheat_a
+        assert_noop!(
+            Provenance::link_processes(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                flour.2,
+                too_many_links
+            ),
+            Error::<Test>::TooManyProcessLinks
+        );
+    });
+}
+
+#[test]
+fn process_rejection_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![
+                (
+                    b"LabTest".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![],
+                    None
+                ),
+                (
+                    b"Packing".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![0],
+                    JoinRule::All,
+                    vec![],
+                    None
+                )
+            ]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+        let findings = || {
+            vec![Attribute {
+                name: b"Aflatoxin".to_vec(),
+                fact: Fact::U32(35),
+            }]
+        };
+
+        assert_noop!(
+            Provenance::reject_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                b"Aflatoxin above limit".to_vec(),
+                findings(),
+                ProcessStatus::Completed
+            ),
+            Error::<Test>::IncorrectStatus
+        );
+        assert_noop!(
+            Provenance::reject_process_step(
+                Origin::signed(3),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                b"Aflatoxin above limit".to_vec(),
+                findings(),
+                ProcessStatus::OnHold
+            ),
+            Error::<Test>::NotAttestor
+        );
+        assert_noop!(
+            Provenance::reject_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                1,
+                b"Aflatoxin above limit".to_vec(),
+                findings(),
+                ProcessStatus::OnHold
+            ),
+            Error::<Test>::ProcessStepNotCurrent
+        );
+
+        assert_ok!(Provenance::reject_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            b"Aflatoxin above limit".to_vec(),
+            findings(),
+            ProcessStatus::OnHold
+        ));
+        assert_eq!(
+            Processes::<Test>::get((registry_id, definition_id), process_id)
+                .unwrap()
+                .status,
+            ProcessStatus::OnHold
+        );
+        assert_eq!(
+            Provenance::get_process_step_rejections(registry_id, definition_id, process_id),
+            vec![(
+                0,
+                ProcessStepRejection {
+                    proposal_id: None,
+                    reason: b"Aflatoxin above limit".to_vec().try_into().unwrap(),
+                    attributes: vec![Attribute {
+                        name: b"Aflatoxin".to_vec().try_into().unwrap(),
+                        fact: Fact::U32(35)
+                    }]
+                }
+            )]
+        );
+        assert_eq!(
+            Provenance::get_process_steps(registry_id, definition_id, process_id)[0].1,
+            ProcessStepStatus::Rejected
+        );
+        assert_eq!(
+            Provenance::get_processes_by_status(registry_id, definition_id, ProcessStatus::OnHold)
+                .len(),
+            1
+        );
+        assert_eq!(
+            Provenance::get_processes_for_attestor_by_status(ATTESTOR, ProcessStatus::OnHold).len(),
+            1
+        );
+        assert!(Provenance::get_processes_for_attestor_pending(ATTESTOR).is_empty());
+
+        assert_noop!(
+            Provenance::attest_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                vec![]
+            ),
+            Error::<Test>::IncorrectStatus
+        );
+        assert_noop!(
+            Provenance::resume_process(Origin::signed(3), registry_id, definition_id, process_id),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(Provenance::resume_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        assert_eq!(
+            Processes::<Test>::get((registry_id, definition_id), process_id)
+                .unwrap()
+                .status,
+            ProcessStatus::InProgress
+        );
+        assert_eq!(
+            Provenance::get_process_steps(registry_id, definition_id, process_id)[0].1,
+            ProcessStepStatus::Eligible
+        );
+
+        assert_ok!(Provenance::reject_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            b"Aflatoxin above limit on retest".to_vec(),
+            findings(),
+            ProcessStatus::Rejected
+        ));
+        assert_noop!(
+            Provenance::resume_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Provenance::resume_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        assert_noop!(
+            Provenance::resume_process(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                definition_id,
+                process_id
+            ),
+            Error::<Test>::IncorrectStatus
+        );
+
+        for definition_step_index in 0..2 {
+            assert_ok!(Provenance::attest_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                definition_step_index,
+                vec![]
+            ));
+        }
+        assert_eq!(
+            Processes::<Test>::get((registry_id, definition_id), process_id)
+                .unwrap()
+                .status,
+            ProcessStatus::Completed
+        );
+
+        assert_ok!(Provenance::remove_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        assert!(
+            Provenance::get_process_step_rejections(registry_id, definition_id, process_id)
+                .is_empty()
+        );
+    });
+}
+
+#[test]
+fn process_subjects_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"Harvest".to_vec(),
+                ATTESTOR,
+                1u32,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+
+        assert_noop!(
+            Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                b"TestProcess".to_vec(),
+                Some((1, 2)),
+                vec![],
+                None
+            ),
+            Error::<Test>::AssetNotFound
+        );
+        assert_noop!(
+            Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                b"TestProcess".to_vec(),
+                None,
+                vec![Did { id: [2u8; 32] }],
+                None
+            ),
+            Error::<Test>::DidNotFound
+        );
+        assert_noop!(
+            Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                b"TestProcess".to_vec(),
+                None,
+                vec![FARMER_DID, FARMER_DID],
+                None
+            ),
+            Error::<Test>::InvalidParticipants
+        );
+        assert_noop!(
+            Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                b"TestProcess".to_vec(),
+                None,
+                vec![],
+                Some(Fact::Bool(true))
+            ),
+            Error::<Test>::InvalidLocation
+        );
+
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            Some(ASSET),
+            vec![FARMER_DID],
+            Some(Fact::Location(51_507_400, 127_800))
+        ));
+        let process_id = 1u32;
+        let process = Processes::<Test>::get((registry_id, definition_id), process_id).unwrap();
+        assert_eq!(process.asset, Some(ASSET));
+        assert_eq!(process.participants, vec![FARMER_DID]);
+        assert_eq!(process.location, Some(Fact::Location(51_507_400, 127_800)));
+
+        let by_asset = Provenance::get_processes_for_asset(ASSET.0, ASSET.1);
+        assert_eq!(by_asset.len(), 1);
+        assert_eq!(
+            (by_asset[0].0, by_asset[0].1, by_asset[0].2),
+            (registry_id, definition_id, process_id)
+        );
+        let by_did = Provenance::get_processes_for_did(FARMER_DID);
+        assert_eq!(by_did.len(), 1);
+        assert_eq!(by_did[0].3, process);
+
+        assert_ok!(Provenance::remove_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        assert!(Provenance::get_processes_for_asset(ASSET.0, ASSET.1).is_empty());
+        assert!(Provenance::get_processes_for_did(FARMER_DID).is_empty());
+    });
+}
+
+#[test]
+fn attestation_evidence_should_be_recorded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"Harvest".to_vec(),
+                ATTESTOR,
+                1u32,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+
+        System::set_block_number(5);
+        Timestamp::set_timestamp(1_622_505_600_000);
+        assert_ok!(Provenance::attest_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            vec![]
+        ));
+
+        assert_eq!(
+            Provenance::get_process_step_evidence(registry_id, definition_id, process_id),
+            vec![(
+                0,
+                AttestationEvidence {
+                    block_number: 5,
+                    extrinsic_index: 0,
+                    timestamp: 1_622_505_600_000,
+                }
+            )]
+        );
+
+        assert_ok!(Provenance::remove_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        assert!(
+            Provenance::get_process_step_evidence(registry_id, definition_id, process_id)
+                .is_empty()
+        );
+    });
+}
+
+#[test]
+fn public_trace_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"Harvest".to_vec(),
+                ATTESTOR,
+                1u32,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            Some(Fact::Location(51_507_400, 127_800))
+        ));
+        let process_id = 1u32;
+
+        System::set_block_number(5);
+        Timestamp::set_timestamp(1_622_505_600_000);
+        assert_ok!(Provenance::attest_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            vec![
+                Attribute {
+                    name: b"Variety".to_vec(),
+                    fact: Fact::Text(b"Arabica".to_vec())
+                },
+                Attribute {
+                    name: b"Price".to_vec(),
+                    fact: Fact::U32(1200)
+                }
+            ]
+        ));
+
+        // definitions are private until made public
+        assert!(Provenance::get_public_trace(registry_id, definition_id, process_id).is_none());
+
+        assert_noop!(
+            Provenance::set_definition_visibility(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                Some(DefinitionVisibility {
+                    public_attributes: vec![b"Variety".to_vec()],
+                    show_location: false,
+                })
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Provenance::set_definition_visibility(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                definition_id,
+                Some(DefinitionVisibility {
+                    public_attributes: vec![b"Variety".to_vec(), b"Variety".to_vec()],
+                    show_location: false,
+                })
+            ),
+            Error::<Test>::InvalidVisibility
+        );
+
+        assert_ok!(Provenance::set_definition_visibility(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            Some(DefinitionVisibility {
+                public_attributes: vec![b"Variety".to_vec()],
+                show_location: false,
+            })
+        ));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            crate::mock::Event::pallet_provenance(crate::Event::DefinitionVisibilityUpdated(
+                DEFINITION_OWNER,
+                DEFINITION_OWNER,
+                registry_id,
+                definition_id
+            ))
+        );
+
+        let (name, status, location, steps) =
+            Provenance::get_public_trace(registry_id, definition_id, process_id).unwrap();
+        assert_eq!(name, b"TestProcess".to_vec().try_into().unwrap());
+        assert_eq!(status, ProcessStatus::Completed);
+        assert_eq!(location, None);
+        assert_eq!(
+            steps,
+            vec![PublicProcessStep {
+                definition_step_index: 0,
+                name: b"Harvest".to_vec().try_into().unwrap(),
+                attestor_name: None,
+                attributes: vec![Attribute {
+                    name: b"Variety".to_vec().try_into().unwrap(),
+                    fact: Fact::Text(b"Arabica".to_vec().try_into().unwrap())
+                }],
+                evidence: AttestationEvidence {
+                    block_number: 5,
+                    extrinsic_index: 0,
+                    timestamp: 1_622_505_600_000,
+                },
+            }]
+        );
+
+        assert_ok!(Provenance::set_definition_visibility(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            Some(DefinitionVisibility {
+                public_attributes: vec![],
+                show_location: true,
+            })
+        ));
+        let (_, _, location, steps) =
+            Provenance::get_public_trace(registry_id, definition_id, process_id).unwrap();
+        assert_eq!(location, Some(Fact::Location(51_507_400, 127_800)));
+        assert!(steps[0].attributes.is_empty());
+
+        assert_ok!(Provenance::set_definition_visibility(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            None
+        ));
+        assert!(Provenance::get_definition_visibility(registry_id, definition_id).is_none());
+        assert!(Provenance::get_public_trace(registry_id, definition_id, process_id).is_none());
+    });
+}
+
+#[test]
+fn amend_process_step_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![(
+                b"Weighing".to_vec(),
+                ATTESTOR,
+                1u32,
+                vec![],
+                JoinRule::All,
+                vec![],
+                None
+            )]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+        let weight = |value: u32| {
+            vec![Attribute {
+                name: b"Weight".to_vec(),
+                fact: Fact::U32(value),
+            }]
+        };
+
+        assert_noop!(
+            Provenance::amend_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                b"Typo".to_vec(),
+                weight(1000)
+            ),
+            Error::<Test>::ProcessStepNotAttested
+        );
+
+        System::set_block_number(5);
+        assert_ok!(Provenance::attest_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            weight(10000)
+        ));
+
+        assert_noop!(
+            Provenance::amend_process_step(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                b"Typo".to_vec(),
+                weight(1000)
+            ),
+            Error::<Test>::NotAttestor
+        );
+
+        System::set_block_number(6);
+        Timestamp::set_timestamp(1_622_505_600_000);
+        assert_ok!(Provenance::amend_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            b"Typo".to_vec(),
+            weight(1000)
+        ));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            crate::mock::Event::pallet_provenance(crate::Event::ProcessStepAmended(
+                ATTESTOR,
+                registry_id,
+                definition_id,
+                process_id,
+                0
+            ))
+        );
+
+        System::set_block_number(7);
+        assert_ok!(Provenance::amend_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            b"Scale recalibrated".to_vec(),
+            weight(1010)
+        ));
+
+        let (process_step, amendments) =
+            Provenance::get_process_step(registry_id, definition_id, process_id, 0).unwrap();
+        assert_eq!(
+            process_step.attributes,
+            vec![Attribute {
+                name: b"Weight".to_vec().try_into().unwrap(),
+                fact: Fact::U32(1010)
+            }]
+        );
+        assert_eq!(amendments.len(), 2);
+        assert_eq!(amendments[0].reason, b"Typo".to_vec().try_into().unwrap());
+        assert_eq!(
+            amendments[0].evidence,
+            AttestationEvidence {
+                block_number: 6,
+                extrinsic_index: 0,
+                timestamp: 1_622_505_600_000,
+            }
+        );
+        assert_eq!(
+            amendments[1].reason,
+            b"Scale recalibrated".to_vec().try_into().unwrap()
+        );
+        assert_eq!(amendments[1].evidence.block_number, 7);
+
+        // the attestation as originally recorded is kept, the steps of the process show the amendment
+        let (_, _, current) =
+            Provenance::get_process_steps(registry_id, definition_id, process_id)[0].clone();
+        assert_eq!(current.unwrap().attributes, process_step.attributes);
+        assert_eq!(
+            ProcessSteps::<Test>::get((registry_id, definition_id, process_id), 0)
+                .unwrap()
+                .attributes,
+            vec![Attribute {
+                name: b"Weight".to_vec().try_into().unwrap(),
+                fact: Fact::U32(10000)
+            }]
+        );
+
+        let amendment_limit = <Test as Config>::AmendmentLimit::get();
+        for _ in 2..amendment_limit {
+            assert_ok!(Provenance::amend_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                b"Scale recalibrated".to_vec(),
+                weight(1010)
+            ));
+        }
+        assert_noop!(
+            Provenance::amend_process_step(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                process_id,
+                0,
+                b"Scale recalibrated".to_vec(),
+                weight(1010)
+            ),
+            Error::<Test>::TooManyAmendments
+        );
+
+        assert_ok!(Provenance::remove_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        assert!(Provenance::get_process_step(registry_id, definition_id, process_id, 0).is_none());
+        assert_eq!(
+            ProcessStepAmendments::<Test>::iter_prefix((registry_id, definition_id, process_id))
+                .count(),
+            0
+        );
+        assert_eq!(
+            Provenance::process_step_amendment_count((registry_id, definition_id, process_id), 0),
+            0
+        );
+    });
+}
+
+#[test]
+fn step_deadlines_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Provenance::create_registry(
+            Origin::signed(DEFINITION_OWNER),
+            b"John Doe".to_vec()
+        ));
+        let registry_id = 1u32;
+        let threshold = 1u32;
+
+        assert_noop!(
+            Provenance::create_definition(
+                Origin::signed(DEFINITION_OWNER),
+                registry_id,
+                b"TestDefinition".to_vec(),
+                vec![(
+                    b"Milking".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![],
+                    Some(0)
+                )]
+            ),
+            Error::<Test>::InvalidMaxDuration
+        );
+
+        assert_ok!(Provenance::create_definition(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            b"TestDefinition".to_vec(),
+            vec![
+                (
+                    b"Milking".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![],
+                    JoinRule::All,
+                    vec![],
+                    Some(10)
+                ),
+                (
+                    b"Chilling".to_vec(),
+                    ATTESTOR,
+                    threshold,
+                    vec![0],
+                    JoinRule::All,
+                    vec![],
+                    Some(5)
+                )
+            ]
+        ));
+        let definition_id = 1u32;
+        assert_ok!(Provenance::create_process(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            b"TestProcess".to_vec(),
+            None,
+            vec![],
+            None
+        ));
+        let process_id = 1u32;
+
+        let overdue_events = || {
+            System::events()
+                .into_iter()
+                .filter(|record| {
+                    matches!(
+                        record.event,
+                        crate::mock::Event::pallet_provenance(crate::Event::ProcessStepOverdue(..))
+                    )
+                })
+                .count()
+        };
+        let step_status = |step_index: usize| {
+            Provenance::get_process_steps(registry_id, definition_id, process_id)[step_index].1
+        };
+        let run_to_block = |block_number: u64| {
+            System::set_block_number(block_number);
+            <Provenance as frame_support::traits::OnInitialize<u64>>::on_initialize(block_number);
+        };
+
+        assert_eq!(Provenance::process_step_deadline_count(11), 1);
+        run_to_block(10);
+        assert_eq!(overdue_events(), 0);
+        assert_eq!(step_status(0), ProcessStepStatus::Eligible);
+        assert!(Provenance::get_overdue_processes(ATTESTOR).is_empty());
+
+        run_to_block(11);
+        assert_eq!(overdue_events(), 1);
+        assert_eq!(Provenance::process_step_deadline_count(11), 0);
+        assert_eq!(step_status(0), ProcessStepStatus::Overdue);
+        let overdue = Provenance::get_overdue_processes(ATTESTOR);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(
+            (overdue[0].0, overdue[0].1, overdue[0].2),
+            (registry_id, definition_id, process_id)
+        );
+        assert!(Provenance::get_overdue_processes(DEFINITION_OWNER).is_empty());
+
+        assert_ok!(Provenance::attest_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            0,
+            vec![]
+        ));
+        assert_eq!(step_status(1), ProcessStepStatus::Eligible);
+        assert!(Provenance::get_overdue_processes(ATTESTOR).is_empty());
+
+        // a process on hold is not overdue
+        run_to_block(12);
+        assert_ok!(Provenance::reject_process_step(
+            Origin::signed(ATTESTOR),
+            registry_id,
+            definition_id,
+            process_id,
+            1,
+            b"Tank temperature too high".to_vec(),
+            vec![],
+            ProcessStatus::OnHold
+        ));
+        run_to_block(16);
+        assert_eq!(overdue_events(), 1);
+        assert!(Provenance::get_overdue_processes(ATTESTOR).is_empty());
+
+        // resuming restarts the clock on the eligible step
+        run_to_block(20);
+        assert_ok!(Provenance::resume_process(
+            Origin::signed(DEFINITION_OWNER),
+            registry_id,
+            definition_id,
+            process_id
+        ));
+        run_to_block(24);
+        assert_eq!(overdue_events(), 1);
+        assert_eq!(step_status(1), ProcessStepStatus::Eligible);
+        run_to_block(25);
+        assert_eq!(overdue_events(), 2);
+        assert_eq!(step_status(1), ProcessStepStatus::Overdue);
+        assert_eq!(Provenance::get_overdue_processes(ATTESTOR).len(), 1);
+
+        // once a block holds `MaxDeadlinesPerBlock` deadlines, later ones move to the next block
+        let max_deadlines = <Test as Config>::MaxDeadlinesPerBlock::get();
+        for i in 0..=max_deadlines {
+            assert_ok!(Provenance::create_process(
+                Origin::signed(ATTESTOR),
+                registry_id,
+                definition_id,
+                i.to_le_bytes().to_vec(),
+                None,
+                vec![],
+                None
+            ));
+        }
+        assert_eq!(Provenance::process_step_deadline_count(35), max_deadlines);
+        assert_eq!(Provenance::process_step_deadline_count(36), 1);
+        run_to_block(35);
+        assert_eq!(overdue_events(), 2 + max_deadlines as usize);
+        run_to_block(36);
+        assert_eq!(overdue_events(), 3 + max_deadlines as usize);
+        assert_eq!(
+            Provenance::get_overdue_processes(ATTESTOR).len(),
+            2 + max_deadlines as usize
+        );
+    });
+}
+
 //Make sure weights cannot exceed 10% of total allowance for block.
 
 #[test]
@@ -518,6 +2402,7 @@ fn weights_should_not_be_excessive() {
             <Test as Config>::NameLimit::get(),
             <Test as Config>::NameLimit::get(),
             <Test as Config>::DefinitionStepLimit::get(),
+            <Test as Config>::AttributeLimit::get(),
         );
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
         let weight = <Test as Config>::WeightInfo::set_definition_active();
@@ -528,16 +2413,35 @@ fn weights_should_not_be_excessive() {
             <Test as Config>::DefinitionStepLimit::get(),
         );
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight = <Test as Config>::WeightInfo::update_definition_step();
+        let weight = <Test as Config>::WeightInfo::link_processes(100);
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
-        let weight =
-            <Test as Config>::WeightInfo::create_process(<Test as Config>::NameLimit::get());
+        let weight = <Test as Config>::WeightInfo::unlink_process();
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::new_definition_version(
+            <Test as Config>::DefinitionStepLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::update_definition_draft(
+            <Test as Config>::DefinitionStepLimit::get(),
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::DefinitionStepLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::update_definition_step(
+            <Test as Config>::AttributeLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::create_process(
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::ParticipantLimit::get(),
+        );
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
         let weight =
             <Test as Config>::WeightInfo::update_process(<Test as Config>::NameLimit::get());
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
         let weight = <Test as Config>::WeightInfo::remove_process(
             <Test as Config>::DefinitionStepLimit::get(),
+            <Test as Config>::ParticipantLimit::get(),
         );
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
         let weight = <Test as Config>::WeightInfo::attest_process_step(
@@ -546,5 +2450,80 @@ fn weights_should_not_be_excessive() {
             <Test as Config>::FactStringLimit::get(),
         );
         assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::reject_process_step(
+            <Test as Config>::AttributeLimit::get(),
+            <Test as Config>::NameLimit::get(),
+            <Test as Config>::FactStringLimit::get(),
+            <Test as Config>::FactStringLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::resume_process(
+            <Test as Config>::DefinitionStepLimit::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+        let weight = <Test as Config>::WeightInfo::on_initialize(
+            <Test as Config>::MaxDeadlinesPerBlock::get(),
+        );
+        assert!(weight < MAXIMUM_ALLOWED_WEIGHT);
+    });
+}
+
+#[test]
+fn migrate_to_v3_from_v1_should_work() {
+    new_test_ext().execute_with(|| {
+        use crate::migration::deprecated::{OldDefinitionStep, OldProcess, OldProcessStep};
+        use frame_support::storage::unhashed;
+
+        let registry_id = 1u32;
+        let definition_id = 1u32;
+        let process_id = 1u32;
+
+        // seed storage as it was encoded in V1
+        StorageVersion::<Test>::put(Releases::V1);
+        for step_index in 0..2u32 {
+            unhashed::put(
+                &DefinitionSteps::<Test>::hashed_key_for((registry_id, definition_id), step_index),
+                &OldDefinitionStep {
+                    name: b"TestStep".to_vec(),
+                    attestor: ATTESTOR,
+                    threshold: 1u32,
+                },
+            );
+        }
+        unhashed::put(
+            &Processes::<Test>::hashed_key_for((registry_id, definition_id), process_id),
+            &OldProcess {
+                name: b"TestProcess".to_vec(),
+                status: ProcessStatus::InProgress,
+            },
+        );
+        unhashed::put(
+            &ProcessSteps::<Test>::hashed_key_for((registry_id, definition_id, process_id), 0u32),
+            &OldProcessStep::<Vec<u8>, Vec<u8>> { attributes: vec![] },
+        );
+
+        crate::migration::migrate_to_v3::<Test>();
+
+        assert_eq!(StorageVersion::<Test>::get(), Some(Releases::V3));
+        assert_eq!(
+            ProcessSteps::<Test>::get((registry_id, definition_id, process_id), 0u32),
+            Some(ProcessStep {
+                proposal_id: None,
+                attributes: vec![]
+            })
+        );
+        let definition_step =
+            DefinitionSteps::<Test>::get((registry_id, definition_id), 1u32).unwrap();
+        assert_eq!(definition_step.attestor, ATTESTOR);
+        assert_eq!(definition_step.predecessors, vec![0u32]);
+        let process = Processes::<Test>::get((registry_id, definition_id), process_id).unwrap();
+        assert_eq!(process.name, b"TestProcess".to_vec());
+        assert_eq!(process.asset, None);
+
+        // the step after the last attested one is eligible
+        assert!(EligibleSteps::<Test>::contains_key(
+            (registry_id, definition_id, process_id),
+            1u32
+        ));
     });
 }
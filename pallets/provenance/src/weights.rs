@@ -53,15 +53,24 @@ pub trait WeightInfo {
     fn create_registry(a: u32) -> Weight;
     fn update_registry(a: u32) -> Weight;
     fn remove_registry() -> Weight;
-    fn create_definition(a: u32, b: u32, c: u32) -> Weight;
+    fn create_definition(a: u32, b: u32, c: u32, d: u32) -> Weight;
     fn set_definition_active() -> Weight;
     fn set_definition_inactive() -> Weight;
     fn remove_definition(a: u32) -> Weight;
-    fn update_definition_step() -> Weight;
-    fn create_process(a: u32) -> Weight;
+    fn update_definition_step(a: u32) -> Weight;
+    fn create_process(a: u32, b: u32) -> Weight;
     fn update_process(a: u32) -> Weight;
-    fn remove_process(a: u32) -> Weight;
+    fn remove_process(a: u32, b: u32) -> Weight;
     fn attest_process_step(a: u32, b: u32, c: u32) -> Weight;
+    fn new_definition_version(a: u32) -> Weight;
+    fn update_definition_draft(a: u32, b: u32, c: u32) -> Weight;
+    fn link_processes(a: u32) -> Weight;
+    fn unlink_process() -> Weight;
+    fn reject_process_step(a: u32, b: u32, c: u32, d: u32) -> Weight;
+    fn resume_process(a: u32) -> Weight;
+    fn on_initialize(a: u32) -> Weight;
+    fn set_definition_visibility(a: u32) -> Weight;
+    fn amend_process_step(a: u32, b: u32, c: u32, d: u32) -> Weight;
 }
 
 /// Weights for pallet_provenance using the Substrate node and recommended hardware.
@@ -84,7 +93,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
-    fn create_definition(a: u32, b: u32, c: u32) -> Weight {
+    fn create_definition(a: u32, b: u32, c: u32, d: u32) -> Weight {
         (0 as Weight)
             // Standard Error: 44_000
             .saturating_add((114_000 as Weight).saturating_mul(a as Weight))
@@ -92,6 +101,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add((2_213_000 as Weight).saturating_mul(b as Weight))
             // Standard Error: 44_000
             .saturating_add((19_855_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((1_352_000 as Weight).saturating_mul(d as Weight))
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(2 as Weight))
             .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
@@ -115,17 +125,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
             .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
     }
-    fn update_definition_step() -> Weight {
+    fn update_definition_step(a: u32) -> Weight {
         (156_973_000 as Weight)
+            .saturating_add((1_352_000 as Weight).saturating_mul(a as Weight))
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
-    fn create_process(a: u32) -> Weight {
+    fn create_process(a: u32, b: u32) -> Weight {
         (150_088_000 as Weight)
             // Standard Error: 2_000
             .saturating_add((12_000 as Weight).saturating_mul(a as Weight))
-            .saturating_add(T::DbWeight::get().reads(4 as Weight))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            // Standard Error: 31_000
+            .saturating_add((6_204_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(b as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(b as Weight)))
     }
     fn update_process(a: u32) -> Weight {
         (115_718_000 as Weight)
@@ -134,12 +149,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
-    fn remove_process(a: u32) -> Weight {
+    fn remove_process(a: u32, b: u32) -> Weight {
         (150_608_000 as Weight)
             // Standard Error: 5_000
             .saturating_add((26_000 as Weight).saturating_mul(a as Weight))
+            // Standard Error: 18_000
+            .saturating_add((3_870_000 as Weight).saturating_mul(b as Weight))
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(b as Weight)))
     }
     fn attest_process_step(a: u32, b: u32, c: u32) -> Weight {
         (0 as Weight)
@@ -149,8 +167,72 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add((1_708_000 as Weight).saturating_mul(b as Weight))
             // Standard Error: 51_000
             .saturating_add((1_712_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn new_definition_version(a: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add((21_450_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn update_definition_draft(a: u32, b: u32, c: u32) -> Weight {
+        (62_000_000 as Weight)
+            .saturating_add((2_213_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((19_855_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((61_240_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn link_processes(a: u32) -> Weight {
+        (64_000_000 as Weight)
+            .saturating_add((38_500_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+    }
+    fn unlink_process() -> Weight {
+        (71_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn reject_process_step(a: u32, b: u32, c: u32, d: u32) -> Weight {
+        (0 as Weight)
+            .saturating_add((4_153_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((1_708_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(d as Weight))
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn resume_process(a: u32) -> Weight {
+        (54_312_000 as Weight)
+            .saturating_add((11_845_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn on_initialize(a: u32) -> Weight {
+        (2_101_000 as Weight)
+            .saturating_add((9_412_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(a as Weight)))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn set_definition_visibility(a: u32) -> Weight {
+        (32_614_000 as Weight)
+            .saturating_add((1_396_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn amend_process_step(a: u32, b: u32, c: u32, d: u32) -> Weight {
+        (0 as Weight)
+            .saturating_add((4_318_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((1_708_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
 }
 
@@ -173,7 +255,7 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2 as Weight))
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
-    fn create_definition(a: u32, b: u32, c: u32) -> Weight {
+    fn create_definition(a: u32, b: u32, c: u32, d: u32) -> Weight {
         (0 as Weight)
             // Standard Error: 44_000
             .saturating_add((114_000 as Weight).saturating_mul(a as Weight))
@@ -181,6 +263,7 @@ impl WeightInfo for () {
             .saturating_add((2_213_000 as Weight).saturating_mul(b as Weight))
             // Standard Error: 44_000
             .saturating_add((19_855_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((1_352_000 as Weight).saturating_mul(d as Weight))
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes(2 as Weight))
             .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
@@ -204,17 +287,22 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
             .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
     }
-    fn update_definition_step() -> Weight {
+    fn update_definition_step(a: u32) -> Weight {
         (156_973_000 as Weight)
+            .saturating_add((1_352_000 as Weight).saturating_mul(a as Weight))
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes(2 as Weight))
     }
-    fn create_process(a: u32) -> Weight {
+    fn create_process(a: u32, b: u32) -> Weight {
         (150_088_000 as Weight)
             // Standard Error: 2_000
             .saturating_add((12_000 as Weight).saturating_mul(a as Weight))
-            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            // Standard Error: 31_000
+            .saturating_add((6_204_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(b as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(b as Weight)))
     }
     fn update_process(a: u32) -> Weight {
         (115_718_000 as Weight)
@@ -223,12 +311,15 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2 as Weight))
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
-    fn remove_process(a: u32) -> Weight {
+    fn remove_process(a: u32, b: u32) -> Weight {
         (150_608_000 as Weight)
             // Standard Error: 5_000
             .saturating_add((26_000 as Weight).saturating_mul(a as Weight))
+            // Standard Error: 18_000
+            .saturating_add((3_870_000 as Weight).saturating_mul(b as Weight))
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(b as Weight)))
     }
     fn attest_process_step(a: u32, b: u32, c: u32) -> Weight {
         (0 as Weight)
@@ -238,7 +329,71 @@ impl WeightInfo for () {
             .saturating_add((1_708_000 as Weight).saturating_mul(b as Weight))
             // Standard Error: 51_000
             .saturating_add((1_712_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn new_definition_version(a: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add((21_450_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn update_definition_draft(a: u32, b: u32, c: u32) -> Weight {
+        (62_000_000 as Weight)
+            .saturating_add((2_213_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((19_855_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((61_240_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn link_processes(a: u32) -> Weight {
+        (64_000_000 as Weight)
+            .saturating_add((38_500_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+    }
+    fn unlink_process() -> Weight {
+        (71_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn reject_process_step(a: u32, b: u32, c: u32, d: u32) -> Weight {
+        (0 as Weight)
+            .saturating_add((4_153_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((1_708_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(d as Weight))
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn resume_process(a: u32) -> Weight {
+        (54_312_000 as Weight)
+            .saturating_add((11_845_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn on_initialize(a: u32) -> Weight {
+        (2_101_000 as Weight)
+            .saturating_add((9_412_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(a as Weight)))
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn set_definition_visibility(a: u32) -> Weight {
+        (32_614_000 as Weight)
+            .saturating_add((1_396_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn amend_process_step(a: u32, b: u32, c: u32, d: u32) -> Weight {
+        (0 as Weight)
+            .saturating_add((4_318_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((1_708_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((1_712_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
     }
 }
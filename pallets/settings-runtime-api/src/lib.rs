@@ -24,5 +24,7 @@ sp_api::decl_runtime_apis! {
 
         fn get_extrinsic_extras() -> Vec<(ModuleIndex,Vec<(ExtrinsicIndex,Balance)>)>;
 
+        fn get_extrinsic_deposit(module_index:ModuleIndex,extrinsic_index:ExtrinsicIndex) -> Option<Balance>;
+
     }
 }
@@ -91,6 +91,41 @@ benchmarks! {
     verify {
         assert!(!<ExtrinsicExtra<T>>::contains_key(module_index,extrinsic_index));
     }
+
+    set_extrinsic_deposit {
+
+        let caller:T::AccountId = whitelisted_caller();
+
+        let new_deposit:T::Balance=10u32.into();
+
+        let module_index:T::ModuleIndex=1u8.into();
+        let extrinsic_index:T::ExtrinsicIndex=1u8.into();
+
+    }: _(SystemOrigin::Root,module_index,extrinsic_index,new_deposit)
+
+    verify {
+        let stored_deposit=<ExtrinsicDeposit<T>>::get(module_index,extrinsic_index);
+        assert!(stored_deposit.is_some());
+        let stored_deposit=stored_deposit.unwrap();
+        assert_eq!(stored_deposit,new_deposit);
+    }
+
+    remove_extrinsic_deposit {
+
+        let caller:T::AccountId = whitelisted_caller();
+
+        let new_deposit:T::Balance=10u32.into();
+
+        let module_index:T::ModuleIndex=1u8.into();
+        let extrinsic_index:T::ExtrinsicIndex=1u8.into();
+
+        SettingsPallet::<T>::set_extrinsic_deposit(SystemOrigin::Root.into(),module_index,extrinsic_index,new_deposit)?;
+
+    }: _(SystemOrigin::Root,module_index,extrinsic_index)
+
+    verify {
+        assert!(!<ExtrinsicDeposit<T>>::contains_key(module_index,extrinsic_index));
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -7,6 +7,8 @@
 //! * The **Fee Split Ratio**. This is the proportion of fees that go to the Treasury vs the block author (validator owner).
 //! * **Extrinsic Extras**. These are special fees that are charged for specific extrinsics.
 //!   Typicaly they involve creating objects on chain such as Audits, Process Definitions etc.
+//! * **Extrinsic Deposits**. These are refundable deposits reserved by specific extrinsics, such as group proposals.
+//!   The calling module decides when the deposit is returned or slashed.
 //! * **Weight to Fee Coefficients**. These are the coeffients used for the Weight to Fee Polinomial.
 //!   For more detail see: https://substrate.dev/recipes/fees.html
 //!
@@ -19,6 +21,8 @@
 //! * `set_fee_split_ratio` - Set the **Fee Split Ratio**.
 //! * `set_extrinsic_extra` - Set an **Extrinsic Extra**.
 //! * `remove_extrinsic_extra` - Set an **Extrinsic Extra**.
+//! * `set_extrinsic_deposit` - Set an **Extrinsic Deposit**.
+//! * `remove_extrinsic_deposit` - Remove an **Extrinsic Deposit**.
 //!
 //! ### RPC Methods
 //!
@@ -27,6 +31,7 @@
 //! * `get_fee_split_ratio` - Get the current **Fee Split Ratio**.
 //! * `get_extrinsic_extra` - Get a specific **Extrinsic Extra**.
 //! * `get_extrinsic_extras` - Get all the current **Extrinsic Extras**.
+//! * `get_extrinsic_deposit` - Get a specific **Extrinsic Deposit**.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -125,6 +130,12 @@ pub mod pallet {
         /// Extrinsic Extra was removed
         /// (module_index, extrinsic_index)
         ExtrinsicExtraRemoved(T::ModuleIndex, T::ExtrinsicIndex),
+        /// Extrinsic Deposit was updated
+        /// (module_index, extrinsic_index, deposit)
+        ExtrinsicDepositUpdated(T::ModuleIndex, T::ExtrinsicIndex, T::Balance),
+        /// Extrinsic Deposit was removed
+        /// (module_index, extrinsic_index)
+        ExtrinsicDepositRemoved(T::ModuleIndex, T::ExtrinsicIndex),
     }
 
     #[pallet::error]
@@ -201,6 +212,19 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Refundable deposits to be reserved by specific extrinsics
+    #[pallet::storage]
+    #[pallet::getter(fn extrinsic_deposit)]
+    pub(super) type ExtrinsicDeposit<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::ModuleIndex,
+        Identity,
+        T::ExtrinsicIndex,
+        T::Balance,
+        OptionQuery,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Change the weight to fee coefficents used to build the polynomial for calcualting weight to fee
@@ -317,6 +341,54 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Set an Extrinsic Deposit - an amount reserved from the caller of specific extrinsics. It is returned or slashed by the calling module.
+        ///
+        /// Arguments:
+        /// - `module_index` module of the extrinsic. See module code for indicies.
+        /// - `extrinsic_index` index of the extrinsic within the module. See module code for indicies.
+        /// - `deposit` amount to be reserved when calling the extrinsic.
+        #[pallet::weight(<T as Config>::WeightInfo::set_extrinsic_deposit())]
+        pub fn set_extrinsic_deposit(
+            origin: OriginFor<T>,
+            module_index: T::ModuleIndex,
+            extrinsic_index: T::ExtrinsicIndex,
+            deposit: T::Balance,
+        ) -> DispatchResultWithPostInfo {
+            T::ChangeSettingOrigin::ensure_origin(origin)?;
+
+            <ExtrinsicDeposit<T>>::insert(&module_index, &extrinsic_index, deposit);
+
+            Self::deposit_event(Event::ExtrinsicDepositUpdated(
+                module_index,
+                extrinsic_index,
+                deposit,
+            ));
+
+            Ok(().into())
+        }
+        /// Remove an Extrinsic Deposit
+        ///
+        /// Arguments:
+        /// - `module_index` module of the extrinsic. See module code for indicies.
+        /// - `extrinsic_index` index of the extrinsic within the module. See module code for indicies.
+        #[pallet::weight(<T as Config>::WeightInfo::remove_extrinsic_deposit())]
+        pub fn remove_extrinsic_deposit(
+            origin: OriginFor<T>,
+            module_index: T::ModuleIndex,
+            extrinsic_index: T::ExtrinsicIndex,
+        ) -> DispatchResultWithPostInfo {
+            T::ChangeSettingOrigin::ensure_origin(origin)?;
+
+            <ExtrinsicDeposit<T>>::remove(&module_index, &extrinsic_index);
+
+            Self::deposit_event(Event::ExtrinsicDepositRemoved(
+                module_index,
+                extrinsic_index,
+            ));
+
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Module<T> {
@@ -371,6 +443,13 @@ pub mod pallet {
             extrinsic_extras
         }
 
+        pub fn get_extrinsic_deposit(
+            module_index: T::ModuleIndex,
+            extrinsic_index: T::ExtrinsicIndex,
+        ) -> Option<T::Balance> {
+            <ExtrinsicDeposit<T>>::get(module_index, extrinsic_index)
+        }
+
         // -- private functions --
         fn initialize_weight_to_fee_coefficients(
             weight_to_fee_coefficients: Vec<WeightToFeeCoefficient<T::Balance>>,
@@ -413,6 +492,7 @@ pub mod pallet {
         type ModuleIndex = T::ModuleIndex;
         type ExtrinsicIndex = T::ExtrinsicIndex;
         type AccountId = T::AccountId;
+        type Balance = T::Balance;
 
         fn charge_extrinsic_extra(
             module_index: &Self::ModuleIndex,
@@ -421,6 +501,13 @@ pub mod pallet {
         ) {
             Self::charge_extrinsic_extra(module_index, extrinsic_index, account)
         }
+
+        fn get_extrinsic_deposit(
+            module_index: &Self::ModuleIndex,
+            extrinsic_index: &Self::ExtrinsicIndex,
+        ) -> Option<Self::Balance> {
+            <ExtrinsicDeposit<T>>::get(module_index, extrinsic_index)
+        }
     }
 
     pub struct TransactionByteFeeGet<T: Config>(PhantomData<T>);
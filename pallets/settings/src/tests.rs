@@ -1,6 +1,7 @@
 //! Tests for the module.
 use super::*;
 use crate::mock::*;
+use extrinsic_extra::GetExtrinsicExtra;
 use frame_support::assert_ok;
 use sp_runtime::Perbill;
 
@@ -84,3 +85,52 @@ fn remove_extrinsic_extra_should_work() {
         ));
     });
 }
+
+#[test]
+fn set_extrinsic_deposit_should_work() {
+    new_test_ext().execute_with(|| {
+        let new_deposit = 10;
+        let module_index = 1u8;
+        let extrinsic_index = 3u8;
+
+        assert_ok!(Settings::set_extrinsic_deposit(
+            Origin::root(),
+            module_index,
+            extrinsic_index,
+            new_deposit
+        ));
+
+        let stored_deposit = ExtrinsicDeposit::<Test>::get(module_index, extrinsic_index);
+        assert!(stored_deposit.is_some());
+        let stored_deposit = stored_deposit.unwrap();
+        assert_eq!(stored_deposit, new_deposit);
+        assert_eq!(
+            <Settings as GetExtrinsicExtra>::get_extrinsic_deposit(&module_index, &extrinsic_index),
+            Some(new_deposit)
+        );
+    });
+}
+#[test]
+fn remove_extrinsic_deposit_should_work() {
+    new_test_ext().execute_with(|| {
+        let module_index = 1u8;
+        let extrinsic_index = 3u8;
+
+        assert_ok!(Settings::set_extrinsic_deposit(
+            Origin::root(),
+            module_index,
+            extrinsic_index,
+            10
+        ));
+        assert_ok!(Settings::remove_extrinsic_deposit(
+            Origin::root(),
+            module_index,
+            extrinsic_index,
+        ));
+
+        assert!(!ExtrinsicDeposit::<Test>::contains_key(
+            module_index,
+            extrinsic_index
+        ));
+    });
+}
@@ -55,6 +55,8 @@ pub trait WeightInfo {
     fn set_fee_split_ratio() -> Weight;
     fn set_extrinsic_extra() -> Weight;
     fn remove_extrinsic_extra() -> Weight;
+    fn set_extrinsic_deposit() -> Weight;
+    fn remove_extrinsic_deposit() -> Weight;
 }
 
 /// Weights for pallet_settings using the Substrate node and recommended hardware.
@@ -78,6 +80,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn remove_extrinsic_extra() -> Weight {
         (65_461_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    fn set_extrinsic_deposit() -> Weight {
+        (70_264_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_extrinsic_deposit() -> Weight {
+        (65_461_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 // For backwards compatibility and tests
@@ -100,4 +108,10 @@ impl WeightInfo for () {
     fn remove_extrinsic_extra() -> Weight {
         (65_461_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
+    fn set_extrinsic_deposit() -> Weight {
+        (70_264_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn remove_extrinsic_deposit() -> Weight {
+        (65_461_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
 }
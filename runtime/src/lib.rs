@@ -51,10 +51,10 @@ use pallet_session::historical as pallet_session_historical;
 pub use pallet_timestamp::Call as TimestampCall;
 pub use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
 use primitives::{
-    AccountId, AssetId, AuditId, Balance, BlockNumber, BoundedStringFact, BoundedStringName,
+    AccountId, AssetId, AuditId, AuditScheduleId, Balance, BlockNumber, BoundedStringFact, BoundedStringName,
     CatalogId, ClaimId, ControlPointId, DefinitionId, DefinitionStepIndex, EvidenceId,
-    ExtrinsicIndex, FactStringLimit, GroupId, Hash, Index, LeaseId, MemberCount, ModuleIndex,
-    Moment, NameLimit, ObservationId, ProcessId, ProposalId, RegistryId, Signature,
+    ExtrinsicIndex, FactStringLimit, FrameworkId, GroupId, Hash, Index, LeaseId, MemberCount,
+    ModuleIndex, Moment, NameLimit, ObservationId, ProcessId, ProposalId, RegistryId, Signature,
 };
 use sp_api::impl_runtime_apis;
 #[cfg(feature = "grandpa_babe")]
@@ -82,10 +82,8 @@ use sp_runtime::{
     curve::PiecewiseLinear, impl_opaque_keys, traits::NumberFor,
     transaction_validity::TransactionPriority,
 };
-#[cfg( feature = "grandpa_aura")]
-use sp_runtime::{
-     impl_opaque_keys, traits::NumberFor
-};
+#[cfg(feature = "grandpa_aura")]
+use sp_runtime::{impl_opaque_keys, traits::NumberFor};
 use sp_std::prelude::*;
 use sp_version::RuntimeVersion;
 
@@ -171,10 +169,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // and set impl_version to 0. If only runtime
     // implementation changes and behavior does not, then leave spec_version as
     // is and increment impl_version.
-    spec_version: 16,
+    spec_version: 17,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 2,
+    transaction_version: 3,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -806,6 +804,13 @@ parameter_types! {
     pub const GroupMaxProposalLength: u32 = 1000;
     pub const GroupMaxMembers: u32 = 100;
     pub const GroupChainLimit: u32 = 100;
+    pub const GroupMaxBudgetTiers: u32 = 10;
+    pub const GroupMaxJointGroups: u32 = 5;
+    pub const GroupProposalLifetime: BlockNumber = 14 * DAYS;
+    pub const GroupMaxScheduledPerBlock: u32 = 20;
+    pub GroupMaximumScheduledWeight: Weight = Perbill::from_percent(80) *
+        RuntimeBlockWeights::get().max_block;
+    pub const GroupMaxScheduleDeferral: u32 = 100;
 }
 
 impl groups::Config for Runtime {
@@ -832,6 +837,13 @@ impl groups::Config for Runtime {
     type GetExtrinsicExtraSource = Settings;
     type NameLimit = NameLimit;
     type GroupChainLimit = GroupChainLimit;
+    type MaxBudgetTiers = GroupMaxBudgetTiers;
+    type GroupsOriginJointApproved = groups::EnsureJointApproved<Runtime>;
+    type MaxJointGroups = GroupMaxJointGroups;
+    type ProposalLifetime = GroupProposalLifetime;
+    type MaxScheduledPerBlock = GroupMaxScheduledPerBlock;
+    type MaximumScheduledWeight = GroupMaximumScheduledWeight;
+    type MaxScheduleDeferral = GroupMaxScheduleDeferral;
 }
 
 parameter_types! {
@@ -880,6 +892,16 @@ impl asset_registry::Config for Runtime {
 parameter_types! {
     pub const MaxLinkRemove: u32 = 50;
     pub const UrlLimit: u32 = 500;
+    pub const ControlPointLimit: u32 = 500;
+    pub const AuditSubjectLimit: u32 = 20;
+    pub const AuditFeeMilestoneLimit: u32 = 10;
+    // evidence is uploaded hex encoded, so twice its size has to fit the 15 MiB RPC payload limit
+    pub const AuditEvidenceSizeLimit: u64 = 7 * 1024 * 1024;
+    pub const AuditNoteRecipientLimit: u32 = 50;
+    pub const AuditMinCadence: BlockNumber = 1 * DAYS;
+    pub const AuditScheduleLimit: u32 = 50;
+    pub const AuditMaxScheduledPerBlock: u32 = 20;
+    pub const AuditFeeEscrowModuleId: ModuleId = ModuleId(*b"py/adfee");
 }
 pub type BoundedStringUrl = BoundedVec<u8, UrlLimit>;
 impl audits::Config for Runtime {
@@ -887,15 +909,48 @@ impl audits::Config for Runtime {
     type ControlPointId = ControlPointId;
     type EvidenceId = EvidenceId;
     type ObservationId = ObservationId;
+    type FrameworkId = FrameworkId;
+    type AuditScheduleId = AuditScheduleId;
     type Event = Event;
     type WeightInfo = audits::weights::SubstrateWeight<Runtime>;
     type NameLimit = NameLimit;
     type UrlLimit = UrlLimit;
     type MaxLinkRemove = MaxLinkRemove;
+    type ControlPointLimit = ControlPointLimit;
+    type AssetRegistryId = RegistryId;
+    type AssetId = AssetId;
+    type RegistryId = RegistryId;
+    type DefinitionId = DefinitionId;
+    type ProcessId = ProcessId;
+    type AssetInfoSource = AssetRegistry;
+    type DidInfoSource = Identity;
+    type ProcessInfoSource = Provenance;
+    type SubjectLimit = AuditSubjectLimit;
+    type ClaimId = ClaimId;
+    type Moment = Moment;
+    type IssueClaimSource = Identity;
+    type FeeMilestoneLimit = AuditFeeMilestoneLimit;
+    type EvidenceSizeLimit = AuditEvidenceSizeLimit;
+    /// More than half of the council resolves fee disputes.
+    type FeeDisputeOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>,
+    >;
+    type NoteRecipientLimit = AuditNoteRecipientLimit;
+    type MinCadence = AuditMinCadence;
+    type AuditScheduleLimit = AuditScheduleLimit;
+    type MaxScheduledPerBlock = AuditMaxScheduledPerBlock;
+    type FeeEscrowModuleId = AuditFeeEscrowModuleId;
 }
 parameter_types! {
     pub const DefinitionStepLimit: u32 = 500;
     pub const AttributeLimit: u32 = 500;
+    pub const DefinitionPredecessorLimit: u32 = 20;
+    pub const ProcessLinkLimit: u32 = 100;
+    pub const ProcessMaxDeadlinesPerBlock: u32 = 50;
+    pub const ProcessStepAmendmentLimit: u32 = 10;
+    pub const ProcessParticipantLimit: u32 = 50;
 }
 impl provenance::Config for Runtime {
     type RegistryId = primitives::RegistryId;
@@ -908,7 +963,16 @@ impl provenance::Config for Runtime {
     type FactStringLimit = FactStringLimit;
     type DefinitionStepLimit = DefinitionStepLimit;
     type AttributeLimit = AttributeLimit;
+    type PredecessorLimit = DefinitionPredecessorLimit;
+    type ProcessLinkLimit = ProcessLinkLimit;
+    type MaxDeadlinesPerBlock = ProcessMaxDeadlinesPerBlock;
+    type AmendmentLimit = ProcessStepAmendmentLimit;
     type GetExtrinsicExtraSource = Settings;
+    type AssetRegistryId = primitives::RegistryId;
+    type AssetId = primitives::AssetId;
+    type AssetInfoSource = AssetRegistry;
+    type DidInfoSource = Identity;
+    type ParticipantLimit = ProcessParticipantLimit;
 }
 #[cfg(feature = "grandpa_babe")]
 construct_runtime!(
@@ -1285,7 +1349,7 @@ impl_runtime_apis! {
 // }
 
 
-    impl groups_runtime_api::GroupsApi<Block,AccountId,GroupId,MemberCount,ProposalId,Hash,BoundedStringName,Balance> for Runtime {
+    impl groups_runtime_api::GroupsApi<Block,AccountId,GroupId,MemberCount,ProposalId,Hash,BoundedStringName,Balance,BlockNumber> for Runtime {
         fn member_of(account_id:AccountId) -> Vec<(GroupId, Group<GroupId, AccountId, MemberCount, BoundedStringName>,Vec<(AccountId, MemberCount)>,Balance)>  {
             Groups::member_of(account_id)
         }
@@ -1313,6 +1377,15 @@ impl_runtime_apis! {
         fn get_proposals_by_account(account_id: AccountId) -> Vec<(GroupId, Vec<(ProposalId, GroupId,Vec<(AccountId, MemberCount)>,Option<(Hash,u32)>,Votes<AccountId, MemberCount>)>)>{
             Groups::get_proposals_by_account(account_id)
         }
+        fn get_scheduled_proposals(group_id:GroupId) -> Vec<(ProposalId,ScheduledExecution<AccountId,BlockNumber,MemberCount>)>{
+            Groups::get_scheduled_proposals(group_id)
+        }
+        fn get_budget(group_id:GroupId) -> Option<(Budget<Balance,BlockNumber,MemberCount>,BudgetUsage<Balance,BlockNumber>)>{
+            Groups::get_budget(group_id)
+        }
+        fn get_invitations(account_id:AccountId) -> Vec<(GroupId,Group<GroupId, AccountId, MemberCount,BoundedStringName>,MemberCount)>{
+            Groups::get_invitations(account_id)
+        }
     }
 
     impl asset_registry_runtime_api::AssetRegistryApi<Block,AccountId,ProposalId,RegistryId,AssetId,LeaseId,Moment,Balance,BoundedStringName,BoundedStringFact> for Runtime {
@@ -1339,7 +1412,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl provenance_runtime_api::ProvenanceApi<Block,AccountId,RegistryId,DefinitionId,ProcessId, ProposalId,MemberCount,DefinitionStepIndex,BoundedStringName,BoundedStringFact> for Runtime {
+    impl provenance_runtime_api::ProvenanceApi<Block,AccountId,RegistryId,DefinitionId,ProcessId, ProposalId,MemberCount,DefinitionStepIndex,RegistryId,AssetId,BlockNumber,Moment,BoundedStringName,BoundedStringFact> for Runtime {
         fn get_registries(account_id: AccountId) -> Vec<(RegistryId,Registry<BoundedStringName>)>  {
             Provenance::get_registries(account_id)
         }
@@ -1352,39 +1425,75 @@ impl_runtime_apis! {
         fn get_definition(registry_id:RegistryId,definition_id:DefinitionId) -> Option<Definition<BoundedStringName>>  {
             Provenance::get_definition(registry_id,definition_id)
         }
-        fn get_definition_step(registry_id:RegistryId,definition_id:DefinitionId,step_index: DefinitionStepIndex) -> Option<DefinitionStep<AccountId, MemberCount,BoundedStringName>>  {
+        fn get_definition_step(registry_id:RegistryId,definition_id:DefinitionId,step_index: DefinitionStepIndex) -> Option<DefinitionStep<AccountId, MemberCount,BoundedStringName,DefinitionStepIndex,BlockNumber>>  {
             Provenance::get_definition_step(registry_id,definition_id,step_index)
         }
-        fn get_definition_steps(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionStepIndex,DefinitionStep<AccountId, MemberCount,BoundedStringName>)>  {
+        fn get_definition_steps(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionStepIndex,DefinitionStep<AccountId, MemberCount,BoundedStringName,DefinitionStepIndex,BlockNumber>)>  {
             Provenance::get_definition_steps(registry_id,definition_id)
         }
+        fn get_definition_versions(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionId,Definition<BoundedStringName>)>  {
+            Provenance::get_definition_versions(registry_id,definition_id)
+        }
         fn get_available_definitions(account_id:AccountId) -> Vec<(RegistryId,DefinitionId,Definition<BoundedStringName>)>   {
             Provenance::get_available_definitions(account_id)
         }
-        fn get_processes(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(ProcessId,Process<BoundedStringName>)>  {
+        fn get_processes(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
             Provenance::get_processes(registry_id,definition_id)
         }
-        fn get_process(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Option<Process<BoundedStringName>>  {
+        fn get_processes_by_status(registry_id:RegistryId,definition_id:DefinitionId,status: ProcessStatus) -> Vec<(ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
+            Provenance::get_processes_by_status(registry_id,definition_id,status)
+        }
+        fn get_processes_all_versions(registry_id:RegistryId,definition_id:DefinitionId) -> Vec<(DefinitionId,ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
+            Provenance::get_processes_all_versions(registry_id,definition_id)
+        }
+        fn get_processes_for_asset(asset_registry_id:RegistryId,asset_id:AssetId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
+            Provenance::get_processes_for_asset(asset_registry_id,asset_id)
+        }
+        fn get_processes_for_did(did:Did) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
+            Provenance::get_processes_for_did(did)
+        }
+        fn get_process(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Option<Process<BoundedStringName,RegistryId,AssetId>>  {
             Provenance::get_process(registry_id,definition_id,process_id)
         }
-        fn get_processes_for_attestor_by_status(account_id: AccountId,status: ProcessStatus) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName>)>  {
+        fn get_processes_for_attestor_by_status(account_id: AccountId,status: ProcessStatus) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
             Provenance::get_processes_for_attestor_by_status(account_id,status)
         }
-        fn get_processes_for_attestor_pending(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName>)>  {
+        fn get_processes_for_attestor_pending(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
             Provenance::get_processes_for_attestor_pending(account_id)
         }
-        fn get_process_steps(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Vec<ProcessStep<ProposalId,BoundedStringName,BoundedStringFact>>  {
+        fn get_overdue_processes(account_id: AccountId) -> Vec<(RegistryId,DefinitionId,ProcessId,Process<BoundedStringName,RegistryId,AssetId>)>  {
+            Provenance::get_overdue_processes(account_id)
+        }
+        fn get_process_steps(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Vec<(DefinitionStepIndex,ProcessStepStatus,Option<ProcessStep<ProposalId,BoundedStringName,BoundedStringFact>>)>  {
             Provenance::get_process_steps(registry_id,definition_id,process_id)
         }
-        fn get_process_step(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId,definition_step_index:DefinitionStepIndex) -> Option<ProcessStep<ProposalId,BoundedStringName,BoundedStringFact>>  {
+        fn get_process_step(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId,definition_step_index:DefinitionStepIndex) -> Option<(ProcessStep<ProposalId,BoundedStringName,BoundedStringFact>,Vec<ProcessStepAmendment<ProposalId,BlockNumber,Moment,BoundedStringName,BoundedStringFact>>)>  {
             Provenance::get_process_step(registry_id,definition_id,process_id,definition_step_index)
         }
+        fn get_process_step_rejections(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Vec<(DefinitionStepIndex,ProcessStepRejection<ProposalId,BoundedStringName,BoundedStringFact>)>  {
+            Provenance::get_process_step_rejections(registry_id,definition_id,process_id)
+        }
+        fn get_process_step_evidence(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Vec<(DefinitionStepIndex,AttestationEvidence<BlockNumber,Moment>)>  {
+            Provenance::get_process_step_evidence(registry_id,definition_id,process_id)
+        }
         fn can_view_definition(account_id: AccountId,registry_id:RegistryId,definition_id:DefinitionId) -> bool  {
             Provenance::can_view_definition(account_id,registry_id,definition_id)
         }
         fn is_attestor(account_id: AccountId,registry_id:RegistryId,definition_id:DefinitionId,definition_step_index: DefinitionStepIndex ) -> bool  {
             Provenance::is_attestor(account_id,registry_id,definition_id,definition_step_index)
         }
+        fn trace_back(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId,depth: u32) -> Vec<(u32,(RegistryId,DefinitionId,ProcessId),(RegistryId,DefinitionId,ProcessId),ProcessLink<BoundedStringName>)>  {
+            Provenance::trace_back(registry_id,definition_id,process_id,depth)
+        }
+        fn trace_forward(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId,depth: u32) -> Vec<(u32,(RegistryId,DefinitionId,ProcessId),(RegistryId,DefinitionId,ProcessId),ProcessLink<BoundedStringName>)>  {
+            Provenance::trace_forward(registry_id,definition_id,process_id,depth)
+        }
+        fn get_definition_visibility(registry_id:RegistryId,definition_id:DefinitionId) -> Option<DefinitionVisibility<BoundedStringName>>  {
+            Provenance::get_definition_visibility(registry_id,definition_id)
+        }
+        fn get_public_trace(registry_id:RegistryId,definition_id:DefinitionId,process_id:ProcessId) -> Option<(BoundedStringName,ProcessStatus,Option<Fact<BoundedStringName>>,Vec<PublicProcessStep<DefinitionStepIndex,BlockNumber,Moment,BoundedStringName,BoundedStringFact>>)>  {
+            Provenance::get_public_trace(registry_id,definition_id,process_id)
+        }
     }
     impl identity_runtime_api::IdentityApi<Block,AccountId,CatalogId,ClaimId,MemberCount,Moment,BoundedStringName,BoundedStringFact> for Runtime {
         fn is_catalog_owner(account_id: AccountId, catalog_id: CatalogId) -> bool {
@@ -1428,7 +1537,7 @@ impl_runtime_apis! {
         fn get_outstanding_attestations(issuer:AccountId) -> Vec<(Did,Moment)>{Identity::get_outstanding_attestations(issuer)}
     }
 
-    impl audits_runtime_api::AuditsApi<Block,AccountId,ProposalId,AuditId,ControlPointId,EvidenceId,ObservationId,BoundedStringName,BoundedStringUrl> for Runtime {
+    impl audits_runtime_api::AuditsApi<Block,AccountId,ProposalId,AuditId,ControlPointId,EvidenceId,ObservationId,FrameworkId,RegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BlockNumber,ClaimId,Moment,Balance,AuditScheduleId,BoundedStringName,BoundedStringUrl> for Runtime {
         fn get_audits_by_creator(account_id: AccountId) -> Vec<(AuditId,Audit<AccountId,ProposalId>)>{
             Audits::get_audits_by_creator(account_id)
         }
@@ -1447,6 +1556,15 @@ impl_runtime_apis! {
         fn get_audit_by_proposal(proposal_id:ProposalId) -> Option<(AuditId,Audit<AccountId,ProposalId>)>{
             Audits::get_audit_by_proposal(proposal_id)
         }
+        fn get_audit_fee(audit_id:AuditId) -> Option<AuditFee<Balance>>{
+            Audits::get_audit_fee(audit_id)
+        }
+        fn get_audit_subjects(audit_id:AuditId) -> Vec<AuditSubject<RegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BoundedStringName>>{
+            Audits::get_audit_subjects(audit_id)
+        }
+        fn get_audits_by_subject(subject:AuditSubject<RegistryId,AssetId,RegistryId,DefinitionId,ProcessId,Vec<u8>>) -> Vec<(AuditId,Audit<AccountId,ProposalId>)>{
+            Audits::get_audits_by_subject(subject)
+        }
         fn get_observation(audit_id:AuditId,control_point_id:ControlPointId,observation_id:ObservationId)->Option<(Observation<ProposalId>,Vec<(EvidenceId,Evidence<ProposalId,BoundedStringName,BoundedStringUrl>)>)>{
             Audits::get_observation(audit_id,control_point_id,observation_id)
         }
@@ -1471,6 +1589,51 @@ impl_runtime_apis! {
         fn get_evidence_links_by_observation(observation_id:ObservationId)->Vec<EvidenceId>{
             Audits::get_evidence_links_by_observation(observation_id)
         }
+        fn get_framework(framework_id:FrameworkId,version:Option<u32>)->Option<(AuditFramework<AccountId,BoundedStringName>,u32,Vec<(ControlPointId,ControlPoint<ControlPointId,BoundedStringName>)>)>{
+            Audits::get_framework(framework_id,version)
+        }
+        fn get_control_point_coverage(audit_id:AuditId)->Option<(FrameworkId,u32,Vec<(ControlPointId,ControlPointCoverage)>)>{
+            Audits::get_control_point_coverage(audit_id)
+        }
+        fn get_corrective_actions(audit_id:AuditId)->Vec<(ControlPointId,ObservationId,CorrectiveAction<AccountId,ProposalId,BlockNumber,EvidenceId>)>{
+            Audits::get_corrective_actions(audit_id)
+        }
+        fn get_open_corrective_actions_by_creator(account_id: AccountId)->Vec<(AuditId,ControlPointId,ObservationId,CorrectiveAction<AccountId,ProposalId,BlockNumber,EvidenceId>)>{
+            Audits::get_open_corrective_actions_by_creator(account_id)
+        }
+        fn get_open_corrective_actions_by_auditing_org(account_id: AccountId)->Vec<(AuditId,ControlPointId,ObservationId,CorrectiveAction<AccountId,ProposalId,BlockNumber,EvidenceId>)>{
+            Audits::get_open_corrective_actions_by_auditing_org(account_id)
+        }
+        fn get_certificates_by_did(did:Did)->Vec<(AuditId,Certificate<ClaimId,Moment,AuditId,BoundedStringName>)>{
+            Audits::get_certificates_by_did(did)
+        }
+        fn get_audit_report(audit_id:AuditId)->Option<AuditReport<AccountId,ProposalId,AuditId,ControlPointId,ObservationId,EvidenceId,FrameworkId,BoundedStringName,BoundedStringUrl>>{
+            Audits::get_audit_report(audit_id)
+        }
+
+        fn get_upcoming_audits_by_creator(account: AccountId)->Vec<(AuditScheduleId,AuditSchedule<AccountId,ProposalId,FrameworkId,BlockNumber,AuditId,AuditSubject<RegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BoundedStringName>>)>{
+            Audits::get_upcoming_audits_by_creator(account)
+        }
+
+        fn get_upcoming_audits_by_auditing_org(account: AccountId)->Vec<(AuditScheduleId,AuditSchedule<AccountId,ProposalId,FrameworkId,BlockNumber,AuditId,AuditSubject<RegistryId,AssetId,RegistryId,DefinitionId,ProcessId,BoundedStringName>>)>{
+            Audits::get_upcoming_audits_by_auditing_org(account)
+        }
+
+        fn get_missed_audits(schedule_id:AuditScheduleId)->Vec<(AuditId,BlockNumber)>{
+            Audits::get_missed_audits(schedule_id)
+        }
+        fn get_audit_trail(audit_id:AuditId)->Vec<AuditTrailEntry<AccountId,ProposalId,BlockNumber,ControlPointId,ObservationId,BoundedStringName>>{
+            Audits::get_audit_trail(audit_id)
+        }
+        fn get_note_key(account:AccountId)->Option<[u8;32]>{
+            Audits::get_note_key(account)
+        }
+        fn get_confidential_notes(audit_id:AuditId)->Vec<([u8;32],ConfidentialNote<AccountId,ProposalId,ControlPointId,ObservationId>)>{
+            Audits::get_confidential_notes(audit_id)
+        }
+        fn get_note_envelopes(audit_id:AuditId,ciphertext_hash:[u8;32])->Vec<(AccountId,KeyEnvelope)>{
+            Audits::get_note_envelopes(audit_id,ciphertext_hash)
+        }
     }
 
     impl settings_runtime_api::SettingsApi<Block,ModuleIndex,ExtrinsicIndex,Balance> for Runtime {
@@ -1489,6 +1652,9 @@ impl_runtime_apis! {
         fn get_extrinsic_extras() ->  Vec<(ModuleIndex,Vec<(ExtrinsicIndex,Balance)>)> {
             Settings::get_extrinsic_extras()
         }
+        fn get_extrinsic_deposit(module_index:ModuleIndex,extrinsic_index:ExtrinsicIndex) -> Option<Balance>{
+            Settings::get_extrinsic_deposit(module_index,extrinsic_index)
+        }
     }
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
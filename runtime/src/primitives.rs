@@ -56,6 +56,8 @@ pub type AuditId = u32;
 pub type ControlPointId = u32;
 pub type EvidenceId = u32;
 pub type ObservationId = u32;
+pub type FrameworkId = u32;
+pub type AuditScheduleId = u32;
 //asset_registry
 pub type AssetId = u32;
 pub type LeaseId = u32;
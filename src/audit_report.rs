@@ -0,0 +1,218 @@
+//! Export of the compliance report of an audit for archival.
+//!
+//! The JSON format is the response of the `get_audit_report` RPC.
+//! The Markdown format renders the same report as a document, with a table of control points
+//! followed by the observations and linked evidence of each control point.
+//! Both record the block the report was taken at.
+
+use crate::{
+    audits_rpc::{AuditReportResponse, ObservationResponse},
+    cli::ReportFormat,
+};
+use audits_runtime_api::AuditsApi as AuditsRuntimeApi;
+use runtime::{primitives, BoundedStringUrl};
+use serde_json::json;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use std::fmt::{Debug, Display, Write};
+
+type Report = AuditReportResponse<
+    primitives::AccountId,
+    primitives::ProposalId,
+    primitives::AuditId,
+    primitives::ControlPointId,
+    primitives::ObservationId,
+    primitives::EvidenceId,
+    primitives::FrameworkId,
+>;
+
+impl crate::cli::ExportAuditReportCmd {
+    /// Export from the best block of the local database.
+    pub fn run<C>(&self, client: &C) -> sc_cli::Result<()>
+    where
+        C: ProvideRuntimeApi<primitives::Block> + HeaderBackend<primitives::Block>,
+        C::Api: AuditsRuntimeApi<
+            primitives::Block,
+            primitives::AccountId,
+            primitives::ProposalId,
+            primitives::AuditId,
+            primitives::ControlPointId,
+            primitives::EvidenceId,
+            primitives::ObservationId,
+            primitives::FrameworkId,
+            primitives::RegistryId,
+            primitives::AssetId,
+            primitives::RegistryId,
+            primitives::DefinitionId,
+            primitives::ProcessId,
+            primitives::BlockNumber,
+            primitives::ClaimId,
+            primitives::Moment,
+            primitives::Balance,
+            primitives::AuditScheduleId,
+            primitives::BoundedStringName,
+            BoundedStringUrl,
+        >,
+    {
+        let info = client.info();
+        let at = BlockId::hash(info.best_hash);
+
+        let report: Report = client
+            .runtime_api()
+            .get_audit_report(&at, self.audit_id)
+            .map_err(|e| format!("Unable to query the audit report: {:?}", e))?
+            .map(|report| (self.audit_id, report).into())
+            .ok_or_else(|| format!("Audit {} not found", self.audit_id))?;
+
+        let document = match self.format {
+            ReportFormat::Json => serde_json::to_string_pretty(&json!({
+                "block_number": info.best_number,
+                "block_hash": info.best_hash,
+                "report": report,
+            }))
+            .map_err(|e| e.to_string())?,
+            ReportFormat::Markdown => render_markdown(&report, info.best_number, &info.best_hash)
+                .map_err(|e| e.to_string())?,
+        };
+
+        match &self.output {
+            Some(path) => std::fs::write(path, document)?,
+            None => println!("{}", document),
+        }
+        Ok(())
+    }
+}
+
+/// Render a report as a Markdown document.
+fn render_markdown(
+    report: &Report,
+    block_number: impl Display,
+    block_hash: impl Debug,
+) -> Result<String, std::fmt::Error> {
+    let mut md = String::new();
+    let audit = &report.audit;
+
+    writeln!(md, "# Audit {}", audit.audit_id)?;
+    writeln!(md)?;
+    writeln!(md, "Taken at block {} (`{:?}`).", block_number, block_hash)?;
+    writeln!(md)?;
+    writeln!(md, "| | |")?;
+    writeln!(md, "|---|---|")?;
+    writeln!(md, "| Status | {} |", audit.status)?;
+    writeln!(md, "| Audit creator | `{}` |", audit.audit_creator)?;
+    writeln!(md, "| Auditing organization | `{}` |", audit.auditing_org)?;
+    if let Some(auditors) = &audit.auditors {
+        writeln!(md, "| Auditors | `{}` |", auditors)?;
+    }
+    if let (Some(framework_id), Some(version)) = (report.framework_id, report.framework_version) {
+        writeln!(md, "| Framework | {} version {} |", framework_id, version)?;
+    }
+    match report.score {
+        Some(score) => writeln!(md, "| Score | {:.1}% |", score)?,
+        None => writeln!(md, "| Score | n/a |")?,
+    }
+    writeln!(md)?;
+
+    writeln!(md, "## Summary")?;
+    writeln!(md)?;
+    writeln!(
+        md,
+        "| Observations | Compliant | Non-compliant | Not applicable |"
+    )?;
+    writeln!(md, "|---:|---:|---:|---:|")?;
+    writeln!(
+        md,
+        "| {} | {} | {} | {} |",
+        report.observations, report.compliant, report.non_compliant, report.not_applicable
+    )?;
+    writeln!(md)?;
+
+    writeln!(md, "## Control points")?;
+    writeln!(md)?;
+    if report.control_points.is_empty() {
+        writeln!(md, "No control points were observed.")?;
+        writeln!(md)?;
+    } else {
+        writeln!(
+            md,
+            "| Control point | Severity | Observations | Compliant | Non-compliant | Not applicable |"
+        )?;
+        writeln!(md, "|---|---|---:|---:|---:|---:|")?;
+        for control_point in &report.control_points {
+            writeln!(
+                md,
+                "| {} | {} | {} | {} | {} | {} |",
+                control_point.control_point_id,
+                control_point.severity.as_deref().unwrap_or("-"),
+                control_point.observation_count,
+                control_point.compliant,
+                control_point.non_compliant,
+                control_point.not_applicable
+            )?;
+        }
+        writeln!(md)?;
+    }
+
+    for control_point in &report.control_points {
+        if control_point.observations.is_empty() {
+            continue;
+        }
+        writeln!(md, "### Control point {}", control_point.control_point_id)?;
+        writeln!(md)?;
+        for observation in &control_point.observations {
+            render_observation(&mut md, observation)?;
+        }
+        writeln!(md)?;
+    }
+
+    writeln!(md, "## Linked audits")?;
+    writeln!(md)?;
+    if report.linked_audits.is_empty() {
+        writeln!(md, "None.")?;
+    } else {
+        for linked_audit_id in &report.linked_audits {
+            writeln!(md, "- Audit {}", linked_audit_id)?;
+        }
+    }
+    Ok(md)
+}
+
+fn render_observation(
+    md: &mut String,
+    observation: &ObservationResponse<
+        primitives::ObservationId,
+        primitives::EvidenceId,
+        primitives::ProposalId,
+    >,
+) -> std::fmt::Result {
+    writeln!(
+        md,
+        "- Observation {}: {}",
+        observation.observation_id,
+        observation.compliance.as_deref().unwrap_or("No finding")
+    )?;
+    if let Some(procedural_note_hash) = observation.procedural_note_hash {
+        writeln!(
+            md,
+            "  - Procedural note hash `0x{}`",
+            hex::encode(procedural_note_hash)
+        )?;
+    }
+    for evidence in &observation.evidences {
+        let hash = match &evidence.legacy_hash {
+            Some(legacy_hash) => format!("legacy hash `{}`", String::from_utf8_lossy(legacy_hash)),
+            None => format!("hash `0x{}`", hex::encode(evidence.hash)),
+        };
+        write!(
+            md,
+            "  - Evidence {}: {} ({}, {} bytes), {}",
+            evidence.evidence_id, evidence.name, evidence.content_type, evidence.size, hash
+        )?;
+        match &evidence.url {
+            Some(url) => writeln!(md, ", <{}>", url)?,
+            None => writeln!(md)?,
+        }
+    }
+    Ok(())
+}
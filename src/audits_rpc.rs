@@ -1,13 +1,23 @@
+use crate::identity_rpc::Did;
 use audits_runtime_api::AuditsApi as AuditsRuntimeApi;
 use codec::Codec;
 use frame_support::dispatch::fmt::Display;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use pallet_primitives::{Audit, AuditStatus, Compliance, Evidence, Observation};
+use pallet_primitives::{
+    Audit, AuditChange, AuditFee, AuditFeeStatus, AuditFramework, AuditReport, AuditSchedule,
+    AuditScheduleStatus, AuditStatus, AuditSubject, AuditTrailEntry, Certificate,
+    CertificateStatus, Compliance, ConfidentialNote, ControlPoint, ControlPointCoverage,
+    ControlPointSeverity, CorrectiveAction, CorrectiveActionStatus, Evidence, KeyEnvelope,
+    Observation,
+};
 use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_runtime::{
+    generic::BlockId,
+    traits::{AtLeast32BitUnsigned, Block as BlockT, UniqueSaturatedInto},
+};
 use std::sync::Arc;
 
 #[rpc]
@@ -19,6 +29,16 @@ pub trait AuditsApi<
     ControlPointId,
     EvidenceId,
     ObservationId,
+    FrameworkId,
+    AssetRegistryId,
+    AssetId,
+    RegistryId,
+    DefinitionId,
+    ProcessId,
+    BlockNumber,
+    ClaimId,
+    Moment,
+    AuditScheduleId,
 >
 {
     #[rpc(name = "get_audits_by_creator")]
@@ -54,7 +74,16 @@ pub trait AuditsApi<
         &self,
         audit_id: AuditId,
         at: Option<BlockHash>,
-    ) -> Result<AuditResponse<AccountId, ProposalId, AuditId>>;
+    ) -> Result<
+        AuditDetailResponse<
+            AccountId,
+            ProposalId,
+            AuditId,
+            BlockNumber,
+            ControlPointId,
+            ObservationId,
+        >,
+    >;
 
     #[rpc(name = "get_audit_by_proposal")]
     fn get_audit_by_proposal(
@@ -63,6 +92,46 @@ pub trait AuditsApi<
         at: Option<BlockHash>,
     ) -> Result<AuditResponse<AccountId, ProposalId, AuditId>>;
 
+    #[rpc(name = "get_audit_subjects")]
+    fn get_audit_subjects(
+        &self,
+        audit_id: AuditId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<AuditSubjectResponse<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId>>,
+    >;
+
+    #[rpc(name = "get_audits_by_did")]
+    fn get_audits_by_did(
+        &self,
+        did: Did,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>>;
+
+    #[rpc(name = "get_audits_by_asset")]
+    fn get_audits_by_asset(
+        &self,
+        asset_registry_id: AssetRegistryId,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>>;
+
+    #[rpc(name = "get_audits_by_process")]
+    fn get_audits_by_process(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>>;
+
+    #[rpc(name = "get_audits_by_site")]
+    fn get_audits_by_site(
+        &self,
+        site: String,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>>;
+
     #[rpc(name = "get_observation")]
     fn get_observation(
         &self,
@@ -123,6 +192,162 @@ pub trait AuditsApi<
         observation_id: ObservationId,
         at: Option<BlockHash>,
     ) -> Result<Vec<EvidenceId>>;
+
+    #[rpc(name = "get_framework")]
+    fn get_framework(
+        &self,
+        framework_id: FrameworkId,
+        version: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> Result<FrameworkResponse<AccountId, FrameworkId, ControlPointId>>;
+
+    #[rpc(name = "get_control_point_coverage")]
+    fn get_control_point_coverage(
+        &self,
+        audit_id: AuditId,
+        at: Option<BlockHash>,
+    ) -> Result<CoverageResponse<AuditId, FrameworkId, ControlPointId>>;
+
+    #[rpc(name = "get_corrective_actions")]
+    fn get_corrective_actions(
+        &self,
+        audit_id: AuditId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            CorrectiveActionResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                BlockNumber,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_open_corrective_actions_by_creator")]
+    fn get_open_corrective_actions_by_creator(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            CorrectiveActionResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                BlockNumber,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_open_corrective_actions_by_auditing_org")]
+    fn get_open_corrective_actions_by_auditing_org(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            CorrectiveActionResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                BlockNumber,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_certificates_by_did")]
+    fn get_certificates_by_did(
+        &self,
+        did: Did,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<CertificateResponse<AuditId, ClaimId, Moment>>>;
+
+    #[rpc(name = "get_audit_report")]
+    fn get_audit_report(
+        &self,
+        audit_id: AuditId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        AuditReportResponse<
+            AccountId,
+            ProposalId,
+            AuditId,
+            ControlPointId,
+            ObservationId,
+            EvidenceId,
+            FrameworkId,
+        >,
+    >;
+
+    #[rpc(name = "get_upcoming_audits_by_creator")]
+    fn get_upcoming_audits_by_creator(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            AuditScheduleResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                FrameworkId,
+                AuditScheduleId,
+                BlockNumber,
+                AssetRegistryId,
+                AssetId,
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_upcoming_audits_by_auditing_org")]
+    fn get_upcoming_audits_by_auditing_org(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            AuditScheduleResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                FrameworkId,
+                AuditScheduleId,
+                BlockNumber,
+                AssetRegistryId,
+                AssetId,
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_missed_audits")]
+    fn get_missed_audits(
+        &self,
+        schedule_id: AuditScheduleId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<MissedAuditResponse<AuditId, BlockNumber>>>;
+
+    #[rpc(name = "get_confidential_notes")]
+    fn get_confidential_notes(
+        &self,
+        audit_id: AuditId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ConfidentialNoteResponse<AccountId, ProposalId, ControlPointId, ObservationId>>>;
 }
 
 #[derive(Serialize, Deserialize)]
@@ -141,19 +366,224 @@ impl<AccountId, ProposalId, AuditId> From<(AuditId, Audit<AccountId, ProposalId>
         AuditResponse::<AccountId, ProposalId, AuditId> {
             audit_id,
             proposal_id: audit.proposal_id,
-            status: match audit.status {
-                AuditStatus::Requested => "Requested".to_string(),
-                AuditStatus::Accepted => "Accepted".to_string(),
-                AuditStatus::Rejected => "Rejected".to_string(),
-                AuditStatus::InProgress => "InProgress".to_string(),
-                AuditStatus::Completed => "Completed".to_string(),
-            },
+            status: audit_status_to_string(audit.status),
             audit_creator: audit.audit_creator,
             auditing_org: audit.auditing_org,
             auditors: audit.auditors,
         }
     }
 }
+
+fn audit_status_to_string(status: AuditStatus) -> String {
+    match status {
+        AuditStatus::Requested => "Requested".to_string(),
+        AuditStatus::Accepted => "Accepted".to_string(),
+        AuditStatus::Rejected => "Rejected".to_string(),
+        AuditStatus::InProgress => "InProgress".to_string(),
+        AuditStatus::Completed => "Completed".to_string(),
+        AuditStatus::CompletedWithOpenFindings => "CompletedWithOpenFindings".to_string(),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AuditDetailResponse<
+    AccountId,
+    ProposalId,
+    AuditId,
+    BlockNumber,
+    ControlPointId,
+    ObservationId,
+> {
+    #[serde(flatten)]
+    pub audit: AuditResponse<AccountId, ProposalId, AuditId>,
+    pub fee: Option<AuditFeeResponse>,
+    pub trail: Vec<
+        AuditTrailEntryResponse<AccountId, ProposalId, BlockNumber, ControlPointId, ObservationId>,
+    >,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AuditTrailEntryResponse<
+    AccountId,
+    ProposalId,
+    BlockNumber,
+    ControlPointId,
+    ObservationId,
+> {
+    pub proposal_id: Option<ProposalId>,
+    pub account: AccountId,
+    pub block_number: BlockNumber,
+    pub change_type: String,
+    pub status: Option<String>,
+    pub reason: Option<String>,
+    pub control_point_id: Option<ControlPointId>,
+    pub observation_id: Option<ObservationId>,
+    pub superseded_by: Option<ObservationId>,
+}
+impl<AccountId, ProposalId, BlockNumber, ControlPointId, ObservationId, BoundedString>
+    From<
+        AuditTrailEntry<
+            AccountId,
+            ProposalId,
+            BlockNumber,
+            ControlPointId,
+            ObservationId,
+            BoundedString,
+        >,
+    > for AuditTrailEntryResponse<AccountId, ProposalId, BlockNumber, ControlPointId, ObservationId>
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        entry: AuditTrailEntry<
+            AccountId,
+            ProposalId,
+            BlockNumber,
+            ControlPointId,
+            ObservationId,
+            BoundedString,
+        >,
+    ) -> Self {
+        let mut response = AuditTrailEntryResponse {
+            proposal_id: entry.proposal_id,
+            account: entry.account,
+            block_number: entry.block_number,
+            change_type: String::new(),
+            status: None,
+            reason: None,
+            control_point_id: None,
+            observation_id: None,
+            superseded_by: None,
+        };
+        match entry.change {
+            AuditChange::Status(status) => {
+                response.change_type = "Status".to_string();
+                response.status = Some(audit_status_to_string(status));
+            }
+            AuditChange::ReopenRequested(reason) => {
+                response.change_type = "ReopenRequested".to_string();
+                response.reason = Some(String::from_utf8_lossy(&reason.into()).to_string());
+            }
+            AuditChange::Reopened(reason) => {
+                response.change_type = "Reopened".to_string();
+                response.reason = Some(String::from_utf8_lossy(&reason.into()).to_string());
+            }
+            AuditChange::ObservationSuperseded(
+                control_point_id,
+                observation_id,
+                superseded_by,
+                reason,
+            ) => {
+                response.change_type = "ObservationSuperseded".to_string();
+                response.control_point_id = Some(control_point_id);
+                response.observation_id = Some(observation_id);
+                response.superseded_by = Some(superseded_by);
+                response.reason = Some(String::from_utf8_lossy(&reason.into()).to_string());
+            }
+            AuditChange::ReopenRequestWithdrawn => {
+                response.change_type = "ReopenRequestWithdrawn".to_string();
+            }
+        }
+        response
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AuditFeeResponse {
+    //u64 instead of Balance due to bug in serde https://github.com/paritytech/substrate/issues/4641
+    pub amount: u64,
+    pub released: u64,
+    pub milestones: Vec<(u32, u64)>,
+    pub observations: u32,
+    pub status: String,
+}
+impl<Balance> From<AuditFee<Balance>> for AuditFeeResponse
+where
+    Balance: AtLeast32BitUnsigned,
+{
+    fn from(fee: AuditFee<Balance>) -> Self {
+        AuditFeeResponse {
+            amount: fee.amount.unique_saturated_into(),
+            released: fee.released.unique_saturated_into(),
+            milestones: fee
+                .milestones
+                .into_iter()
+                .map(|(observations, amount)| (observations, amount.unique_saturated_into()))
+                .collect(),
+            observations: fee.observations,
+            status: match fee.status {
+                AuditFeeStatus::Pending => "Pending".to_string(),
+                AuditFeeStatus::Reserved => "Reserved".to_string(),
+                AuditFeeStatus::Released => "Released".to_string(),
+                AuditFeeStatus::Refunded => "Refunded".to_string(),
+                AuditFeeStatus::Disputed => "Disputed".to_string(),
+                AuditFeeStatus::Resolved => "Resolved".to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AuditSubjectResponse<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId> {
+    pub subject_type: String,
+    pub did: Option<String>,
+    pub asset_registry_id: Option<AssetRegistryId>,
+    pub asset_id: Option<AssetId>,
+    pub registry_id: Option<RegistryId>,
+    pub definition_id: Option<DefinitionId>,
+    pub process_id: Option<ProcessId>,
+    pub site: Option<String>,
+}
+impl<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId, BoundedString>
+    From<AuditSubject<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId, BoundedString>>
+    for AuditSubjectResponse<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId>
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        subject: AuditSubject<
+            AssetRegistryId,
+            AssetId,
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            BoundedString,
+        >,
+    ) -> Self {
+        let mut response = AuditSubjectResponse {
+            subject_type: String::new(),
+            did: None,
+            asset_registry_id: None,
+            asset_id: None,
+            registry_id: None,
+            definition_id: None,
+            process_id: None,
+            site: None,
+        };
+        match subject {
+            AuditSubject::Did(did) => {
+                response.subject_type = "Did".to_string();
+                response.did = Some(Did::from(did).to_string());
+            }
+            AuditSubject::Asset(asset_registry_id, asset_id) => {
+                response.subject_type = "Asset".to_string();
+                response.asset_registry_id = Some(asset_registry_id);
+                response.asset_id = Some(asset_id);
+            }
+            AuditSubject::Process(registry_id, definition_id, process_id) => {
+                response.subject_type = "Process".to_string();
+                response.registry_id = Some(registry_id);
+                response.definition_id = Some(definition_id);
+                response.process_id = Some(process_id);
+            }
+            AuditSubject::Site(site) => {
+                response.subject_type = "Site".to_string();
+                response.site = Some(String::from_utf8_lossy(&site.into()).to_string());
+            }
+        }
+        response
+    }
+}
 #[derive(Serialize, Deserialize)]
 pub struct ObservationResponse<ObservationId, EvidenceId, ProposalId> {
     pub observation_id: ObservationId,
@@ -186,60 +616,604 @@ where
             )>,
         ),
     ) -> Self {
-        ObservationResponse {
-            observation_id,
-            proposal_id: observation.proposal_id,
-            compliance: observation.compliance.map(|compliance| match compliance {
-                Compliance::Compliant => "Compliant".to_string(),
-                Compliance::NonCompliant => "NonCompliant".to_string(),
-                Compliance::NotApplicable => "NotApplicable".to_string(),
-            }),
-            procedural_note_hash: observation.procedural_note_hash,
-            evidences: evidences
+        ObservationResponse {
+            observation_id,
+            proposal_id: observation.proposal_id,
+            compliance: observation.compliance.map(|compliance| match compliance {
+                Compliance::Compliant => "Compliant".to_string(),
+                Compliance::NonCompliant => "NonCompliant".to_string(),
+                Compliance::NotApplicable => "NotApplicable".to_string(),
+            }),
+            procedural_note_hash: observation.procedural_note_hash,
+            evidences: evidences
+                .into_iter()
+                .map(|(evidence_id, evidence)| (evidence_id, evidence).into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EvidenceResponse<EvidenceId, ProposalId> {
+    pub evidence_id: EvidenceId,
+    pub proposal_id: ProposalId,
+    pub name: String,
+    pub content_type: String,
+    pub url: Option<String>,
+    pub hash: [u8; 32],
+    pub size: u64,
+    pub legacy_hash: Option<Vec<u8>>,
+}
+
+impl<EvidenceId, ProposalId, BoundedString, BoundedStringUrl>
+    From<(
+        EvidenceId,
+        Evidence<ProposalId, BoundedString, BoundedStringUrl>,
+    )> for EvidenceResponse<EvidenceId, ProposalId>
+where
+    BoundedString: Into<Vec<u8>>,
+    BoundedStringUrl: Into<Vec<u8>>,
+{
+    fn from(
+        (evidence_id, evidence): (
+            EvidenceId,
+            Evidence<ProposalId, BoundedString, BoundedStringUrl>,
+        ),
+    ) -> Self {
+        EvidenceResponse {
+            evidence_id,
+            proposal_id: evidence.proposal_id,
+            name: String::from_utf8_lossy(&evidence.name.into()).to_string(),
+            content_type: String::from_utf8_lossy(&evidence.content_type.into()).to_string(),
+            url: evidence
+                .url
+                .map(|url| String::from_utf8_lossy(&url.into()).to_string()),
+            hash: evidence.hash,
+            size: evidence.size,
+            legacy_hash: evidence.legacy_hash,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FrameworkResponse<AccountId, FrameworkId, ControlPointId> {
+    pub framework_id: FrameworkId,
+    pub publisher: AccountId,
+    pub name: String,
+    pub version: u32,
+    pub latest_version: u32,
+    pub control_points: Vec<ControlPointResponse<ControlPointId>>,
+}
+
+impl<AccountId, FrameworkId, ControlPointId, BoundedString>
+    From<(
+        FrameworkId,
+        AuditFramework<AccountId, BoundedString>,
+        u32,
+        Vec<(ControlPointId, ControlPoint<ControlPointId, BoundedString>)>,
+    )> for FrameworkResponse<AccountId, FrameworkId, ControlPointId>
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        (framework_id, framework, version, control_points): (
+            FrameworkId,
+            AuditFramework<AccountId, BoundedString>,
+            u32,
+            Vec<(ControlPointId, ControlPoint<ControlPointId, BoundedString>)>,
+        ),
+    ) -> Self {
+        FrameworkResponse {
+            framework_id,
+            publisher: framework.publisher,
+            name: String::from_utf8_lossy(&framework.name.into()).to_string(),
+            version,
+            latest_version: framework.latest_version,
+            control_points: control_points
+                .into_iter()
+                .map(|(control_point_id, control_point)| (control_point_id, control_point).into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ControlPointResponse<ControlPointId> {
+    pub control_point_id: ControlPointId,
+    pub code: String,
+    pub title: String,
+    pub description_hash: [u8; 32],
+    pub severity: String,
+    pub parent: Option<ControlPointId>,
+}
+
+impl<ControlPointId, BoundedString>
+    From<(ControlPointId, ControlPoint<ControlPointId, BoundedString>)>
+    for ControlPointResponse<ControlPointId>
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        (control_point_id, control_point): (
+            ControlPointId,
+            ControlPoint<ControlPointId, BoundedString>,
+        ),
+    ) -> Self {
+        ControlPointResponse {
+            control_point_id,
+            code: String::from_utf8_lossy(&control_point.code.into()).to_string(),
+            title: String::from_utf8_lossy(&control_point.title.into()).to_string(),
+            description_hash: control_point.description_hash,
+            severity: match control_point.severity {
+                ControlPointSeverity::Low => "Low".to_string(),
+                ControlPointSeverity::Medium => "Medium".to_string(),
+                ControlPointSeverity::High => "High".to_string(),
+                ControlPointSeverity::Critical => "Critical".to_string(),
+            },
+            parent: control_point.parent,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CoverageResponse<AuditId, FrameworkId, ControlPointId> {
+    pub audit_id: AuditId,
+    pub framework_id: FrameworkId,
+    pub version: u32,
+    pub control_points: Vec<ControlPointCoverageResponse<ControlPointId>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ControlPointCoverageResponse<ControlPointId> {
+    pub control_point_id: ControlPointId,
+    pub observations: u32,
+    pub compliant: u32,
+    pub non_compliant: u32,
+    pub not_applicable: u32,
+}
+
+impl<ControlPointId> From<(ControlPointId, ControlPointCoverage)>
+    for ControlPointCoverageResponse<ControlPointId>
+{
+    fn from((control_point_id, coverage): (ControlPointId, ControlPointCoverage)) -> Self {
+        ControlPointCoverageResponse {
+            control_point_id,
+            observations: coverage.observations,
+            compliant: coverage.compliant,
+            non_compliant: coverage.non_compliant,
+            not_applicable: coverage.not_applicable,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CorrectiveActionResponse<
+    AccountId,
+    ProposalId,
+    AuditId,
+    ControlPointId,
+    ObservationId,
+    EvidenceId,
+    BlockNumber,
+> {
+    pub audit_id: AuditId,
+    pub control_point_id: ControlPointId,
+    pub observation_id: ObservationId,
+    pub proposal_id: ProposalId,
+    pub description_hash: [u8; 32],
+    pub due_date: BlockNumber,
+    pub responsible: AccountId,
+    pub status: String,
+    pub closing_evidence: Option<EvidenceId>,
+}
+
+impl<AccountId, ProposalId, AuditId, ControlPointId, ObservationId, EvidenceId, BlockNumber>
+    From<(
+        AuditId,
+        ControlPointId,
+        ObservationId,
+        CorrectiveAction<AccountId, ProposalId, BlockNumber, EvidenceId>,
+    )>
+    for CorrectiveActionResponse<
+        AccountId,
+        ProposalId,
+        AuditId,
+        ControlPointId,
+        ObservationId,
+        EvidenceId,
+        BlockNumber,
+    >
+{
+    fn from(
+        (audit_id, control_point_id, observation_id, corrective_action): (
+            AuditId,
+            ControlPointId,
+            ObservationId,
+            CorrectiveAction<AccountId, ProposalId, BlockNumber, EvidenceId>,
+        ),
+    ) -> Self {
+        CorrectiveActionResponse {
+            audit_id,
+            control_point_id,
+            observation_id,
+            proposal_id: corrective_action.proposal_id,
+            description_hash: corrective_action.description_hash,
+            due_date: corrective_action.due_date,
+            responsible: corrective_action.responsible,
+            status: match corrective_action.status {
+                CorrectiveActionStatus::Open => "Open".to_string(),
+                CorrectiveActionStatus::Closed => "Closed".to_string(),
+            },
+            closing_evidence: corrective_action.closing_evidence,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CertificateResponse<AuditId, ClaimId, Moment> {
+    pub audit_id: AuditId,
+    pub claim_id: ClaimId,
+    pub grade: String,
+    pub valid_until: Moment,
+    pub status: String,
+    pub revoked_by_audit: Option<AuditId>,
+}
+
+impl<AuditId, ClaimId, Moment, BoundedString>
+    From<(
+        AuditId,
+        Certificate<ClaimId, Moment, AuditId, BoundedString>,
+    )> for CertificateResponse<AuditId, ClaimId, Moment>
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        (audit_id, certificate): (
+            AuditId,
+            Certificate<ClaimId, Moment, AuditId, BoundedString>,
+        ),
+    ) -> Self {
+        let (status, revoked_by_audit) = match certificate.status {
+            CertificateStatus::Valid => ("Valid".to_string(), None),
+            CertificateStatus::Revoked(failed_audit_id) => ("Revoked".to_string(), failed_audit_id),
+        };
+        CertificateResponse {
+            audit_id,
+            claim_id: certificate.claim_id,
+            grade: String::from_utf8_lossy(&certificate.grade.into()).to_string(),
+            valid_until: certificate.valid_until,
+            status,
+            revoked_by_audit,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AuditReportResponse<
+    AccountId,
+    ProposalId,
+    AuditId,
+    ControlPointId,
+    ObservationId,
+    EvidenceId,
+    FrameworkId,
+> {
+    pub audit: AuditResponse<AccountId, ProposalId, AuditId>,
+    pub framework_id: Option<FrameworkId>,
+    pub framework_version: Option<u32>,
+    pub control_points:
+        Vec<ControlPointReportResponse<ControlPointId, ObservationId, EvidenceId, ProposalId>>,
+    pub observations: u32,
+    pub compliant: u32,
+    pub non_compliant: u32,
+    pub not_applicable: u32,
+    /// percentage, weighted by control point severity
+    pub score: Option<f64>,
+    pub linked_audits: Vec<AuditId>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ControlPointReportResponse<ControlPointId, ObservationId, EvidenceId, ProposalId> {
+    pub control_point_id: ControlPointId,
+    pub severity: Option<String>,
+    pub observation_count: u32,
+    pub compliant: u32,
+    pub non_compliant: u32,
+    pub not_applicable: u32,
+    pub observations: Vec<ObservationResponse<ObservationId, EvidenceId, ProposalId>>,
+}
+
+impl<
+        AccountId,
+        ProposalId,
+        AuditId,
+        ControlPointId,
+        ObservationId,
+        EvidenceId,
+        FrameworkId,
+        BoundedString,
+        BoundedStringUrl,
+    >
+    From<(
+        AuditId,
+        AuditReport<
+            AccountId,
+            ProposalId,
+            AuditId,
+            ControlPointId,
+            ObservationId,
+            EvidenceId,
+            FrameworkId,
+            BoundedString,
+            BoundedStringUrl,
+        >,
+    )>
+    for AuditReportResponse<
+        AccountId,
+        ProposalId,
+        AuditId,
+        ControlPointId,
+        ObservationId,
+        EvidenceId,
+        FrameworkId,
+    >
+where
+    BoundedString: Into<Vec<u8>>,
+    BoundedStringUrl: Into<Vec<u8>>,
+{
+    fn from(
+        (audit_id, report): (
+            AuditId,
+            AuditReport<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                FrameworkId,
+                BoundedString,
+                BoundedStringUrl,
+            >,
+        ),
+    ) -> Self {
+        let (framework_id, framework_version) = match report.framework {
+            Some((framework_id, version)) => (Some(framework_id), Some(version)),
+            None => (None, None),
+        };
+        AuditReportResponse {
+            audit: (audit_id, report.audit).into(),
+            framework_id,
+            framework_version,
+            control_points: report
+                .control_points
+                .into_iter()
+                .map(|control_point| ControlPointReportResponse {
+                    control_point_id: control_point.control_point_id,
+                    severity: control_point.severity.map(|severity| match severity {
+                        ControlPointSeverity::Low => "Low".to_string(),
+                        ControlPointSeverity::Medium => "Medium".to_string(),
+                        ControlPointSeverity::High => "High".to_string(),
+                        ControlPointSeverity::Critical => "Critical".to_string(),
+                    }),
+                    observation_count: control_point.coverage.observations,
+                    compliant: control_point.coverage.compliant,
+                    non_compliant: control_point.coverage.non_compliant,
+                    not_applicable: control_point.coverage.not_applicable,
+                    observations: control_point
+                        .observations
+                        .into_iter()
+                        .map(|observation| observation.into())
+                        .collect(),
+                })
+                .collect(),
+            observations: report.totals.observations,
+            compliant: report.totals.compliant,
+            non_compliant: report.totals.non_compliant,
+            not_applicable: report.totals.not_applicable,
+            score: report
+                .score
+                .map(|score| score.deconstruct() as f64 / 10_000f64),
+            linked_audits: report.linked_audits,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AuditScheduleResponse<
+    AccountId,
+    ProposalId,
+    AuditId,
+    FrameworkId,
+    AuditScheduleId,
+    BlockNumber,
+    AssetRegistryId,
+    AssetId,
+    RegistryId,
+    DefinitionId,
+    ProcessId,
+> {
+    pub schedule_id: AuditScheduleId,
+    pub proposal_id: ProposalId,
+    pub audit_creator: AccountId,
+    pub auditing_org: AccountId,
+    pub framework_id: Option<FrameworkId>,
+    pub subjects:
+        Vec<AuditSubjectResponse<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId>>,
+    pub cadence: BlockNumber,
+    pub lead_time: BlockNumber,
+    pub next_due: BlockNumber,
+    pub last_audit_id: Option<AuditId>,
+    pub last_audit_due: Option<BlockNumber>,
+    pub missed: u32,
+    pub status: String,
+}
+
+impl<
+        AccountId,
+        ProposalId,
+        AuditId,
+        FrameworkId,
+        AuditScheduleId,
+        BlockNumber,
+        AssetRegistryId,
+        AssetId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        BoundedString,
+    >
+    From<(
+        AuditScheduleId,
+        AuditSchedule<
+            AccountId,
+            ProposalId,
+            FrameworkId,
+            BlockNumber,
+            AuditId,
+            AuditSubject<
+                AssetRegistryId,
+                AssetId,
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                BoundedString,
+            >,
+        >,
+    )>
+    for AuditScheduleResponse<
+        AccountId,
+        ProposalId,
+        AuditId,
+        FrameworkId,
+        AuditScheduleId,
+        BlockNumber,
+        AssetRegistryId,
+        AssetId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+    >
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        (schedule_id, schedule): (
+            AuditScheduleId,
+            AuditSchedule<
+                AccountId,
+                ProposalId,
+                FrameworkId,
+                BlockNumber,
+                AuditId,
+                AuditSubject<
+                    AssetRegistryId,
+                    AssetId,
+                    RegistryId,
+                    DefinitionId,
+                    ProcessId,
+                    BoundedString,
+                >,
+            >,
+        ),
+    ) -> Self {
+        let (last_audit_id, last_audit_due) = match schedule.last_audit {
+            Some((audit_id, due)) => (Some(audit_id), Some(due)),
+            None => (None, None),
+        };
+        AuditScheduleResponse {
+            schedule_id,
+            proposal_id: schedule.proposal_id,
+            audit_creator: schedule.audit_creator,
+            auditing_org: schedule.auditing_org,
+            framework_id: schedule.framework,
+            subjects: schedule
+                .subjects
                 .into_iter()
-                .map(|(evidence_id, evidence)| (evidence_id, evidence).into())
+                .map(|subject| subject.into())
                 .collect(),
+            cadence: schedule.cadence,
+            lead_time: schedule.lead_time,
+            next_due: schedule.next_due,
+            last_audit_id,
+            last_audit_due,
+            missed: schedule.missed,
+            status: match schedule.status {
+                AuditScheduleStatus::Active => "Active".to_string(),
+                AuditScheduleStatus::Paused => "Paused".to_string(),
+                AuditScheduleStatus::Cancelled => "Cancelled".to_string(),
+            },
         }
     }
 }
 
 #[derive(Serialize, Deserialize)]
-pub struct EvidenceResponse<EvidenceId, ProposalId> {
-    pub evidence_id: EvidenceId,
-    pub proposal_id: ProposalId,
-    pub name: String,
-    pub content_type: String,
-    pub url: Option<String>,
-    pub hash: String,
+pub struct MissedAuditResponse<AuditId, BlockNumber> {
+    pub audit_id: AuditId,
+    pub due: BlockNumber,
 }
 
-impl<EvidenceId, ProposalId, BoundedString, BoundedStringUrl>
+#[derive(Serialize, Deserialize)]
+pub struct ConfidentialNoteResponse<AccountId, ProposalId, ControlPointId, ObservationId> {
+    pub ciphertext_hash: [u8; 32],
+    pub proposal_id: ProposalId,
+    pub author: AccountId,
+    pub control_point_id: Option<ControlPointId>,
+    pub observation_id: Option<ObservationId>,
+    pub envelopes: Vec<KeyEnvelopeResponse<AccountId>>,
+}
+impl<AccountId, ProposalId, ControlPointId, ObservationId>
     From<(
-        EvidenceId,
-        Evidence<ProposalId, BoundedString, BoundedStringUrl>,
-    )> for EvidenceResponse<EvidenceId, ProposalId>
-where
-    BoundedString: Into<Vec<u8>>,
-    BoundedStringUrl: Into<Vec<u8>>,
+        [u8; 32],
+        ConfidentialNote<AccountId, ProposalId, ControlPointId, ObservationId>,
+        Vec<(AccountId, KeyEnvelope)>,
+    )> for ConfidentialNoteResponse<AccountId, ProposalId, ControlPointId, ObservationId>
 {
     fn from(
-        (evidence_id, evidence): (
-            EvidenceId,
-            Evidence<ProposalId, BoundedString, BoundedStringUrl>,
+        (ciphertext_hash, note, envelopes): (
+            [u8; 32],
+            ConfidentialNote<AccountId, ProposalId, ControlPointId, ObservationId>,
+            Vec<(AccountId, KeyEnvelope)>,
         ),
     ) -> Self {
-        EvidenceResponse {
-            evidence_id,
-            proposal_id: evidence.proposal_id,
-            name: String::from_utf8_lossy(&evidence.name.into()).to_string(),
-            content_type: String::from_utf8_lossy(&evidence.content_type.into()).to_string(),
-            url: evidence
-                .url
-                .map(|url| String::from_utf8_lossy(&url.into()).to_string()),
-            hash: String::from_utf8_lossy(&evidence.hash.into()).to_string(),
+        let (control_point_id, observation_id) = match note.observation {
+            Some((control_point_id, observation_id)) => {
+                (Some(control_point_id), Some(observation_id))
+            }
+            None => (None, None),
+        };
+        ConfidentialNoteResponse {
+            ciphertext_hash,
+            proposal_id: note.proposal_id,
+            author: note.author,
+            control_point_id,
+            observation_id,
+            envelopes: envelopes
+                .into_iter()
+                .map(|(recipient, envelope)| (recipient, envelope).into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct KeyEnvelopeResponse<AccountId> {
+    pub recipient: AccountId,
+    pub recipient_key: [u8; 32],
+    pub ephemeral_key: [u8; 32],
+    pub encrypted_key: Vec<u8>,
+}
+impl<AccountId> From<(AccountId, KeyEnvelope)> for KeyEnvelopeResponse<AccountId> {
+    fn from((recipient, envelope): (AccountId, KeyEnvelope)) -> Self {
+        KeyEnvelopeResponse {
+            recipient,
+            recipient_key: envelope.recipient_key,
+            ephemeral_key: envelope.ephemeral_key,
+            encrypted_key: envelope.encrypted_key.to_vec(),
         }
     }
 }
+
 pub struct Audits<C, M> {
     client: Arc<C>,
     _marker: std::marker::PhantomData<M>,
@@ -283,6 +1257,17 @@ impl<
         ControlPointId,
         EvidenceId,
         ObservationId,
+        FrameworkId,
+        AssetRegistryId,
+        AssetId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        BlockNumber,
+        ClaimId,
+        Moment,
+        Balance,
+        AuditScheduleId,
         BoundedStringName,
         BoundedStringUrl,
     >
@@ -294,6 +1279,16 @@ impl<
         ControlPointId,
         EvidenceId,
         ObservationId,
+        FrameworkId,
+        AssetRegistryId,
+        AssetId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        BlockNumber,
+        ClaimId,
+        Moment,
+        AuditScheduleId,
     >
     for Audits<
         C,
@@ -305,6 +1300,17 @@ impl<
             ControlPointId,
             EvidenceId,
             ObservationId,
+            FrameworkId,
+            AssetRegistryId,
+            AssetId,
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            BlockNumber,
+            ClaimId,
+            Moment,
+            Balance,
+            AuditScheduleId,
             BoundedStringName,
             BoundedStringUrl,
         ),
@@ -322,6 +1328,17 @@ where
         ControlPointId,
         EvidenceId,
         ObservationId,
+        FrameworkId,
+        AssetRegistryId,
+        AssetId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        BlockNumber,
+        ClaimId,
+        Moment,
+        Balance,
+        AuditScheduleId,
         BoundedStringName,
         BoundedStringUrl,
     >,
@@ -331,6 +1348,17 @@ where
     ControlPointId: Codec + Copy + Send + Sync + 'static,
     EvidenceId: Codec + Copy + Send + Display + Sync + 'static,
     ObservationId: Codec + Copy + Send + Display + Sync + 'static,
+    FrameworkId: Codec + Copy + Send + Display + Sync + 'static,
+    AssetRegistryId: Codec + Copy + Send + Sync + 'static,
+    AssetId: Codec + Copy + Send + Sync + 'static,
+    RegistryId: Codec + Copy + Send + Sync + 'static,
+    DefinitionId: Codec + Copy + Send + Sync + 'static,
+    ProcessId: Codec + Copy + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + 'static,
+    ClaimId: Codec + Send + Sync + 'static,
+    Moment: Codec + Send + Sync + 'static,
+    Balance: Codec + AtLeast32BitUnsigned + Send + Sync + 'static,
+    AuditScheduleId: Codec + Copy + Send + Sync + 'static,
     BoundedStringName: Codec + Clone + Send + Sync + 'static + Into<Vec<u8>>,
     BoundedStringUrl: Codec + Clone + Send + Sync + 'static + Into<Vec<u8>>,
 {
@@ -406,7 +1434,16 @@ where
         &self,
         audit_id: AuditId,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<AuditResponse<AccountId, ProposalId, AuditId>> {
+    ) -> Result<
+        AuditDetailResponse<
+            AccountId,
+            ProposalId,
+            AuditId,
+            BlockNumber,
+            ControlPointId,
+            ObservationId,
+        >,
+    > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
@@ -414,7 +1451,15 @@ where
             .get_audit(&at, audit_id)
             .map_err(convert_error!())?
             .ok_or(not_found_error!(audit_id))?;
-        Ok((audit_id, audit).into())
+        let fee = api.get_audit_fee(&at, audit_id).map_err(convert_error!())?;
+        let trail = api
+            .get_audit_trail(&at, audit_id)
+            .map_err(convert_error!())?;
+        Ok(AuditDetailResponse {
+            audit: (audit_id, audit).into(),
+            fee: fee.map(|fee| fee.into()),
+            trail: trail.into_iter().map(|entry| entry.into()).collect(),
+        })
     }
 
     fn get_audit_by_proposal(
@@ -432,6 +1477,96 @@ where
         Ok((audit_id, audit).into())
     }
 
+    fn get_audit_subjects(
+        &self,
+        audit_id: AuditId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<AuditSubjectResponse<AssetRegistryId, AssetId, RegistryId, DefinitionId, ProcessId>>,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let subjects = api
+            .get_audit_subjects(&at, audit_id)
+            .map_err(convert_error!())?;
+        Ok(subjects.into_iter().map(|subject| subject.into()).collect())
+    }
+
+    fn get_audits_by_did(
+        &self,
+        did: Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let audits = api
+            .get_audits_by_subject(&at, AuditSubject::Did(did.into()))
+            .map_err(convert_error!())?;
+        Ok(audits
+            .into_iter()
+            .map(|(audit_id, audit)| (audit_id, audit).into())
+            .collect())
+    }
+
+    fn get_audits_by_asset(
+        &self,
+        asset_registry_id: AssetRegistryId,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let audits = api
+            .get_audits_by_subject(&at, AuditSubject::Asset(asset_registry_id, asset_id))
+            .map_err(convert_error!())?;
+        Ok(audits
+            .into_iter()
+            .map(|(audit_id, audit)| (audit_id, audit).into())
+            .collect())
+    }
+
+    fn get_audits_by_process(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let audits = api
+            .get_audits_by_subject(
+                &at,
+                AuditSubject::Process(registry_id, definition_id, process_id),
+            )
+            .map_err(convert_error!())?;
+        Ok(audits
+            .into_iter()
+            .map(|(audit_id, audit)| (audit_id, audit).into())
+            .collect())
+    }
+
+    fn get_audits_by_site(
+        &self,
+        site: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<AuditResponse<AccountId, ProposalId, AuditId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let audits = api
+            .get_audits_by_subject(&at, AuditSubject::Site(site.into_bytes()))
+            .map_err(convert_error!())?;
+        Ok(audits
+            .into_iter()
+            .map(|(audit_id, audit)| (audit_id, audit).into())
+            .collect())
+    }
+
     fn get_observation(
         &self,
         audit_id: AuditId,
@@ -559,4 +1694,286 @@ where
             .map_err(convert_error!())?;
         Ok(evidence_links)
     }
+
+    fn get_framework(
+        &self,
+        framework_id: FrameworkId,
+        version: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<FrameworkResponse<AccountId, FrameworkId, ControlPointId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let (framework, version, control_points) = api
+            .get_framework(&at, framework_id, version)
+            .map_err(convert_error!())?
+            .ok_or(not_found_error!(framework_id))?;
+        Ok((framework_id, framework, version, control_points).into())
+    }
+
+    fn get_control_point_coverage(
+        &self,
+        audit_id: AuditId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<CoverageResponse<AuditId, FrameworkId, ControlPointId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let (framework_id, version, coverage) = api
+            .get_control_point_coverage(&at, audit_id)
+            .map_err(convert_error!())?
+            .ok_or(not_found_error!(audit_id))?;
+        Ok(CoverageResponse {
+            audit_id,
+            framework_id,
+            version,
+            control_points: coverage
+                .into_iter()
+                .map(|(control_point_id, coverage)| (control_point_id, coverage).into())
+                .collect(),
+        })
+    }
+
+    fn get_corrective_actions(
+        &self,
+        audit_id: AuditId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            CorrectiveActionResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                BlockNumber,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let corrective_actions = api
+            .get_corrective_actions(&at, audit_id)
+            .map_err(convert_error!())?;
+        Ok(corrective_actions
+            .into_iter()
+            .map(|(control_point_id, observation_id, corrective_action)| {
+                (
+                    audit_id,
+                    control_point_id,
+                    observation_id,
+                    corrective_action,
+                )
+                    .into()
+            })
+            .collect())
+    }
+
+    fn get_open_corrective_actions_by_creator(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            CorrectiveActionResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                BlockNumber,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let corrective_actions = api
+            .get_open_corrective_actions_by_creator(&at, account)
+            .map_err(convert_error!())?;
+        Ok(corrective_actions
+            .into_iter()
+            .map(|corrective_action| corrective_action.into())
+            .collect())
+    }
+
+    fn get_open_corrective_actions_by_auditing_org(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            CorrectiveActionResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                ControlPointId,
+                ObservationId,
+                EvidenceId,
+                BlockNumber,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let corrective_actions = api
+            .get_open_corrective_actions_by_auditing_org(&at, account)
+            .map_err(convert_error!())?;
+        Ok(corrective_actions
+            .into_iter()
+            .map(|corrective_action| corrective_action.into())
+            .collect())
+    }
+
+    fn get_certificates_by_did(
+        &self,
+        did: Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<CertificateResponse<AuditId, ClaimId, Moment>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let certificates = api
+            .get_certificates_by_did(&at, did.into())
+            .map_err(convert_error!())?;
+        Ok(certificates
+            .into_iter()
+            .map(|(audit_id, certificate)| (audit_id, certificate).into())
+            .collect())
+    }
+
+    fn get_audit_report(
+        &self,
+        audit_id: AuditId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        AuditReportResponse<
+            AccountId,
+            ProposalId,
+            AuditId,
+            ControlPointId,
+            ObservationId,
+            EvidenceId,
+            FrameworkId,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let report = api
+            .get_audit_report(&at, audit_id)
+            .map_err(convert_error!())?
+            .ok_or(not_found_error!(audit_id))?;
+        Ok((audit_id, report).into())
+    }
+
+    fn get_upcoming_audits_by_creator(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            AuditScheduleResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                FrameworkId,
+                AuditScheduleId,
+                BlockNumber,
+                AssetRegistryId,
+                AssetId,
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let schedules = api
+            .get_upcoming_audits_by_creator(&at, account)
+            .map_err(convert_error!())?;
+        Ok(schedules
+            .into_iter()
+            .map(|(schedule_id, schedule)| (schedule_id, schedule).into())
+            .collect())
+    }
+
+    fn get_upcoming_audits_by_auditing_org(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            AuditScheduleResponse<
+                AccountId,
+                ProposalId,
+                AuditId,
+                FrameworkId,
+                AuditScheduleId,
+                BlockNumber,
+                AssetRegistryId,
+                AssetId,
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let schedules = api
+            .get_upcoming_audits_by_auditing_org(&at, account)
+            .map_err(convert_error!())?;
+        Ok(schedules
+            .into_iter()
+            .map(|(schedule_id, schedule)| (schedule_id, schedule).into())
+            .collect())
+    }
+
+    fn get_missed_audits(
+        &self,
+        schedule_id: AuditScheduleId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<MissedAuditResponse<AuditId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let missed_audits = api
+            .get_missed_audits(&at, schedule_id)
+            .map_err(convert_error!())?;
+        Ok(missed_audits
+            .into_iter()
+            .map(|(audit_id, due)| MissedAuditResponse { audit_id, due })
+            .collect())
+    }
+
+    fn get_confidential_notes(
+        &self,
+        audit_id: AuditId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ConfidentialNoteResponse<AccountId, ProposalId, ControlPointId, ObservationId>>>
+    {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let notes = api
+            .get_confidential_notes(&at, audit_id)
+            .map_err(convert_error!())?;
+        notes
+            .into_iter()
+            .map(|(ciphertext_hash, note)| {
+                let envelopes = api
+                    .get_note_envelopes(&at, audit_id, ciphertext_hash)
+                    .map_err(convert_error!())?;
+                Ok((ciphertext_hash, note, envelopes).into())
+            })
+            .collect()
+    }
 }
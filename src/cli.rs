@@ -16,7 +16,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use sc_cli::{KeySubcommand, RunCmd, SignCmd, VanityCmd, VerifyCmd};
+use runtime::primitives::{AuditId, DefinitionId, RegistryId};
+use sc_cli::{
+    CliConfiguration, KeySubcommand, PruningParams, RunCmd, SharedParams, SignCmd, VanityCmd,
+    VerifyCmd,
+};
+use std::{path::PathBuf, str::FromStr};
 use structopt::StructOpt;
 
 /// An overarching CLI command definition.
@@ -28,6 +33,22 @@ pub struct Cli {
     #[allow(missing_docs)]
     #[structopt(flatten)]
     pub run: RunCmd,
+
+    /// Serve public provenance traces over HTTP on this port.
+    ///
+    /// Traces are only served for processes of definitions made public by their creator.
+    #[structopt(long = "public-trace-port", value_name = "PORT")]
+    pub public_trace_port: Option<u16>,
+
+    /// Listen for public trace requests on all interfaces, not only on localhost.
+    #[structopt(long = "public-trace-external")]
+    pub public_trace_external: bool,
+
+    /// Serve the content of evidence held by this node to its reserved peers.
+    ///
+    /// Off by default, in which case content is only fetched from peers and never served.
+    #[structopt(long = "serve-evidence")]
+    pub serve_evidence: bool,
 }
 
 /// Possible subcommands of the main binary.
@@ -69,4 +90,121 @@ pub enum Subcommand {
 
     /// Revert the chain to a previous state.
     Revert(sc_cli::RevertCmd),
+
+    /// Export provenance history as a GS1 EPCIS 2.0 JSON-LD document.
+    ExportEpcis(ExportEpcisCmd),
+
+    /// Export the compliance report of an audit as JSON or Markdown.
+    ExportAuditReport(ExportAuditReportCmd),
+}
+
+/// The `export-epcis` command.
+#[derive(Debug, StructOpt)]
+pub struct ExportEpcisCmd {
+    /// Registry whose processes are exported.
+    #[structopt(long)]
+    pub registry_id: RegistryId,
+
+    /// Only export processes of this definition.
+    #[structopt(long)]
+    pub definition_id: Option<DefinitionId>,
+
+    /// Only export events attested at or after this time (RFC 3339, e.g. 2021-06-01T00:00:00Z).
+    #[structopt(long, parse(try_from_str = parse_time))]
+    pub from: Option<u64>,
+
+    /// Only export events attested at or before this time (RFC 3339).
+    #[structopt(long, parse(try_from_str = parse_time))]
+    pub to: Option<u64>,
+
+    /// JSON file mapping definition steps to business steps and attributes to ILMD or sensor elements.
+    #[structopt(long, parse(from_os_str))]
+    pub mapping: Option<PathBuf>,
+
+    /// Write the document to this file instead of stdout.
+    #[structopt(long, short, parse(from_os_str))]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl CliConfiguration for ExportEpcisCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+}
+
+/// The `export-audit-report` command.
+#[derive(Debug, StructOpt)]
+pub struct ExportAuditReportCmd {
+    /// Audit to report on.
+    #[structopt(long)]
+    pub audit_id: AuditId,
+
+    /// `json` or `markdown`.
+    #[structopt(long, default_value = "json")]
+    pub format: ReportFormat,
+
+    /// Write the report to this file instead of stdout.
+    #[structopt(long, short, parse(from_os_str))]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl CliConfiguration for ExportAuditReportCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+}
+
+/// Output format of an exported report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// The report as returned by the `get_audit_report` RPC.
+    Json,
+    /// A human readable document for archival.
+    Markdown,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "json" => Ok(ReportFormat::Json),
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            _ => Err(format!(
+                "Invalid report format {}, expected json or markdown",
+                format
+            )),
+        }
+    }
+}
+
+/// Parse an RFC 3339 time into unix milliseconds.
+fn parse_time(time: &str) -> Result<u64, String> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .map(|time| time.timestamp_millis() as u64)
+        .map_err(|e| format!("Invalid time {}: {}", time, e))
 }
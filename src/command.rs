@@ -22,6 +22,7 @@ use crate::{
 use runtime::Block;
 use sc_cli::{ChainSpec, Role, RuntimeVersion, SubstrateCli};
 use sc_service::PartialComponents;
+use std::net::{Ipv4Addr, SocketAddr};
 
 impl SubstrateCli for Cli {
     fn impl_name() -> String {
@@ -98,10 +99,19 @@ pub fn run() -> sc_cli::Result<()> {
     match &cli.subcommand {
         None => {
             let runner = cli.create_runner(&cli.run)?;
+            let public_trace = cli.public_trace_port.map(|port| {
+                let ip = if cli.public_trace_external {
+                    Ipv4Addr::UNSPECIFIED
+                } else {
+                    Ipv4Addr::LOCALHOST
+                };
+                SocketAddr::new(ip.into(), port)
+            });
+            let serve_evidence = cli.serve_evidence;
             runner.run_node_until_exit(|config| async move {
                 match config.role {
                     Role::Light => service::new_light(config),
-                    _ => service::new_full(config),
+                    _ => service::new_full(config, public_trace, serve_evidence),
                 }
                 .map_err(sc_cli::Error::Service)
             })
@@ -176,6 +186,20 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run(config.database))
         }
+        Some(Subcommand::ExportEpcis(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| {
+                let PartialComponents { client, .. } = new_partial(&config)?;
+                cmd.run(&*client)
+            })
+        }
+        Some(Subcommand::ExportAuditReport(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| {
+                let PartialComponents { client, .. } = new_partial(&config)?;
+                cmd.run(&*client)
+            })
+        }
         Some(Subcommand::Revert(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|config| {
@@ -0,0 +1,482 @@
+//! Export of provenance history as GS1 EPCIS 2.0 JSON-LD.
+//!
+//! Every attested step of a process becomes an `ObjectEvent` on the lot the process tracks:
+//! the first step of a process is an `ADD`, later steps are `OBSERVE`s.
+//! A process with recorded inputs (see `link_processes`) also gets a `TransformationEvent`
+//! from the upstream lots to its own lot, dated by its first attested step.
+//!
+//! Step attributes are placed according to an [`EpcisMapping`], e.g.
+//!
+//! ```json
+//! {
+//!   "epc_prefix": "urn:epc:id:sgtin:0614141.107346",
+//!   "biz_steps": { "Harvest": "commissioning", "Packing": "packing" },
+//!   "ilmd": { "Variety": "cbvmda:itemDescription" },
+//!   "sensors": { "Temperature": { "type": "gs1:Temperature", "uom": "CEL" } }
+//! }
+//! ```
+//!
+//! Attributes that are not mapped are kept as `borlaug:` extension elements.
+//! Each event carries the hash of the block and the index of the extrinsic that attested it.
+//! Steps attested before attestation evidence was recorded on chain cannot be dated and are left out.
+
+use chrono::NaiveDateTime;
+use codec::Codec;
+use pallet_primitives::{Attribute, Fact};
+use provenance_runtime_api::ProvenanceApi as ProvenanceRuntimeApi;
+use runtime::primitives;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, NumberFor},
+};
+use std::{collections::BTreeMap, fmt::Display, fs::File};
+
+const EPCIS_CONTEXT: &str = "https://ref.gs1.org/standards/epcis/2.0.0/epcis-context.jsonld";
+const BORLAUG_NAMESPACE: &str = "urn:borlaug:epcis:";
+const DEFAULT_EPC_PREFIX: &str = "urn:borlaug:process";
+
+/// How step attributes are placed in EPCIS events.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct EpcisMapping {
+    /// Prefix of the EPC minted for the lot of each process, followed by `:registry.definition.process`
+    pub epc_prefix: Option<String>,
+    /// Definition step name => CBV business step
+    pub biz_steps: BTreeMap<String, String>,
+    /// Attribute name => ILMD element name
+    pub ilmd: BTreeMap<String, String>,
+    /// Attribute name => sensor report type and unit of measure
+    pub sensors: BTreeMap<String, SensorMapping>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SensorMapping {
+    #[serde(rename = "type")]
+    pub report_type: String,
+    pub uom: Option<String>,
+}
+
+/// Which processes and events to export. Times are unix timestamps in milliseconds.
+pub struct EpcisFilter<RegistryId, DefinitionId> {
+    pub registry_id: RegistryId,
+    pub definition_id: Option<DefinitionId>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+impl<RegistryId, DefinitionId> EpcisFilter<RegistryId, DefinitionId> {
+    fn includes(&self, timestamp: u64) -> bool {
+        self.from.map_or(true, |from| timestamp >= from)
+            && self.to.map_or(true, |to| timestamp <= to)
+    }
+}
+
+/// Build an EPCIS document from the provenance state at block `at`.
+#[allow(clippy::type_complexity)]
+pub fn export<
+    C,
+    Block,
+    AccountId,
+    RegistryId,
+    DefinitionId,
+    ProcessId,
+    ProposalId,
+    MemberCount,
+    DefinitionStepIndex,
+    AssetRegistryId,
+    AssetId,
+    BlockNumber,
+    Moment,
+    BoundedStringName,
+    BoundedStringFact,
+>(
+    client: &C,
+    at: &BlockId<Block>,
+    filter: &EpcisFilter<RegistryId, DefinitionId>,
+    mapping: &EpcisMapping,
+) -> Result<Value, String>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ProvenanceRuntimeApi<
+        Block,
+        AccountId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        ProposalId,
+        MemberCount,
+        DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BlockNumber,
+        Moment,
+        BoundedStringName,
+        BoundedStringFact,
+    >,
+    AccountId: Codec,
+    RegistryId: Codec + Copy + Display,
+    DefinitionId: Codec + Copy + Display,
+    ProcessId: Codec + Copy + Display,
+    ProposalId: Codec,
+    MemberCount: Codec,
+    DefinitionStepIndex: Codec + Copy + PartialEq + Display,
+    AssetRegistryId: Codec,
+    AssetId: Codec,
+    BlockNumber: Codec + Copy + Into<NumberFor<Block>>,
+    Moment: Codec + Copy + Into<u64>,
+    BoundedStringName: Codec + Into<Vec<u8>>,
+    BoundedStringFact: Codec + Into<Vec<u8>>,
+{
+    let api = client.runtime_api();
+    let registry_id = filter.registry_id;
+
+    let definition_ids = match filter.definition_id {
+        Some(definition_id) => vec![definition_id],
+        None => api
+            .get_definitions(at, registry_id)
+            .map_err(|e| format!("{:?}", e))?
+            .into_iter()
+            .map(|(definition_id, _)| definition_id)
+            .collect(),
+    };
+
+    let mut events: Vec<(u64, Value)> = Vec::new();
+
+    for definition_id in definition_ids {
+        let step_names: Vec<(DefinitionStepIndex, String)> = api
+            .get_definition_steps(at, registry_id, definition_id)
+            .map_err(|e| format!("{:?}", e))?
+            .into_iter()
+            .map(|(step_index, step)| (step_index, to_string(step.name)))
+            .collect();
+
+        let processes = api
+            .get_processes(at, registry_id, definition_id)
+            .map_err(|e| format!("{:?}", e))?;
+
+        for (process_id, process) in processes {
+            let epc = mapping.epc(registry_id, definition_id, process_id);
+            let read_point = match process.location {
+                Some(Fact::Location(lat, lng)) => Some(json!({
+                    "id": format!(
+                        "geo:{},{}",
+                        (lat as f64) / 1_000_000f64,
+                        (lng as f64) / 1_000_000f64
+                    )
+                })),
+                _ => None,
+            };
+            let evidence = api
+                .get_process_step_evidence(at, registry_id, definition_id, process_id)
+                .map_err(|e| format!("{:?}", e))?;
+            let steps = api
+                .get_process_steps(at, registry_id, definition_id, process_id)
+                .map_err(|e| format!("{:?}", e))?;
+
+            // ((block number, extrinsic index), timestamp, block hash) of the earliest attested step
+            let mut first_attested: Option<((NumberFor<Block>, u32), u64, String)> = None;
+
+            for (step_index, _, step) in steps {
+                let step = match step {
+                    Some(step) => step,
+                    None => continue,
+                };
+                let step_evidence = match evidence.iter().find(|(index, _)| *index == step_index) {
+                    Some((_, step_evidence)) => step_evidence,
+                    None => continue,
+                };
+                let timestamp: u64 = step_evidence.timestamp.into();
+                // Steps attested in the same block share its timestamp, so order by position in the chain
+                let attested_at: (NumberFor<Block>, u32) = (
+                    step_evidence.block_number.into(),
+                    step_evidence.extrinsic_index,
+                );
+                let block_hash = client
+                    .hash(attested_at.0)
+                    .map_err(|e| format!("{:?}", e))?
+                    .ok_or_else(|| String::from("Attestation block not found"))?;
+                let block_hash = format!("{:?}", block_hash);
+
+                if first_attested
+                    .as_ref()
+                    .map_or(true, |(first, _, _)| attested_at < *first)
+                {
+                    first_attested = Some((attested_at, timestamp, block_hash.clone()));
+                }
+                if !filter.includes(timestamp) {
+                    continue;
+                }
+
+                let step_name = step_names
+                    .iter()
+                    .find(|(index, _)| *index == step_index)
+                    .map(|(_, name)| name.clone())
+                    .unwrap_or_default();
+                let action = if evidence.iter().all(|(_, other)| {
+                    (
+                        Into::<NumberFor<Block>>::into(other.block_number),
+                        other.extrinsic_index,
+                    ) >= attested_at
+                }) {
+                    "ADD"
+                } else {
+                    "OBSERVE"
+                };
+
+                let mut event = Map::new();
+                event.insert("type".into(), json!("ObjectEvent"));
+                event.insert(
+                    "eventID".into(),
+                    json!(format!(
+                        "{}event:{}.{}.{}.{}",
+                        BORLAUG_NAMESPACE, registry_id, definition_id, process_id, step_index
+                    )),
+                );
+                event.insert("eventTime".into(), json!(event_time(timestamp)));
+                event.insert("eventTimeZoneOffset".into(), json!("+00:00"));
+                event.insert("epcList".into(), json!([epc]));
+                event.insert("action".into(), json!(action));
+                if let Some(biz_step) = mapping.biz_steps.get(&step_name) {
+                    event.insert("bizStep".into(), json!(biz_step));
+                }
+                if let Some(read_point) = &read_point {
+                    event.insert("readPoint".into(), read_point.clone());
+                }
+                mapping.place_attributes(&mut event, step.attributes, timestamp, action == "ADD");
+                event.insert("borlaug:stepName".into(), json!(step_name));
+                event.insert("borlaug:blockHash".into(), json!(block_hash));
+                event.insert(
+                    "borlaug:extrinsicIndex".into(),
+                    json!(step_evidence.extrinsic_index),
+                );
+
+                events.push((timestamp, Value::Object(event)));
+            }
+
+            if let Some(((_, extrinsic_index), timestamp, block_hash)) = first_attested {
+                if !filter.includes(timestamp) {
+                    continue;
+                }
+                let inputs = api
+                    .trace_back(at, registry_id, definition_id, process_id, 1)
+                    .map_err(|e| format!("{:?}", e))?;
+                if inputs.is_empty() {
+                    continue;
+                }
+                let input_epcs: Vec<String> = inputs
+                    .into_iter()
+                    .map(
+                        |(_, _, (upstream_registry, upstream_definition, upstream_process), _)| {
+                            mapping.epc(upstream_registry, upstream_definition, upstream_process)
+                        },
+                    )
+                    .collect();
+                events.push((
+                    timestamp,
+                    json!({
+                        "type": "TransformationEvent",
+                        "eventID": format!(
+                            "{}transformation:{}.{}.{}",
+                            BORLAUG_NAMESPACE, registry_id, definition_id, process_id
+                        ),
+                        "eventTime": event_time(timestamp),
+                        "eventTimeZoneOffset": "+00:00",
+                        "inputEPCList": input_epcs,
+                        "outputEPCList": [epc],
+                        "borlaug:blockHash": block_hash,
+                        "borlaug:extrinsicIndex": extrinsic_index,
+                    }),
+                ));
+            }
+        }
+    }
+
+    events.sort_by_key(|(timestamp, _)| *timestamp);
+
+    Ok(json!({
+        "@context": [EPCIS_CONTEXT, { "borlaug": BORLAUG_NAMESPACE }],
+        "type": "EPCISDocument",
+        "schemaVersion": "2.0",
+        "creationDate": chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+        "epcisBody": {
+            "eventList": events.into_iter().map(|(_, event)| event).collect::<Vec<_>>()
+        }
+    }))
+}
+
+impl EpcisMapping {
+    fn epc<RegistryId: Display, DefinitionId: Display, ProcessId: Display>(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+    ) -> String {
+        format!(
+            "{}:{}.{}.{}",
+            self.epc_prefix.as_deref().unwrap_or(DEFAULT_EPC_PREFIX),
+            registry_id,
+            definition_id,
+            process_id
+        )
+    }
+
+    /// ILMD may only be given when a lot is first seen, so on later events ILMD attributes become extension elements.
+    fn place_attributes<BoundedStringName, BoundedStringFact>(
+        &self,
+        event: &mut Map<String, Value>,
+        attributes: Vec<Attribute<BoundedStringName, BoundedStringFact>>,
+        timestamp: u64,
+        with_ilmd: bool,
+    ) where
+        BoundedStringName: Into<Vec<u8>>,
+        BoundedStringFact: Into<Vec<u8>>,
+    {
+        let mut ilmd = Map::new();
+        let mut sensor_reports = Vec::new();
+
+        for attribute in attributes {
+            let name = to_string(attribute.name);
+            if let Some(sensor) = self.sensors.get(&name) {
+                let mut report = Map::new();
+                report.insert("type".into(), json!(sensor.report_type));
+                match fact_value(attribute.fact) {
+                    Value::Number(value) => report.insert("value".into(), Value::Number(value)),
+                    Value::Bool(value) => report.insert("booleanValue".into(), Value::Bool(value)),
+                    value => report.insert("stringValue".into(), value),
+                };
+                if let Some(uom) = &sensor.uom {
+                    report.insert("uom".into(), json!(uom));
+                }
+                sensor_reports.push(Value::Object(report));
+                continue;
+            }
+            let element = self
+                .ilmd
+                .get(&name)
+                .cloned()
+                .unwrap_or_else(|| extension_name(&name));
+            if with_ilmd {
+                ilmd.insert(element, fact_value(attribute.fact));
+            } else {
+                event.insert(element, fact_value(attribute.fact));
+            }
+        }
+
+        if !ilmd.is_empty() {
+            event.insert("ilmd".into(), Value::Object(ilmd));
+        }
+        if !sensor_reports.is_empty() {
+            event.insert(
+                "sensorElementList".into(),
+                json!([{
+                    "sensorMetadata": { "time": event_time(timestamp) },
+                    "sensorReport": sensor_reports,
+                }]),
+            );
+        }
+    }
+}
+
+fn to_string<S: Into<Vec<u8>>>(value: S) -> String {
+    String::from_utf8_lossy(&value.into()).to_string()
+}
+
+/// Attribute names may contain characters that are not valid in a compact IRI.
+fn extension_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("borlaug:{}", name)
+}
+
+pub(crate) fn event_time(timestamp: u64) -> String {
+    NaiveDateTime::from_timestamp(
+        (timestamp / 1000) as i64,
+        ((timestamp % 1000) * 1_000_000) as u32,
+    )
+    .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+    .to_string()
+}
+
+fn fact_value<BoundedString: Into<Vec<u8>>>(fact: Fact<BoundedString>) -> Value {
+    match fact {
+        Fact::Bool(value) => json!(value),
+        Fact::Text(value) => json!(to_string(value)),
+        Fact::Float(value) => json!(f64::from_le_bytes(value)),
+        Fact::U8(value) => json!(value),
+        Fact::U16(value) => json!(value),
+        Fact::U32(value) => json!(value),
+        fact => json!(crate::identity_rpc::FactResponse::from(fact).value),
+    }
+}
+
+impl crate::cli::ExportEpcisCmd {
+    /// Export from the best block of the local database.
+    pub fn run<C>(&self, client: &C) -> sc_cli::Result<()>
+    where
+        C: ProvideRuntimeApi<primitives::Block> + HeaderBackend<primitives::Block>,
+        C::Api: ProvenanceRuntimeApi<
+            primitives::Block,
+            primitives::AccountId,
+            primitives::RegistryId,
+            primitives::DefinitionId,
+            primitives::ProcessId,
+            primitives::ProposalId,
+            primitives::MemberCount,
+            primitives::DefinitionStepIndex,
+            primitives::RegistryId,
+            primitives::AssetId,
+            primitives::BlockNumber,
+            primitives::Moment,
+            primitives::BoundedStringName,
+            primitives::BoundedStringFact,
+        >,
+    {
+        let mapping: EpcisMapping = match &self.mapping {
+            Some(path) => {
+                let file = File::open(path)?;
+                serde_json::from_reader(file)
+                    .map_err(|e| format!("Invalid EPCIS mapping file: {}", e))?
+            }
+            None => EpcisMapping::default(),
+        };
+        let filter = EpcisFilter {
+            registry_id: self.registry_id,
+            definition_id: self.definition_id,
+            from: self.from,
+            to: self.to,
+        };
+        let at = BlockId::hash(client.info().best_hash);
+
+        let document = export::<
+            C,
+            primitives::Block,
+            primitives::AccountId,
+            primitives::RegistryId,
+            primitives::DefinitionId,
+            primitives::ProcessId,
+            primitives::ProposalId,
+            primitives::MemberCount,
+            primitives::DefinitionStepIndex,
+            primitives::RegistryId,
+            primitives::AssetId,
+            primitives::BlockNumber,
+            primitives::Moment,
+            primitives::BoundedStringName,
+            primitives::BoundedStringFact,
+        >(client, &at, &filter, &mapping)?;
+        let document = serde_json::to_string_pretty(&document).map_err(|e| e.to_string())?;
+
+        match &self.output {
+            Some(path) => std::fs::write(path, document)?,
+            None => println!("{}", document),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::evidence_store::EvidenceStore;
+use audits_runtime_api::AuditsApi as AuditsRuntimeApi;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use runtime::{primitives, AuditEvidenceSizeLimit, BoundedStringUrl};
+use sc_rpc_api::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+type BlockHash = <primitives::Block as BlockT>::Hash;
+
+#[rpc]
+pub trait EvidenceApi {
+    /// Store content in this node's evidence store, returning the hash and size to declare with `create_evidence`.
+    #[rpc(name = "upload_evidence")]
+    fn upload_evidence(&self, content: Bytes) -> Result<EvidenceUploadResponse>;
+
+    /// The content of an item of evidence. Content not held by this node is requested from its peers,
+    /// so the call can be retried. Unsafe, as evidence may be confidential to the parties of an audit.
+    #[rpc(name = "get_evidence_content")]
+    fn get_evidence_content(
+        &self,
+        audit_id: primitives::AuditId,
+        evidence_id: primitives::EvidenceId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EvidenceUploadResponse {
+    pub hash: [u8; 32],
+    pub size: u64,
+}
+
+pub struct Evidence<C> {
+    client: Arc<C>,
+    store: EvidenceStore,
+    deny_unsafe: DenyUnsafe,
+}
+
+impl<C> Evidence<C> {
+    pub fn new(client: Arc<C>, store: EvidenceStore, deny_unsafe: DenyUnsafe) -> Self {
+        Self {
+            client,
+            store,
+            deny_unsafe,
+        }
+    }
+}
+
+macro_rules! convert_error {
+    () => {{
+        |e| RpcError {
+            code: ErrorCode::ServerError(1),
+            message: "Error in Evidence API".into(),
+            data: Some(format!("{:?}", e).into()),
+        }
+    }};
+}
+
+macro_rules! not_found_error {
+    ($id:expr) => {{
+        RpcError {
+            code: ErrorCode::ServerError(404),
+            message: "Entity not found".into(),
+            data: Some(format!("{}", $id).into()),
+        }
+    }};
+}
+
+impl<C> EvidenceApi for Evidence<C>
+where
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<primitives::Block>,
+    C: HeaderBackend<primitives::Block>,
+    C::Api: AuditsRuntimeApi<
+        primitives::Block,
+        primitives::AccountId,
+        primitives::ProposalId,
+        primitives::AuditId,
+        primitives::ControlPointId,
+        primitives::EvidenceId,
+        primitives::ObservationId,
+        primitives::FrameworkId,
+        primitives::RegistryId,
+        primitives::AssetId,
+        primitives::RegistryId,
+        primitives::DefinitionId,
+        primitives::ProcessId,
+        primitives::BlockNumber,
+        primitives::ClaimId,
+        primitives::Moment,
+        primitives::Balance,
+        primitives::AuditScheduleId,
+        primitives::BoundedStringName,
+        BoundedStringUrl,
+    >,
+{
+    fn upload_evidence(&self, content: Bytes) -> Result<EvidenceUploadResponse> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let size = content.len() as u64;
+        if size == 0 || size > AuditEvidenceSizeLimit::get() {
+            return Err(RpcError::invalid_params(format!(
+                "Evidence must be between 1 and {} bytes",
+                AuditEvidenceSizeLimit::get()
+            )));
+        }
+        let hash = self.store.put(&content).map_err(convert_error!())?;
+
+        Ok(EvidenceUploadResponse { hash, size })
+    }
+
+    fn get_evidence_content(
+        &self,
+        audit_id: primitives::AuditId,
+        evidence_id: primitives::EvidenceId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let evidence = api
+            .get_evidence(&at, audit_id, evidence_id)
+            .map_err(convert_error!())?
+            .ok_or(not_found_error!(evidence_id))?;
+        // evidence created before sizes were declared has no content in the store
+        if evidence.size == 0 {
+            return Err(not_found_error!(format!("content of {}", evidence_id)));
+        }
+
+        match self.store.get(&evidence.hash).map_err(convert_error!())? {
+            Some(content) => Ok(content.into()),
+            None => {
+                self.store.request(evidence.hash, evidence.size);
+                Err(RpcError {
+                    code: ErrorCode::ServerError(503),
+                    message: "Evidence content requested from peers, retry later".into(),
+                    data: Some(format!("0x{}", hex::encode(evidence.hash)).into()),
+                })
+            }
+        }
+    }
+}
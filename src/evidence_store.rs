@@ -0,0 +1,258 @@
+//! Content-addressed store for the content of evidence attached to audits.
+//!
+//! Content is uploaded to a node with the `upload_evidence` RPC and kept on disk in the `evidence`
+//! directory of the chain's config directory, in a file named by its blake2-256 hash. The hash and
+//! size are then declared on chain with `create_evidence`.
+//!
+//! A node that does not hold the content of an item of evidence fetches it from its peers over the
+//! `/{protocol_id}/evidence/1` request-response protocol. The request is the SCALE encoded hash and
+//! the response is the SCALE encoded `Option` of the content. Fetched content is kept only if it has
+//! the size and hash declared on chain.
+//!
+//! Content is only served to peers when the node is started with `--serve-evidence`, and then only
+//! to its reserved peers. Requests from any other peer are refused.
+
+use codec::{Decode, Encode};
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use runtime::{primitives::Block, AuditEvidenceSizeLimit};
+use sc_network::{
+    config::{IncomingRequest, ProtocolId, RequestResponseConfig},
+    NetworkService, PeerId,
+};
+use sc_service::{Configuration, Error as ServiceError, SpawnTaskHandle};
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::HashSet, fs, io, path::PathBuf, sync::Arc, time::Duration};
+
+const LOG_TARGET: &str = "evidence";
+
+/// Capacity of the queue of requests from peers.
+const INBOUND_QUEUE_SIZE: usize = 20;
+
+/// Content is fetched from the peers of a node, which are on the same chain, so a generous timeout
+/// allows for content at the size limit.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Content of evidence to fetch from peers, by hash and declared size.
+pub struct EvidenceRequests(UnboundedReceiver<([u8; 32], u64)>);
+
+/// The content of evidence held by this node.
+#[derive(Clone)]
+pub struct EvidenceStore {
+    dir: PathBuf,
+    requests: UnboundedSender<([u8; 32], u64)>,
+}
+
+impl EvidenceStore {
+    /// Open the store of the chain of `config`, creating its directory if needed.
+    pub fn open(config: &Configuration) -> Result<(Self, EvidenceRequests), ServiceError> {
+        let dir = config
+            .base_path
+            .as_ref()
+            .ok_or_else(|| ServiceError::Other("A base path is required for evidence".into()))?
+            .config_dir(config.chain_spec.id())
+            .join("evidence");
+        fs::create_dir_all(&dir)?;
+        let (requests, receiver) = mpsc::unbounded();
+        Ok((EvidenceStore { dir, requests }, EvidenceRequests(receiver)))
+    }
+
+    /// Store `content`, returning its hash.
+    pub fn put(&self, content: &[u8]) -> io::Result<[u8; 32]> {
+        let hash = blake2_256(content);
+        let path = self.path(&hash);
+        if !path.exists() {
+            // write then rename so a partially written file is never served
+            let tmp = path.with_extension("tmp");
+            fs::write(&tmp, content)?;
+            fs::rename(&tmp, &path)?;
+        }
+        Ok(hash)
+    }
+
+    /// The content with `hash`, if held. Content that no longer matches its hash is removed.
+    pub fn get(&self, hash: &[u8; 32]) -> io::Result<Option<Vec<u8>>> {
+        let path = self.path(hash);
+        let content = match fs::read(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if blake2_256(&content) != *hash {
+            log::warn!(
+                target: LOG_TARGET,
+                "Removing corrupt evidence 0x{}",
+                hex::encode(hash)
+            );
+            fs::remove_file(&path)?;
+            return Ok(None);
+        }
+        Ok(Some(content))
+    }
+
+    /// Fetch the content with `hash` and `size` from peers.
+    pub fn request(&self, hash: [u8; 32], size: u64) {
+        if self.requests.unbounded_send((hash, size)).is_err() {
+            log::debug!(
+                target: LOG_TARGET,
+                "Evidence fetcher is not running, not fetching 0x{}",
+                hex::encode(hash)
+            );
+        }
+    }
+
+    fn path(&self, hash: &[u8; 32]) -> PathBuf {
+        self.dir.join(hex::encode(hash))
+    }
+}
+
+fn protocol_name(protocol_id: &ProtocolId) -> String {
+    format!("/{}/evidence/1", protocol_id.as_ref())
+}
+
+/// The reserved peers of the node of `config`, the only peers content may be served to.
+pub fn reserved_peers(config: &Configuration) -> HashSet<PeerId> {
+    config
+        .network
+        .default_peers_set
+        .reserved_nodes
+        .iter()
+        .map(|node| node.peer_id.clone())
+        .collect()
+}
+
+/// The request-response protocol fetching content from peers, and serving the content held by
+/// `store` to the peers in `serve_to`. If `serve_to` is `None` no content is served.
+pub fn request_response_config(
+    protocol_id: &ProtocolId,
+    store: EvidenceStore,
+    serve_to: Option<HashSet<PeerId>>,
+    spawn_handle: SpawnTaskHandle,
+) -> RequestResponseConfig {
+    let mut config = RequestResponseConfig {
+        name: protocol_name(protocol_id).into(),
+        max_request_size: 32,
+        // the content and the SCALE prefixes of the `Option` and `Vec`
+        max_response_size: AuditEvidenceSizeLimit::get() + 16,
+        request_timeout: REQUEST_TIMEOUT,
+        inbound_queue: None,
+    };
+    let serve_to = match serve_to {
+        Some(serve_to) => serve_to,
+        None => return config,
+    };
+
+    let (inbound_queue, mut requests) = mpsc::channel::<IncomingRequest>(INBOUND_QUEUE_SIZE);
+    config.inbound_queue = Some(inbound_queue);
+
+    spawn_handle.spawn_blocking("evidence-request-handler", async move {
+        while let Some(request) = requests.next().await {
+            let IncomingRequest {
+                peer,
+                payload,
+                pending_response,
+            } = request;
+            if !serve_to.contains(&peer) {
+                // dropping the pending response refuses the request
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Refusing request from {}, which is not a reserved peer",
+                    peer
+                );
+                continue;
+            }
+            let hash = match <[u8; 32]>::decode(&mut &payload[..]) {
+                Ok(hash) => hash,
+                Err(e) => {
+                    // dropping the pending response refuses the request
+                    log::debug!(target: LOG_TARGET, "Invalid request from {}: {}", peer, e);
+                    continue;
+                }
+            };
+            let content = store.get(&hash).unwrap_or_else(|e| {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Unable to read evidence 0x{}: {}",
+                    hex::encode(hash),
+                    e
+                );
+                None
+            });
+            let _ = pending_response.send(content.encode());
+        }
+    });
+
+    config
+}
+
+/// Fetch requested content from connected peers, one request at a time, until a peer has it.
+pub fn spawn_fetcher(
+    protocol_id: &ProtocolId,
+    store: EvidenceStore,
+    requests: EvidenceRequests,
+    network: Arc<NetworkService<Block, <Block as BlockT>::Hash>>,
+    spawn_handle: SpawnTaskHandle,
+) {
+    let protocol = protocol_name(protocol_id);
+    let mut requests = requests.0;
+
+    spawn_handle.spawn_blocking("evidence-fetcher", async move {
+        while let Some((hash, size)) = requests.next().await {
+            if let Ok(Some(_)) = store.get(&hash) {
+                continue;
+            }
+            let peers: Vec<PeerId> = match network.network_state().await {
+                Ok(state) => state
+                    .connected_peers
+                    .keys()
+                    .filter_map(|peer| peer.parse().ok())
+                    .collect(),
+                Err(_) => {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Unable to read the network state to request evidence 0x{}",
+                        hex::encode(hash)
+                    );
+                    continue;
+                }
+            };
+            for peer in peers {
+                let response = match network.request(peer, protocol.clone(), hash.encode()).await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        log::debug!(target: LOG_TARGET, "Request to {} failed: {}", peer, e);
+                        continue;
+                    }
+                };
+                match Option::<Vec<u8>>::decode(&mut &response[..]) {
+                    Ok(Some(content))
+                        if content.len() as u64 == size && blake2_256(&content) == hash =>
+                    {
+                        if let Err(e) = store.put(&content) {
+                            log::warn!(
+                                target: LOG_TARGET,
+                                "Unable to store evidence 0x{}: {}",
+                                hex::encode(hash),
+                                e
+                            );
+                        }
+                        break;
+                    }
+                    Ok(Some(_)) => log::warn!(
+                        target: LOG_TARGET,
+                        "{} sent content not matching evidence 0x{}",
+                        peer,
+                        hex::encode(hash)
+                    ),
+                    Ok(None) => (),
+                    Err(e) => {
+                        log::debug!(target: LOG_TARGET, "Invalid response from {}: {}", peer, e)
+                    }
+                }
+            }
+        }
+    });
+}
@@ -2,7 +2,7 @@ use codec::Codec;
 use groups_runtime_api::GroupsApi as GroupsRuntimeApi;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use pallet_primitives::{Group, Votes};
+use pallet_primitives::{Budget, BudgetUsage, Group, ScheduledExecution, Votes};
 use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
@@ -13,7 +13,7 @@ use sp_runtime::{
 use std::sync::Arc;
 
 #[rpc]
-pub trait GroupsApi<BlockHash, AccountId, GroupId, MemberCount, ProposalId, Hash> {
+pub trait GroupsApi<BlockHash, AccountId, GroupId, MemberCount, ProposalId, Hash, BlockNumber> {
     #[rpc(name = "member_of")]
     fn member_of(
         &self,
@@ -78,6 +78,27 @@ pub trait GroupsApi<BlockHash, AccountId, GroupId, MemberCount, ProposalId, Hash
             Vec<ProposalResponse<ProposalId, GroupId, AccountId, MemberCount>>,
         )>,
     >;
+
+    #[rpc(name = "get_scheduled_proposals")]
+    fn get_scheduled_proposals(
+        &self,
+        group_id: GroupId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ScheduledExecutionResponse<ProposalId, AccountId, BlockNumber, MemberCount>>>;
+
+    #[rpc(name = "get_budget")]
+    fn get_budget(
+        &self,
+        group_id: GroupId,
+        at: Option<BlockHash>,
+    ) -> Result<BudgetResponse<BlockNumber, MemberCount>>;
+
+    #[rpc(name = "get_invitations")]
+    fn get_invitations(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<InvitationResponse<GroupId, AccountId, MemberCount>>>;
 }
 
 #[derive(Serialize, Deserialize)]
@@ -186,6 +207,109 @@ impl<AccountId, MemberCount> From<Votes<AccountId, MemberCount>>
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ScheduledExecutionResponse<ProposalId, AccountId, BlockNumber, MemberCount> {
+    pub proposal_id: ProposalId,
+    pub when: BlockNumber,
+    pub yes_votes: MemberCount,
+    pub no_votes: MemberCount,
+    pub vetoed_by: Option<AccountId>,
+}
+impl<ProposalId, AccountId, BlockNumber, MemberCount>
+    From<(
+        ProposalId,
+        ScheduledExecution<AccountId, BlockNumber, MemberCount>,
+    )> for ScheduledExecutionResponse<ProposalId, AccountId, BlockNumber, MemberCount>
+{
+    fn from(
+        (proposal_id, scheduled): (
+            ProposalId,
+            ScheduledExecution<AccountId, BlockNumber, MemberCount>,
+        ),
+    ) -> Self {
+        ScheduledExecutionResponse {
+            proposal_id,
+            when: scheduled.when,
+            yes_votes: scheduled.yes_votes,
+            no_votes: scheduled.no_votes,
+            vetoed_by: scheduled.vetoed_by,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BudgetResponse<BlockNumber, MemberCount> {
+    pub period: BlockNumber,
+    //u64 instead of Balance due to bug in serde https://github.com/paritytech/substrate/issues/4641
+    pub period_cap: u64,
+    pub small_spend: u64,
+    pub tiers: Vec<(u64, MemberCount)>,
+    pub period_start: BlockNumber,
+    pub spent: u64,
+}
+impl<Balance, BlockNumber, MemberCount>
+    From<(
+        Budget<Balance, BlockNumber, MemberCount>,
+        BudgetUsage<Balance, BlockNumber>,
+    )> for BudgetResponse<BlockNumber, MemberCount>
+where
+    Balance: AtLeast32BitUnsigned,
+{
+    fn from(
+        (budget, usage): (
+            Budget<Balance, BlockNumber, MemberCount>,
+            BudgetUsage<Balance, BlockNumber>,
+        ),
+    ) -> Self {
+        BudgetResponse {
+            period: budget.period,
+            period_cap: budget.period_cap.unique_saturated_into(),
+            small_spend: budget.small_spend.unique_saturated_into(),
+            tiers: budget
+                .tiers
+                .into_iter()
+                .map(|(amount, threshold)| (amount.unique_saturated_into(), threshold))
+                .collect(),
+            period_start: usage.period_start,
+            spent: usage.spent.unique_saturated_into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InvitationResponse<GroupId, AccountId, MemberCount> {
+    pub group_id: GroupId,
+    pub name: String,
+    pub anonymous_account: AccountId,
+    pub parent: Option<GroupId>,
+    pub weight: MemberCount,
+}
+impl<GroupId, AccountId, MemberCount, BoundedString>
+    From<(
+        GroupId,
+        Group<GroupId, AccountId, MemberCount, BoundedString>,
+        MemberCount,
+    )> for InvitationResponse<GroupId, AccountId, MemberCount>
+where
+    BoundedString: Into<Vec<u8>>,
+{
+    fn from(
+        (group_id, group, weight): (
+            GroupId,
+            Group<GroupId, AccountId, MemberCount, BoundedString>,
+            MemberCount,
+        ),
+    ) -> Self {
+        InvitationResponse {
+            group_id,
+            name: String::from_utf8_lossy(&group.name.into()).to_string(),
+            anonymous_account: group.anonymous_account,
+            parent: group.parent,
+            weight,
+        }
+    }
+}
+
 pub struct Groups<C, M> {
     client: Arc<C>,
     _marker: std::marker::PhantomData<M>,
@@ -220,8 +344,27 @@ macro_rules! not_found_error {
     }};
 }
 
-impl<C, Block, AccountId, GroupId, MemberCount, ProposalId, Hash, BoundedString, Balance>
-    GroupsApi<<Block as BlockT>::Hash, AccountId, GroupId, MemberCount, ProposalId, Hash>
+impl<
+        C,
+        Block,
+        AccountId,
+        GroupId,
+        MemberCount,
+        ProposalId,
+        Hash,
+        BoundedString,
+        Balance,
+        BlockNumber,
+    >
+    GroupsApi<
+        <Block as BlockT>::Hash,
+        AccountId,
+        GroupId,
+        MemberCount,
+        ProposalId,
+        Hash,
+        BlockNumber,
+    >
     for Groups<
         C,
         (
@@ -233,6 +376,7 @@ impl<C, Block, AccountId, GroupId, MemberCount, ProposalId, Hash, BoundedString,
             Hash,
             BoundedString,
             Balance,
+            BlockNumber,
         ),
     >
 where
@@ -249,6 +393,7 @@ where
         Hash,
         BoundedString,
         Balance,
+        BlockNumber,
     >,
     GroupId: Codec + Copy + Send + Sync + 'static,
     MemberCount: Codec + Copy + Send + Sync + 'static,
@@ -257,6 +402,7 @@ where
     Hash: Codec + Clone + Send + Sync + 'static + AsRef<[u8]>,
     BoundedString: Codec + Clone + Send + Sync + 'static + Into<Vec<u8>>,
     Balance: Codec + Copy + Send + Sync + AtLeast32BitUnsigned + 'static,
+    BlockNumber: Codec + Copy + Send + Sync + 'static,
 {
     fn member_of(
         &self,
@@ -418,4 +564,52 @@ where
             })
             .collect())
     }
+
+    fn get_scheduled_proposals(
+        &self,
+        group_id: GroupId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ScheduledExecutionResponse<ProposalId, AccountId, BlockNumber, MemberCount>>>
+    {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let scheduled = api
+            .get_scheduled_proposals(&at, group_id)
+            .map_err(convert_error!())?;
+
+        Ok(scheduled
+            .into_iter()
+            .map(|(proposal_id, scheduled)| (proposal_id, scheduled).into())
+            .collect())
+    }
+
+    fn get_budget(
+        &self,
+        group_id: GroupId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<BudgetResponse<BlockNumber, MemberCount>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let (budget, usage) = api
+            .get_budget(&at, group_id)
+            .map_err(convert_error!())?
+            .ok_or(not_found_error!())?;
+        Ok((budget, usage).into())
+    }
+
+    fn get_invitations(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<InvitationResponse<GroupId, AccountId, MemberCount>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let invitations = api
+            .get_invitations(&at, account_id)
+            .map_err(convert_error!())?;
+        Ok(invitations.into_iter().map(|i| i.into()).collect())
+    }
 }
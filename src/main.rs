@@ -9,12 +9,18 @@ mod chain_spec;
 #[cfg_attr(feature = "grandpa_aura", path = "service_grandpa_aura.rs")]
 mod service;
 mod asset_registry_rpc;
+mod audit_report;
 mod audits_rpc;
 mod cli;
 mod command;
+mod epcis;
+mod evidence_rpc;
+mod evidence_store;
 mod groups_rpc;
 mod identity_rpc;
+mod notes_rpc;
 mod provenance_rpc;
+mod public_trace;
 #[cfg_attr(feature = "grandpa_babe", path = "rpc_grandpa_babe.rs")]
 #[cfg_attr(feature = "grandpa_aura", path = "rpc_grandpa_aura.rs")]
 mod rpc;
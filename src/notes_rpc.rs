@@ -0,0 +1,359 @@
+//! Helpers for the key envelopes of confidential audit notes.
+//!
+//! A note is encrypted client-side with a 32 byte symmetric key. The key is shared with each
+//! recipient in an envelope: it is encrypted with ChaCha20-Poly1305 under the blake2-256 hash of
+//! the X25519 shared secret of a fresh ephemeral key and the recipient's registered key, followed
+//! by both public keys. The nonce is zero, which is safe as each envelope key is used only once.
+//!
+//! The X25519 keys of a node are kept in its keystore under the `note` key type, so they can also
+//! be inserted with `author_insertKey` with the hex encoded secret as the suri.
+
+use audits_runtime_api::AuditsApi as AuditsRuntimeApi;
+use chacha20poly1305::{
+    aead::{generic_array::GenericArray, Aead, NewAead},
+    ChaCha20Poly1305,
+};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_primitives::KeyEnvelope;
+use rand::rngs::OsRng;
+use runtime::{primitives, BoundedStringUrl};
+use sc_rpc_api::DenyUnsafe;
+use sc_service::{config::KeystoreConfig, Configuration};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::KeyTypeId, hashing::blake2_256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use crate::audits_rpc::KeyEnvelopeResponse;
+
+type BlockHash = <primitives::Block as BlockT>::Hash;
+
+/// Key type of the X25519 keys of confidential notes in the keystore.
+pub const NOTE_KEY_TYPE: KeyTypeId = KeyTypeId(*b"note");
+
+const NONCE: [u8; 12] = [0u8; 12];
+
+#[rpc]
+pub trait NotesApi {
+    /// Generate an X25519 key in this node's keystore, returning the public key to register with `register_note_key`.
+    #[rpc(name = "generate_note_key")]
+    fn generate_note_key(&self) -> Result<[u8; 32]>;
+
+    /// Seal the symmetric key of a confidential note to the key each recipient registered,
+    /// returning the envelopes for `create_confidential_note` or `grant_note_access`.
+    #[rpc(name = "build_note_envelopes")]
+    fn build_note_envelopes(
+        &self,
+        note_key: [u8; 32],
+        recipients: Vec<primitives::AccountId>,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<KeyEnvelopeResponse<primitives::AccountId>>>;
+
+    /// Open the envelope of a recipient of a confidential note with the key in this node's keystore,
+    /// returning the symmetric key of the note.
+    #[rpc(name = "open_note_envelope")]
+    fn open_note_envelope(
+        &self,
+        audit_id: primitives::AuditId,
+        ciphertext_hash: [u8; 32],
+        recipient: primitives::AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<[u8; 32]>;
+}
+
+/// The X25519 keys of confidential notes held in the keystore of this node.
+#[derive(Clone)]
+pub struct NoteKeystore {
+    path: Option<PathBuf>,
+}
+
+impl NoteKeystore {
+    /// The keystore of `config`. Keys can only be kept in a keystore on disk.
+    /// The keystore password is not applied: secrets are stored as plain hex, protected only by the permissions of their files.
+    pub fn open(config: &Configuration) -> Self {
+        let path = match &config.keystore {
+            KeystoreConfig::Path { path, .. } => Some(path.clone()),
+            _ => None,
+        };
+        NoteKeystore { path }
+    }
+
+    /// Generate and store a key, returning its public key.
+    pub fn generate(&self) -> io::Result<[u8; 32]> {
+        let secret = StaticSecret::new(OsRng);
+        let public = PublicKey::from(&secret).to_bytes();
+        let suri = format!("0x{}", hex::encode(secret.to_bytes()));
+        let path = self.key_file_path(&public)?;
+        // the file is only readable by the node from the moment it exists
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(target_family = "unix")]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&path)?;
+        file.write_all(&serde_json::to_vec(&suri)?)?;
+        Ok(public)
+    }
+
+    /// The secret of the key with `public`, if held.
+    pub fn secret(&self, public: &[u8; 32]) -> io::Result<Option<StaticSecret>> {
+        let content = match fs::read(self.key_file_path(public)?) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let suri: String = serde_json::from_slice(&content)?;
+        let mut secret = [0u8; 32];
+        hex::decode_to_slice(suri.trim_start_matches("0x"), &mut secret)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Some(StaticSecret::from(secret)))
+    }
+
+    /// Named like the keys the keystore itself stores.
+    fn key_file_path(&self, public: &[u8; 32]) -> io::Result<PathBuf> {
+        let path = self.path.as_ref().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "The keystore is not kept on disk")
+        })?;
+        Ok(path.join(format!(
+            "{}{}",
+            hex::encode(NOTE_KEY_TYPE.0),
+            hex::encode(public)
+        )))
+    }
+}
+
+fn envelope_cipher(
+    shared_secret: &[u8; 32],
+    ephemeral_key: &[u8; 32],
+    recipient_key: &[u8; 32],
+) -> ChaCha20Poly1305 {
+    let mut material = Vec::with_capacity(96);
+    material.extend_from_slice(shared_secret);
+    material.extend_from_slice(ephemeral_key);
+    material.extend_from_slice(recipient_key);
+    ChaCha20Poly1305::new(GenericArray::from_slice(&blake2_256(&material)))
+}
+
+/// Encrypt `note_key` to `recipient_key`.
+pub fn seal(note_key: &[u8; 32], recipient_key: [u8; 32]) -> KeyEnvelope {
+    let ephemeral = StaticSecret::new(OsRng);
+    let ephemeral_key = PublicKey::from(&ephemeral).to_bytes();
+    let shared_secret = ephemeral.diffie_hellman(&PublicKey::from(recipient_key));
+    let ciphertext = envelope_cipher(shared_secret.as_bytes(), &ephemeral_key, &recipient_key)
+        .encrypt(GenericArray::from_slice(&NONCE), &note_key[..])
+        .expect("encrypting 32 bytes cannot exceed the ChaCha20-Poly1305 message limit");
+    let mut encrypted_key = [0u8; 48];
+    encrypted_key.copy_from_slice(&ciphertext);
+    KeyEnvelope {
+        recipient_key,
+        ephemeral_key,
+        encrypted_key,
+    }
+}
+
+/// Decrypt the note key in `envelope` with the secret of its recipient key. `None` if the envelope was not sealed to it or was tampered with.
+pub fn open(secret: &StaticSecret, envelope: &KeyEnvelope) -> Option<[u8; 32]> {
+    let shared_secret = secret.diffie_hellman(&PublicKey::from(envelope.ephemeral_key));
+    let plaintext = envelope_cipher(
+        shared_secret.as_bytes(),
+        &envelope.ephemeral_key,
+        &envelope.recipient_key,
+    )
+    .decrypt(
+        GenericArray::from_slice(&NONCE),
+        &envelope.encrypted_key[..],
+    )
+    .ok()?;
+    let mut note_key = [0u8; 32];
+    note_key.copy_from_slice(&plaintext);
+    Some(note_key)
+}
+
+pub struct Notes<C> {
+    client: Arc<C>,
+    keystore: NoteKeystore,
+    deny_unsafe: DenyUnsafe,
+}
+
+impl<C> Notes<C> {
+    pub fn new(client: Arc<C>, keystore: NoteKeystore, deny_unsafe: DenyUnsafe) -> Self {
+        Self {
+            client,
+            keystore,
+            deny_unsafe,
+        }
+    }
+}
+
+macro_rules! convert_error {
+    () => {{
+        |e| RpcError {
+            code: ErrorCode::ServerError(1),
+            message: "Error in Notes API".into(),
+            data: Some(format!("{:?}", e).into()),
+        }
+    }};
+}
+
+macro_rules! not_found_error {
+    ($id:expr) => {{
+        RpcError {
+            code: ErrorCode::ServerError(404),
+            message: "Entity not found".into(),
+            data: Some(format!("{}", $id).into()),
+        }
+    }};
+}
+
+impl<C> NotesApi for Notes<C>
+where
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<primitives::Block>,
+    C: HeaderBackend<primitives::Block>,
+    C::Api: AuditsRuntimeApi<
+        primitives::Block,
+        primitives::AccountId,
+        primitives::ProposalId,
+        primitives::AuditId,
+        primitives::ControlPointId,
+        primitives::EvidenceId,
+        primitives::ObservationId,
+        primitives::FrameworkId,
+        primitives::RegistryId,
+        primitives::AssetId,
+        primitives::RegistryId,
+        primitives::DefinitionId,
+        primitives::ProcessId,
+        primitives::BlockNumber,
+        primitives::ClaimId,
+        primitives::Moment,
+        primitives::Balance,
+        primitives::AuditScheduleId,
+        primitives::BoundedStringName,
+        BoundedStringUrl,
+    >,
+{
+    fn generate_note_key(&self) -> Result<[u8; 32]> {
+        self.deny_unsafe.check_if_safe()?;
+
+        self.keystore.generate().map_err(convert_error!())
+    }
+
+    fn build_note_envelopes(
+        &self,
+        note_key: [u8; 32],
+        recipients: Vec<primitives::AccountId>,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<KeyEnvelopeResponse<primitives::AccountId>>> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        recipients
+            .into_iter()
+            .map(|recipient| {
+                let recipient_key = api
+                    .get_note_key(&at, recipient.clone())
+                    .map_err(convert_error!())?
+                    .ok_or_else(|| not_found_error!(format!("note key of {}", recipient)))?;
+                Ok((recipient, seal(&note_key, recipient_key)).into())
+            })
+            .collect()
+    }
+
+    fn open_note_envelope(
+        &self,
+        audit_id: primitives::AuditId,
+        ciphertext_hash: [u8; 32],
+        recipient: primitives::AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<[u8; 32]> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let envelope = api
+            .get_note_envelopes(&at, audit_id, ciphertext_hash)
+            .map_err(convert_error!())?
+            .into_iter()
+            .find(|(account, _)| *account == recipient)
+            .map(|(_, envelope)| envelope)
+            .ok_or_else(|| not_found_error!(format!("envelope of {}", recipient)))?;
+        let secret = self
+            .keystore
+            .secret(&envelope.recipient_key)
+            .map_err(convert_error!())?
+            .ok_or_else(|| {
+                not_found_error!(format!(
+                    "note key 0x{}",
+                    hex::encode(envelope.recipient_key)
+                ))
+            })?;
+
+        open(&secret, &envelope).ok_or_else(|| RpcError {
+            code: ErrorCode::ServerError(2),
+            message: "Envelope could not be opened".into(),
+            data: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient() -> (StaticSecret, [u8; 32]) {
+        let secret = StaticSecret::new(OsRng);
+        let public = PublicKey::from(&secret).to_bytes();
+        (secret, public)
+    }
+
+    #[test]
+    fn seal_and_open_should_work() {
+        let (secret, recipient_key) = recipient();
+        let note_key = [42u8; 32];
+
+        let envelope = seal(&note_key, recipient_key);
+        assert_eq!(envelope.recipient_key, recipient_key);
+        assert_eq!(open(&secret, &envelope), Some(note_key));
+    }
+
+    #[test]
+    fn open_with_wrong_key_should_fail() {
+        let (_, recipient_key) = recipient();
+        let (other_secret, _) = recipient();
+
+        let envelope = seal(&[42u8; 32], recipient_key);
+        assert_eq!(open(&other_secret, &envelope), None);
+    }
+
+    #[test]
+    fn open_tampered_envelope_should_fail() {
+        let (secret, recipient_key) = recipient();
+        let envelope = seal(&[42u8; 32], recipient_key);
+
+        let mut tampered = envelope.clone();
+        tampered.encrypted_key[0] ^= 1;
+        assert_eq!(open(&secret, &tampered), None);
+
+        //both public keys are bound to the envelope
+        let mut tampered = envelope.clone();
+        tampered.ephemeral_key = recipient().1;
+        assert_eq!(open(&secret, &tampered), None);
+        let mut tampered = envelope;
+        tampered.recipient_key[0] ^= 1;
+        assert_eq!(open(&secret, &tampered), None);
+    }
+}
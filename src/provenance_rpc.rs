@@ -1,16 +1,23 @@
-use crate::identity_rpc::FactResponse;
+use crate::epcis::{EpcisFilter, EpcisMapping};
+use crate::identity_rpc::{Did, FactResponse};
 use codec::Codec;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
 use pallet_primitives::{
-    Definition, DefinitionStep, Process, ProcessStatus, ProcessStep, Registry,
+    AttributeBound, AttributeSchema, Definition, DefinitionStep, DefinitionVisibility, FactType,
+    JoinRule, Process, ProcessLink, ProcessLinkKind, ProcessStatus, ProcessStep,
+    ProcessStepAmendment, ProcessStepRejection, ProcessStepStatus, Registry,
 };
 use provenance_runtime_api::ProvenanceApi as ProvenanceRuntimeApi;
+use sc_rpc_api::DenyUnsafe;
 use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
-use std::sync::Arc;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, NumberFor},
+};
+use std::{fmt::Display, sync::Arc};
 
 #[rpc]
 pub trait ProvenanceApi<
@@ -22,6 +29,10 @@ pub trait ProvenanceApi<
     ProposalId,
     MemberCount,
     DefinitionStepIndex,
+    AssetRegistryId,
+    AssetId,
+    BlockNumber,
+    Moment,
 >
 {
     #[rpc(name = "get_definition_registries")]
@@ -52,6 +63,7 @@ pub trait ProvenanceApi<
                 DefinitionId,
                 MemberCount,
                 DefinitionStepIndex,
+                BlockNumber,
             >,
         >,
     >;
@@ -63,7 +75,14 @@ pub trait ProvenanceApi<
         definition_id: DefinitionId,
         at: Option<BlockHash>,
     ) -> Result<
-        DefinitionResponse<AccountId, RegistryId, DefinitionId, MemberCount, DefinitionStepIndex>,
+        DefinitionResponse<
+            AccountId,
+            RegistryId,
+            DefinitionId,
+            MemberCount,
+            DefinitionStepIndex,
+            BlockNumber,
+        >,
     >;
 
     #[rpc(name = "get_definition_step")]
@@ -73,7 +92,26 @@ pub trait ProvenanceApi<
         definition_id: DefinitionId,
         step_index: DefinitionStepIndex,
         at: Option<BlockHash>,
-    ) -> Result<DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex>>;
+    ) -> Result<DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex, BlockNumber>>;
+
+    #[rpc(name = "get_definition_versions")]
+    fn get_definition_versions(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            DefinitionResponse<
+                AccountId,
+                RegistryId,
+                DefinitionId,
+                MemberCount,
+                DefinitionStepIndex,
+                BlockNumber,
+            >,
+        >,
+    >;
 
     #[rpc(name = "get_available_definitions")]
     fn get_available_definitions(
@@ -88,6 +126,7 @@ pub trait ProvenanceApi<
                 DefinitionId,
                 MemberCount,
                 DefinitionStepIndex,
+                BlockNumber,
             >,
         >,
     >;
@@ -98,7 +137,99 @@ pub trait ProvenanceApi<
         registry_id: RegistryId,
         definition_id: DefinitionId,
         at: Option<BlockHash>,
-    ) -> Result<Vec<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>>;
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_processes_by_status")]
+    fn get_processes_by_status(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        status: String,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_processes_all_versions")]
+    fn get_processes_all_versions(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_processes_for_asset")]
+    fn get_processes_for_asset(
+        &self,
+        asset_registry_id: AssetRegistryId,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_processes_for_did")]
+    fn get_processes_for_did(
+        &self,
+        did: Did,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
 
     #[rpc(name = "get_process")]
     fn get_process(
@@ -107,7 +238,17 @@ pub trait ProvenanceApi<
         definition_id: DefinitionId,
         process_id: ProcessId,
         at: Option<BlockHash>,
-    ) -> Result<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>;
+    ) -> Result<
+        ProcessResponse<
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            ProposalId,
+            DefinitionStepIndex,
+            AssetRegistryId,
+            AssetId,
+        >,
+    >;
 
     #[rpc(name = "get_processes_for_attestor_by_status")]
     fn get_processes_for_attestor_by_status(
@@ -115,14 +256,57 @@ pub trait ProvenanceApi<
         account_id: AccountId,
         status: String,
         at: Option<BlockHash>,
-    ) -> Result<Vec<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>>;
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
 
     #[rpc(name = "get_processes_for_attestor_pending")]
     fn get_processes_for_attestor_pending(
         &self,
         account_id: AccountId,
         at: Option<BlockHash>,
-    ) -> Result<Vec<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>>;
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
+
+    #[rpc(name = "get_overdue_processes")]
+    fn get_overdue_processes(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    >;
 
     #[rpc(name = "get_process_step")]
     fn get_process_step(
@@ -132,7 +316,39 @@ pub trait ProvenanceApi<
         process_id: ProcessId,
         definition_step_index: DefinitionStepIndex,
         at: Option<BlockHash>,
-    ) -> Result<ProcessStepResponse<ProposalId>>;
+    ) -> Result<ProcessStepHistoryResponse<ProposalId, BlockNumber, Moment>>;
+
+    #[rpc(name = "get_process_step_rejections")]
+    fn get_process_step_rejections(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ProcessStepRejectionResponse<ProposalId, DefinitionStepIndex>>>;
+
+    #[rpc(name = "get_process_step_evidence")]
+    fn get_process_step_evidence(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ProcessStepEvidenceResponse<DefinitionStepIndex, BlockNumber, Moment, BlockHash>>>;
+
+    /// Export the attested steps of processes in a registry as a GS1 EPCIS 2.0 document.
+    /// `from` and `to` limit the events to those attested in a time range (unix milliseconds).
+    /// Unsafe, as a registry may hold many processes; use the `export-epcis` command for public exports.
+    #[rpc(name = "export_epcis")]
+    fn export_epcis(
+        &self,
+        registry_id: RegistryId,
+        definition_id: Option<DefinitionId>,
+        from: Option<u64>,
+        to: Option<u64>,
+        mapping: Option<EpcisMapping>,
+        at: Option<BlockHash>,
+    ) -> Result<serde_json::Value>;
 
     #[rpc(name = "can_view_definition")]
     fn can_view_definition(
@@ -152,6 +368,54 @@ pub trait ProvenanceApi<
         definition_step_index: DefinitionStepIndex,
         at: Option<BlockHash>,
     ) -> Result<bool>;
+
+    #[rpc(name = "trace_back")]
+    fn trace_back(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        depth: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ProcessLinkResponse<RegistryId, DefinitionId, ProcessId>>>;
+
+    #[rpc(name = "trace_forward")]
+    fn trace_forward(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        depth: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ProcessLinkResponse<RegistryId, DefinitionId, ProcessId>>>;
+
+    #[rpc(name = "get_definition_visibility")]
+    fn get_definition_visibility(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        at: Option<BlockHash>,
+    ) -> Result<DefinitionVisibilityResponse>;
+
+    /// The trace of a process as shown to consumers, if its definition is public.
+    #[rpc(name = "get_public_trace")]
+    fn get_public_trace(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<BlockHash>,
+    ) -> Result<
+        PublicTraceResponse<
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            DefinitionStepIndex,
+            BlockNumber,
+            Moment,
+            BlockHash,
+        >,
+    >;
 }
 
 #[derive(Serialize, Deserialize)]
@@ -174,17 +438,32 @@ where
 }
 
 #[derive(Serialize, Deserialize)]
-pub struct DefinitionResponse<AccountId, RegistryId, DefinitionId, MemberCount, DefinitionStepIndex>
-{
+pub struct DefinitionResponse<
+    AccountId,
+    RegistryId,
+    DefinitionId,
+    MemberCount,
+    DefinitionStepIndex,
+    BlockNumber,
+> {
     pub registry_id: RegistryId,
     pub definition_id: DefinitionId,
     pub name: String,
     pub status: String,
-    pub definition_steps:
-        Option<Vec<DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex>>>,
+    pub definition_steps: Option<
+        Vec<DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex, BlockNumber>>,
+    >,
 }
 
-impl<AccountId, RegistryId, DefinitionId, MemberCount, DefinitionStepIndex, BoundedStringName>
+impl<
+        AccountId,
+        RegistryId,
+        DefinitionId,
+        MemberCount,
+        DefinitionStepIndex,
+        BlockNumber,
+        BoundedStringName,
+    >
     From<(
         RegistryId,
         DefinitionId,
@@ -192,10 +471,24 @@ impl<AccountId, RegistryId, DefinitionId, MemberCount, DefinitionStepIndex, Boun
         Option<
             Vec<(
                 DefinitionStepIndex,
-                DefinitionStep<AccountId, MemberCount, BoundedStringName>,
+                DefinitionStep<
+                    AccountId,
+                    MemberCount,
+                    BoundedStringName,
+                    DefinitionStepIndex,
+                    BlockNumber,
+                >,
             )>,
         >,
-    )> for DefinitionResponse<AccountId, RegistryId, DefinitionId, MemberCount, DefinitionStepIndex>
+    )>
+    for DefinitionResponse<
+        AccountId,
+        RegistryId,
+        DefinitionId,
+        MemberCount,
+        DefinitionStepIndex,
+        BlockNumber,
+    >
 where
     BoundedStringName: Into<Vec<u8>>,
 {
@@ -207,7 +500,13 @@ where
             Option<
                 Vec<(
                     DefinitionStepIndex,
-                    DefinitionStep<AccountId, MemberCount, BoundedStringName>,
+                    DefinitionStep<
+                        AccountId,
+                        MemberCount,
+                        BoundedStringName,
+                        DefinitionStepIndex,
+                        BlockNumber,
+                    >,
                 )>,
             >,
         ),
@@ -219,6 +518,7 @@ where
             status: match definition.status {
                 pallet_primitives::DefinitionStatus::Active => "Active".to_string(),
                 pallet_primitives::DefinitionStatus::Inactive => "Inactive".to_string(),
+                pallet_primitives::DefinitionStatus::Draft => "Draft".to_string(),
             },
             definition_steps: definition_steps.map(|definition_steps| {
                 definition_steps
@@ -233,25 +533,35 @@ where
 }
 
 #[derive(Serialize, Deserialize)]
-pub struct DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex> {
+pub struct DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex, BlockNumber> {
     pub definition_step_index: DefinitionStepIndex,
     pub name: String,
     pub attestor: AccountId,
     pub threshold: MemberCount,
+    pub predecessors: Vec<DefinitionStepIndex>,
+    pub join: String,
+    pub attributes: Vec<AttributeSchemaResponse>,
+    pub max_duration: Option<BlockNumber>,
 }
 
-impl<AccountId, MemberCount, DefinitionStepIndex, BoundedStringName>
+impl<AccountId, MemberCount, DefinitionStepIndex, BlockNumber, BoundedStringName>
     From<(
         DefinitionStepIndex,
-        DefinitionStep<AccountId, MemberCount, BoundedStringName>,
-    )> for DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex>
+        DefinitionStep<AccountId, MemberCount, BoundedStringName, DefinitionStepIndex, BlockNumber>,
+    )> for DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex, BlockNumber>
 where
     BoundedStringName: Into<Vec<u8>>,
 {
     fn from(
         (definition_step_index, definition_step): (
             DefinitionStepIndex,
-            DefinitionStep<AccountId, MemberCount, BoundedStringName>,
+            DefinitionStep<
+                AccountId,
+                MemberCount,
+                BoundedStringName,
+                DefinitionStepIndex,
+                BlockNumber,
+            >,
         ),
     ) -> Self {
         DefinitionStepResponse {
@@ -259,26 +569,117 @@ where
             name: String::from_utf8_lossy(&definition_step.name.into()).to_string(),
             attestor: definition_step.attestor,
             threshold: definition_step.threshold,
+            predecessors: definition_step.predecessors,
+            join: match definition_step.join {
+                JoinRule::All => "All".to_string(),
+                JoinRule::Any => "Any".to_string(),
+            },
+            attributes: definition_step
+                .attributes
+                .into_iter()
+                .map(|attribute| attribute.into())
+                .collect(),
+            max_duration: definition_step.max_duration,
         }
     }
 }
 
 #[derive(Serialize, Deserialize)]
-pub struct ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId> {
+pub struct AttributeSchemaResponse {
+    pub name: String,
+    pub fact_type: String,
+    pub required: bool,
+    pub unit: Option<String>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+}
+
+impl<BoundedStringName> From<AttributeSchema<BoundedStringName>> for AttributeSchemaResponse
+where
+    BoundedStringName: Into<Vec<u8>>,
+{
+    fn from(attribute: AttributeSchema<BoundedStringName>) -> Self {
+        AttributeSchemaResponse {
+            name: String::from_utf8_lossy(&attribute.name.into()).to_string(),
+            fact_type: match attribute.fact_type {
+                FactType::Bool => "Bool",
+                FactType::Text => "Text",
+                FactType::Attachment => "Attachment",
+                FactType::Location => "Location",
+                FactType::Did => "Did",
+                FactType::Float => "Float",
+                FactType::U8 => "U8",
+                FactType::U16 => "U16",
+                FactType::U32 => "U32",
+                FactType::U128 => "U128",
+                FactType::Date => "Date",
+                FactType::Iso8601 => "Iso8601",
+            }
+            .to_string(),
+            required: attribute.required,
+            unit: attribute
+                .unit
+                .map(|unit| String::from_utf8_lossy(&unit.into()).to_string()),
+            min: attribute.min.map(bound_to_string),
+            max: attribute.max.map(bound_to_string),
+        }
+    }
+}
+
+/// Bounds are returned as strings, as u128 integers do not fit a JSON number
+fn bound_to_string(bound: AttributeBound) -> String {
+    match bound {
+        AttributeBound::Float(bytes) => f64::from_le_bytes(bytes).to_string(),
+        AttributeBound::Integer(value) => value.to_string(),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessResponse<
+    RegistryId,
+    DefinitionId,
+    ProcessId,
+    ProposalId,
+    DefinitionStepIndex,
+    AssetRegistryId,
+    AssetId,
+> {
     pub registry_id: RegistryId,
     pub definition_id: DefinitionId,
     pub process_id: ProcessId,
     pub name: String,
-    pub process_steps: Option<Vec<ProcessStepResponse<ProposalId>>>,
+    pub process_steps: Option<Vec<ProcessStepStatusResponse<ProposalId, DefinitionStepIndex>>>,
     pub status: String,
+    pub asset_registry_id: Option<AssetRegistryId>,
+    pub asset_id: Option<AssetId>,
+    pub participants: Vec<String>,
+    pub location: Option<FactResponse>,
 }
-impl<RegistryId, DefinitionId, ProcessId, ProposalId, BoundedStringName>
+impl<
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        ProposalId,
+        DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BoundedStringName,
+    >
     From<(
         RegistryId,
         DefinitionId,
         ProcessId,
-        Process<BoundedStringName>,
-    )> for ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>
+        Process<BoundedStringName, AssetRegistryId, AssetId>,
+    )>
+    for ProcessResponse<
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        ProposalId,
+        DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+    >
 where
     BoundedStringName: Into<Vec<u8>>,
 {
@@ -287,9 +688,13 @@ where
             RegistryId,
             DefinitionId,
             ProcessId,
-            Process<BoundedStringName>,
+            Process<BoundedStringName, AssetRegistryId, AssetId>,
         ),
     ) -> Self {
+        let (asset_registry_id, asset_id) = match process.asset {
+            Some((asset_registry_id, asset_id)) => (Some(asset_registry_id), Some(asset_id)),
+            None => (None, None),
+        };
         ProcessResponse {
             registry_id,
             definition_id,
@@ -299,18 +704,51 @@ where
             status: match process.status {
                 pallet_primitives::ProcessStatus::Completed => "Completed".to_string(),
                 pallet_primitives::ProcessStatus::InProgress => "InProgress".to_string(),
+                pallet_primitives::ProcessStatus::Rejected => "Rejected".to_string(),
+                pallet_primitives::ProcessStatus::OnHold => "OnHold".to_string(),
             },
+            asset_registry_id,
+            asset_id,
+            participants: process
+                .participants
+                .into_iter()
+                .map(|did| Did::from(did).to_string())
+                .collect(),
+            location: process.location.map(|location| location.into()),
         }
     }
 }
-impl<RegistryId, DefinitionId, ProcessId, ProposalId, BoundedStringName, BoundedStringFact>
+impl<
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        ProposalId,
+        DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BoundedStringName,
+        BoundedStringFact,
+    >
     From<(
         RegistryId,
         DefinitionId,
         ProcessId,
-        Process<BoundedStringName>,
-        Vec<ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>>,
-    )> for ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>
+        Process<BoundedStringName, AssetRegistryId, AssetId>,
+        Vec<(
+            DefinitionStepIndex,
+            ProcessStepStatus,
+            Option<ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>>,
+        )>,
+    )>
+    for ProcessResponse<
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        ProposalId,
+        DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+    >
 where
     BoundedStringName: Into<Vec<u8>>,
     BoundedStringFact: Into<Vec<u8>>,
@@ -320,10 +758,18 @@ where
             RegistryId,
             DefinitionId,
             ProcessId,
-            Process<BoundedStringName>,
-            Vec<ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>>,
+            Process<BoundedStringName, AssetRegistryId, AssetId>,
+            Vec<(
+                DefinitionStepIndex,
+                ProcessStepStatus,
+                Option<ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>>,
+            )>,
         ),
     ) -> Self {
+        let (asset_registry_id, asset_id) = match process.asset {
+            Some((asset_registry_id, asset_id)) => (Some(asset_registry_id), Some(asset_id)),
+            None => (None, None),
+        };
         ProcessResponse {
             registry_id,
             definition_id,
@@ -332,21 +778,51 @@ where
             process_steps: Some(
                 process_steps
                     .into_iter()
-                    .map(|process_step| process_step.into())
+                    .map(|(definition_step_index, status, process_step)| {
+                        ProcessStepStatusResponse {
+                            definition_step_index,
+                            status: match status {
+                                ProcessStepStatus::Pending => "Pending".to_string(),
+                                ProcessStepStatus::Eligible => "Eligible".to_string(),
+                                ProcessStepStatus::Attested => "Attested".to_string(),
+                                ProcessStepStatus::Skipped => "Skipped".to_string(),
+                                ProcessStepStatus::Rejected => "Rejected".to_string(),
+                                ProcessStepStatus::Overdue => "Overdue".to_string(),
+                            },
+                            process_step: process_step.map(|process_step| process_step.into()),
+                        }
+                    })
                     .collect(),
             ),
             status: match process.status {
                 pallet_primitives::ProcessStatus::Completed => "Completed".to_string(),
                 pallet_primitives::ProcessStatus::InProgress => "InProgress".to_string(),
+                pallet_primitives::ProcessStatus::Rejected => "Rejected".to_string(),
+                pallet_primitives::ProcessStatus::OnHold => "OnHold".to_string(),
             },
+            asset_registry_id,
+            asset_id,
+            participants: process
+                .participants
+                .into_iter()
+                .map(|did| Did::from(did).to_string())
+                .collect(),
+            location: process.location.map(|location| location.into()),
         }
     }
 }
 #[derive(Serialize, Deserialize)]
-pub struct ProcessStepResponse<ProposalId> {
-    pub proposal_id: Option<ProposalId>,
-    pub attributes: Vec<AttributeResponse>,
-}
+pub struct ProcessStepStatusResponse<ProposalId, DefinitionStepIndex> {
+    pub definition_step_index: DefinitionStepIndex,
+    pub status: String,
+    pub process_step: Option<ProcessStepResponse<ProposalId>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessStepResponse<ProposalId> {
+    pub proposal_id: Option<ProposalId>,
+    pub attributes: Vec<AttributeResponse>,
+}
 
 impl<ProposalId, BoundedStringName, BoundedStringFact>
     From<ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>>
@@ -370,21 +846,260 @@ where
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ProcessStepHistoryResponse<ProposalId, BlockNumber, Moment> {
+    /// The proposal and attributes of the latest amendment, or of the attestation if the step was not amended
+    pub proposal_id: Option<ProposalId>,
+    pub attributes: Vec<AttributeResponse>,
+    pub amendments: Vec<ProcessStepAmendmentResponse<ProposalId, BlockNumber, Moment>>,
+}
+
+impl<ProposalId, BlockNumber, Moment, BoundedStringName, BoundedStringFact>
+    From<(
+        ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>,
+        Vec<
+            ProcessStepAmendment<
+                ProposalId,
+                BlockNumber,
+                Moment,
+                BoundedStringName,
+                BoundedStringFact,
+            >,
+        >,
+    )> for ProcessStepHistoryResponse<ProposalId, BlockNumber, Moment>
+where
+    BoundedStringName: Into<Vec<u8>>,
+    BoundedStringFact: Into<Vec<u8>>,
+{
+    fn from(
+        (process_step, amendments): (
+            ProcessStep<ProposalId, BoundedStringName, BoundedStringFact>,
+            Vec<
+                ProcessStepAmendment<
+                    ProposalId,
+                    BlockNumber,
+                    Moment,
+                    BoundedStringName,
+                    BoundedStringFact,
+                >,
+            >,
+        ),
+    ) -> Self {
+        let process_step: ProcessStepResponse<ProposalId> = process_step.into();
+        ProcessStepHistoryResponse {
+            proposal_id: process_step.proposal_id,
+            attributes: process_step.attributes,
+            amendments: amendments
+                .into_iter()
+                .map(|amendment| ProcessStepAmendmentResponse {
+                    proposal_id: amendment.proposal_id,
+                    reason: String::from_utf8_lossy(&amendment.reason.into()).to_string(),
+                    attributes: amendment
+                        .attributes
+                        .into_iter()
+                        .map(|attribute| AttributeResponse {
+                            name: String::from_utf8_lossy(&attribute.name.into()).to_string(),
+                            fact: attribute.fact.into(),
+                        })
+                        .collect(),
+                    block_number: amendment.evidence.block_number,
+                    extrinsic_index: amendment.evidence.extrinsic_index,
+                    timestamp: amendment.evidence.timestamp,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessStepAmendmentResponse<ProposalId, BlockNumber, Moment> {
+    pub proposal_id: Option<ProposalId>,
+    pub reason: String,
+    pub attributes: Vec<AttributeResponse>,
+    pub block_number: BlockNumber,
+    pub extrinsic_index: u32,
+    pub timestamp: Moment,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessStepRejectionResponse<ProposalId, DefinitionStepIndex> {
+    pub definition_step_index: DefinitionStepIndex,
+    pub proposal_id: Option<ProposalId>,
+    pub reason: String,
+    pub attributes: Vec<AttributeResponse>,
+}
+
+impl<ProposalId, DefinitionStepIndex, BoundedStringName, BoundedStringFact>
+    From<(
+        DefinitionStepIndex,
+        ProcessStepRejection<ProposalId, BoundedStringName, BoundedStringFact>,
+    )> for ProcessStepRejectionResponse<ProposalId, DefinitionStepIndex>
+where
+    BoundedStringName: Into<Vec<u8>>,
+    BoundedStringFact: Into<Vec<u8>>,
+{
+    fn from(
+        (definition_step_index, rejection): (
+            DefinitionStepIndex,
+            ProcessStepRejection<ProposalId, BoundedStringName, BoundedStringFact>,
+        ),
+    ) -> Self {
+        ProcessStepRejectionResponse {
+            definition_step_index,
+            proposal_id: rejection.proposal_id,
+            reason: String::from_utf8_lossy(&rejection.reason.into()).to_string(),
+            attributes: rejection
+                .attributes
+                .into_iter()
+                .map(|attribute| AttributeResponse {
+                    name: String::from_utf8_lossy(&attribute.name.into()).to_string(),
+                    fact: attribute.fact.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessStepEvidenceResponse<DefinitionStepIndex, BlockNumber, Moment, BlockHash> {
+    pub definition_step_index: DefinitionStepIndex,
+    pub block_number: BlockNumber,
+    pub block_hash: Option<BlockHash>,
+    pub extrinsic_index: u32,
+    pub timestamp: Moment,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessLinkResponse<RegistryId, DefinitionId, ProcessId> {
+    /// How many links away from the traced process
+    pub depth: u32,
+    pub registry_id: RegistryId,
+    pub definition_id: DefinitionId,
+    pub process_id: ProcessId,
+    pub upstream_registry_id: RegistryId,
+    pub upstream_definition_id: DefinitionId,
+    pub upstream_process_id: ProcessId,
+    pub kind: String,
+    pub quantity: u128,
+    pub unit: String,
+}
+
+impl<RegistryId, DefinitionId, ProcessId, BoundedStringName>
+    From<(
+        u32,
+        (RegistryId, DefinitionId, ProcessId),
+        (RegistryId, DefinitionId, ProcessId),
+        ProcessLink<BoundedStringName>,
+    )> for ProcessLinkResponse<RegistryId, DefinitionId, ProcessId>
+where
+    BoundedStringName: Into<Vec<u8>>,
+{
+    fn from(
+        (
+            depth,
+            (registry_id, definition_id, process_id),
+            (upstream_registry_id, upstream_definition_id, upstream_process_id),
+            link,
+        ): (
+            u32,
+            (RegistryId, DefinitionId, ProcessId),
+            (RegistryId, DefinitionId, ProcessId),
+            ProcessLink<BoundedStringName>,
+        ),
+    ) -> Self {
+        ProcessLinkResponse {
+            depth,
+            registry_id,
+            definition_id,
+            process_id,
+            upstream_registry_id,
+            upstream_definition_id,
+            upstream_process_id,
+            kind: match link.kind {
+                ProcessLinkKind::Input => "Input".to_string(),
+                ProcessLinkKind::Split => "Split".to_string(),
+                ProcessLinkKind::Merge => "Merge".to_string(),
+            },
+            quantity: link.quantity,
+            unit: String::from_utf8_lossy(&link.unit.into()).to_string(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct AttributeResponse {
     pub name: String,
     pub fact: FactResponse,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct DefinitionVisibilityResponse {
+    pub public_attributes: Vec<String>,
+    pub show_location: bool,
+}
+
+impl<BoundedStringName> From<DefinitionVisibility<BoundedStringName>>
+    for DefinitionVisibilityResponse
+where
+    BoundedStringName: Into<Vec<u8>>,
+{
+    fn from(visibility: DefinitionVisibility<BoundedStringName>) -> Self {
+        DefinitionVisibilityResponse {
+            public_attributes: visibility
+                .public_attributes
+                .into_iter()
+                .map(|name| String::from_utf8_lossy(&name.into()).to_string())
+                .collect(),
+            show_location: visibility.show_location,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicTraceResponse<
+    RegistryId,
+    DefinitionId,
+    ProcessId,
+    DefinitionStepIndex,
+    BlockNumber,
+    Moment,
+    BlockHash,
+> {
+    pub registry_id: RegistryId,
+    pub definition_id: DefinitionId,
+    pub process_id: ProcessId,
+    pub name: String,
+    pub status: String,
+    pub location: Option<FactResponse>,
+    pub steps: Vec<PublicProcessStepResponse<DefinitionStepIndex, BlockNumber, Moment, BlockHash>>,
+    /// Whether later steps were left out to bound the size of the trace
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicProcessStepResponse<DefinitionStepIndex, BlockNumber, Moment, BlockHash> {
+    pub definition_step_index: DefinitionStepIndex,
+    pub name: String,
+    /// Name of the group that attested the step
+    pub attestor: Option<String>,
+    pub attributes: Vec<AttributeResponse>,
+    pub block_number: BlockNumber,
+    pub block_hash: Option<BlockHash>,
+    pub extrinsic_index: u32,
+    pub timestamp: Moment,
+}
+
 pub struct Provenance<C, M> {
     client: Arc<C>,
+    deny_unsafe: DenyUnsafe,
     _marker: std::marker::PhantomData<M>,
 }
 
 impl<C, M> Provenance<C, M> {
-    pub fn new(client: Arc<C>) -> Self {
+    pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
         Self {
             client,
+            deny_unsafe,
             _marker: Default::default(),
         }
     }
@@ -410,6 +1125,20 @@ macro_rules! not_found_error {
     }};
 }
 
+fn parse_process_status(status: &str) -> Result<ProcessStatus> {
+    match status {
+        "InProgress" => Ok(ProcessStatus::InProgress),
+        "Completed" => Ok(ProcessStatus::Completed),
+        "Rejected" => Ok(ProcessStatus::Rejected),
+        "OnHold" => Ok(ProcessStatus::OnHold),
+        _ => Err(RpcError {
+            code: ErrorCode::ServerError(1),
+            message: "Unknown status".into(),
+            data: Some("Unknown status".into()),
+        }),
+    }
+}
+
 impl<
         C,
         Block,
@@ -420,6 +1149,10 @@ impl<
         ProposalId,
         MemberCount,
         DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BlockNumber,
+        Moment,
         BoundedStringName,
         BoundedStringFact,
     >
@@ -432,6 +1165,10 @@ impl<
         ProposalId,
         MemberCount,
         DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BlockNumber,
+        Moment,
     >
     for Provenance<
         C,
@@ -444,6 +1181,10 @@ impl<
             ProposalId,
             MemberCount,
             DefinitionStepIndex,
+            AssetRegistryId,
+            AssetId,
+            BlockNumber,
+            Moment,
             BoundedStringName,
             BoundedStringFact,
         ),
@@ -462,16 +1203,24 @@ where
         ProposalId,
         MemberCount,
         DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BlockNumber,
+        Moment,
         BoundedStringName,
         BoundedStringFact,
     >,
     AccountId: Codec + Send + Sync + 'static,
-    RegistryId: Codec + Copy + Send + Sync + 'static,
-    DefinitionId: Codec + Copy + Send + Sync + 'static,
-    ProcessId: Codec + Copy + Send + Sync + 'static,
+    RegistryId: Codec + Copy + Send + Sync + 'static + Display,
+    DefinitionId: Codec + Copy + Send + Sync + 'static + Display,
+    ProcessId: Codec + Copy + Send + Sync + 'static + Display,
     ProposalId: Codec + Copy + Send + Sync + 'static,
     MemberCount: Codec + Copy + Send + Sync + 'static,
-    DefinitionStepIndex: Codec + Copy + Send + Sync + 'static,
+    DefinitionStepIndex: Codec + Copy + Send + Sync + 'static + PartialEq + Display,
+    AssetRegistryId: Codec + Copy + Send + Sync + 'static,
+    AssetId: Codec + Copy + Send + Sync + 'static,
+    BlockNumber: Codec + Copy + Send + Sync + 'static + Into<NumberFor<Block>>,
+    Moment: Codec + Copy + Send + Sync + 'static + Into<u64>,
     BoundedStringName: Codec + Clone + Send + Sync + 'static + Into<Vec<u8>>,
     BoundedStringFact: Codec + Clone + Send + Sync + 'static + Into<Vec<u8>>,
 {
@@ -523,6 +1272,7 @@ where
                 DefinitionId,
                 MemberCount,
                 DefinitionStepIndex,
+                BlockNumber,
             >,
         >,
     > {
@@ -547,7 +1297,14 @@ where
         definition_id: DefinitionId,
         at: Option<<Block as BlockT>::Hash>,
     ) -> Result<
-        DefinitionResponse<AccountId, RegistryId, DefinitionId, MemberCount, DefinitionStepIndex>,
+        DefinitionResponse<
+            AccountId,
+            RegistryId,
+            DefinitionId,
+            MemberCount,
+            DefinitionStepIndex,
+            BlockNumber,
+        >,
     > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
@@ -576,7 +1333,8 @@ where
         definition_id: DefinitionId,
         step_index: DefinitionStepIndex,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex>> {
+    ) -> Result<DefinitionStepResponse<AccountId, MemberCount, DefinitionStepIndex, BlockNumber>>
+    {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
@@ -588,6 +1346,38 @@ where
         Ok((step_index, definition_step).into())
     }
 
+    fn get_definition_versions(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            DefinitionResponse<
+                AccountId,
+                RegistryId,
+                DefinitionId,
+                MemberCount,
+                DefinitionStepIndex,
+                BlockNumber,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let definitions = api
+            .get_definition_versions(&at, registry_id, definition_id)
+            .map_err(convert_error!())?;
+
+        Ok(definitions
+            .into_iter()
+            .map(|(definition_id, definition)| {
+                (registry_id, definition_id, definition, None).into()
+            })
+            .collect())
+    }
+
     fn get_available_definitions(
         &self,
         account_id: AccountId,
@@ -600,6 +1390,7 @@ where
                 DefinitionId,
                 MemberCount,
                 DefinitionStepIndex,
+                BlockNumber,
             >,
         >,
     > {
@@ -623,7 +1414,19 @@ where
         registry_id: RegistryId,
         definition_id: DefinitionId,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<Vec<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>> {
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
@@ -637,13 +1440,154 @@ where
             .collect())
     }
 
+    fn get_processes_by_status(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        status: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let status = parse_process_status(&status)?;
+        let processes = api
+            .get_processes_by_status(&at, registry_id, definition_id, status)
+            .map_err(convert_error!())?;
+
+        Ok(processes
+            .into_iter()
+            .map(|(process_id, process)| (registry_id, definition_id, process_id, process).into())
+            .collect())
+    }
+
+    fn get_processes_all_versions(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let processes = api
+            .get_processes_all_versions(&at, registry_id, definition_id)
+            .map_err(convert_error!())?;
+
+        Ok(processes
+            .into_iter()
+            .map(|(definition_id, process_id, process)| {
+                (registry_id, definition_id, process_id, process).into()
+            })
+            .collect())
+    }
+
+    fn get_processes_for_asset(
+        &self,
+        asset_registry_id: AssetRegistryId,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let processes = api
+            .get_processes_for_asset(&at, asset_registry_id, asset_id)
+            .map_err(convert_error!())?;
+
+        Ok(processes
+            .into_iter()
+            .map(|(registry_id, definition_id, process_id, process)| {
+                (registry_id, definition_id, process_id, process).into()
+            })
+            .collect())
+    }
+
+    fn get_processes_for_did(
+        &self,
+        did: Did,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let processes = api
+            .get_processes_for_did(&at, did.into())
+            .map_err(convert_error!())?;
+
+        Ok(processes
+            .into_iter()
+            .map(|(registry_id, definition_id, process_id, process)| {
+                (registry_id, definition_id, process_id, process).into()
+            })
+            .collect())
+    }
+
     fn get_process(
         &self,
         registry_id: RegistryId,
         definition_id: DefinitionId,
         process_id: ProcessId,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>> {
+    ) -> Result<
+        ProcessResponse<
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            ProposalId,
+            DefinitionStepIndex,
+            AssetRegistryId,
+            AssetId,
+        >,
+    > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
@@ -671,21 +1615,23 @@ where
         account_id: AccountId,
         status: String,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<Vec<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>> {
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
-        let status = match status.as_str() {
-            "InProgress" => ProcessStatus::InProgress,
-            "Completed" => ProcessStatus::Completed,
-            _ => {
-                return Err(RpcError {
-                    code: ErrorCode::ServerError(1),
-                    message: "Unknown status".into(),
-                    data: Some("Unknown status".into()),
-                })
-            }
-        };
+        let status = parse_process_status(&status)?;
         let processes = api
             .get_processes_for_attestor_by_status(&at, account_id, status)
             .map_err(convert_error!())?;
@@ -702,7 +1648,19 @@ where
         &self,
         account_id: AccountId,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<Vec<ProcessResponse<RegistryId, DefinitionId, ProcessId, ProposalId>>> {
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
@@ -718,6 +1676,38 @@ where
             .collect())
     }
 
+    fn get_overdue_processes(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            ProcessResponse<
+                RegistryId,
+                DefinitionId,
+                ProcessId,
+                ProposalId,
+                DefinitionStepIndex,
+                AssetRegistryId,
+                AssetId,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let processes = api
+            .get_overdue_processes(&at, account_id)
+            .map_err(convert_error!())?;
+
+        Ok(processes
+            .into_iter()
+            .map(|(registry_id, definition_id, process_id, process)| {
+                (registry_id, definition_id, process_id, process).into()
+            })
+            .collect())
+    }
+
     fn get_process_step(
         &self,
         registry_id: RegistryId,
@@ -725,7 +1715,7 @@ where
         process_id: ProcessId,
         definition_step_index: DefinitionStepIndex,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> Result<ProcessStepResponse<ProposalId>> {
+    ) -> Result<ProcessStepHistoryResponse<ProposalId, BlockNumber, Moment>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
@@ -742,6 +1732,107 @@ where
 
         Ok(process_step.into())
     }
+
+    fn get_process_step_rejections(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ProcessStepRejectionResponse<ProposalId, DefinitionStepIndex>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let rejections = api
+            .get_process_step_rejections(&at, registry_id, definition_id, process_id)
+            .map_err(convert_error!())?;
+
+        Ok(rejections
+            .into_iter()
+            .map(|rejection| rejection.into())
+            .collect())
+    }
+
+    fn get_process_step_evidence(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        Vec<
+            ProcessStepEvidenceResponse<
+                DefinitionStepIndex,
+                BlockNumber,
+                Moment,
+                <Block as BlockT>::Hash,
+            >,
+        >,
+    > {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let evidence = api
+            .get_process_step_evidence(&at, registry_id, definition_id, process_id)
+            .map_err(convert_error!())?;
+
+        evidence
+            .into_iter()
+            .map(|(definition_step_index, evidence)| {
+                let block_hash = self
+                    .client
+                    .hash(evidence.block_number.into())
+                    .map_err(convert_error!())?;
+                Ok(ProcessStepEvidenceResponse {
+                    definition_step_index,
+                    block_number: evidence.block_number,
+                    block_hash,
+                    extrinsic_index: evidence.extrinsic_index,
+                    timestamp: evidence.timestamp,
+                })
+            })
+            .collect()
+    }
+
+    fn export_epcis(
+        &self,
+        registry_id: RegistryId,
+        definition_id: Option<DefinitionId>,
+        from: Option<u64>,
+        to: Option<u64>,
+        mapping: Option<EpcisMapping>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<serde_json::Value> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let filter = EpcisFilter {
+            registry_id,
+            definition_id,
+            from,
+            to,
+        };
+
+        crate::epcis::export::<
+            C,
+            Block,
+            AccountId,
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            ProposalId,
+            MemberCount,
+            DefinitionStepIndex,
+            AssetRegistryId,
+            AssetId,
+            BlockNumber,
+            Moment,
+            BoundedStringName,
+            BoundedStringFact,
+        >(&*self.client, &at, &filter, &mapping.unwrap_or_default())
+        .map_err(convert_error!())
+    }
     fn can_view_definition(
         &self,
         account_id: AccountId,
@@ -783,4 +1874,104 @@ where
 
         Ok(can_veiw)
     }
+
+    fn trace_back(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        depth: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ProcessLinkResponse<RegistryId, DefinitionId, ProcessId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let links = api
+            .trace_back(&at, registry_id, definition_id, process_id, depth)
+            .map_err(convert_error!())?;
+
+        Ok(links.into_iter().map(|link| link.into()).collect())
+    }
+
+    fn trace_forward(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        depth: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ProcessLinkResponse<RegistryId, DefinitionId, ProcessId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let links = api
+            .trace_forward(&at, registry_id, definition_id, process_id, depth)
+            .map_err(convert_error!())?;
+
+        Ok(links.into_iter().map(|link| link.into()).collect())
+    }
+
+    fn get_definition_visibility(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<DefinitionVisibilityResponse> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let visibility = api
+            .get_definition_visibility(&at, registry_id, definition_id)
+            .map_err(convert_error!())?
+            .ok_or(not_found_error!())?;
+
+        Ok(visibility.into())
+    }
+
+    fn get_public_trace(
+        &self,
+        registry_id: RegistryId,
+        definition_id: DefinitionId,
+        process_id: ProcessId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<
+        PublicTraceResponse<
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            DefinitionStepIndex,
+            BlockNumber,
+            Moment,
+            <Block as BlockT>::Hash,
+        >,
+    > {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        crate::public_trace::public_trace::<
+            C,
+            Block,
+            AccountId,
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            ProposalId,
+            MemberCount,
+            DefinitionStepIndex,
+            AssetRegistryId,
+            AssetId,
+            BlockNumber,
+            Moment,
+            BoundedStringName,
+            BoundedStringFact,
+        >(
+            &*self.client,
+            &at,
+            registry_id,
+            definition_id,
+            process_id,
+            usize::MAX,
+        )
+        .map_err(convert_error!())?
+        .ok_or(not_found_error!())
+    }
 }
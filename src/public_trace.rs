@@ -0,0 +1,476 @@
+//! Public, read-only provenance traces for consumers, e.g. behind a QR code on a product.
+//!
+//! Only processes of definitions made public with `set_definition_visibility` are served.
+//! A trace has the public attributes of each attested step and the name of the group that attested it,
+//! never account ids or DIDs. Each step carries the number and hash of the block and the index of the
+//! extrinsic that attested it, so the trace can be checked against the chain.
+//!
+//! When the node is started with `--public-trace-port` it serves, without authentication:
+//!
+//! * `GET /trace/{registry_id}/{definition_id}/{process_id}` - the trace as JSON
+//! * `GET /trace/{registry_id}/{definition_id}/{process_id}.html` - the trace as a plain HTML page
+//!
+//! At most `MAX_CONCURRENT_TRACES` traces are built at once, further requests get `503 Service Unavailable`.
+//! A trace has at most `MAX_TRACE_STEPS` steps, later steps are left out and the trace is marked as truncated.
+
+use crate::identity_rpc::FactResponse;
+use crate::provenance_rpc::{AttributeResponse, PublicProcessStepResponse, PublicTraceResponse};
+use codec::Codec;
+use futures::channel::oneshot;
+use hyper::{
+    header,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use pallet_primitives::ProcessStatus;
+use provenance_runtime_api::ProvenanceApi as ProvenanceRuntimeApi;
+use runtime::primitives;
+use sc_service::{Error as ServiceError, SpawnTaskHandle};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, NumberFor},
+};
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// Traces built at once. Runtime calls block, so requests beyond this are refused rather than queued.
+const MAX_CONCURRENT_TRACES: usize = 8;
+/// Steps served in a trace by the HTTP server.
+const MAX_TRACE_STEPS: usize = 200;
+
+type PublicTrace = PublicTraceResponse<
+    primitives::RegistryId,
+    primitives::DefinitionId,
+    primitives::ProcessId,
+    primitives::DefinitionStepIndex,
+    primitives::BlockNumber,
+    primitives::Moment,
+    <primitives::Block as BlockT>::Hash,
+>;
+
+/// The public trace of a process at block `at`, or `None` if the process does not exist or its definition is not public.
+/// Only the first `max_steps` steps are included.
+#[allow(clippy::type_complexity)]
+pub fn public_trace<
+    C,
+    Block,
+    AccountId,
+    RegistryId,
+    DefinitionId,
+    ProcessId,
+    ProposalId,
+    MemberCount,
+    DefinitionStepIndex,
+    AssetRegistryId,
+    AssetId,
+    BlockNumber,
+    Moment,
+    BoundedStringName,
+    BoundedStringFact,
+>(
+    client: &C,
+    at: &BlockId<Block>,
+    registry_id: RegistryId,
+    definition_id: DefinitionId,
+    process_id: ProcessId,
+    max_steps: usize,
+) -> Result<
+    Option<
+        PublicTraceResponse<
+            RegistryId,
+            DefinitionId,
+            ProcessId,
+            DefinitionStepIndex,
+            BlockNumber,
+            Moment,
+            <Block as BlockT>::Hash,
+        >,
+    >,
+    String,
+>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ProvenanceRuntimeApi<
+        Block,
+        AccountId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        ProposalId,
+        MemberCount,
+        DefinitionStepIndex,
+        AssetRegistryId,
+        AssetId,
+        BlockNumber,
+        Moment,
+        BoundedStringName,
+        BoundedStringFact,
+    >,
+    AccountId: Codec,
+    RegistryId: Codec + Copy,
+    DefinitionId: Codec + Copy,
+    ProcessId: Codec + Copy,
+    ProposalId: Codec,
+    MemberCount: Codec,
+    DefinitionStepIndex: Codec,
+    AssetRegistryId: Codec,
+    AssetId: Codec,
+    BlockNumber: Codec + Copy + Into<NumberFor<Block>>,
+    Moment: Codec,
+    BoundedStringName: Codec + Into<Vec<u8>>,
+    BoundedStringFact: Codec + Into<Vec<u8>>,
+{
+    let trace = client
+        .runtime_api()
+        .get_public_trace(at, registry_id, definition_id, process_id)
+        .map_err(|e| format!("{:?}", e))?;
+    let (name, status, location, steps) = match trace {
+        Some(trace) => trace,
+        None => return Ok(None),
+    };
+
+    let truncated = steps.len() > max_steps;
+    let steps = steps
+        .into_iter()
+        .take(max_steps)
+        .map(|step| {
+            let block_hash = client
+                .hash(step.evidence.block_number.into())
+                .map_err(|e| format!("{:?}", e))?;
+            Ok(PublicProcessStepResponse {
+                definition_step_index: step.definition_step_index,
+                name: String::from_utf8_lossy(&step.name.into()).to_string(),
+                attestor: step
+                    .attestor_name
+                    .map(|name| String::from_utf8_lossy(&name).to_string()),
+                attributes: step
+                    .attributes
+                    .into_iter()
+                    .map(|attribute| AttributeResponse {
+                        name: String::from_utf8_lossy(&attribute.name.into()).to_string(),
+                        fact: attribute.fact.into(),
+                    })
+                    .collect(),
+                block_number: step.evidence.block_number,
+                block_hash,
+                extrinsic_index: step.evidence.extrinsic_index,
+                timestamp: step.evidence.timestamp,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Some(PublicTraceResponse {
+        registry_id,
+        definition_id,
+        process_id,
+        name: String::from_utf8_lossy(&name.into()).to_string(),
+        status: match status {
+            ProcessStatus::InProgress => "InProgress".to_string(),
+            ProcessStatus::Completed => "Completed".to_string(),
+            ProcessStatus::Rejected => "Rejected".to_string(),
+            ProcessStatus::OnHold => "OnHold".to_string(),
+        },
+        location: location.map(FactResponse::from),
+        steps,
+        truncated,
+    }))
+}
+
+/// Serve public traces from the best block over HTTP on `addr`.
+pub fn spawn_server<C>(
+    client: Arc<C>,
+    addr: SocketAddr,
+    spawn_handle: SpawnTaskHandle,
+) -> Result<(), ServiceError>
+where
+    C: ProvideRuntimeApi<primitives::Block>
+        + HeaderBackend<primitives::Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: ProvenanceRuntimeApi<
+        primitives::Block,
+        primitives::AccountId,
+        primitives::RegistryId,
+        primitives::DefinitionId,
+        primitives::ProcessId,
+        primitives::ProposalId,
+        primitives::MemberCount,
+        primitives::DefinitionStepIndex,
+        primitives::RegistryId,
+        primitives::AssetId,
+        primitives::BlockNumber,
+        primitives::Moment,
+        primitives::BoundedStringName,
+        primitives::BoundedStringFact,
+    >,
+{
+    let builder = Server::try_bind(&addr).map_err(|e| {
+        ServiceError::Other(format!("Unable to serve public traces on {}: {}", addr, e))
+    })?;
+
+    let request_handle = spawn_handle.clone();
+    let limit = TraceLimit::default();
+    let make_service = make_service_fn(move |_| {
+        let client = client.clone();
+        let request_handle = request_handle.clone();
+        let limit = limit.clone();
+        async move {
+            Ok::<_, hyper::Error>(service_fn(move |request| {
+                // Runtime calls block, so they must not run on the server's executor
+                let receiver = limit.try_acquire().map(|permit| {
+                    let client = client.clone();
+                    let (sender, receiver) = oneshot::channel();
+                    request_handle.spawn_blocking("public-trace-request", async move {
+                        let _permit = permit;
+                        let _ = sender.send(respond(&*client, request));
+                    });
+                    receiver
+                });
+                async move {
+                    Ok::<_, hyper::Error>(match receiver {
+                        Some(receiver) => receiver
+                            .await
+                            .unwrap_or_else(|_| status_response(StatusCode::INTERNAL_SERVER_ERROR)),
+                        None => busy_response(),
+                    })
+                }
+            }))
+        }
+    });
+    let server = builder.serve(make_service);
+
+    log::info!("Serving public provenance traces on http://{}/trace", addr);
+    spawn_handle.spawn("public-trace", async move {
+        if let Err(e) = server.await {
+            log::error!("Public trace server failed: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn respond<C>(client: &C, request: Request<Body>) -> Response<Body>
+where
+    C: ProvideRuntimeApi<primitives::Block> + HeaderBackend<primitives::Block>,
+    C::Api: ProvenanceRuntimeApi<
+        primitives::Block,
+        primitives::AccountId,
+        primitives::RegistryId,
+        primitives::DefinitionId,
+        primitives::ProcessId,
+        primitives::ProposalId,
+        primitives::MemberCount,
+        primitives::DefinitionStepIndex,
+        primitives::RegistryId,
+        primitives::AssetId,
+        primitives::BlockNumber,
+        primitives::Moment,
+        primitives::BoundedStringName,
+        primitives::BoundedStringFact,
+    >,
+{
+    if request.method() != Method::GET {
+        return status_response(StatusCode::METHOD_NOT_ALLOWED);
+    }
+    let path = request.uri().path();
+    let (path, html) = match path.strip_suffix(".html") {
+        Some(path) => (path, true),
+        None => (path, false),
+    };
+    let (registry_id, definition_id, process_id) = match parse_ids(path) {
+        Some(ids) => ids,
+        None => return status_response(StatusCode::NOT_FOUND),
+    };
+
+    let at = BlockId::hash(client.info().best_hash);
+    let trace = public_trace::<
+        C,
+        primitives::Block,
+        primitives::AccountId,
+        primitives::RegistryId,
+        primitives::DefinitionId,
+        primitives::ProcessId,
+        primitives::ProposalId,
+        primitives::MemberCount,
+        primitives::DefinitionStepIndex,
+        primitives::RegistryId,
+        primitives::AssetId,
+        primitives::BlockNumber,
+        primitives::Moment,
+        primitives::BoundedStringName,
+        primitives::BoundedStringFact,
+    >(
+        client,
+        &at,
+        registry_id,
+        definition_id,
+        process_id,
+        MAX_TRACE_STEPS,
+    );
+
+    match trace {
+        Ok(Some(trace)) if html => Response::builder()
+            .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(render_html(&trace)))
+            .unwrap_or_else(|_| status_response(StatusCode::INTERNAL_SERVER_ERROR)),
+        Ok(Some(trace)) => match serde_json::to_vec(&trace) {
+            Ok(body) => Response::builder()
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")
+                .body(Body::from(body))
+                .unwrap_or_else(|_| status_response(StatusCode::INTERNAL_SERVER_ERROR)),
+            Err(_) => status_response(StatusCode::INTERNAL_SERVER_ERROR),
+        },
+        Ok(None) => status_response(StatusCode::NOT_FOUND),
+        Err(e) => {
+            log::warn!("Unable to build public trace: {}", e);
+            status_response(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// `/trace/{registry_id}/{definition_id}/{process_id}`
+fn parse_ids(
+    path: &str,
+) -> Option<(
+    primitives::RegistryId,
+    primitives::DefinitionId,
+    primitives::ProcessId,
+)> {
+    let mut ids = path.strip_prefix("/trace/")?.split('/');
+    let registry_id = ids.next()?.parse().ok()?;
+    let definition_id = ids.next()?.parse().ok()?;
+    let process_id = ids.next()?.parse().ok()?;
+    if ids.next().is_some() {
+        return None;
+    }
+    Some((registry_id, definition_id, process_id))
+}
+
+fn status_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::from(status.canonical_reason().unwrap_or_default()));
+    *response.status_mut() = status;
+    response
+}
+
+fn busy_response() -> Response<Body> {
+    let mut response = status_response(StatusCode::SERVICE_UNAVAILABLE);
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, header::HeaderValue::from_static("1"));
+    response
+}
+
+/// Counts the traces being built.
+#[derive(Clone, Default)]
+struct TraceLimit(Arc<AtomicUsize>);
+
+impl TraceLimit {
+    /// A permit to build a trace, or `None` if `MAX_CONCURRENT_TRACES` are already being built.
+    fn try_acquire(&self) -> Option<TracePermit> {
+        self.0
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                if count < MAX_CONCURRENT_TRACES {
+                    Some(count + 1)
+                } else {
+                    None
+                }
+            })
+            .ok()
+            .map(|_| TracePermit(self.0.clone()))
+    }
+}
+
+/// Held while a trace is built, released when dropped.
+struct TracePermit(Arc<AtomicUsize>);
+
+impl Drop for TracePermit {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn render_html(trace: &PublicTrace) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{name}</title>\n</head>\n<body>\n<h1>{name}</h1>\n<p>Status: {status}</p>\n",
+        name = escape(&trace.name),
+        status = escape(&trace.status),
+    );
+    if let Some(location) = &trace.location {
+        html.push_str(&format!("<p>Location: {}</p>\n", escape(&location.value)));
+    }
+    for step in &trace.steps {
+        html.push_str(&format!("<h2>{}</h2>\n", escape(&step.name)));
+        html.push_str(&format!(
+            "<p>{} by {}</p>\n",
+            crate::epcis::event_time(step.timestamp),
+            escape(step.attestor.as_deref().unwrap_or("an individual attestor"))
+        ));
+        if !step.attributes.is_empty() {
+            html.push_str("<dl>\n");
+            for attribute in &step.attributes {
+                html.push_str(&format!(
+                    "<dt>{}</dt><dd>{}</dd>\n",
+                    escape(&attribute.name),
+                    escape(&attribute.fact.value)
+                ));
+            }
+            html.push_str("</dl>\n");
+        }
+        html.push_str(&format!(
+            "<p><small>Block {} ({}), extrinsic {}</small></p>\n",
+            step.block_number,
+            step.block_hash
+                .map(|hash| format!("{:?}", hash))
+                .unwrap_or_default(),
+            step.extrinsic_index
+        ));
+    }
+    if trace.truncated {
+        html.push_str("<p>Later steps are not shown.</p>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_limit_should_work() {
+        let limit = TraceLimit::default();
+        let permits: Vec<TracePermit> = (0..MAX_CONCURRENT_TRACES)
+            .map(|_| limit.try_acquire().unwrap())
+            .collect();
+        assert!(limit.try_acquire().is_none());
+
+        drop(permits);
+        assert!(limit.try_acquire().is_some());
+    }
+
+    #[test]
+    fn busy_response_should_work() {
+        let response = busy_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+    }
+}
@@ -4,10 +4,10 @@ use std::sync::Arc;
 
 use futures::channel::mpsc::Sender;
 use runtime::primitives::{
-    AccountId, AssetId, AuditId, Balance, Block, BoundedStringFact, BoundedStringName, CatalogId,
-    ClaimId, ControlPointId, DefinitionId, DefinitionStepIndex, EvidenceId, ExtrinsicIndex,
-    GroupId, Hash, Index, LeaseId, MemberCount, ModuleIndex, Moment, ObservationId, ProcessId,
-    ProposalId, RegistryId,
+    AccountId, AssetId, AuditId, AuditScheduleId, Balance, Block, BlockNumber, BoundedStringFact,
+    BoundedStringName, CatalogId, ClaimId, ControlPointId, DefinitionId, DefinitionStepIndex,
+    EvidenceId, ExtrinsicIndex, FrameworkId, GroupId, Hash, Index, LeaseId, MemberCount,
+    ModuleIndex, Moment, ObservationId, ProcessId, ProposalId, RegistryId,
 };
 use runtime::BoundedStringUrl;
 use sc_consensus_manual_seal::{
@@ -28,6 +28,10 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// Content of evidence held by this node.
+    pub evidence_store: crate::evidence_store::EvidenceStore,
+    /// Keys of confidential notes held by this node.
+    pub note_keystore: crate::notes_rpc::NoteKeystore,
     /// A command stream to send authoring commands to manual seal consensus engine
     pub command_sink: Sender<EngineCommand<Hash>>,
 }
@@ -50,6 +54,7 @@ where
         Hash,
         BoundedStringName,
         Balance,
+        BlockNumber,
     >,
     C::Api: provenance_runtime_api::ProvenanceApi<
         Block,
@@ -60,6 +65,10 @@ where
         ProposalId,
         MemberCount,
         DefinitionStepIndex,
+        RegistryId,
+        AssetId,
+        BlockNumber,
+        Moment,
         BoundedStringName,
         BoundedStringFact,
     >,
@@ -81,6 +90,17 @@ where
         ControlPointId,
         EvidenceId,
         ObservationId,
+        FrameworkId,
+        RegistryId,
+        AssetId,
+        RegistryId,
+        DefinitionId,
+        ProcessId,
+        BlockNumber,
+        ClaimId,
+        Moment,
+        Balance,
+        AuditScheduleId,
         BoundedStringName,
         BoundedStringUrl,
     >,
@@ -103,6 +123,9 @@ where
     let FullDeps {
         command_sink,
         client,
+        deny_unsafe,
+        evidence_store,
+        note_keystore,
         ..
     } = deps;
 
@@ -112,7 +135,7 @@ where
     ));
     // Add the provenance api
     io.extend_with(crate::provenance_rpc::ProvenanceApi::to_delegate(
-        crate::provenance_rpc::Provenance::new(client.clone()),
+        crate::provenance_rpc::Provenance::new(client.clone(), deny_unsafe),
     ));
     // Add the identity api
     io.extend_with(crate::identity_rpc::IdentityApi::to_delegate(
@@ -122,6 +145,14 @@ where
     io.extend_with(crate::audits_rpc::AuditsApi::to_delegate(
         crate::audits_rpc::Audits::new(client.clone()),
     ));
+    // Add the evidence api
+    io.extend_with(crate::evidence_rpc::EvidenceApi::to_delegate(
+        crate::evidence_rpc::Evidence::new(client.clone(), evidence_store, deny_unsafe),
+    ));
+    // Add the notes api
+    io.extend_with(crate::notes_rpc::NotesApi::to_delegate(
+        crate::notes_rpc::Notes::new(client.clone(), note_keystore, deny_unsafe),
+    ));
     // Add the asset_registry api
     io.extend_with(crate::asset_registry_rpc::AssetRegistryApi::to_delegate(
         crate::asset_registry_rpc::AssetRegistry::new(client.clone()),
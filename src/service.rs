@@ -9,7 +9,7 @@ use sc_service::{error::Error as ServiceError, Configuration, PartialComponents,
 use sp_api::TransactionFor;
 use sp_consensus::import_queue::BasicQueue;
 use sp_inherents::InherentDataProviders;
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 // Our native executor instance.
 native_executor_instance!(
@@ -78,8 +78,13 @@ pub fn new_partial(
     })
 }
 
-/// Builds a new service for a full client.
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
+/// Builds a new service for a full client, serving public provenance traces on `public_trace` if given.
+/// The content of evidence is served to reserved peers if `serve_evidence` is set.
+pub fn new_full(
+    mut config: Configuration,
+    public_trace: Option<SocketAddr>,
+    serve_evidence: bool,
+) -> Result<TaskManager, ServiceError> {
     let sc_service::PartialComponents {
         client,
         backend,
@@ -92,6 +97,24 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
         ..
     } = new_partial(&config)?;
 
+    let (evidence_store, evidence_requests) = crate::evidence_store::EvidenceStore::open(&config)?;
+    let note_keystore = crate::notes_rpc::NoteKeystore::open(&config);
+    let protocol_id = config.protocol_id();
+    let evidence_peers = if serve_evidence {
+        Some(crate::evidence_store::reserved_peers(&config))
+    } else {
+        None
+    };
+    config
+        .network
+        .request_response_protocols
+        .push(crate::evidence_store::request_response_config(
+            &protocol_id,
+            evidence_store.clone(),
+            evidence_peers,
+            task_manager.spawn_handle(),
+        ));
+
     let (network, network_status_sinks, system_rpc_tx, network_starter) =
         sc_service::build_network(sc_service::BuildNetworkParams {
             config: &config,
@@ -103,6 +126,14 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
             block_announce_validator_builder: None,
         })?;
 
+    crate::evidence_store::spawn_fetcher(
+        &protocol_id,
+        evidence_store.clone(),
+        evidence_requests,
+        network.clone(),
+        task_manager.spawn_handle(),
+    );
+
     if config.offchain_worker.enabled {
         sc_service::build_offchain_workers(
             &config,
@@ -127,6 +158,8 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                evidence_store: evidence_store.clone(),
+                note_keystore: note_keystore.clone(),
                 command_sink: command_sink.clone(),
             };
 
@@ -149,6 +182,10 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
         config,
     })?;
 
+    if let Some(addr) = public_trace {
+        crate::public_trace::spawn_server(client.clone(), addr, task_manager.spawn_handle())?;
+    }
+
     if is_authority {
         let proposer = sc_basic_authorship::ProposerFactory::new(
             task_manager.spawn_handle(),
@@ -9,8 +9,8 @@ use sc_keystore::LocalKeystore;
 use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
 use sp_inherents::InherentDataProviders;
-use std::sync::Arc;
 use std::time::Duration;
+use std::{net::SocketAddr, sync::Arc};
 
 // Our native executor instance.
 native_executor_instance!(
@@ -113,8 +113,13 @@ fn remote_keystore(_url: &String) -> Result<Arc<LocalKeystore>, &'static str> {
     Err("Remote Keystore not supported.")
 }
 
-/// Builds a new service for a full client.
-pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError> {
+/// Builds a new service for a full client, serving public provenance traces on `public_trace` if given.
+/// The content of evidence is served to reserved peers if `serve_evidence` is set.
+pub fn new_full(
+    mut config: Configuration,
+    public_trace: Option<SocketAddr>,
+    serve_evidence: bool,
+) -> Result<TaskManager, ServiceError> {
     let sc_service::PartialComponents {
         client,
         backend,
@@ -144,6 +149,24 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
         .extra_sets
         .push(sc_finality_grandpa::grandpa_peers_set_config());
 
+    let (evidence_store, evidence_requests) = crate::evidence_store::EvidenceStore::open(&config)?;
+    let note_keystore = crate::notes_rpc::NoteKeystore::open(&config);
+    let protocol_id = config.protocol_id();
+    let evidence_peers = if serve_evidence {
+        Some(crate::evidence_store::reserved_peers(&config))
+    } else {
+        None
+    };
+    config
+        .network
+        .request_response_protocols
+        .push(crate::evidence_store::request_response_config(
+            &protocol_id,
+            evidence_store.clone(),
+            evidence_peers,
+            task_manager.spawn_handle(),
+        ));
+
     let (network, network_status_sinks, system_rpc_tx, network_starter) =
         sc_service::build_network(sc_service::BuildNetworkParams {
             config: &config,
@@ -155,6 +178,14 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
             block_announce_validator_builder: None,
         })?;
 
+    crate::evidence_store::spawn_fetcher(
+        &protocol_id,
+        evidence_store.clone(),
+        evidence_requests,
+        network.clone(),
+        task_manager.spawn_handle(),
+    );
+
     if config.offchain_worker.enabled {
         sc_service::build_offchain_workers(
             &config,
@@ -181,6 +212,8 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                evidence_store: evidence_store.clone(),
+                note_keystore: note_keystore.clone(),
             };
 
             crate::rpc::create_full(deps)
@@ -203,6 +236,10 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
             config,
         })?;
 
+    if let Some(addr) = public_trace {
+        crate::public_trace::spawn_server(client.clone(), addr, task_manager.spawn_handle())?;
+    }
+
     if role.is_authority() {
         let proposer = sc_basic_authorship::ProposerFactory::new(
             task_manager.spawn_handle(),
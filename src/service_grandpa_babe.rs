@@ -31,7 +31,7 @@ use sc_service::{config::Configuration, error::Error as ServiceError, RpcHandler
 use sc_telemetry::TelemetryConnectionNotifier;
 use sp_inherents::InherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 type FullClient = sc_service::TFullClient<Block, RuntimeApi, Executor>;
 type FullBackend = sc_service::TFullBackend<Block>;
@@ -65,6 +65,10 @@ pub fn new_partial(
                 sc_consensus_babe::BabeLink<Block>,
             ),
             sc_finality_grandpa::SharedVoterState,
+            (
+                crate::evidence_store::EvidenceStore,
+                crate::evidence_store::EvidenceRequests,
+            ),
         ),
     >,
     ServiceError,
@@ -112,6 +116,8 @@ pub fn new_partial(
 
     let import_setup = (block_import, grandpa_link, babe_link);
 
+    let (evidence_store, evidence_requests) = crate::evidence_store::EvidenceStore::open(config)?;
+
     let (rpc_extensions_builder, rpc_setup) = {
         let (_, grandpa_link, babe_link) = &import_setup;
 
@@ -133,6 +139,8 @@ pub fn new_partial(
         let select_chain = select_chain.clone();
         let keystore = keystore_container.sync_keystore();
         let chain_spec = config.chain_spec.cloned_box();
+        let evidence_store = evidence_store.clone();
+        let note_keystore = crate::notes_rpc::NoteKeystore::open(config);
 
         let rpc_extensions_builder = move |deny_unsafe, subscription_executor| {
             let deps = rpc::FullDeps {
@@ -141,6 +149,8 @@ pub fn new_partial(
                 select_chain: select_chain.clone(),
                 chain_spec: chain_spec.cloned_box(),
                 deny_unsafe,
+                evidence_store: evidence_store.clone(),
+                note_keystore: note_keystore.clone(),
                 babe: rpc::BabeDeps {
                     babe_config: babe_config.clone(),
                     shared_epoch_changes: shared_epoch_changes.clone(),
@@ -170,7 +180,12 @@ pub fn new_partial(
         import_queue,
         transaction_pool,
         inherent_data_providers,
-        other: (rpc_extensions_builder, import_setup, rpc_setup),
+        other: (
+            rpc_extensions_builder,
+            import_setup,
+            rpc_setup,
+            (evidence_store, evidence_requests),
+        ),
     })
 }
 
@@ -187,6 +202,7 @@ pub struct NewFullBase {
 
 pub fn new_full_base(
     mut config: Configuration,
+    serve_evidence: bool,
     with_startup_data: impl FnOnce(
         &sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
         &sc_consensus_babe::BabeLink<Block>,
@@ -201,7 +217,8 @@ pub fn new_full_base(
         select_chain,
         transaction_pool,
         inherent_data_providers,
-        other: (rpc_extensions_builder, import_setup, rpc_setup),
+        other:
+            (rpc_extensions_builder, import_setup, rpc_setup, (evidence_store, evidence_requests)),
     } = new_partial(&config)?;
 
     let shared_voter_state = rpc_setup;
@@ -220,6 +237,22 @@ pub fn new_full_base(
         ),
     );
 
+    let protocol_id = config.protocol_id();
+    let evidence_peers = if serve_evidence {
+        Some(crate::evidence_store::reserved_peers(&config))
+    } else {
+        None
+    };
+    config
+        .network
+        .request_response_protocols
+        .push(crate::evidence_store::request_response_config(
+            &protocol_id,
+            evidence_store.clone(),
+            evidence_peers,
+            task_manager.spawn_handle(),
+        ));
+
     let (network, network_status_sinks, system_rpc_tx, network_starter) =
         sc_service::build_network(sc_service::BuildNetworkParams {
             config: &config,
@@ -231,6 +264,14 @@ pub fn new_full_base(
             block_announce_validator_builder: None,
         })?;
 
+    crate::evidence_store::spawn_fetcher(
+        &protocol_id,
+        evidence_store,
+        evidence_requests,
+        network.clone(),
+        task_manager.spawn_handle(),
+    );
+
     if config.offchain_worker.enabled {
         sc_service::build_offchain_workers(
             &config,
@@ -380,10 +421,24 @@ pub fn new_full_base(
     })
 }
 
-/// Builds a new service for a full client.
+/// Builds a new service for a full client, serving public provenance traces on `public_trace` if given.
+/// The content of evidence is served to reserved peers if `serve_evidence` is set.
 
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
-    new_full_base(config, |_, _| ()).map(|NewFullBase { task_manager, .. }| task_manager)
+pub fn new_full(
+    config: Configuration,
+    public_trace: Option<SocketAddr>,
+    serve_evidence: bool,
+) -> Result<TaskManager, ServiceError> {
+    let NewFullBase {
+        task_manager,
+        client,
+        ..
+    } = new_full_base(config, serve_evidence, |_, _| ())?;
+
+    if let Some(addr) = public_trace {
+        crate::public_trace::spawn_server(client, addr, task_manager.spawn_handle())?;
+    }
+    Ok(task_manager)
 }
 
 pub fn new_light_base(
@@ -39,6 +39,13 @@ pub trait SettingsApi<BlockHash, ModuleIndex, ExtrinsicIndex, Balance> {
         &self,
         at: Option<BlockHash>,
     ) -> Result<ExtrinsicExtrasResponse<ModuleIndex, ExtrinsicIndex>>;
+    #[rpc(name = "get_extrinsic_deposit")]
+    fn get_extrinsic_deposit(
+        &self,
+        module_index: ModuleIndex,
+        extrinsic_index: ExtrinsicIndex,
+        at: Option<BlockHash>,
+    ) -> Result<ExtrinsicDepositResponse>;
 }
 
 #[derive(Serialize, Deserialize)]
@@ -70,6 +77,12 @@ pub struct ExtrinsicExtrasResponse<ModuleIndex, ExtrinsicIndex> {
     //TODO: change once bug is fixed
     pub fees: Vec<(ModuleIndex, Vec<(ExtrinsicIndex, u64)>)>,
 }
+#[derive(Serialize, Deserialize)]
+pub struct ExtrinsicDepositResponse {
+    //u64 instead of Balance due to bug in serde https://github.com/paritytech/substrate/issues/4641
+    //TODO: change once bug is fixed
+    pub deposit: Option<u64>,
+}
 
 pub struct Settings<C, M> {
     client: Arc<C>,
@@ -197,4 +210,22 @@ where
                 .collect(),
         })
     }
+
+    fn get_extrinsic_deposit(
+        &self,
+        module_index: ModuleIndex,
+        extrinsic_index: ExtrinsicIndex,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<ExtrinsicDepositResponse> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let deposit = api
+            .get_extrinsic_deposit(&at, module_index, extrinsic_index)
+            .map_err(convert_error!())?;
+
+        Ok(ExtrinsicDepositResponse {
+            deposit: deposit.map(|d| d.unique_saturated_into()),
+        })
+    }
 }
@@ -0,0 +1,9 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Types that implement the AssetInfo trait are able to check if an asset is registered
+pub trait AssetInfo {
+    type RegistryId;
+    type AssetId;
+
+    fn asset_exists(registry_id: Self::RegistryId, asset_id: Self::AssetId) -> bool;
+}
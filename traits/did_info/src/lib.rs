@@ -0,0 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Types that implement the DidInfo trait are able to check if a DID has been created
+pub trait DidInfo {
+    type Did;
+
+    fn did_exists(did: &Self::Did) -> bool;
+}
@@ -5,10 +5,17 @@ pub trait GetExtrinsicExtra {
     type ModuleIndex;
     type ExtrinsicIndex;
     type AccountId;
+    type Balance;
 
     fn charge_extrinsic_extra(
         module_index: &Self::ModuleIndex,
         extrinsic_index: &Self::ExtrinsicIndex,
         account: &Self::AccountId,
     );
+
+    /// A refundable deposit to be reserved by extrinsics that create objects which may later be cleaned up
+    fn get_extrinsic_deposit(
+        module_index: &Self::ModuleIndex,
+        extrinsic_index: &Self::ExtrinsicIndex,
+    ) -> Option<Self::Balance>;
 }
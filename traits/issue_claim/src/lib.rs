@@ -0,0 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::dispatch::{DispatchError, DispatchResult, Vec};
+
+/// Types that implement the IssueClaim trait are able to make and attest claims against a DID on behalf of another pallet
+pub trait IssueClaim {
+    type AccountId;
+    type Did;
+    type ClaimId;
+    type Moment;
+    type Statement;
+
+    /// Make a claim against `did` and attest it as `issuer` in one step. `issuer` must be authorized as a claim issuer for `did` and pays as for making a claim.
+    fn issue_claim(
+        issuer: &Self::AccountId,
+        did: &Self::Did,
+        description: Vec<u8>,
+        statements: Vec<Self::Statement>,
+        valid_until: Self::Moment,
+    ) -> Result<Self::ClaimId, DispatchError>;
+
+    /// Remove the attestation of a claim made with `issue_claim`
+    fn revoke_claim(
+        issuer: &Self::AccountId,
+        did: &Self::Did,
+        claim_id: Self::ClaimId,
+    ) -> DispatchResult;
+}
@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Types that implement the ProcessInfo trait are able to check if a provenance process has been created
+pub trait ProcessInfo {
+    type RegistryId;
+    type DefinitionId;
+    type ProcessId;
+
+    fn process_exists(
+        registry_id: Self::RegistryId,
+        definition_id: Self::DefinitionId,
+        process_id: Self::ProcessId,
+    ) -> bool;
+}